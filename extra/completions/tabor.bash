@@ -52,15 +52,30 @@ _tabor() {
             tabor__help__msg,get-capabilities)
                 cmd="tabor__help__msg__get__capabilities"
                 ;;
+            tabor__help__msg,get-closed-tabs)
+                cmd="tabor__help__msg__get__closed__tabs"
+                ;;
             tabor__help__msg,get-config)
                 cmd="tabor__help__msg__get__config"
                 ;;
+            tabor__help__msg,get-page-text)
+                cmd="tabor__help__msg__get__page__text"
+                ;;
+            tabor__help__msg,get-scroll)
+                cmd="tabor__help__msg__get__scroll"
+                ;;
             tabor__help__msg,get-tab-panel)
                 cmd="tabor__help__msg__get__tab__panel"
                 ;;
             tabor__help__msg,get-tab-state)
                 cmd="tabor__help__msg__get__tab__state"
                 ;;
+            tabor__help__msg,get-typing-status)
+                cmd="tabor__help__msg__get__typing__status"
+                ;;
+            tabor__help__msg,inject-css)
+                cmd="tabor__help__msg__inject__css"
+                ;;
             tabor__help__msg,inspector)
                 cmd="tabor__help__msg__inspector"
                 ;;
@@ -73,6 +88,9 @@ _tabor() {
             tabor__help__msg,move-tab)
                 cmd="tabor__help__msg__move__tab"
                 ;;
+            tabor__help__msg,move-tab-to-new-window)
+                cmd="tabor__help__msg__move__tab__to__new__window"
+                ;;
             tabor__help__msg,open-inspector)
                 cmd="tabor__help__msg__open__inspector"
                 ;;
@@ -85,12 +103,21 @@ _tabor() {
             tabor__help__msg,reload-web)
                 cmd="tabor__help__msg__reload__web"
                 ;;
+            tabor__help__msg,remove-css)
+                cmd="tabor__help__msg__remove__css"
+                ;;
             tabor__help__msg,restore-closed-tab)
                 cmd="tabor__help__msg__restore__closed__tab"
                 ;;
+            tabor__help__msg,restore-session)
+                cmd="tabor__help__msg__restore__session"
+                ;;
             tabor__help__msg,run-command-bar)
                 cmd="tabor__help__msg__run__command__bar"
                 ;;
+            tabor__help__msg,save-session)
+                cmd="tabor__help__msg__save__session"
+                ;;
             tabor__help__msg,select-tab)
                 cmd="tabor__help__msg__select__tab"
                 ;;
@@ -100,9 +127,18 @@ _tabor() {
             tabor__help__msg,send-input)
                 cmd="tabor__help__msg__send__input"
                 ;;
+            tabor__help__msg,send-input-typed)
+                cmd="tabor__help__msg__send__input__typed"
+                ;;
+            tabor__help__msg,send-key)
+                cmd="tabor__help__msg__send__key"
+                ;;
             tabor__help__msg,set-group-name)
                 cmd="tabor__help__msg__set__group__name"
                 ;;
+            tabor__help__msg,set-scroll)
+                cmd="tabor__help__msg__set__scroll"
+                ;;
             tabor__help__msg,set-tab-panel)
                 cmd="tabor__help__msg__set__tab__panel"
                 ;;
@@ -112,6 +148,12 @@ _tabor() {
             tabor__help__msg,set-web-url)
                 cmd="tabor__help__msg__set__web__url"
                 ;;
+            tabor__help__msg,set-window-title)
+                cmd="tabor__help__msg__set__window__title"
+                ;;
+            tabor__help__msg,subscribe)
+                cmd="tabor__help__msg__subscribe"
+                ;;
             tabor__help__msg__inspector,attach)
                 cmd="tabor__help__msg__inspector__attach"
                 ;;
@@ -145,18 +187,33 @@ _tabor() {
             tabor__msg,get-capabilities)
                 cmd="tabor__msg__get__capabilities"
                 ;;
+            tabor__msg,get-closed-tabs)
+                cmd="tabor__msg__get__closed__tabs"
+                ;;
             tabor__msg,get-config)
                 cmd="tabor__msg__get__config"
                 ;;
+            tabor__msg,get-page-text)
+                cmd="tabor__msg__get__page__text"
+                ;;
+            tabor__msg,get-scroll)
+                cmd="tabor__msg__get__scroll"
+                ;;
             tabor__msg,get-tab-panel)
                 cmd="tabor__msg__get__tab__panel"
                 ;;
             tabor__msg,get-tab-state)
                 cmd="tabor__msg__get__tab__state"
                 ;;
+            tabor__msg,get-typing-status)
+                cmd="tabor__msg__get__typing__status"
+                ;;
             tabor__msg,help)
                 cmd="tabor__msg__help"
                 ;;
+            tabor__msg,inject-css)
+                cmd="tabor__msg__inject__css"
+                ;;
             tabor__msg,inspector)
                 cmd="tabor__msg__inspector"
                 ;;
@@ -169,6 +226,9 @@ _tabor() {
             tabor__msg,move-tab)
                 cmd="tabor__msg__move__tab"
                 ;;
+            tabor__msg,move-tab-to-new-window)
+                cmd="tabor__msg__move__tab__to__new__window"
+                ;;
             tabor__msg,open-inspector)
                 cmd="tabor__msg__open__inspector"
                 ;;
@@ -181,12 +241,21 @@ _tabor() {
             tabor__msg,reload-web)
                 cmd="tabor__msg__reload__web"
                 ;;
+            tabor__msg,remove-css)
+                cmd="tabor__msg__remove__css"
+                ;;
             tabor__msg,restore-closed-tab)
                 cmd="tabor__msg__restore__closed__tab"
                 ;;
+            tabor__msg,restore-session)
+                cmd="tabor__msg__restore__session"
+                ;;
             tabor__msg,run-command-bar)
                 cmd="tabor__msg__run__command__bar"
                 ;;
+            tabor__msg,save-session)
+                cmd="tabor__msg__save__session"
+                ;;
             tabor__msg,select-tab)
                 cmd="tabor__msg__select__tab"
                 ;;
@@ -196,9 +265,18 @@ _tabor() {
             tabor__msg,send-input)
                 cmd="tabor__msg__send__input"
                 ;;
+            tabor__msg,send-input-typed)
+                cmd="tabor__msg__send__input__typed"
+                ;;
+            tabor__msg,send-key)
+                cmd="tabor__msg__send__key"
+                ;;
             tabor__msg,set-group-name)
                 cmd="tabor__msg__set__group__name"
                 ;;
+            tabor__msg,set-scroll)
+                cmd="tabor__msg__set__scroll"
+                ;;
             tabor__msg,set-tab-panel)
                 cmd="tabor__msg__set__tab__panel"
                 ;;
@@ -208,6 +286,12 @@ _tabor() {
             tabor__msg,set-web-url)
                 cmd="tabor__msg__set__web__url"
                 ;;
+            tabor__msg,set-window-title)
+                cmd="tabor__msg__set__window__title"
+                ;;
+            tabor__msg,subscribe)
+                cmd="tabor__msg__subscribe"
+                ;;
             tabor__msg__help,close-tab)
                 cmd="tabor__msg__help__close__tab"
                 ;;
@@ -226,18 +310,33 @@ _tabor() {
             tabor__msg__help,get-capabilities)
                 cmd="tabor__msg__help__get__capabilities"
                 ;;
+            tabor__msg__help,get-closed-tabs)
+                cmd="tabor__msg__help__get__closed__tabs"
+                ;;
             tabor__msg__help,get-config)
                 cmd="tabor__msg__help__get__config"
                 ;;
+            tabor__msg__help,get-page-text)
+                cmd="tabor__msg__help__get__page__text"
+                ;;
+            tabor__msg__help,get-scroll)
+                cmd="tabor__msg__help__get__scroll"
+                ;;
             tabor__msg__help,get-tab-panel)
                 cmd="tabor__msg__help__get__tab__panel"
                 ;;
             tabor__msg__help,get-tab-state)
                 cmd="tabor__msg__help__get__tab__state"
                 ;;
+            tabor__msg__help,get-typing-status)
+                cmd="tabor__msg__help__get__typing__status"
+                ;;
             tabor__msg__help,help)
                 cmd="tabor__msg__help__help"
                 ;;
+            tabor__msg__help,inject-css)
+                cmd="tabor__msg__help__inject__css"
+                ;;
             tabor__msg__help,inspector)
                 cmd="tabor__msg__help__inspector"
                 ;;
@@ -250,6 +349,9 @@ _tabor() {
             tabor__msg__help,move-tab)
                 cmd="tabor__msg__help__move__tab"
                 ;;
+            tabor__msg__help,move-tab-to-new-window)
+                cmd="tabor__msg__help__move__tab__to__new__window"
+                ;;
             tabor__msg__help,open-inspector)
                 cmd="tabor__msg__help__open__inspector"
                 ;;
@@ -262,12 +364,21 @@ _tabor() {
             tabor__msg__help,reload-web)
                 cmd="tabor__msg__help__reload__web"
                 ;;
+            tabor__msg__help,remove-css)
+                cmd="tabor__msg__help__remove__css"
+                ;;
             tabor__msg__help,restore-closed-tab)
                 cmd="tabor__msg__help__restore__closed__tab"
                 ;;
+            tabor__msg__help,restore-session)
+                cmd="tabor__msg__help__restore__session"
+                ;;
             tabor__msg__help,run-command-bar)
                 cmd="tabor__msg__help__run__command__bar"
                 ;;
+            tabor__msg__help,save-session)
+                cmd="tabor__msg__help__save__session"
+                ;;
             tabor__msg__help,select-tab)
                 cmd="tabor__msg__help__select__tab"
                 ;;
@@ -277,9 +388,18 @@ _tabor() {
             tabor__msg__help,send-input)
                 cmd="tabor__msg__help__send__input"
                 ;;
+            tabor__msg__help,send-input-typed)
+                cmd="tabor__msg__help__send__input__typed"
+                ;;
+            tabor__msg__help,send-key)
+                cmd="tabor__msg__help__send__key"
+                ;;
             tabor__msg__help,set-group-name)
                 cmd="tabor__msg__help__set__group__name"
                 ;;
+            tabor__msg__help,set-scroll)
+                cmd="tabor__msg__help__set__scroll"
+                ;;
             tabor__msg__help,set-tab-panel)
                 cmd="tabor__msg__help__set__tab__panel"
                 ;;
@@ -289,6 +409,12 @@ _tabor() {
             tabor__msg__help,set-web-url)
                 cmd="tabor__msg__help__set__web__url"
                 ;;
+            tabor__msg__help,set-window-title)
+                cmd="tabor__msg__help__set__window__title"
+                ;;
+            tabor__msg__help,subscribe)
+                cmd="tabor__msg__help__subscribe"
+                ;;
             tabor__msg__help__inspector,attach)
                 cmd="tabor__msg__help__inspector__attach"
                 ;;
@@ -347,7 +473,7 @@ _tabor() {
 
     case "${cmd}" in
         tabor)
-            opts="-q -v -e -T -o -h -V --print-events --ref-test --embed --config-file --socket --daemon --working-directory --hold --command --title --class --option --help --version msg migrate help"
+            opts="-q -v -e -T -o -h -V --print-events --ref-test --embed --config-file --socket --daemon --new-tab --working-directory --hold --command --title --class --option --help --version msg migrate help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -480,7 +606,7 @@ _tabor() {
             return 0
             ;;
         tabor__help__msg)
-            opts="config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests"
+            opts="config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -577,6 +703,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__help__msg__get__closed__tabs)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__help__msg__get__config)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -591,6 +731,34 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__help__msg__get__page__text)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tabor__help__msg__get__scroll)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__help__msg__get__tab__panel)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -619,6 +787,34 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__help__msg__get__typing__status)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tabor__help__msg__inject__css)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__help__msg__inspector)
             opts="list-targets attach detach send poll"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -745,6 +941,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__help__msg__move__tab__to__new__window)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__help__msg__open__inspector)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -801,6 +1011,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__help__msg__remove__css)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__help__msg__restore__closed__tab)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -815,6 +1039,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__help__msg__restore__session)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__help__msg__run__command__bar)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -829,6 +1067,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__help__msg__save__session)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__help__msg__select__tab)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -871,6 +1123,34 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__help__msg__send__input__typed)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tabor__help__msg__send__key)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__help__msg__set__group__name)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -885,6 +1165,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__help__msg__set__scroll)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__help__msg__set__tab__panel)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -927,6 +1221,34 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__help__msg__set__window__title)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tabor__help__msg__subscribe)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__migrate)
             opts="-c -d -i -s -h --config-file --dry-run --skip-imports --skip-renames --silent --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
@@ -972,7 +1294,7 @@ _tabor() {
             return 0
             ;;
         tabor__msg)
-            opts="-s -h --socket --help config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help"
+            opts="-s -h --socket --help config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1194,6 +1516,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__get__closed__tabs)
+            opts="-h --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__get__config)
             opts="-w -h --window-id --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -1216,13 +1552,17 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        tabor__msg__get__tab__panel)
-            opts="-h --help"
+        tabor__msg__get__page__text)
+            opts="-h --tab-id --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --tab-id)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -1230,7 +1570,7 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        tabor__msg__get__tab__state)
+        tabor__msg__get__scroll)
             opts="-h --tab-id --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -1248,8 +1588,8 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        tabor__msg__help)
-            opts="config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help"
+        tabor__msg__get__tab__panel)
+            opts="-h --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1262,15 +1602,61 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        tabor__msg__help__close__tab)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+        tabor__msg__get__tab__state)
+            opts="-h --tab-id --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
-                *)
-                    COMPREPLY=()
+                --tab-id)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tabor__msg__get__typing__status)
+            opts="-h --help <TYPING_ID>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tabor__msg__help)
+            opts="config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tabor__msg__help__close__tab)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
                     ;;
             esac
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -1346,6 +1732,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__help__get__closed__tabs)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__help__get__config)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -1360,6 +1760,34 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__help__get__page__text)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tabor__msg__help__get__scroll)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__help__get__tab__panel)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -1388,6 +1816,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__help__get__typing__status)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__help__help)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -1402,6 +1844,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__help__inject__css)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__help__inspector)
             opts="list-targets attach detach send poll"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -1528,6 +1984,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__help__move__tab__to__new__window)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__help__open__inspector)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -1584,6 +2054,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__help__remove__css)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__help__restore__closed__tab)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -1598,6 +2082,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__help__restore__session)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__help__run__command__bar)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -1612,6 +2110,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__help__save__session)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__help__select__tab)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -1654,6 +2166,34 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__help__send__input__typed)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tabor__msg__help__send__key)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__help__set__group__name)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -1668,6 +2208,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__help__set__scroll)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__help__set__tab__panel)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -1710,6 +2264,52 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__help__set__window__title)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tabor__msg__help__subscribe)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tabor__msg__inject__css)
+            opts="-h --tab-id --help <CSS>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --tab-id)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__inspector)
             opts="-h --help list-targets attach detach send poll help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -1725,7 +2325,7 @@ _tabor() {
             return 0
             ;;
         tabor__msg__inspector__attach)
-            opts="-h --tab-id --target-id --help"
+            opts="-h --tab-id --target-id --domains --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1739,6 +2339,10 @@ _tabor() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --domains)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -1877,7 +2481,7 @@ _tabor() {
             return 0
             ;;
         tabor__msg__inspector__poll)
-            opts="-h --session-id --max --help"
+            opts="-h --session-id --max --timeout-ms --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1891,6 +2495,10 @@ _tabor() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --timeout-ms)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -1974,6 +2582,24 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__move__tab__to__new__window)
+            opts="-h --tab-id --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --tab-id)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__open__inspector)
             opts="-h --tab-id --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -2042,6 +2668,24 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__remove__css)
+            opts="-h --tab-id --help <HANDLE>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --tab-id)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__restore__closed__tab)
             opts="-h --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -2056,6 +2700,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__restore__session)
+            opts="-h --help <PATH>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__run__command__bar)
             opts="-h --tab-id --help <INPUT>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -2074,6 +2732,20 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__save__session)
+            opts="-h --help <PATH>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__select__tab)
             opts="-h --active --next --previous --last --index --tab-id --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -2128,6 +2800,50 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__send__input__typed)
+            opts="-h --delay-ms --tab-id --help <TEXT>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --delay-ms)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --tab-id)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tabor__msg__send__key)
+            opts="-h --modifiers --tab-id --help <KEY>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --modifiers)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --tab-id)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__set__group__name)
             opts="-h --group-id --name --clear --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -2150,6 +2866,32 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__set__scroll)
+            opts="-h --x --y --tab-id --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --x)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --y)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --tab-id)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         tabor__msg__set__tab__panel)
             opts="-h --enable --disable --width --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -2208,6 +2950,38 @@ _tabor() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        tabor__msg__set__window__title)
+            opts="-h --title --clear --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --title)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        tabor__msg__subscribe)
+            opts="-h --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
     esac
 }
 
@@ -1,6 +1,6 @@
 # Print an optspec for argparse to handle cmd's options that are independent of any subcommand.
 function __fish_tabor_global_optspecs
-	string join \n print-events ref-test embed= config-file= socket= q v daemon working-directory= hold e/command= T/title= class= o/option= h/help V/version
+	string join \n print-events ref-test embed= config-file= socket= q v daemon new-tab working-directory= hold e/command= T/title= class= o/option= h/help V/version
 end
 
 function __fish_tabor_needs_command
@@ -37,41 +37,56 @@ complete -c tabor -n "__fish_tabor_needs_command" -l ref-test -d 'Generates ref
 complete -c tabor -n "__fish_tabor_needs_command" -s q -d 'Reduces the level of verbosity (the min level is -qq)'
 complete -c tabor -n "__fish_tabor_needs_command" -s v -d 'Increases the level of verbosity (the max level is -vvv)'
 complete -c tabor -n "__fish_tabor_needs_command" -l daemon -d 'Do not spawn an initial window'
+complete -c tabor -n "__fish_tabor_needs_command" -l new-tab -d 'Open a new tab in the focused window of a running instance, if there is one'
 complete -c tabor -n "__fish_tabor_needs_command" -l hold -d 'Remain open after child process exit'
 complete -c tabor -n "__fish_tabor_needs_command" -s h -l help -d 'Print help'
 complete -c tabor -n "__fish_tabor_needs_command" -s V -l version -d 'Print version'
 complete -c tabor -n "__fish_tabor_needs_command" -f -a "msg" -d 'Send a message to the Tabor socket'
 complete -c tabor -n "__fish_tabor_needs_command" -f -a "migrate" -d 'Migrate the configuration file'
 complete -c tabor -n "__fish_tabor_needs_command" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -s s -l socket -d 'IPC socket connection path override' -r -F
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -s h -l help -d 'Print help'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "config" -d 'Update the Tabor configuration'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "get-config" -d 'Read runtime Tabor configuration'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "ping" -d 'Ping the IPC socket'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "get-capabilities" -d 'List IPC capabilities'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "list-tabs" -d 'List all tabs'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "get-tab-state" -d 'Get a single tab state'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "create-tab" -d 'Create a new tab'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "create-group" -d 'Create a new tab group'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "close-tab" -d 'Close a tab (defaults to active)'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "select-tab" -d 'Select a tab'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "move-tab" -d 'Move a tab within or across groups'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "set-tab-title" -d 'Set or clear a tab title'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "set-group-name" -d 'Set or clear a tab group name'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "restore-closed-tab" -d 'Restore the most recently closed tab'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "open-url" -d 'Open a URL in a tab'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "set-web-url" -d 'Set the URL for a web tab'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "reload-web" -d 'Reload a web tab'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "open-inspector" -d 'Open the Web Inspector for a web tab'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "get-tab-panel" -d 'Get tab panel state'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "set-tab-panel" -d 'Set tab panel state'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "dispatch-action" -d 'Dispatch a configured action'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "send-input" -d 'Send literal input to a tab'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "run-command-bar" -d 'Run a command in the command bar'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "inspector" -d 'Web Inspector commands'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "send" -d 'Send raw JSON IPC message'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "list-requests" -d 'List available IPC request types'
-complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab set-tab-title set-group-name restore-closed-tab open-url set-web-url reload-web open-inspector get-tab-panel set-tab-panel dispatch-action send-input run-command-bar inspector send list-requests help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -s s -l socket -d 'IPC socket connection path override' -r -F
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "config" -d 'Update the Tabor configuration'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "get-config" -d 'Read runtime Tabor configuration'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "ping" -d 'Ping the IPC socket'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "get-capabilities" -d 'List IPC capabilities'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "list-tabs" -d 'List all tabs'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "get-tab-state" -d 'Get a single tab state'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "create-tab" -d 'Create a new tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "create-group" -d 'Create a new tab group'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "close-tab" -d 'Close a tab (defaults to active)'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "select-tab" -d 'Select a tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "move-tab" -d 'Move a tab within or across groups'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "move-tab-to-new-window" -d 'Move a tab into a new window (defaults to active)'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "set-tab-title" -d 'Set or clear a tab title'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "set-group-name" -d 'Set or clear a tab group name'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "set-window-title" -d 'Set or clear a window title override'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "restore-closed-tab" -d 'Restore the most recently closed tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "get-closed-tabs" -d 'Get the number of closed tabs which can be restored'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "open-url" -d 'Open a URL in a tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "set-web-url" -d 'Set the URL for a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "reload-web" -d 'Reload a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "open-inspector" -d 'Open the Web Inspector for a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "get-scroll" -d 'Get the scroll offset of a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "get-page-text" -d 'Get the visible text of a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "set-scroll" -d 'Scroll a web tab to an offset'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "inject-css" -d 'Add a user stylesheet to a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "remove-css" -d 'Remove a stylesheet added with `inject-css`'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "get-tab-panel" -d 'Get tab panel state'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "set-tab-panel" -d 'Set tab panel state'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "dispatch-action" -d 'Dispatch a configured action'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "send-input" -d 'Send literal input to a tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "send-input-typed" -d 'Type input into a tab one character at a time'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "get-typing-status" -d 'Get the progress of input typed with `send-input-typed`'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "send-key" -d 'Send a key press to a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "run-command-bar" -d 'Run a command in the command bar'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "inspector" -d 'Web Inspector commands'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "send" -d 'Send raw JSON IPC message'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "list-requests" -d 'List available IPC request types'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "subscribe" -d 'Stream tab lifecycle events as newline-delimited JSON'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "save-session" -d 'Save all windows and tabs to a session file'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "restore-session" -d 'Open the windows and tabs of a session file'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and not __fish_seen_subcommand_from config get-config ping get-capabilities list-tabs get-tab-state create-tab create-group close-tab select-tab move-tab move-tab-to-new-window set-tab-title set-group-name set-window-title restore-closed-tab get-closed-tabs open-url set-web-url reload-web open-inspector get-scroll get-page-text set-scroll inject-css remove-css get-tab-panel set-tab-panel dispatch-action send-input send-input-typed get-typing-status send-key run-command-bar inspector send list-requests subscribe save-session restore-session help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from config" -s w -l window-id -d 'Window ID for the new config' -r
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from config" -s r -l reset -d 'Clear all runtime configuration changes'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from config" -s h -l help -d 'Print help (see more with \'--help\')'
@@ -106,6 +121,8 @@ complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcomm
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from move-tab" -l target-group-id -r
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from move-tab" -l target-index -r
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from move-tab" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from move-tab-to-new-window" -l tab-id -d 'Tab id formatted as <index>:<generation>' -r
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from move-tab-to-new-window" -s h -l help -d 'Print help'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-tab-title" -l tab-id -d 'Tab id formatted as <index>:<generation>' -r
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-tab-title" -l title -r
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-tab-title" -l clear
@@ -114,7 +131,11 @@ complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcomm
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-group-name" -l name -r
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-group-name" -l clear
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-group-name" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-window-title" -l title -r
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-window-title" -l clear -d 'Restore dynamic window titles'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-window-title" -s h -l help -d 'Print help'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from restore-closed-tab" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from get-closed-tabs" -s h -l help -d 'Print help'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from open-url" -l tab-id -d 'Target tab id formatted as <index>:<generation>' -r
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from open-url" -l new-tab
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from open-url" -s h -l help -d 'Print help'
@@ -124,6 +145,18 @@ complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcomm
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from reload-web" -s h -l help -d 'Print help'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from open-inspector" -l tab-id -d 'Tab id formatted as <index>:<generation> (defaults to active tab)' -r
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from open-inspector" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from get-scroll" -l tab-id -d 'Tab id formatted as <index>:<generation> (defaults to active tab)' -r
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from get-scroll" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from get-page-text" -l tab-id -d 'Tab id formatted as <index>:<generation> (defaults to active tab)' -r
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from get-page-text" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-scroll" -l x -d 'Horizontal offset in CSS pixels' -r
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-scroll" -l y -d 'Vertical offset in CSS pixels' -r
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-scroll" -l tab-id -d 'Tab id formatted as <index>:<generation> (defaults to active tab)' -r
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-scroll" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from inject-css" -l tab-id -d 'Tab id formatted as <index>:<generation> (defaults to active tab)' -r
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from inject-css" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from remove-css" -l tab-id -d 'Tab id formatted as <index>:<generation> (defaults to active tab)' -r
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from remove-css" -s h -l help -d 'Print help'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from get-tab-panel" -s h -l help -d 'Print help'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-tab-panel" -l width -r
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from set-tab-panel" -l enable
@@ -140,6 +173,13 @@ complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcomm
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from dispatch-action" -s h -l help -d 'Print help'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from send-input" -l tab-id -d 'Tab id formatted as <index>:<generation> (defaults to active tab)' -r
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from send-input" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from send-input-typed" -l delay-ms -d 'Delay in milliseconds before each character' -r
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from send-input-typed" -l tab-id -d 'Tab id formatted as <index>:<generation> (defaults to active tab)' -r
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from send-input-typed" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from get-typing-status" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from send-key" -l modifiers -d 'Modifiers held while pressing the key [example: shift,control]' -r
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from send-key" -l tab-id -d 'Tab id formatted as <index>:<generation> (defaults to active tab)' -r
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from send-key" -s h -l help -d 'Print help'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from run-command-bar" -l tab-id -d 'Tab id formatted as <index>:<generation> (defaults to active tab)' -r
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from run-command-bar" -s h -l help -d 'Print help'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from inspector" -s h -l help -d 'Print help'
@@ -151,6 +191,9 @@ complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcomm
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from inspector" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from send" -s h -l help -d 'Print help'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from list-requests" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from subscribe" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from save-session" -s h -l help -d 'Print help'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from restore-session" -s h -l help -d 'Print help'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "config" -d 'Update the Tabor configuration'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "get-config" -d 'Read runtime Tabor configuration'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "ping" -d 'Ping the IPC socket'
@@ -162,21 +205,35 @@ complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcomm
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "close-tab" -d 'Close a tab (defaults to active)'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "select-tab" -d 'Select a tab'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "move-tab" -d 'Move a tab within or across groups'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "move-tab-to-new-window" -d 'Move a tab into a new window (defaults to active)'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "set-tab-title" -d 'Set or clear a tab title'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "set-group-name" -d 'Set or clear a tab group name'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "set-window-title" -d 'Set or clear a window title override'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "restore-closed-tab" -d 'Restore the most recently closed tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "get-closed-tabs" -d 'Get the number of closed tabs which can be restored'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "open-url" -d 'Open a URL in a tab'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "set-web-url" -d 'Set the URL for a web tab'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "reload-web" -d 'Reload a web tab'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "open-inspector" -d 'Open the Web Inspector for a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "get-scroll" -d 'Get the scroll offset of a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "get-page-text" -d 'Get the visible text of a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "set-scroll" -d 'Scroll a web tab to an offset'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "inject-css" -d 'Add a user stylesheet to a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "remove-css" -d 'Remove a stylesheet added with `inject-css`'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "get-tab-panel" -d 'Get tab panel state'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "set-tab-panel" -d 'Set tab panel state'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "dispatch-action" -d 'Dispatch a configured action'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "send-input" -d 'Send literal input to a tab'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "send-input-typed" -d 'Type input into a tab one character at a time'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "get-typing-status" -d 'Get the progress of input typed with `send-input-typed`'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "send-key" -d 'Send a key press to a web tab'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "run-command-bar" -d 'Run a command in the command bar'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "inspector" -d 'Web Inspector commands'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "send" -d 'Send raw JSON IPC message'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "list-requests" -d 'List available IPC request types'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "subscribe" -d 'Stream tab lifecycle events as newline-delimited JSON'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "save-session" -d 'Save all windows and tabs to a session file'
+complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "restore-session" -d 'Open the windows and tabs of a session file'
 complete -c tabor -n "__fish_tabor_using_subcommand msg; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c tabor -n "__fish_tabor_using_subcommand migrate" -s c -l config-file -d 'Path to the configuration file' -r -F
 complete -c tabor -n "__fish_tabor_using_subcommand migrate" -s d -l dry-run -d 'Only output TOML config to STDOUT'
@@ -198,18 +255,32 @@ complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcom
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "close-tab" -d 'Close a tab (defaults to active)'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "select-tab" -d 'Select a tab'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "move-tab" -d 'Move a tab within or across groups'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "move-tab-to-new-window" -d 'Move a tab into a new window (defaults to active)'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "set-tab-title" -d 'Set or clear a tab title'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "set-group-name" -d 'Set or clear a tab group name'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "set-window-title" -d 'Set or clear a window title override'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "restore-closed-tab" -d 'Restore the most recently closed tab'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "get-closed-tabs" -d 'Get the number of closed tabs which can be restored'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "open-url" -d 'Open a URL in a tab'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "set-web-url" -d 'Set the URL for a web tab'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "reload-web" -d 'Reload a web tab'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "open-inspector" -d 'Open the Web Inspector for a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "get-scroll" -d 'Get the scroll offset of a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "get-page-text" -d 'Get the visible text of a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "set-scroll" -d 'Scroll a web tab to an offset'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "inject-css" -d 'Add a user stylesheet to a web tab'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "remove-css" -d 'Remove a stylesheet added with `inject-css`'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "get-tab-panel" -d 'Get tab panel state'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "set-tab-panel" -d 'Set tab panel state'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "dispatch-action" -d 'Dispatch a configured action'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "send-input" -d 'Send literal input to a tab'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "send-input-typed" -d 'Type input into a tab one character at a time'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "get-typing-status" -d 'Get the progress of input typed with `send-input-typed`'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "send-key" -d 'Send a key press to a web tab'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "run-command-bar" -d 'Run a command in the command bar'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "inspector" -d 'Web Inspector commands'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "send" -d 'Send raw JSON IPC message'
 complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "list-requests" -d 'List available IPC request types'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "subscribe" -d 'Stream tab lifecycle events as newline-delimited JSON'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "save-session" -d 'Save all windows and tabs to a session file'
+complete -c tabor -n "__fish_tabor_using_subcommand help; and __fish_seen_subcommand_from msg" -f -a "restore-session" -d 'Open the windows and tabs of a session file'
@@ -68,6 +68,11 @@ pub struct Options {
     #[clap(long)]
     pub daemon: bool,
 
+    /// Open a new tab in the focused window of a running instance, if there is one.
+    #[cfg(unix)]
+    #[clap(long, conflicts_with("daemon"))]
+    pub new_tab: bool,
+
     /// CLI options for config overrides.
     #[clap(skip)]
     pub config_options: ParsedOptions,
@@ -182,7 +187,7 @@ fn parse_hex_or_decimal(input: &str) -> Option<u32> {
 }
 
 #[cfg(not(windows))]
-fn shell_escape_arg(input: &str) -> String {
+pub(crate) fn shell_escape_arg(input: &str) -> String {
     if input.is_empty() {
         return String::from("''");
     }
@@ -201,7 +206,7 @@ fn shell_escape_arg(input: &str) -> String {
 }
 
 #[cfg(windows)]
-fn shell_escape_arg(input: &str) -> String {
+pub(crate) fn shell_escape_arg(input: &str) -> String {
     if input.is_empty() {
         return String::from("''");
     }
@@ -264,6 +269,11 @@ impl TerminalOptions {
         Some(parts.join(" "))
     }
 
+    /// Set the command executed in the default shell.
+    pub(crate) fn set_command(&mut self, command: Vec<String>) {
+        self.command = command;
+    }
+
     /// Override the [`PtyOptions`]'s fields with the [`TerminalOptions`].
     pub fn override_pty_config(&self, pty_config: &mut PtyOptions) {
         if let Some(working_directory) = &self.working_directory {
@@ -275,6 +285,9 @@ impl TerminalOptions {
         }
 
         pty_config.drain_on_exit |= self.hold;
+        if self.hold {
+            pty_config.shutdown_grace = None;
+        }
     }
 }
 
@@ -284,6 +297,7 @@ impl From<TerminalOptions> for PtyOptions {
             working_directory: options.working_directory.take(),
             shell: None,
             drain_on_exit: options.hold,
+            shutdown_grace: None,
             env: HashMap::new(),
             #[cfg(target_os = "windows")]
             escape_args: false,
@@ -338,7 +352,7 @@ pub struct MessageOptions {
 
 /// Available socket messages.
 #[cfg(unix)]
-#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+#[derive(Subcommand, Debug, Clone, PartialEq)]
 pub enum MessageCommand {
     /// Update the Tabor configuration.
     Config(IpcConfig),
@@ -373,15 +387,24 @@ pub enum MessageCommand {
     /// Move a tab within or across groups.
     MoveTab(MsgMoveTab),
 
+    /// Move a tab into a new window (defaults to active).
+    MoveTabToNewWindow(MsgMoveTabToNewWindow),
+
     /// Set or clear a tab title.
     SetTabTitle(MsgSetTabTitle),
 
     /// Set or clear a tab group name.
     SetGroupName(MsgSetGroupName),
 
+    /// Set or clear a window title override.
+    SetWindowTitle(MsgSetWindowTitle),
+
     /// Restore the most recently closed tab.
     RestoreClosedTab,
 
+    /// Get the number of closed tabs which can be restored.
+    GetClosedTabs,
+
     /// Open a URL in a tab.
     OpenUrl(MsgOpenUrl),
 
@@ -394,6 +417,21 @@ pub enum MessageCommand {
     /// Open the Web Inspector for a web tab.
     OpenInspector(MsgOpenInspector),
 
+    /// Get the scroll offset of a web tab.
+    GetScroll(MsgGetScroll),
+
+    /// Get the visible text of a web tab.
+    GetPageText(MsgGetPageText),
+
+    /// Scroll a web tab to an offset.
+    SetScroll(MsgSetScroll),
+
+    /// Add a user stylesheet to a web tab.
+    InjectCss(MsgInjectCss),
+
+    /// Remove a stylesheet added with `inject-css`.
+    RemoveCss(MsgRemoveCss),
+
     /// Get tab panel state.
     GetTabPanel,
 
@@ -406,6 +444,15 @@ pub enum MessageCommand {
     /// Send literal input to a tab.
     SendInput(MsgSendInput),
 
+    /// Type input into a tab one character at a time.
+    SendInputTyped(MsgSendInputTyped),
+
+    /// Get the progress of input typed with `send-input-typed`.
+    GetTypingStatus(MsgGetTypingStatus),
+
+    /// Send a key press to a web tab.
+    SendKey(MsgSendKey),
+
     /// Run a command in the command bar.
     RunCommandBar(MsgRunCommandBar),
 
@@ -424,6 +471,15 @@ pub enum MessageCommand {
 
     /// List available IPC request types.
     ListRequests,
+
+    /// Stream tab lifecycle events as newline-delimited JSON.
+    Subscribe,
+
+    /// Save all windows and tabs to a session file.
+    SaveSession(MsgSession),
+
+    /// Open the windows and tabs of a session file.
+    RestoreSession(MsgSession),
 }
 
 #[cfg(unix)]
@@ -500,6 +556,22 @@ pub struct MsgSelectTab {
     pub tab_id: Option<TabIdArg>,
 }
 
+#[cfg(unix)]
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+pub struct MsgSession {
+    /// Path of the session file.
+    #[clap(value_hint = ValueHint::FilePath)]
+    pub path: PathBuf,
+}
+
+#[cfg(unix)]
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+pub struct MsgMoveTabToNewWindow {
+    /// Tab id formatted as <index>:<generation>.
+    #[clap(long, value_parser = parse_tab_id, value_name = "INDEX:GEN")]
+    pub tab_id: Option<TabIdArg>,
+}
+
 #[cfg(unix)]
 #[derive(Args, Debug, Clone, PartialEq, Eq)]
 #[clap(group(
@@ -556,6 +628,22 @@ pub struct MsgSetGroupName {
     pub clear: bool,
 }
 
+#[cfg(unix)]
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+#[clap(group(
+    ArgGroup::new("window_title_choice")
+        .required(true)
+        .args(&["title", "clear"])
+))]
+pub struct MsgSetWindowTitle {
+    #[clap(long)]
+    pub title: Option<String>,
+
+    /// Restore dynamic window titles.
+    #[clap(long, conflicts_with = "title")]
+    pub clear: bool,
+}
+
 #[cfg(unix)]
 #[derive(Args, Debug, Clone, PartialEq, Eq)]
 pub struct MsgOpenUrl {
@@ -595,6 +683,59 @@ pub struct MsgOpenInspector {
     pub tab_id: Option<TabIdArg>,
 }
 
+#[cfg(unix)]
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+pub struct MsgGetScroll {
+    /// Tab id formatted as <index>:<generation> (defaults to active tab).
+    #[clap(long, value_parser = parse_tab_id, value_name = "INDEX:GEN")]
+    pub tab_id: Option<TabIdArg>,
+}
+
+#[cfg(unix)]
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+pub struct MsgInjectCss {
+    pub css: String,
+
+    /// Tab id formatted as <index>:<generation> (defaults to active tab).
+    #[clap(long, value_parser = parse_tab_id, value_name = "INDEX:GEN")]
+    pub tab_id: Option<TabIdArg>,
+}
+
+#[cfg(unix)]
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+pub struct MsgRemoveCss {
+    /// Handle returned when the stylesheet was added.
+    pub handle: u64,
+
+    /// Tab id formatted as <index>:<generation> (defaults to active tab).
+    #[clap(long, value_parser = parse_tab_id, value_name = "INDEX:GEN")]
+    pub tab_id: Option<TabIdArg>,
+}
+
+#[cfg(unix)]
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+pub struct MsgGetPageText {
+    /// Tab id formatted as <index>:<generation> (defaults to active tab).
+    #[clap(long, value_parser = parse_tab_id, value_name = "INDEX:GEN")]
+    pub tab_id: Option<TabIdArg>,
+}
+
+#[cfg(unix)]
+#[derive(Args, Debug, Clone, PartialEq)]
+pub struct MsgSetScroll {
+    /// Horizontal offset in CSS pixels.
+    #[clap(long, default_value_t = 0.)]
+    pub x: f64,
+
+    /// Vertical offset in CSS pixels.
+    #[clap(long, default_value_t = 0.)]
+    pub y: f64,
+
+    /// Tab id formatted as <index>:<generation> (defaults to active tab).
+    #[clap(long, value_parser = parse_tab_id, value_name = "INDEX:GEN")]
+    pub tab_id: Option<TabIdArg>,
+}
+
 #[cfg(unix)]
 #[derive(Args, Debug, Clone, PartialEq, Eq)]
 #[clap(group(
@@ -665,6 +806,42 @@ pub struct MsgSendInput {
     pub tab_id: Option<TabIdArg>,
 }
 
+#[cfg(unix)]
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+pub struct MsgSendInputTyped {
+    pub text: String,
+
+    /// Delay in milliseconds before each character.
+    #[clap(long)]
+    pub delay_ms: u64,
+
+    /// Tab id formatted as <index>:<generation> (defaults to active tab).
+    #[clap(long, value_parser = parse_tab_id, value_name = "INDEX:GEN")]
+    pub tab_id: Option<TabIdArg>,
+}
+
+#[cfg(unix)]
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+pub struct MsgGetTypingStatus {
+    /// Id returned when the input was started.
+    pub typing_id: u64,
+}
+
+#[cfg(unix)]
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+pub struct MsgSendKey {
+    /// Key name like `Escape`, `Tab` or `ArrowDown`, or a single character.
+    pub key: String,
+
+    /// Modifiers held while pressing the key [example: shift,control].
+    #[clap(long, value_delimiter = ',')]
+    pub modifiers: Vec<String>,
+
+    /// Tab id formatted as <index>:<generation> (defaults to active tab).
+    #[clap(long, value_parser = parse_tab_id, value_name = "INDEX:GEN")]
+    pub tab_id: Option<TabIdArg>,
+}
+
 #[cfg(unix)]
 #[derive(Args, Debug, Clone, PartialEq, Eq)]
 pub struct MsgRunCommandBar {
@@ -708,6 +885,10 @@ pub struct MsgInspectorAttach {
 
     #[clap(long)]
     pub target_id: Option<u64>,
+
+    /// Protocol domains to enable after attaching, like `Page,Runtime`.
+    #[clap(long, value_delimiter = ',', value_name = "DOMAIN")]
+    pub domains: Vec<String>,
 }
 
 #[cfg(unix)]
@@ -735,6 +916,10 @@ pub struct MsgInspectorPoll {
 
     #[clap(long)]
     pub max: Option<usize>,
+
+    /// Wait up to this many milliseconds for new messages when none are queued.
+    #[clap(long, value_name = "MS")]
+    pub timeout_ms: Option<u64>,
 }
 
 /// Migrate the configuration file.
@@ -780,6 +965,26 @@ pub struct WindowOptions {
     #[serde(default)]
     pub command_input: Option<String>,
 
+    /// Host connected to with `:ssh`.
+    #[clap(skip)]
+    #[serde(default)]
+    pub ssh_host: Option<String>,
+
+    /// Focus the tab which opened this one once it's closed.
+    #[clap(skip)]
+    #[serde(default)]
+    pub return_focus: bool,
+
+    /// Open the tab without selecting it.
+    #[clap(skip)]
+    #[serde(default)]
+    pub background: bool,
+
+    /// Keep the browsing data of the web tab in memory only.
+    #[clap(skip)]
+    #[serde(default)]
+    pub private: bool,
+
     #[clap(skip)]
     #[cfg(not(any(target_os = "macos", windows)))]
     /// `ActivationToken` that we pass to winit.
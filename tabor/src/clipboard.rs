@@ -1,3 +1,5 @@
+use std::collections::VecDeque;
+
 use log::{debug, warn};
 use winit::raw_window_handle::RawDisplayHandle;
 
@@ -15,6 +17,8 @@ use copypasta::x11_clipboard::{Primary as X11SelectionClipboard, X11ClipboardCon
 pub struct Clipboard {
     clipboard: Box<dyn ClipboardProvider>,
     selection: Option<Box<dyn ClipboardProvider>>,
+    /// Distinct clipboard contents captured over time, most recent first.
+    history: VecDeque<String>,
 }
 
 impl Clipboard {
@@ -25,7 +29,11 @@ impl Clipboard {
                 let (selection, clipboard) = unsafe {
                     wayland_clipboard::create_clipboards_from_external(display.display.as_ptr())
                 };
-                Self { clipboard: Box::new(clipboard), selection: Some(Box::new(selection)) }
+                Self {
+                    clipboard: Box::new(clipboard),
+                    selection: Some(Box::new(selection)),
+                    history: VecDeque::new(),
+                }
             },
             _ => Self::default(),
         }
@@ -34,19 +42,28 @@ impl Clipboard {
     /// Used for tests, to handle missing clipboard provider when built without the `x11`
     /// feature, and as default clipboard value.
     pub fn new_nop() -> Self {
-        Self { clipboard: Box::new(NopClipboardContext::new().unwrap()), selection: None }
+        Self {
+            clipboard: Box::new(NopClipboardContext::new().unwrap()),
+            selection: None,
+            history: VecDeque::new(),
+        }
     }
 }
 
 impl Default for Clipboard {
     fn default() -> Self {
         #[cfg(any(target_os = "macos", windows))]
-        return Self { clipboard: Box::new(ClipboardContext::new().unwrap()), selection: None };
+        return Self {
+            clipboard: Box::new(ClipboardContext::new().unwrap()),
+            selection: None,
+            history: VecDeque::new(),
+        };
 
         #[cfg(all(feature = "x11", not(any(target_os = "macos", windows))))]
         return Self {
             clipboard: Box::new(ClipboardContext::new().unwrap()),
             selection: Some(Box::new(X11ClipboardContext::<X11SelectionClipboard>::new().unwrap())),
+            history: VecDeque::new(),
         };
 
         #[cfg(not(any(feature = "x11", target_os = "macos", windows)))]
@@ -67,6 +84,14 @@ impl Clipboard {
         });
     }
 
+    /// Store text in the clipboard, offering an HTML flavor for rich text editors.
+    #[cfg(target_os = "macos")]
+    pub fn store_rich_text(&mut self, text: String, html: &str) {
+        if !crate::macos::store_rich_text(&text, html) {
+            self.store(ClipboardType::Clipboard, text);
+        }
+    }
+
     pub fn load(&mut self, ty: ClipboardType) -> String {
         let clipboard = match (ty, &mut self.selection) {
             (ClipboardType::Selection, Some(provider)) => provider,
@@ -81,4 +106,42 @@ impl Clipboard {
             Ok(text) => text,
         }
     }
+
+    /// Capture the clipboard content, keeping at most `limit` distinct entries.
+    pub fn capture_history(&mut self, limit: usize) {
+        let text = self.load(ClipboardType::Clipboard);
+        self.push_history(text, limit);
+    }
+
+    /// Clipboard history, most recent first.
+    pub fn history(&self) -> &VecDeque<String> {
+        &self.history
+    }
+
+    fn push_history(&mut self, text: String, limit: usize) {
+        if text.is_empty() || self.history.front() == Some(&text) {
+            return;
+        }
+
+        self.history.retain(|entry| entry != &text);
+        self.history.push_front(text);
+        self.history.truncate(limit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_keeps_distinct_recent_entries() {
+        let mut clipboard = Clipboard::new_nop();
+        for text in ["a", "b", "", "b", "a", "c"] {
+            clipboard.push_history(text.into(), 3);
+        }
+        assert_eq!(clipboard.history(), &["c", "a", "b"]);
+
+        clipboard.push_history(String::from("d"), 3);
+        assert_eq!(clipboard.history(), &["d", "c", "a"]);
+    }
 }
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use serde::Serialize;
 
@@ -7,6 +7,12 @@ use tabor_config_derive::ConfigDeserialize;
 use crate::config::ui_config::Program;
 use crate::display::color::Rgb;
 
+/// Longest visual bell flash in milliseconds.
+const MAX_DURATION_MS: u16 = 10_000;
+
+/// Longest cooldown between bell commands in milliseconds.
+const MAX_COMMAND_COOLDOWN_MS: u16 = 60_000;
+
 #[derive(ConfigDeserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct BellConfig {
     /// Visual bell animation function.
@@ -15,11 +21,20 @@ pub struct BellConfig {
     /// Command to run on bell.
     pub command: Option<Program>,
 
+    /// Minimum time between bell commands in milliseconds.
+    command_cooldown_ms: u16,
+
     /// Visual bell flash color.
     pub color: Rgb,
 
     /// Visual bell duration in milliseconds.
     duration: u16,
+
+    /// Post a native notification when the bell rings in the background.
+    pub notify: bool,
+
+    /// Set the urgency hint when the bell rings in a background tab.
+    pub background_urgency: bool,
 }
 
 impl Default for BellConfig {
@@ -28,14 +43,32 @@ impl Default for BellConfig {
             color: Rgb::new(255, 255, 255),
             animation: Default::default(),
             command: Default::default(),
+            command_cooldown_ms: 100,
             duration: Default::default(),
+            notify: Default::default(),
+            background_urgency: Default::default(),
         }
     }
 }
 
 impl BellConfig {
     pub fn duration(&self) -> Duration {
-        Duration::from_millis(self.duration as u64)
+        Duration::from_millis(self.duration.min(MAX_DURATION_MS) as u64)
+    }
+
+    pub fn command_cooldown(&self) -> Duration {
+        Duration::from_millis(self.command_cooldown_ms.min(MAX_COMMAND_COOLDOWN_MS) as u64)
+    }
+
+    /// Command to run for a bell, `None` during the cooldown after the `last_command`.
+    pub fn take_command(&self, last_command: &mut Option<Instant>) -> Option<&Program> {
+        let command = self.command.as_ref()?;
+        if last_command.is_some_and(|last| last.elapsed() < self.command_cooldown()) {
+            return None;
+        }
+
+        *last_command = Some(Instant::now());
+        Some(command)
     }
 }
 
@@ -65,3 +98,23 @@ pub enum BellAnimation {
     #[default]
     Linear,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bell_command_cooldown() {
+        let mut last_command = None;
+        let mut config = BellConfig::default();
+        assert_eq!(config.take_command(&mut last_command), None);
+        assert_eq!(last_command, None);
+
+        config.command = Some(Program::Just(String::from("true")));
+        assert!(config.take_command(&mut last_command).is_some());
+        assert!(config.take_command(&mut last_command).is_none());
+
+        config.command_cooldown_ms = 0;
+        assert!(config.take_command(&mut last_command).is_some());
+    }
+}
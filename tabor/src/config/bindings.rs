@@ -85,6 +85,28 @@ impl<T: Eq> Binding<T> {
     }
 }
 
+/// Display trait used to list key bindings in the command palette.
+impl Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mods = [
+            (ModifiersState::CONTROL, "Control"),
+            (ModifiersState::ALT, "Alt"),
+            (ModifiersState::SUPER, "Super"),
+            (ModifiersState::SHIFT, "Shift"),
+        ];
+        for (_, name) in mods.iter().filter(|(mods, _)| self.mods.contains(*mods)) {
+            write!(f, "{name}+")?;
+        }
+
+        match &self.trigger {
+            BindingKey::Keycode { key: Key::Character(ch), .. } => f.write_str(ch),
+            BindingKey::Keycode { key: Key::Named(key), .. } => write!(f, "{key:?}"),
+            BindingKey::Keycode { key, .. } => write!(f, "{key:?}"),
+            BindingKey::Scancode(key) => write!(f, "{key:?}"),
+        }
+    }
+}
+
 #[derive(ConfigDeserialize, Debug, Clone, PartialEq, Eq)]
 pub enum Action {
     /// Write an escape sequence.
@@ -223,6 +245,36 @@ pub enum Action {
     /// Create new window in a tab.
     CreateNewTab,
 
+    /// Show a new terminal tab next to the active tab.
+    SplitTab,
+
+    /// Close the split, keeping the focused tab.
+    CloseSplit,
+
+    /// Move focus to the other pane of the split.
+    FocusNextPane,
+
+    /// Swap the left and right panes of the split.
+    SwapPanes,
+
+    /// Grow the focused split pane.
+    GrowPane,
+
+    /// Shrink the focused split pane.
+    ShrinkPane,
+
+    /// Move the active tab into a new window.
+    MoveTabToNewWindow,
+
+    /// Show or hide the tab panel.
+    ToggleTabPanel,
+
+    /// Cycle through tabs in most recently used order while modifiers are held.
+    SelectRecentTab,
+
+    /// Cycle through tabs in least recently used order while modifiers are held.
+    SelectRecentTabReverse,
+
     /// Toggle fullscreen.
     ToggleFullscreen,
 
@@ -241,6 +293,12 @@ pub enum Action {
     /// Toggle the command bar.
     ToggleCommandBar,
 
+    /// Record key input into the register typed next, or stop the recording.
+    RecordMacro,
+
+    /// Replay the key input recorded into the register typed next.
+    ReplayMacro,
+
     /// Allow receiving char input.
     ReceiveChar,
 
@@ -331,6 +389,10 @@ pub enum ViAction {
     InlineSearchNext,
     /// Jump to the previous inline search match.
     InlineSearchPrevious,
+    /// Set a mark at the vi mode cursor, named by the next character.
+    SetMark,
+    /// Jump to the mark named by the next character.
+    JumpToMark,
     /// Search forward for selection or word under the cursor.
     SemanticSearchForward,
     /// Search backward for selection or word under the cursor.
@@ -479,6 +541,8 @@ pub fn default_key_bindings() -> Vec<KeyBinding> {
         "y",                                +BindingMode::VI, ~BindingMode::SEARCH; Action::ClearSelection;
         "/",                                +BindingMode::VI, ~BindingMode::SEARCH; Action::SearchForward;
         "?",      ModifiersState::SHIFT,    +BindingMode::VI, ~BindingMode::SEARCH; Action::SearchBackward;
+        "q",                                +BindingMode::VI, ~BindingMode::SEARCH; Action::RecordMacro;
+        "@",      ModifiersState::SHIFT,    +BindingMode::VI, ~BindingMode::SEARCH; Action::ReplayMacro;
         "y",      ModifiersState::SHIFT,    +BindingMode::VI, ~BindingMode::SEARCH; ViAction::ToggleNormalSelection;
         "y",      ModifiersState::SHIFT,    +BindingMode::VI, ~BindingMode::SEARCH; ViMotion::Last;
         "y",      ModifiersState::SHIFT,    +BindingMode::VI, ~BindingMode::SEARCH; Action::Copy;
@@ -497,6 +561,8 @@ pub fn default_key_bindings() -> Vec<KeyBinding> {
         "t",      ModifiersState::SHIFT,    +BindingMode::VI, ~BindingMode::SEARCH; ViAction::InlineSearchBackwardShort;
         ";",                                +BindingMode::VI, ~BindingMode::SEARCH; ViAction::InlineSearchNext;
         ",",                                +BindingMode::VI, ~BindingMode::SEARCH; ViAction::InlineSearchPrevious;
+        "m",                                +BindingMode::VI, ~BindingMode::SEARCH; ViAction::SetMark;
+        "`",                                +BindingMode::VI, ~BindingMode::SEARCH; ViAction::JumpToMark;
         "*",      ModifiersState::SHIFT,    +BindingMode::VI, ~BindingMode::SEARCH; ViAction::SemanticSearchForward;
         "#",      ModifiersState::SHIFT,    +BindingMode::VI, ~BindingMode::SEARCH; ViAction::SemanticSearchBackward;
         "k",                                +BindingMode::VI, ~BindingMode::SEARCH; ViMotion::Up;
@@ -589,10 +655,18 @@ pub fn platform_key_bindings() -> Vec<KeyBinding> {
         Escape, ModifiersState::SUPER, +BindingMode::VI, ~BindingMode::SEARCH; Action::ScrollToBottom;
         // Tabbing api.
         "t",    ModifiersState::SUPER;                                         Action::CreateNewTab;
+        "d",    ModifiersState::SUPER;                                         Action::SplitTab;
+        "d",    ModifiersState::SUPER | ModifiersState::SHIFT;                 Action::CloseSplit;
+        "]",    ModifiersState::SUPER;                                         Action::FocusNextPane;
+        "[",    ModifiersState::SUPER;                                         Action::FocusNextPane;
+        "d",    ModifiersState::SUPER | ModifiersState::ALT;                   Action::SwapPanes;
+        "s",    ModifiersState::SUPER | ModifiersState::CONTROL;               Action::ToggleTabPanel;
         "]",    ModifiersState::SUPER | ModifiersState::SHIFT;                 Action::SelectNextTab;
         "[",    ModifiersState::SUPER | ModifiersState::SHIFT;                 Action::SelectPreviousTab;
         Tab,    ModifiersState::SUPER;                                         Action::SelectNextTab;
         Tab,    ModifiersState::SUPER | ModifiersState::SHIFT;                 Action::SelectPreviousTab;
+        Tab,    ModifiersState::CONTROL;                                       Action::SelectRecentTab;
+        Tab,    ModifiersState::CONTROL | ModifiersState::SHIFT;               Action::SelectRecentTabReverse;
         "1",    ModifiersState::SUPER;                                         Action::SelectTab1;
         "2",    ModifiersState::SUPER;                                         Action::SelectTab2;
         "3",    ModifiersState::SUPER;                                         Action::SelectTab3;
@@ -1298,6 +1372,26 @@ mod tests {
         assert!(!subset_mods.triggers_match(&superset_mods));
     }
 
+    #[test]
+    fn key_binding_display() {
+        let binding = KeyBinding {
+            mods: ModifiersState::CONTROL | ModifiersState::SHIFT,
+            action: Action::Copy,
+            mode: BindingMode::empty(),
+            notmode: BindingMode::empty(),
+            trigger: BindingKey::Keycode {
+                key: Key::Character("c".into()),
+                location: KeyLocation::Any,
+            },
+        };
+        assert_eq!(binding.to_string(), "Control+Shift+c");
+
+        let trigger =
+            BindingKey::Keycode { key: Key::Named(NamedKey::PageUp), location: KeyLocation::Any };
+        let binding = KeyBinding { mods: ModifiersState::empty(), trigger, ..binding };
+        assert_eq!(binding.to_string(), "PageUp");
+    }
+
     #[test]
     fn binding_matches_identical_mode() {
         let b1 = MockBinding { mode: BindingMode::ALT_SCREEN, ..MockBinding::default() };
@@ -1,12 +1,14 @@
 use std::fmt;
 
 use crossfont::Size as FontSize;
+use log::error;
 use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use tabor_config_derive::{ConfigDeserialize, SerdeReplace};
 
 use crate::config::ui_config::Delta;
+use crate::logging::LOG_TARGET_CONFIG;
 
 /// Font config.
 ///
@@ -40,6 +42,12 @@ pub struct Font {
     /// Font size in points.
     size: Size,
 
+    /// Smallest font size in points reachable by changing the font size.
+    min_size: Size,
+
+    /// Largest font size in points reachable by changing the font size.
+    max_size: Size,
+
     /// Whether to use the built-in font for box drawing characters.
     pub builtin_box_drawing: bool,
 }
@@ -55,6 +63,33 @@ impl Font {
         self.size.0
     }
 
+    #[inline]
+    pub fn min_size(&self) -> FontSize {
+        self.min_size.0
+    }
+
+    #[inline]
+    pub fn max_size(&self) -> FontSize {
+        self.max_size.0
+    }
+
+    /// Reset the font size bounds to their defaults when `min_size` is above `max_size`.
+    pub fn validate_size_bounds(&mut self) {
+        if self.min_size.0.as_pt() <= self.max_size.0.as_pt() {
+            return;
+        }
+
+        error!(
+            target: LOG_TARGET_CONFIG,
+            "Config error: font.min_size {} is above font.max_size {}, using the defaults",
+            self.min_size.0.as_pt(),
+            self.max_size.0.as_pt(),
+        );
+        let default = Self::default();
+        self.min_size = default.min_size;
+        self.max_size = default.max_size;
+    }
+
     /// Get normal font description.
     pub fn normal(&self) -> &FontDescription {
         &self.normal
@@ -88,6 +123,8 @@ impl Default for Font {
             normal: Default::default(),
             bold: Default::default(),
             size: Default::default(),
+            min_size: Size(FontSize::new(4.)),
+            max_size: Size(FontSize::new(72.)),
         }
     }
 }
@@ -148,14 +185,20 @@ impl<'de> Deserialize<'de> for Size {
             type Value = Size;
 
             fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.write_str("f64 or i64")
+                f.write_str("positive f64 or i64")
             }
 
             fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+                if !value.is_finite() || value <= 0. {
+                    return Err(E::invalid_value(de::Unexpected::Float(value), &self));
+                }
                 Ok(Size(FontSize::new(value as f32)))
             }
 
             fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                if value <= 0 {
+                    return Err(E::invalid_value(de::Unexpected::Signed(value), &self));
+                }
                 Ok(Size(FontSize::new(value as f32)))
             }
         }
@@ -172,3 +215,25 @@ impl Serialize for Size {
         serializer.serialize_f32(self.0.as_pt())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_bounds_are_validated() {
+        assert!(Size::deserialize(toml::Value::Integer(0)).is_err());
+        assert!(Size::deserialize(toml::Value::Float(-4.5)).is_err());
+        assert!(Size::deserialize(toml::Value::Float(f64::NAN)).is_err());
+        assert_eq!(Size::deserialize(toml::Value::Integer(12)).unwrap(), Size(FontSize::new(12.)));
+
+        let mut font: Font = toml::from_str("min_size = 30\nmax_size = 20").unwrap();
+        font.validate_size_bounds();
+        assert_eq!(font.min_size(), Font::default().min_size());
+        assert_eq!(font.max_size(), Font::default().max_size());
+
+        let mut font: Font = toml::from_str("min_size = 20\nmax_size = 20").unwrap();
+        font.validate_size_bounds();
+        assert_eq!(font.min_size(), FontSize::new(20.));
+    }
+}
@@ -6,6 +6,8 @@ use serde::Serialize;
 
 use tabor_config_derive::ConfigDeserialize;
 
+use crate::config::ui_config::Program;
+
 /// General config section.
 ///
 /// This section is for fields which can not be easily categorized,
@@ -24,6 +26,9 @@ pub struct General {
     /// Live config reload.
     pub live_config_reload: bool,
 
+    /// Program opening the text written by `:edit`.
+    pub editor: Option<Program>,
+
     /// Offer IPC through a unix socket.
     #[allow(unused)]
     pub ipc_socket: bool,
@@ -35,6 +40,7 @@ impl Default for General {
             live_config_reload: true,
             ipc_socket: true,
             working_directory: Default::default(),
+            editor: Default::default(),
             import: Default::default(),
         }
     }
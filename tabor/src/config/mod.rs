@@ -17,11 +17,15 @@ pub mod debug;
 pub mod font;
 pub mod general;
 pub mod monitor;
+pub mod notify;
 pub mod scrolling;
 pub mod selection;
 pub mod serde_utils;
+pub mod tabs;
 pub mod terminal;
+pub mod touch;
 pub mod ui_config;
+pub mod web;
 pub mod window;
 
 mod bindings;
@@ -187,6 +191,7 @@ fn read_config(path: &Path) -> Result<UiConfig> {
     // Deserialize to concrete type.
     let mut config = UiConfig::deserialize(config_value)?;
     config.config_paths = config_paths;
+    config.font.validate_size_bounds();
 
     Ok(config)
 }
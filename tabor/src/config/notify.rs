@@ -0,0 +1,22 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use tabor_config_derive::ConfigDeserialize;
+
+/// Native notification configuration.
+#[derive(ConfigDeserialize, Serialize, Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Notify {
+    /// Minimum runtime in seconds for a command's completion to be notified.
+    long_command_threshold: u32,
+}
+
+impl Notify {
+    /// Minimum runtime for command completion notifications, `None` when they're disabled.
+    pub fn long_command_threshold(self) -> Option<Duration> {
+        match self.long_command_threshold {
+            0 => None,
+            secs => Some(Duration::from_secs(u64::from(secs))),
+        }
+    }
+}
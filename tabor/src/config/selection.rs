@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use serde::Serialize;
 
 use tabor_config_derive::ConfigDeserialize;
@@ -7,6 +9,10 @@ use tabor_terminal::term::SEMANTIC_ESCAPE_CHARS;
 pub struct Selection {
     pub semantic_escape_chars: String,
     pub save_to_clipboard: bool,
+    /// Maximum number of lines copied from the scrollback with `:yank`.
+    pub scrollback_copy_limit: u32,
+    /// Periodic capture of the clipboard for `:clip`.
+    pub clipboard_history: ClipboardHistory,
 }
 
 impl Default for Selection {
@@ -14,6 +20,32 @@ impl Default for Selection {
         Self {
             semantic_escape_chars: SEMANTIC_ESCAPE_CHARS.to_owned(),
             save_to_clipboard: Default::default(),
+            scrollback_copy_limit: 10_000,
+            clipboard_history: Default::default(),
         }
     }
 }
+
+#[derive(ConfigDeserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct ClipboardHistory {
+    pub enabled: bool,
+    /// Maximum number of remembered clipboard entries.
+    pub size: usize,
+    interval_ms: u16,
+}
+
+impl Default for ClipboardHistory {
+    fn default() -> Self {
+        Self { enabled: false, size: 50, interval_ms: 1000 }
+    }
+}
+
+impl ClipboardHistory {
+    /// Time between clipboard captures.
+    pub fn interval(&self) -> Duration {
+        Duration::from_millis(u64::from(self.interval_ms.max(MIN_CLIPBOARD_POLL_MS)))
+    }
+}
+
+/// Shortest time between clipboard captures.
+const MIN_CLIPBOARD_POLL_MS: u16 = 100;
@@ -0,0 +1,221 @@
+use regex_automata::meta::Regex;
+use serde::de::Error as SerdeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use tabor_config_derive::ConfigDeserialize;
+
+use crate::config::scrolling::MAX_SCROLLBACK_LINES;
+use crate::display::color::Rgb;
+
+/// Tab configuration.
+#[derive(ConfigDeserialize, Serialize, Default, Clone, Debug, PartialEq, Eq)]
+pub struct Tabs {
+    /// Rules matched against tabs, the first matching rule wins.
+    pub rules: Vec<TabRule>,
+
+    /// Profiles applied to SSH tabs, the first matching profile wins.
+    pub ssh_profiles: Vec<SshProfile>,
+
+    /// Position of new tabs in their group.
+    pub new_position: NewTabPosition,
+}
+
+impl Tabs {
+    /// Find the first rule matching a tab.
+    pub fn matching_rule(&self, target: TabRuleTarget<'_>) -> Option<&TabRule> {
+        self.rules.iter().find(|rule| rule.matches(target))
+    }
+
+    /// Find the first profile matching an SSH destination.
+    ///
+    /// The user part of `user@host` destinations is ignored for matching.
+    pub fn ssh_profile(&self, destination: &str) -> Option<&SshProfile> {
+        let host = destination.rsplit('@').next().unwrap_or(destination);
+        self.ssh_profiles.iter().find(|profile| profile.host.is_match(host))
+    }
+}
+
+/// Position of new tabs in their group.
+#[derive(ConfigDeserialize, Serialize, Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NewTabPosition {
+    /// After the last tab.
+    #[default]
+    End,
+    /// Right after the active tab.
+    AfterCurrent,
+    /// Before the first tab.
+    Start,
+}
+
+/// Tab property matched by rules.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TabRuleTarget<'a> {
+    /// Foreground process name of a terminal tab.
+    Process(&'a str),
+    /// Host of a web tab.
+    Host(&'a str),
+}
+
+/// Rule applied to matching tabs.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct TabRule {
+    /// Regex matching the foreground process name of terminal tabs.
+    #[serde(default)]
+    pub process: Option<TabPattern>,
+
+    /// Regex matching the host of web tabs.
+    #[serde(default)]
+    pub host: Option<TabPattern>,
+
+    /// Accent color of matching tabs.
+    #[serde(default)]
+    pub color: Option<Rgb>,
+
+    /// Require confirmation before closing matching tabs.
+    #[serde(default)]
+    pub protected: bool,
+}
+
+impl TabRule {
+    fn matches(&self, target: TabRuleTarget<'_>) -> bool {
+        match target {
+            TabRuleTarget::Process(name) => self.process.as_ref().is_some_and(|p| p.is_match(name)),
+            TabRuleTarget::Host(host) => self.host.as_ref().is_some_and(|p| p.is_match(host)),
+        }
+    }
+}
+
+/// Appearance of tabs connected to a matching SSH host.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct SshProfile {
+    /// Regex matching the SSH host.
+    pub host: TabPattern,
+
+    /// Text prepended to the host in the tab title.
+    #[serde(default)]
+    pub title_prefix: Option<String>,
+
+    /// Accent color of matching tabs.
+    #[serde(default)]
+    pub color: Option<Rgb>,
+
+    /// Scrollback history lines of matching tabs.
+    #[serde(default)]
+    scrollback: Option<u32>,
+
+    /// Input typed into the remote shell, replacing `terminal.startup_input`.
+    #[serde(default)]
+    pub startup_input: Option<String>,
+}
+
+impl SshProfile {
+    /// Tab title for a session connected to `destination`.
+    pub fn title(&self, destination: &str) -> String {
+        format!("{}{destination}", self.title_prefix.as_deref().unwrap_or_default())
+    }
+
+    /// Scrollback history lines, limited to the maximum allowed by `scrolling.history`.
+    pub fn scrollback(&self) -> Option<usize> {
+        self.scrollback.map(|lines| lines.min(MAX_SCROLLBACK_LINES) as usize)
+    }
+}
+
+/// Regex pattern compiled when the config is loaded.
+#[derive(Clone, Debug)]
+pub struct TabPattern {
+    pattern: String,
+    regex: Regex,
+}
+
+impl TabPattern {
+    pub fn is_match(&self, text: &str) -> bool {
+        self.regex.is_match(text)
+    }
+}
+
+impl PartialEq for TabPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Eq for TabPattern {}
+
+impl<'de> Deserialize<'de> for TabPattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let pattern = String::deserialize(deserializer)?;
+        let regex = Regex::new(&pattern).map_err(D::Error::custom)?;
+        Ok(Self { pattern, regex })
+    }
+}
+
+impl Serialize for TabPattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let tabs: Tabs = toml::from_str(
+            r##"
+            [[rules]]
+            process = "^ssh$"
+            color = "#ff0000"
+
+            [[rules]]
+            process = "s"
+            color = "#00ff00"
+
+            [[rules]]
+            host = "(^|\\.)example\\.com$"
+            color = "#0000ff"
+            "##,
+        )
+        .unwrap();
+
+        let color = |target| tabs.matching_rule(target).and_then(|rule| rule.color);
+        assert_eq!(color(TabRuleTarget::Process("ssh")), Some(Rgb::new(0xff, 0, 0)));
+        assert_eq!(color(TabRuleTarget::Process("fish")), Some(Rgb::new(0, 0xff, 0)));
+        assert_eq!(color(TabRuleTarget::Process("vim")), None);
+        assert_eq!(color(TabRuleTarget::Host("docs.example.com")), Some(Rgb::new(0, 0, 0xff)));
+        assert_eq!(color(TabRuleTarget::Host("ssh.org")), None);
+    }
+
+    #[test]
+    fn ssh_profile_matches_host_without_user() {
+        let tabs: Tabs = toml::from_str(
+            r##"
+            [[ssh_profiles]]
+            host = "^prod-"
+            title_prefix = "PROD "
+            color = "#ff0000"
+            scrollback = 1000000
+            "##,
+        )
+        .unwrap();
+
+        let profile = tabs.ssh_profile("admin@prod-db").unwrap();
+        assert_eq!(profile.title("admin@prod-db"), "PROD admin@prod-db");
+        assert_eq!(profile.color, Some(Rgb::new(0xff, 0, 0)));
+        assert_eq!(profile.scrollback(), Some(MAX_SCROLLBACK_LINES as usize));
+        assert!(tabs.ssh_profile("prod-db@staging").is_none());
+    }
+
+    #[test]
+    fn invalid_pattern_is_rejected() {
+        assert!(toml::from_str::<TabRule>("process = \"(\"").is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Deserializer, Serialize, de};
 use toml::Value;
 
@@ -6,12 +8,40 @@ use tabor_terminal::term::Osc52;
 
 use crate::config::ui_config::{Program, StringVisitor};
 
-#[derive(ConfigDeserialize, Serialize, Default, Clone, Debug, PartialEq)]
+#[derive(ConfigDeserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct Terminal {
     /// OSC52 support mode.
     pub osc52: SerdeOsc52,
     /// Path to a shell program to run on startup.
     pub shell: Option<Program>,
+    /// Input typed into the shell of new terminal tabs once it's ready.
+    pub startup_input: Option<String>,
+    /// Time in milliseconds a closed tab's shell has to exit before it is killed.
+    shutdown_grace_ms: u16,
+    /// Approximate size in bytes the scrollback history of a tab may grow to.
+    pub max_scrollback_bytes: Option<usize>,
+}
+
+impl Default for Terminal {
+    fn default() -> Self {
+        Self {
+            osc52: Default::default(),
+            shell: Default::default(),
+            startup_input: Default::default(),
+            shutdown_grace_ms: 500,
+            max_scrollback_bytes: Default::default(),
+        }
+    }
+}
+
+impl Terminal {
+    /// Grace period between `SIGTERM` and `SIGKILL`, `None` when it's disabled.
+    pub fn shutdown_grace(&self) -> Option<Duration> {
+        match self.shutdown_grace_ms {
+            0 => None,
+            millis => Some(Duration::from_millis(u64::from(millis))),
+        }
+    }
 }
 
 #[derive(SerdeReplace, Serialize, Default, Copy, Clone, Debug, PartialEq)]
@@ -0,0 +1,64 @@
+use serde::de::Error as SerdeError;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use tabor_config_derive::{ConfigDeserialize, SerdeReplace};
+
+/// Touchscreen configuration.
+#[derive(ConfigDeserialize, Serialize, Copy, Clone, Debug, PartialEq)]
+pub struct Touch {
+    /// Zoom by pinching with two fingers.
+    pub zoom_enabled: bool,
+
+    /// Font size change per pixel the fingers move apart while pinching.
+    zoom_sensitivity: ZoomSensitivity,
+}
+
+impl Default for Touch {
+    fn default() -> Self {
+        Self { zoom_enabled: true, zoom_sensitivity: Default::default() }
+    }
+}
+
+impl Touch {
+    pub fn zoom_sensitivity(self) -> f32 {
+        self.zoom_sensitivity.0
+    }
+}
+
+#[derive(SerdeReplace, Serialize, Copy, Clone, Debug, PartialEq)]
+struct ZoomSensitivity(f32);
+
+impl Default for ZoomSensitivity {
+    fn default() -> Self {
+        Self(0.01)
+    }
+}
+
+impl<'de> Deserialize<'de> for ZoomSensitivity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let sensitivity = f32::deserialize(deserializer)?;
+
+        if sensitivity.is_finite() && sensitivity > 0. {
+            Ok(Self(sensitivity))
+        } else {
+            Err(SerdeError::custom(format!("zoom sensitivity must be positive, got {sensitivity}")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zoom_sensitivity_must_be_positive() {
+        let touch: Touch = toml::from_str("zoom_sensitivity = 0.05").unwrap();
+        assert_eq!(touch.zoom_sensitivity(), 0.05);
+
+        let touch: Touch = toml::from_str("zoom_sensitivity = -1.0").unwrap();
+        assert_eq!(touch.zoom_sensitivity(), Touch::default().zoom_sensitivity());
+    }
+}
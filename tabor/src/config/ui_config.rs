@@ -30,9 +30,13 @@ use crate::config::debug::Debug;
 use crate::config::font::Font;
 use crate::config::general::General;
 use crate::config::mouse::Mouse;
+use crate::config::notify::Notify;
 use crate::config::scrolling::Scrolling;
 use crate::config::selection::Selection;
+use crate::config::tabs::Tabs;
 use crate::config::terminal::Terminal;
+use crate::config::touch::Touch;
+use crate::config::web::WebConfig;
 use crate::config::window::WindowConfig;
 
 /// Regex used for the default URL hint.
@@ -66,15 +70,24 @@ pub struct UiConfig {
     /// Mouse configuration.
     pub mouse: Mouse,
 
+    /// Touchscreen configuration.
+    pub touch: Touch,
+
     /// Debug options.
     pub debug: Debug,
 
     /// Bell configuration.
     pub bell: BellConfig,
 
+    /// Native notification configuration.
+    pub notify: Notify,
+
     /// RGB values for colors.
     pub colors: Colors,
 
+    /// Named color schemes selected with `:theme`, each overriding `colors`.
+    pub themes: HashMap<String, Colors>,
+
     /// Path where config was loaded from.
     #[config(skip)]
     #[serde(skip_serializing)]
@@ -86,6 +99,12 @@ pub struct UiConfig {
     /// Config for the tabor_terminal itself.
     pub terminal: Terminal,
 
+    /// Web tab configuration.
+    pub web: WebConfig,
+
+    /// Tab configuration.
+    pub tabs: Tabs,
+
     /// Keyboard configuration.
     keyboard: Keyboard,
 
@@ -120,6 +139,7 @@ impl UiConfig {
         TermConfig {
             semantic_escape_chars: self.selection.semantic_escape_chars.clone(),
             scrolling_history: self.scrolling.history() as usize,
+            scrolling_history_bytes: self.terminal.max_scrollback_bytes,
             vi_mode_cursor_style: self.cursor.vi_mode_style(),
             default_cursor_style: self.cursor.style(),
             osc52: self.terminal.osc52.0,
@@ -136,6 +156,7 @@ impl UiConfig {
             working_directory,
             shell,
             drain_on_exit: false,
+            shutdown_grace: self.terminal.shutdown_grace(),
             env: HashMap::new(),
             #[cfg(target_os = "windows")]
             escape_args: false,
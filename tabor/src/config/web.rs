@@ -0,0 +1,326 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use tabor_config_derive::ConfigDeserialize;
+
+use crate::config::tabs::TabPattern;
+use crate::config::ui_config::Percentage;
+
+/// User agent of the `mobile-safari` preset.
+const MOBILE_SAFARI_USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) \
+                                        AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 \
+                                        Mobile/15E148 Safari/604.1";
+
+/// User agent of the `chrome` preset.
+const CHROME_USER_AGENT: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) \
+                                 AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 \
+                                 Safari/537.36";
+
+/// Minimum distance scrolled by each key press in normal mode.
+const MIN_SCROLL_STEP: u32 = 8;
+
+/// Minimum fraction of the page height scrolled by half-page motions.
+const MIN_HALF_PAGE: f32 = 0.05;
+
+/// Web tab configuration.
+#[derive(ConfigDeserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct WebConfig {
+    /// Search engine URL templates by keyword, with `%s` replaced by the query.
+    pub search_engines: HashMap<String, String>,
+
+    /// Select an open tab showing the same page instead of opening a duplicate.
+    pub switch_to_existing: bool,
+
+    /// Start the omnibar opened with `o` with the URL of the page.
+    pub prefill_omnibar: bool,
+
+    /// User agent of new web views, either a preset name or a custom string.
+    user_agent: Option<String>,
+
+    /// Query parameters removed from opened URLs, matching by prefix when ending with `*`.
+    pub strip_query_params: Vec<String>,
+
+    /// Highlight all matches of find, instead of only selecting the current one.
+    pub highlight_matches: bool,
+
+    /// Require a second Enter before submitting forms in insert mode.
+    pub confirm_form_submit: bool,
+
+    /// Include the values of password fields in form dumps.
+    pub reveal_form_passwords: bool,
+
+    /// Shell command opened in a terminal tab by `gC`, with `%s` replaced by the page URL.
+    pub terminal_command: String,
+
+    /// Maximum number of Web Inspector messages queued per IPC session.
+    pub inspector_queue_limit: usize,
+
+    /// Maximum number of bytes of page text returned by the `get_page_text` IPC request.
+    pub page_text_limit: usize,
+
+    /// Scrolling in normal mode.
+    pub scroll: WebScroll,
+
+    /// Animate scrolling in normal mode.
+    pub smooth_scroll: bool,
+
+    /// Normal mode bindings by key sequence, overriding the defaults.
+    pub bindings: HashMap<String, WebAction>,
+
+    /// Pages showing link hints as soon as they're loaded.
+    pub auto_hints: Vec<WebAutoHints>,
+
+    /// Pages forcing dark mode on or off, overriding the toggle of their tab.
+    pub dark_mode: Vec<WebDarkMode>,
+
+    /// Pages forcing autofill on or off, instead of enabling it while web tabs are open.
+    pub autofill: Vec<WebAutofill>,
+
+    /// Handling of links opening a new window, like `target="_blank"` links.
+    pub new_window_links: WebNewWindowLinks,
+
+    /// Only follow previous and next page links to the same origin.
+    pub same_origin_pages: bool,
+
+    /// Pages allowed to post notifications, which are shown as native notifications.
+    pub notifications: Vec<TabPattern>,
+
+    /// Page zoom toggled by double tapping the trackpad.
+    pub double_tap_zoom: f64,
+
+    /// Selectors of elements hidden by focus mode, in addition to common cookie banners.
+    pub focus_mode_selectors: Vec<String>,
+}
+
+impl Default for WebConfig {
+    fn default() -> Self {
+        let google = (String::from("g"), String::from("https://www.google.com/search?q=%s"));
+        Self {
+            search_engines: HashMap::from([google]),
+            switch_to_existing: false,
+            prefill_omnibar: false,
+            user_agent: None,
+            strip_query_params: Vec::new(),
+            highlight_matches: false,
+            confirm_form_submit: false,
+            reveal_form_passwords: false,
+            terminal_command: String::from("curl -sSL %s | less"),
+            inspector_queue_limit: 10_000,
+            page_text_limit: 1_000_000,
+            scroll: Default::default(),
+            smooth_scroll: false,
+            bindings: HashMap::new(),
+            auto_hints: Vec::new(),
+            dark_mode: Vec::new(),
+            autofill: Vec::new(),
+            new_window_links: Default::default(),
+            same_origin_pages: false,
+            notifications: Vec::new(),
+            double_tap_zoom: 1.5,
+            focus_mode_selectors: Vec::new(),
+        }
+    }
+}
+
+impl WebConfig {
+    /// Custom user agent of new web views, `None` keeping the Safari user agent.
+    pub fn user_agent(&self) -> Option<&str> {
+        self.user_agent.as_deref().and_then(user_agent_preset)
+    }
+
+    /// Find the first automatic hints matching a page URL.
+    pub fn auto_hints(&self, url: &str) -> Option<&WebAutoHints> {
+        self.auto_hints.iter().find(|hints| hints.url.is_match(url))
+    }
+
+    /// Dark mode of the first rule matching a page URL.
+    pub fn dark_mode(&self, url: &str) -> Option<bool> {
+        self.dark_mode.iter().find(|rule| rule.url.is_match(url)).map(|rule| rule.enabled)
+    }
+
+    /// Autofill of the first rule matching a page URL.
+    pub fn autofill(&self, url: &str) -> Option<bool> {
+        self.autofill.iter().find(|rule| rule.url.is_match(url)).map(|rule| rule.enabled)
+    }
+
+    /// Check if a page is allowed to post notifications.
+    pub fn notifications_allowed(&self, url: &str) -> bool {
+        self.notifications.iter().any(|pattern| pattern.is_match(url))
+    }
+}
+
+/// Link hints shown once a matching page is loaded.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct WebAutoHints {
+    /// Regex matching the page URL.
+    pub url: TabPattern,
+
+    /// JavaScript regex matching the URLs of links which get a hint, all links without it.
+    #[serde(default)]
+    pub links: Option<String>,
+}
+
+/// Dark mode forced for matching pages.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct WebDarkMode {
+    /// Regex matching the page URL.
+    pub url: TabPattern,
+
+    /// Invert the colors of matching pages.
+    pub enabled: bool,
+}
+
+/// Autofill of credentials and contact details forced for matching pages.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct WebAutofill {
+    /// Regex matching the page URL.
+    pub url: TabPattern,
+
+    /// Offer to fill in the forms of matching pages.
+    pub enabled: bool,
+}
+
+/// Handling of links opening a new window.
+#[derive(ConfigDeserialize, Serialize, Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WebNewWindowLinks {
+    /// Open a popup tab which the opening page can script.
+    #[default]
+    Popup,
+    /// Open the URL in a new tab.
+    Tab,
+    /// Open the URL in a new tab without selecting it.
+    BackgroundTab,
+}
+
+/// Resolve user agent preset names, `None` being the Safari user agent.
+pub fn user_agent_preset(user_agent: &str) -> Option<&str> {
+    match user_agent.trim() {
+        "" | "safari" => None,
+        "mobile-safari" => Some(MOBILE_SAFARI_USER_AGENT),
+        "chrome" => Some(CHROME_USER_AGENT),
+        user_agent => Some(user_agent),
+    }
+}
+
+/// Action of a web normal mode binding.
+#[derive(ConfigDeserialize, Serialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WebAction {
+    ScrollDown,
+    ScrollUp,
+    ScrollLeft,
+    ScrollRight,
+    ScrollHalfPageDown,
+    ScrollHalfPageUp,
+    ScrollToTop,
+    ScrollToBottom,
+    ScrollFarLeft,
+    ScrollFarRight,
+    Back,
+    Forward,
+    /// Open a link selected with hints.
+    HintOpen,
+    /// Open a link selected with hints in a new tab.
+    HintOpenNewTab,
+    /// Copy the URL of a link selected with hints.
+    HintCopyLink,
+    /// Show information about an element selected with hints.
+    HintInspect,
+    /// Scroll to a heading selected from the table of contents.
+    HintHeading,
+    CopyUrl,
+    /// Copy the page title, or its host without one.
+    CopyTitle,
+    /// Copy the URLs of all visible links.
+    CopyLinks,
+    DumpForm,
+    /// Focus the first input field and enter insert mode.
+    FocusInput,
+    /// Focus the next form field, staying in normal mode.
+    FocusNextInput,
+    /// Focus the previous form field, staying in normal mode.
+    FocusPreviousInput,
+    Find,
+    FindNext,
+    FindPrevious,
+    ToggleVisual,
+    ToggleVisualLine,
+    /// Open the URL in the clipboard.
+    OpenClipboard,
+    OpenClipboardNewTab,
+    /// Go up one level of the URL path.
+    UpUrl,
+    /// Go to the root of the URL.
+    RootUrl,
+    /// Follow the `rel="prev"` link of the page.
+    PreviousPage,
+    /// Follow the `rel="next"` link of the page.
+    NextPage,
+    NewTab,
+    CloseTab,
+    RestoreTab,
+    PreviousTab,
+    NextTab,
+    FirstTab,
+    LastTab,
+    Omnibar,
+    OmnibarNewTab,
+    Bookmarks,
+    BookmarksNewTab,
+    /// Open a URL in a new private tab.
+    PrivateTab,
+    TabSearch,
+    Reload,
+    /// Reset the page zoom and scroll to the top-left corner.
+    ResetView,
+    ViewSource,
+    Console,
+    /// Open the URL in a terminal tab with `web.terminal_command`.
+    OpenInTerminal,
+    SecurityInfo,
+    ToggleImages,
+    /// Disable or enable the scripts of pages in the tab.
+    ToggleJavaScript,
+    /// Invert the colors of pages without a dark theme.
+    ToggleDarkMode,
+    /// Unpin fixed and sticky elements and hide banners of pages in the tab.
+    ToggleFocusMode,
+    /// Pass all keys on to the page, until Escape is pressed twice.
+    Passthrough,
+    SetMark,
+    JumpToMark,
+    ToggleHelp,
+    /// Disable a default binding.
+    None,
+}
+
+/// Scroll distances of web normal mode.
+#[derive(ConfigDeserialize, Serialize, Copy, Clone, Debug, PartialEq)]
+pub struct WebScroll {
+    /// Pixels scrolled by each `hjkl` key press.
+    step: u32,
+
+    /// Fraction of the page height scrolled by `d` and `u`.
+    half_page: Percentage,
+}
+
+impl Default for WebScroll {
+    fn default() -> Self {
+        Self { step: 48, half_page: Percentage::new(0.5) }
+    }
+}
+
+impl WebScroll {
+    /// Pixels scrolled by each key press.
+    pub fn step(&self) -> f64 {
+        f64::from(self.step.max(MIN_SCROLL_STEP))
+    }
+
+    /// Fraction of the page height scrolled by half-page motions.
+    pub fn half_page(&self) -> f64 {
+        f64::from(self.half_page.as_f32().max(MIN_HALF_PAGE))
+    }
+}
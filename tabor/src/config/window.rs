@@ -211,17 +211,24 @@ pub struct TabPanelConfig {
 
     /// Panel width in logical pixels.
     pub width: usize,
+
+    /// Window edge the panel is attached to.
+    pub position: TabPanelPosition,
 }
 
 impl Default for TabPanelConfig {
     fn default() -> Self {
-        Self {
-            enabled: true,
-            width: 250,
-        }
+        Self { enabled: true, width: 250, position: Default::default() }
     }
 }
 
+#[derive(ConfigDeserialize, Serialize, Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TabPanelPosition {
+    #[default]
+    Left,
+    Right,
+}
+
 /// Window class hint.
 #[derive(SerdeReplace, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct Class {
@@ -5,14 +5,17 @@ use std::ffi::CString;
 use std::ffi::OsStr;
 #[cfg(not(any(target_os = "macos", windows)))]
 use std::fs;
-use std::io;
+use std::io::{self, Read, Write};
 #[cfg(not(windows))]
 use std::os::unix::ffi::OsStringExt;
 #[cfg(windows)]
 use std::os::windows::process::CommandExt;
-use std::process::{Command, Stdio};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
 #[cfg(target_os = "openbsd")]
 use std::ptr;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
 
 #[rustfmt::skip]
 #[cfg(not(windows))]
@@ -23,6 +26,8 @@ use {
     std::path::PathBuf,
 };
 
+use tabor_terminal::thread;
+
 #[cfg(not(windows))]
 use libc::pid_t;
 #[cfg(windows)]
@@ -112,6 +117,130 @@ where
     }
 }
 
+/// Maximum number of bytes captured from the output of a filter command.
+pub const FILTER_OUTPUT_LIMIT: usize = 1024 * 1024;
+
+/// Time a filter command may run before it is killed.
+pub const FILTER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Output of a filter command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterOutput {
+    pub text: String,
+
+    /// Whether the output was cut off at [`FILTER_OUTPUT_LIMIT`].
+    pub truncated: bool,
+}
+
+/// Run a shell command with `input` on its stdin and capture its stdout.
+///
+/// This blocks until the command exits, so it should be called off the event loop. Commands
+/// producing more than [`FILTER_OUTPUT_LIMIT`] bytes are killed once the limit is reached, commands
+/// running longer than [`FILTER_TIMEOUT`] fail. On failure, the error contains the first line
+/// written to stderr.
+pub fn run_filter(
+    command: &str,
+    input: String,
+    working_directory: Option<&Path>,
+) -> Result<FilterOutput, String> {
+    #[cfg(not(windows))]
+    let mut process = Command::new("sh");
+    #[cfg(not(windows))]
+    process.args(["-c", command]).process_group(0);
+
+    #[cfg(windows)]
+    let mut process = Command::new("cmd");
+    #[cfg(windows)]
+    process.args(["/C", command]).creation_flags(CREATE_NO_WINDOW);
+
+    if let Some(working_directory) = working_directory {
+        process.current_dir(working_directory);
+    }
+
+    let mut child = process
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("Unable to run {command}: {err}"))?;
+
+    // Feed stdin and drain stderr concurrently, so neither pipe can fill up and block the command.
+    let mut stdin = child.stdin.take().expect("piped stdin");
+    thread::spawn_named("filter stdin", move || {
+        let _ = stdin.write_all(input.as_bytes());
+    });
+    let mut stderr = child.stderr.take().expect("piped stderr");
+    let stderr = thread::spawn_named("filter stderr", move || {
+        let mut text = Vec::new();
+        let _ = stderr.read_to_end(&mut text);
+        String::from_utf8_lossy(&text).into_owned()
+    });
+
+    // Read stdout on its own thread, so a command which hangs can be killed after the timeout.
+    let deadline = Instant::now() + FILTER_TIMEOUT;
+    let stdout = child.stdout.take().expect("piped stdout");
+    let (output_sender, output_receiver) = mpsc::channel();
+    thread::spawn_named("filter stdout", move || {
+        let mut output = Vec::new();
+        let result = stdout.take(FILTER_OUTPUT_LIMIT as u64 + 1).read_to_end(&mut output);
+        let _ = output_sender.send((output, result));
+    });
+
+    let timed_out = || format!("{command} timed out after {}s", FILTER_TIMEOUT.as_secs());
+    let (mut output, result) = match output_receiver.recv_timeout(FILTER_TIMEOUT) {
+        Ok(output) => output,
+        Err(_) => {
+            kill_filter(&mut child);
+            return Err(timed_out());
+        },
+    };
+
+    let truncated = output.len() > FILTER_OUTPUT_LIMIT;
+    if truncated {
+        output.truncate(FILTER_OUTPUT_LIMIT);
+        kill_filter(&mut child);
+    }
+
+    // The command can keep running after closing its stdout.
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() >= deadline => {
+                kill_filter(&mut child);
+                return Err(timed_out());
+            },
+            Ok(None) => std::thread::sleep(Duration::from_millis(10)),
+            Err(err) => return Err(format!("Unable to wait for {command}: {err}")),
+        }
+    };
+    let stderr = stderr.join().unwrap_or_default();
+    result.map_err(|err| format!("Unable to read output of {command}: {err}"))?;
+
+    if !status.success() && !truncated {
+        return Err(match stderr.lines().find(|line| !line.trim().is_empty()) {
+            Some(line) => format!("{command}: {}", line.trim()),
+            None => format!("{command} failed with {status}"),
+        });
+    }
+
+    Ok(FilterOutput { text: String::from_utf8_lossy(&output).into_owned(), truncated })
+}
+
+/// Kill a filter command and reap it.
+///
+/// On Unix the whole process group is killed, so no process started by the shell keeps the
+/// output pipes open.
+fn kill_filter(child: &mut Child) {
+    #[cfg(not(windows))]
+    unsafe {
+        libc::kill(-(child.id() as pid_t), libc::SIGKILL);
+    }
+    #[cfg(windows)]
+    let _ = child.kill();
+
+    let _ = child.wait();
+}
+
 /// Get working directory of controlling process.
 #[cfg(not(any(windows, target_os = "openbsd")))]
 pub fn foreground_process_path(
@@ -219,3 +348,102 @@ pub fn foreground_process_name(
         Ok(name.to_string())
     }
 }
+
+/// Get the command line arguments of the shell's foreground process.
+#[cfg(not(windows))]
+pub fn foreground_process_args(
+    master_fd: RawFd,
+    shell_pid: u32,
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut pid = unsafe { libc::tcgetpgrp(master_fd) };
+    if pid < 0 {
+        pid = shell_pid as pid_t;
+    }
+
+    #[cfg(target_os = "macos")]
+    return Ok(macos::proc::arguments(pid)?);
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        #[cfg(target_os = "freebsd")]
+        let cmdline_path = format!("/compat/linux/proc/{pid}/cmdline");
+        #[cfg(not(target_os = "freebsd"))]
+        let cmdline_path = format!("/proc/{pid}/cmdline");
+
+        let cmdline = fs::read(cmdline_path)?;
+        let args = cmdline
+            .split(|&byte| byte == 0)
+            .filter(|arg| !arg.is_empty())
+            .map(|arg| String::from_utf8_lossy(arg).into_owned())
+            .collect();
+        Ok(args)
+    }
+}
+
+/// Extract the destination from the arguments of an `ssh` invocation.
+///
+/// The returned destination keeps its `user@` part but drops `ssh://` and any port.
+#[cfg(not(windows))]
+pub fn ssh_destination(args: &[String]) -> Option<String> {
+    // Options of OpenSSH's client which take an argument.
+    const OPTIONS_WITH_ARGUMENT: &str = "BbcDEeFIiJLlmOoPpQRSWw";
+
+    let mut args = args.iter().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            return args.next().map(|arg| normalize_ssh_destination(arg));
+        }
+
+        let Some(flags) = arg.strip_prefix('-').filter(|flags| !flags.is_empty()) else {
+            return Some(normalize_ssh_destination(arg));
+        };
+
+        // Flags can be combined, the first one taking an argument consumes the rest.
+        for (i, flag) in flags.char_indices() {
+            if OPTIONS_WITH_ARGUMENT.contains(flag) {
+                if i + flag.len_utf8() == flags.len() {
+                    args.next();
+                }
+                break;
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(not(windows))]
+fn normalize_ssh_destination(destination: &str) -> String {
+    match destination.strip_prefix("ssh://") {
+        Some(destination) => {
+            let destination = destination.split('/').next().unwrap_or_default();
+            match destination.rsplit_once(':') {
+                Some((host, port)) if port.bytes().all(|byte| byte.is_ascii_digit()) => {
+                    host.to_owned()
+                },
+                _ => destination.to_owned(),
+            }
+        },
+        None => destination.to_owned(),
+    }
+}
+
+#[cfg(all(test, not(windows)))]
+mod tests {
+    use super::*;
+
+    fn destination(command: &str) -> Option<String> {
+        let args: Vec<_> = command.split_whitespace().map(String::from).collect();
+        ssh_destination(&args)
+    }
+
+    #[test]
+    fn ssh_destination_skips_options() {
+        assert_eq!(destination("ssh host"), Some(String::from("host")));
+        assert_eq!(destination("ssh -v -p 2222 user@host ls"), Some(String::from("user@host")));
+        assert_eq!(destination("ssh -vp2222 -A host"), Some(String::from("host")));
+        assert_eq!(destination("ssh -i key -- host"), Some(String::from("host")));
+        assert_eq!(destination("ssh ssh://user@host:2222"), Some(String::from("user@host")));
+        assert_eq!(destination("ssh -V"), None);
+    }
+}
@@ -29,6 +29,7 @@ pub struct RenderableContent<'a> {
     cursor: RenderableCursor,
     cursor_shape: CursorShape,
     cursor_point: Point<usize>,
+    cursor_color: Option<Rgb>,
     search: Option<HintMatches<'a>>,
     hint: Option<Hint<'a>>,
     config: &'a UiConfig,
@@ -81,12 +82,50 @@ impl<'a> RenderableContent<'a> {
             focused_match,
             cursor_shape,
             cursor_point,
+            cursor_color: display.cursor_color,
             search,
             config,
             hint,
         }
     }
 
+    /// Content of a split pane without keyboard focus.
+    pub fn unfocused<T: EventListener>(
+        config: &'a UiConfig,
+        colors: &'a List,
+        size: &'a SizeInfo,
+        term: &'a Term<T>,
+        cursor_color: Option<Rgb>,
+    ) -> Self {
+        let terminal_content = term.renderable_content();
+
+        let cursor_shape = if terminal_content.cursor.shape == CursorShape::Hidden {
+            CursorShape::Hidden
+        } else if config.cursor.unfocused_hollow {
+            CursorShape::HollowBlock
+        } else {
+            terminal_content.cursor.shape
+        };
+
+        let cursor_point = terminal_content.cursor.point;
+        let display_offset = terminal_content.display_offset;
+        let cursor_point = term::point_to_viewport(display_offset, cursor_point).unwrap();
+
+        Self {
+            colors,
+            size,
+            cursor: RenderableCursor::new_hidden(),
+            terminal_content,
+            focused_match: None,
+            cursor_shape,
+            cursor_point,
+            cursor_color,
+            search: None,
+            config,
+            hint: None,
+        }
+    }
+
     /// Viewport offset.
     pub fn display_offset(&self) -> usize {
         self.terminal_content.display_offset
@@ -112,13 +151,15 @@ impl<'a> RenderableContent<'a> {
     /// Assemble the information required to render the terminal cursor.
     fn renderable_cursor(&mut self, cell: &RenderableCell) -> RenderableCursor {
         // Cursor colors.
-        let color = if self.terminal_content.mode.contains(TermMode::VI) {
-            self.config.colors.vi_mode_cursor
-        } else {
-            self.config.colors.cursor
+        let vi_mode = self.terminal_content.mode.contains(TermMode::VI);
+        let color =
+            if vi_mode { self.config.colors.vi_mode_cursor } else { self.config.colors.cursor };
+        let cursor_color = match self.cursor_color {
+            // Per-tab override takes precedence over config and escape sequences.
+            Some(override_color) if !vi_mode => CellRgb::Rgb(override_color),
+            _ => self.terminal_content.colors[NamedColor::Cursor]
+                .map_or(color.background, |c| CellRgb::Rgb(Rgb(c))),
         };
-        let cursor_color = self.terminal_content.colors[NamedColor::Cursor]
-            .map_or(color.background, |c| CellRgb::Rgb(Rgb(c)));
         let text_color = color.foreground;
 
         let insufficient_contrast = (!matches!(cursor_color, CellRgb::Rgb(_))
@@ -1,5 +1,7 @@
 //! Convert a cursor into an iterator of rects.
 
+use std::time::{Duration, Instant};
+
 use tabor_terminal::vte::ansi::CursorShape;
 
 use crate::display::SizeInfo;
@@ -15,9 +17,7 @@ pub trait IntoRects {
 
 impl IntoRects for RenderableCursor {
     fn rects(self, size_info: &SizeInfo, thickness: f32) -> CursorRects {
-        let point = self.point();
-        let x = point.column.0 as f32 * size_info.cell_width() + size_info.padding_x();
-        let y = point.line as f32 * size_info.cell_height() + size_info.padding_y();
+        let (x, y) = cell_origin(&self, size_info);
 
         let mut width = size_info.cell_width();
         let height = size_info.cell_height();
@@ -35,6 +35,117 @@ impl IntoRects for RenderableCursor {
     }
 }
 
+/// Duration of the cursor's motion between two cells.
+const CURSOR_MOTION_DURATION: Duration = Duration::from_millis(80);
+
+/// Opacity of the block cursor while it's moving.
+const MOVING_BLOCK_ALPHA: f32 = 0.5;
+
+/// Smooth cursor motion between cells.
+#[derive(Debug, Default)]
+pub struct CursorMotion {
+    /// Pixel position the cursor is moving away from.
+    from: (f32, f32),
+
+    /// Pixel position of the cursor's cell.
+    to: Option<(f32, f32)>,
+
+    /// Start of the current motion.
+    start: Option<Instant>,
+
+    /// Viewport offset of the last update.
+    display_offset: usize,
+}
+
+impl CursorMotion {
+    /// Move towards the cursor's cell, returning the current pixel offset from it.
+    ///
+    /// Returns `None` once the cursor has reached its cell.
+    pub fn update(
+        &mut self,
+        cursor: &RenderableCursor,
+        size_info: &SizeInfo,
+        display_offset: usize,
+    ) -> Option<(f32, f32)> {
+        let target = cell_origin(cursor, size_info);
+
+        // Jump straight to the new cell while scrolling through the history.
+        if self.to.is_none() || display_offset != self.display_offset {
+            self.display_offset = display_offset;
+            self.to = Some(target);
+            self.start = None;
+            return None;
+        }
+
+        let now = Instant::now();
+        if self.to != Some(target) {
+            self.from = self.position(now).or(self.to).unwrap_or(target);
+            self.to = Some(target);
+            self.start = Some(now);
+        }
+
+        let (x, y) = self.position(now)?;
+        Some((x - target.0, y - target.1))
+    }
+
+    /// Check whether the cursor has reached its cell.
+    pub fn completed(&self) -> bool {
+        self.start.is_none()
+    }
+
+    /// Current pixel position of the cursor, if it's still moving.
+    fn position(&mut self, now: Instant) -> Option<(f32, f32)> {
+        let (start, to) = (self.start?, self.to?);
+
+        let progress =
+            now.duration_since(start).as_secs_f32() / CURSOR_MOTION_DURATION.as_secs_f32();
+        if progress >= 1. {
+            self.start = None;
+            return None;
+        }
+
+        // Ease out, so the cursor settles gently into its cell.
+        let progress = 1. - (1. - progress).powi(3);
+        let x = self.from.0 + (to.0 - self.from.0) * progress;
+        let y = self.from.1 + (to.1 - self.from.1) * progress;
+        Some((x, y))
+    }
+}
+
+/// Create rects for a cursor which is still moving towards its cell.
+pub fn moving_rects(
+    cursor: RenderableCursor,
+    size_info: &SizeInfo,
+    thickness: f32,
+    offset: (f32, f32),
+) -> CursorRects {
+    let mut rects = match cursor.shape() {
+        // Block cursors are part of the cell content, so they need a rect while moving.
+        CursorShape::Block => {
+            let (x, y) = cell_origin(&cursor, size_info);
+            let width = size_info.cell_width() * cursor.width().get() as f32;
+            let height = size_info.cell_height();
+            RenderRect::new(x, y, width, height, cursor.color(), MOVING_BLOCK_ALPHA).into()
+        },
+        _ => cursor.rects(size_info, thickness),
+    };
+
+    for rect in rects.rects.iter_mut().flatten() {
+        rect.x += offset.0;
+        rect.y += offset.1;
+    }
+
+    rects
+}
+
+/// Pixel position of the cursor's cell.
+fn cell_origin(cursor: &RenderableCursor, size_info: &SizeInfo) -> (f32, f32) {
+    let point = cursor.point();
+    let x = point.column.0 as f32 * size_info.cell_width() + size_info.padding_x();
+    let y = point.line as f32 * size_info.cell_height() + size_info.padding_y();
+    (x, y)
+}
+
 /// Cursor rect iterator.
 #[derive(Default)]
 pub struct CursorRects {
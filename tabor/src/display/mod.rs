@@ -45,7 +45,7 @@ use crate::config::window::StartupMode;
 use crate::display::bell::VisualBell;
 use crate::display::color::{List, Rgb};
 use crate::display::content::{RenderableContent, RenderableCursor};
-use crate::display::cursor::IntoRects;
+use crate::display::cursor::{CursorMotion, IntoRects, moving_rects};
 use crate::display::damage::{DamageTracker, damage_y_to_viewport_y};
 use crate::display::hint::{HintMatch, HintState};
 use crate::display::meter::Meter;
@@ -292,6 +292,31 @@ impl SizeInfo<f32> {
         self.screen_lines = cmp::max(self.screen_lines.saturating_sub(count), MIN_SCREEN_LINES);
     }
 
+    /// Divide the grid into two side-by-side panes separated by a single column.
+    ///
+    /// The `ratio` is the share of columns given to the left pane. Returns `None` when there
+    /// isn't enough space for two panes.
+    pub fn split(&self, ratio: f32) -> Option<(SizeInfo, SizeInfo)> {
+        if self.columns < 3 {
+            return None;
+        }
+
+        let available = self.columns - 1;
+        let left_columns = ((available as f32 * ratio).round() as usize).clamp(1, available - 1);
+        let right_columns = available - left_columns;
+
+        let left = self.pane(0, left_columns);
+        let right = self.pane(left_columns + 1, right_columns);
+        Some((left, right))
+    }
+
+    /// Restrict the grid to `columns` columns, starting `offset` columns from the left.
+    fn pane(&self, offset: usize, columns: usize) -> SizeInfo {
+        let padding_x = self.padding_x + offset as f32 * self.cell_width;
+        let padding_right = self.width - padding_x - columns as f32 * self.cell_width;
+        SizeInfo { padding_x, padding_right: padding_right.floor(), columns, ..*self }
+    }
+
     /// Check if coordinates are inside the terminal grid.
     ///
     /// The padding, message bar or search are not counted as part of the grid.
@@ -365,6 +390,25 @@ impl DisplayUpdate {
     }
 }
 
+/// Layout of a window showing two tabs side by side.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SplitLayout {
+    /// Share of the columns given to the left pane.
+    pub ratio: f32,
+
+    /// Whether the left pane has keyboard focus.
+    pub focus_left: bool,
+}
+
+/// Split pane without keyboard focus.
+pub struct SplitPane<'a, T> {
+    /// Terminal shown in the pane, web views draw themselves.
+    pub terminal: Option<MutexGuard<'a, Term<T>>>,
+
+    /// Cursor color override of the pane's tab.
+    pub cursor_color: Option<Rgb>,
+}
+
 /// The display wraps a window, font rasterizer, and GPU renderer.
 pub struct Display {
     pub window: Window,
@@ -389,6 +433,24 @@ pub struct Display {
     /// UI cursor visibility for blinking.
     pub cursor_hidden: bool,
 
+    /// Smooth motion of the terminal cursor between cells.
+    pub cursor_motion: CursorMotion,
+
+    /// Cursor color override of the active tab.
+    pub cursor_color: Option<Rgb>,
+
+    /// Layout of the window split, if it shows two tabs side by side.
+    pub split: Option<SplitLayout>,
+
+    /// Size of the split pane without keyboard focus.
+    pub pane_size_info: Option<SizeInfo>,
+
+    /// Whether keyboard input is broadcast to other tabs.
+    pub broadcast: bool,
+
+    /// Register of the active macro recording.
+    pub macro_recording: Option<char>,
+
     pub visual_bell: VisualBell,
 
     /// Mapped RGB values for each terminal color.
@@ -487,7 +549,7 @@ impl Display {
         );
         #[cfg(not(target_os = "macos"))]
         let panel_dimensions = PanelDimensions::default();
-        let panel_padding = panel_dimensions.width;
+        let (padding_left, padding_right) = panel_dimensions.padding(padding.0);
         let dynamic_padding = config.window.dynamic_padding
             && config.window.dimensions().is_none()
             && panel_dimensions.columns == 0;
@@ -498,8 +560,8 @@ impl Display {
             viewport_size.height as f32,
             cell_width,
             cell_height,
-            padding.0 + panel_padding,
-            padding.0,
+            padding_left,
+            padding_right,
             padding.1,
             dynamic_padding,
         );
@@ -593,6 +655,12 @@ impl Display {
             hint_mouse_point: Default::default(),
             pending_update: Default::default(),
             cursor_hidden: Default::default(),
+            cursor_motion: Default::default(),
+            cursor_color: Default::default(),
+            split: Default::default(),
+            pane_size_info: Default::default(),
+            broadcast: Default::default(),
+            macro_recording: Default::default(),
             meter: Default::default(),
             ime: Default::default(),
         })
@@ -756,7 +824,7 @@ impl Display {
         );
         #[cfg(not(target_os = "macos"))]
         let panel_dimensions = PanelDimensions::default();
-        let panel_padding = panel_dimensions.width;
+        let (padding_left, padding_right) = panel_dimensions.padding(padding.0);
         let dynamic_padding = config.window.dynamic_padding && panel_dimensions.columns == 0;
 
         let mut new_size = SizeInfo::new(
@@ -764,8 +832,8 @@ impl Display {
             height,
             cell_width,
             cell_height,
-            padding.0 + panel_padding,
-            padding.0,
+            padding_left,
+            padding_right,
             padding.1,
             dynamic_padding,
         );
@@ -776,10 +844,23 @@ impl Display {
             self.tab_panel.set_dimensions(panel_dimensions);
         }
 
+        // Give the focused pane of a split its share of the columns.
+        let mut pane_size = None;
+        if let Some((left, right)) = self.split.and_then(|split| new_size.split(split.ratio)) {
+            let focus_left = self.split.is_some_and(|split| split.focus_left);
+            let (focused, unfocused) = if focus_left { (left, right) } else { (right, left) };
+            new_size = focused;
+            pane_size = Some(unfocused);
+        }
+
         // Update number of column/lines in the viewport.
         let message_bar_lines = message_buffer.message().map_or(0, |m| m.text(&new_size).len());
         let status_lines = usize::from(web_status_bar);
         new_size.reserve_lines(message_bar_lines + status_lines);
+        self.pane_size_info = pane_size.map(|mut pane_size| {
+            pane_size.reserve_lines(message_bar_lines + status_lines);
+            pane_size
+        });
 
         // Update resize increments.
         if config.window.resize_increments {
@@ -848,6 +929,7 @@ impl Display {
     /// A reference to Term whose state is being drawn must be provided.
     ///
     /// This call may block if vsync is enabled.
+    #[allow(clippy::too_many_arguments)]
     pub fn draw<T: EventListener>(
         &mut self,
         mut terminal: MutexGuard<'_, Term<T>>,
@@ -856,6 +938,7 @@ impl Display {
         config: &UiConfig,
         search_state: &mut SearchState,
         command_state: &CommandState,
+        split_pane: Option<SplitPane<'_, T>>,
     ) {
         // Collect renderable content before the terminal is dropped.
         let mut content = RenderableContent::new(config, self, &terminal, search_state);
@@ -897,10 +980,22 @@ impl Display {
 
         // Add damage from tabor's UI elements overlapping terminal.
 
+        // Animate cursor movement, unless it is hidden.
+        let cursor_offset = if config.cursor.smooth_motion && cursor.shape() != CursorShape::Hidden
+        {
+            self.cursor_motion.update(&cursor, &size_info, display_offset)
+        } else {
+            self.cursor_motion = CursorMotion::default();
+            None
+        };
+
         let requires_full_damage = self.visual_bell.intensity() != 0.
+            || cursor_offset.is_some()
             || self.hint_state.active()
             || search_state.regex().is_some()
-            || command_active;
+            || command_active
+            || split_pane.is_some()
+            || self.broadcast;
         if requires_full_damage {
             self.damage_tracker.frame().mark_fully_damaged();
             self.damage_tracker.next_frame().mark_fully_damaged();
@@ -959,6 +1054,10 @@ impl Display {
 
         let mut rects = lines.rects(&metrics, &size_info);
 
+        if let Some(split_pane) = split_pane {
+            self.draw_split_pane(split_pane, config, &mut rects);
+        }
+
         if let Some(vi_cursor_point) = vi_cursor_point {
             // Indicate vi mode by showing the cursor's position in the top right corner.
             let line = (-vi_cursor_point.line.0 + size_info.bottommost_line().0) as usize;
@@ -971,15 +1070,24 @@ impl Display {
             self.draw_line_indicator(config, total_lines, None, display_offset);
         };
 
+        if let Some(register) = self.macro_recording {
+            self.draw_macro_indicator(config, register);
+        }
+
         // Draw cursor.
-        rects.extend(cursor.rects(&size_info, config.cursor.thickness()));
+        match cursor_offset {
+            Some(offset) => {
+                rects.extend(moving_rects(cursor, &size_info, config.cursor.thickness(), offset))
+            },
+            None => rects.extend(cursor.rects(&size_info, config.cursor.thickness())),
+        }
 
         #[cfg(target_os = "macos")]
         if self.tab_panel.is_enabled() {
             self.tab_panel.push_rects(&size_info, config, &mut rects);
             self.damage_tracker.frame().add_viewport_rect(
                 &size_info,
-                0,
+                self.tab_panel.x().round() as i32,
                 0,
                 self.tab_panel.width().round() as i32,
                 size_info.height() as i32,
@@ -1000,6 +1108,17 @@ impl Display {
             rects.push(visual_bell_rect);
         }
 
+        // Frame the window while broadcasting, so input is never sent to other tabs unknowingly.
+        if self.broadcast {
+            let color = config.colors.normal.red;
+            let (width, height) = (size_info.width(), size_info.height());
+            let border = (size_info.cell_width() * 0.25).max(2.).round();
+            rects.push(RenderRect::new(0., 0., width, border, color, 1.));
+            rects.push(RenderRect::new(0., height - border, width, border, color, 1.));
+            rects.push(RenderRect::new(0., 0., border, height, color, 1.));
+            rects.push(RenderRect::new(width - border, 0., border, height, color, 1.));
+        }
+
         // Handle IME positioning and command/search bar rendering.
         let footer_offset =
             if command_active || search_state.regex().is_some() { self.footer_offset() } else { 0. };
@@ -1100,9 +1219,14 @@ impl Display {
                 size_info.cell_height().mul_add(start_line as f32, size_info.padding_y())
                     + background_offset;
 
-            let bg = match message.ty() {
-                MessageType::Error => config.colors.normal.red,
-                MessageType::Warning => config.colors.normal.yellow,
+            let (fg, bg) = match message.ty() {
+                MessageType::Error => (config.colors.primary.background, config.colors.normal.red),
+                MessageType::Warning => {
+                    (config.colors.primary.background, config.colors.normal.yellow)
+                },
+                MessageType::Info => {
+                    (config.colors.footer_bar_foreground(), config.colors.footer_bar_background())
+                },
             };
 
             let x = 0;
@@ -1124,7 +1248,6 @@ impl Display {
             );
 
             // Relay messages to the user.
-            let fg = config.colors.primary.background;
             for (i, message_text) in text.iter().enumerate() {
                 self.draw_footer_bar_line_with_text_offset(
                     message_text,
@@ -1186,12 +1309,13 @@ impl Display {
         self.damage_tracker.swap_damage();
     }
 
-    pub fn draw_web(
+    pub fn draw_web<T: EventListener>(
         &mut self,
         scheduler: &mut Scheduler,
         message_buffer: &MessageBuffer,
         config: &UiConfig,
         command_state: &CommandState,
+        split_pane: Option<SplitPane<'_, T>>,
     ) {
         let size_info = self.size_info;
         let metrics = self.glyph_cache.font_metrics();
@@ -1208,12 +1332,16 @@ impl Display {
 
         let mut rects = Vec::new();
 
+        if let Some(split_pane) = split_pane {
+            self.draw_split_pane(split_pane, config, &mut rects);
+        }
+
         #[cfg(target_os = "macos")]
         if self.tab_panel.is_enabled() {
             self.tab_panel.push_rects(&size_info, config, &mut rects);
             self.damage_tracker.frame().add_viewport_rect(
                 &size_info,
-                0,
+                self.tab_panel.x().round() as i32,
                 0,
                 self.tab_panel.width().round() as i32,
                 size_info.height() as i32,
@@ -1266,9 +1394,14 @@ impl Display {
                 size_info.cell_height().mul_add(start_line as f32, size_info.padding_y())
                     + background_offset;
 
-            let bg = match message.ty() {
-                MessageType::Error => config.colors.normal.red,
-                MessageType::Warning => config.colors.normal.yellow,
+            let (fg, bg) = match message.ty() {
+                MessageType::Error => (config.colors.primary.background, config.colors.normal.red),
+                MessageType::Warning => {
+                    (config.colors.primary.background, config.colors.normal.yellow)
+                },
+                MessageType::Info => {
+                    (config.colors.footer_bar_foreground(), config.colors.footer_bar_background())
+                },
             };
 
             let x = 0;
@@ -1281,7 +1414,6 @@ impl Display {
             #[cfg(target_os = "macos")]
             self.tab_panel.draw_text(&size_info, config, &mut self.renderer, &mut self.glyph_cache);
 
-            let fg = config.colors.primary.background;
             for (i, message_text) in text.iter().enumerate() {
                 self.draw_footer_bar_line_with_text_offset(
                     message_text,
@@ -1635,6 +1767,59 @@ impl Display {
         }
     }
 
+    /// Draw the unfocused pane of a split and the divider between both panes.
+    fn draw_split_pane<T: EventListener>(
+        &mut self,
+        split_pane: SplitPane<'_, T>,
+        config: &UiConfig,
+        rects: &mut Vec<RenderRect>,
+    ) {
+        let Some(pane_size) = self.pane_size_info else {
+            return;
+        };
+
+        if let Some(terminal) = split_pane.terminal {
+            let metrics = self.glyph_cache.font_metrics();
+            let mut content = RenderableContent::unfocused(
+                config,
+                &self.colors,
+                &pane_size,
+                &terminal,
+                split_pane.cursor_color,
+            );
+            let mut lines = RenderLines::new();
+            let mut cells = Vec::new();
+            for cell in &mut content {
+                lines.update(&cell);
+                cells.push(cell);
+            }
+            let cursor = content.cursor();
+            drop(terminal);
+
+            self.renderer.resize(&pane_size);
+            self.renderer.draw_cells(&pane_size, &mut self.glyph_cache, cells.into_iter());
+            self.renderer.resize(&self.size_info);
+
+            rects.extend(lines.rects(&metrics, &pane_size));
+            rects.extend(cursor.rects(&pane_size, config.cursor.thickness()));
+        }
+
+        // Draw the divider in the column between both panes.
+        let left = if pane_size.padding_x() < self.size_info.padding_x() {
+            pane_size
+        } else {
+            self.size_info
+        };
+        let cell_width = left.cell_width();
+        let divider_width = (cell_width * 0.1).max(1.).round();
+        let x = left.padding_x() + left.columns() as f32 * cell_width;
+        let x = (x + (cell_width - divider_width) / 2.).round();
+        let y = left.padding_y();
+        let height = left.screen_lines() as f32 * left.cell_height();
+        let color = config.colors.primary.foreground;
+        rects.push(RenderRect::new(x, y, divider_width, height, color, 0.3));
+    }
+
     fn footer_offset(&self) -> f32 {
         let size_info = self.size_info;
         let grid_bottom =
@@ -1781,6 +1966,25 @@ impl Display {
         }
     }
 
+    /// Draw an indicator for the active macro recording in the bottom right corner.
+    fn draw_macro_indicator(&mut self, config: &UiConfig, register: char) {
+        let columns = self.size_info.columns();
+        let text = format!("recording @{register}");
+        let column = Column(columns.saturating_sub(text.len()));
+        let point = Point::new(self.size_info.screen_lines().saturating_sub(1), column);
+
+        // Damage the indicator for current and next frame.
+        let damage = LineDamageBounds::new(point.line, point.column.0, columns - 1);
+        self.damage_tracker.frame().damage_line(damage);
+        self.damage_tracker.next_frame().damage_line(damage);
+
+        let colors = &config.colors;
+        let fg = colors.line_indicator.foreground.unwrap_or(colors.primary.background);
+        let bg = colors.line_indicator.background.unwrap_or(colors.primary.foreground);
+        let glyph_cache = &mut self.glyph_cache;
+        self.renderer.draw_string(point, fg, bg, text.chars(), &self.size_info, glyph_cache);
+    }
+
     /// Highlight damaged rects.
     ///
     /// This function is for debug purposes only.
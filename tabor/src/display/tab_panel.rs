@@ -1,4 +1,4 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use winit::dpi::PhysicalPosition;
 use winit::event::{ElementState, KeyEvent, MouseButton};
@@ -16,11 +16,12 @@ use tabor_terminal::index::{Column, Point};
 use tabor_terminal::term::MIN_COLUMNS;
 
 use crate::config::UiConfig;
+use crate::config::window::TabPanelPosition;
 use crate::display::color::Rgb;
 use crate::display::SizeInfo;
 use crate::renderer::rects::RenderRect;
 use crate::renderer::{GlyphCache, Renderer};
-use crate::tab_panel::{TabPanelCommand, TabPanelGroup, TabPanelTab};
+use crate::tab_panel::{TabActivityKind, TabPanelCommand, TabPanelGroup, TabPanelTab};
 use crate::tabs::TabId;
 
 const RESIZE_HANDLE_WIDTH_PX: f64 = 6.0;
@@ -31,11 +32,26 @@ const TAB_INDENT_COLS: usize = 1;
 const ACTIVITY_INDICATOR_COLS: usize = 2;
 const ACTIVITY_INDICATOR_FILLED: char = '\u{25CF}';
 const ACTIVITY_INDICATOR_OUTLINE: char = '\u{25CB}';
+const ACTIVITY_INDICATOR_BELL: char = '\u{237E}';
+const ACTIVITY_INDICATOR_TITLE: char = '*';
 
 #[derive(Default, Clone, Copy)]
 pub struct PanelDimensions {
     pub columns: usize,
     pub width: f32,
+    /// Left edge of the panel in the window.
+    pub x: f32,
+    pub position: TabPanelPosition,
+}
+
+impl PanelDimensions {
+    /// Horizontal terminal padding leaving room for the panel, as `(left, right)`.
+    pub fn padding(&self, padding_x: f32) -> (f32, f32) {
+        match self.position {
+            TabPanelPosition::Left => (padding_x + self.width, padding_x),
+            TabPanelPosition::Right => (padding_x, padding_x + self.width),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -84,7 +100,13 @@ pub fn compute_panel_dimensions(
         return PanelDimensions::default();
     }
 
-    PanelDimensions { columns, width }
+    let position = config.window.tab_panel.position;
+    let x = match position {
+        TabPanelPosition::Left => 0.,
+        TabPanelPosition::Right => viewport_width - width,
+    };
+
+    PanelDimensions { columns, width, x, position }
 }
 
 #[derive(Default)]
@@ -92,6 +114,8 @@ pub struct TabPanel {
     enabled: bool,
     width_cols: usize,
     width_px: f32,
+    x: f32,
+    position: TabPanelPosition,
     groups: Vec<TabPanelGroup>,
     new_group_id: Option<usize>,
     edit: Option<EditState>,
@@ -121,12 +145,19 @@ impl TabPanel {
     pub fn set_dimensions(&mut self, dimensions: PanelDimensions) {
         self.width_cols = dimensions.columns;
         self.width_px = dimensions.width;
+        self.x = dimensions.x;
+        self.position = dimensions.position;
     }
 
     pub fn width(&self) -> f32 {
         self.width_px
     }
 
+    /// Left edge of the panel in the window.
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
     pub fn is_enabled(&self) -> bool {
         self.enabled && self.width_cols > 0
     }
@@ -279,6 +310,8 @@ impl TabPanel {
         position: PhysicalPosition<f64>,
         size_info: &SizeInfo,
     ) -> TabPanelCursorUpdate {
+        let window_position = position;
+        let position = self.panel_position(position);
         self.last_mouse_pos = Some(position);
 
         let panel_size_info = self.panel_size_info(size_info);
@@ -287,7 +320,7 @@ impl TabPanel {
         let capture = self.should_capture(Some(position)) || resize_hit;
 
         if resizing {
-            let width_px = self.resize.as_ref().unwrap().width(position);
+            let width_px = self.resize.as_ref().unwrap().width(window_position);
             return TabPanelCursorUpdate {
                 capture: true,
                 needs_redraw: true,
@@ -372,7 +405,8 @@ impl TabPanel {
         }
 
         if matches!(state, ElementState::Pressed) && self.is_on_resize_handle(position) {
-            self.resize = Some(ResizeState::new(self.width_px, position));
+            let window_position = PhysicalPosition::new(position.x + self.x as f64, position.y);
+            self.resize = Some(ResizeState::new(self.width_px, self.position, window_position));
             return TabPanelMouseUpdate { capture: true, needs_redraw: true, command: None };
         }
 
@@ -492,17 +526,16 @@ impl TabPanel {
         let ghost_drag_bg = mix(base, fg, 0.2);
         let divider = mix(base, fg, 0.2);
 
-        rects.push(RenderRect::new(0., 0., self.width_px, size_info.height(), panel_bg, 1.));
+        // Edges of the panel facing the terminal and the window border.
+        let (inner_x, outer_x) = match self.position {
+            TabPanelPosition::Left => (self.x + self.width_px - 1., self.x),
+            TabPanelPosition::Right => (self.x, self.x + self.width_px),
+        };
+
+        rects.push(RenderRect::new(self.x, 0., self.width_px, size_info.height(), panel_bg, 1.));
 
         if self.width_px >= 1.0 {
-            rects.push(RenderRect::new(
-                self.width_px - 1.0,
-                0.,
-                1.0,
-                size_info.height(),
-                divider,
-                1.0,
-            ));
+            rects.push(RenderRect::new(inner_x, 0., 1.0, size_info.height(), divider, 1.0));
         }
 
         let line_height = panel_size_info.cell_height();
@@ -514,17 +547,41 @@ impl TabPanel {
                 PanelItemKind::GroupHeader { .. } => header_bg,
                 PanelItemKind::GhostGroupHeader { .. } => ghost_header_bg,
                 PanelItemKind::Tab { tab } => {
-                    if item.style == RenderStyle::Ghost {
+                    let bg = if item.style == RenderStyle::Ghost {
                         ghost_bg
                     } else if tab.is_active {
                         active_bg
                     } else {
                         panel_bg
-                    }
+                    };
+
+                    // Tint private tabs, so they're not mistaken for regular ones.
+                    if tab.private { mix(bg, config.colors.normal.magenta, 0.2) } else { bg }
                 },
             };
 
-            rects.push(RenderRect::new(0., y, self.width_px, line_height, bg, 1.));
+            rects.push(RenderRect::new(self.x, y, self.width_px, line_height, bg, 1.));
+
+            // Accent stripe for tabs marked with a color.
+            if let PanelItemKind::Tab { tab: TabPanelTab { color: Some(color), .. } } = &item.kind {
+                let stripe_width = (panel_size_info.cell_width() * 0.4).max(1.);
+                let x = match self.position {
+                    TabPanelPosition::Left => outer_x,
+                    TabPanelPosition::Right => outer_x - stripe_width,
+                };
+                rects.push(RenderRect::new(x, y, stripe_width, line_height, *color, 1.));
+            }
+
+            // Stripe along the divider for tabs receiving broadcast input.
+            if let PanelItemKind::Tab { tab: TabPanelTab { broadcast: true, .. } } = &item.kind {
+                let stripe_width = (panel_size_info.cell_width() * 0.4).max(1.);
+                let x = match self.position {
+                    TabPanelPosition::Left => (inner_x - stripe_width).max(self.x),
+                    TabPanelPosition::Right => inner_x + 1.,
+                };
+                let color = config.colors.normal.red;
+                rects.push(RenderRect::new(x, y, stripe_width, line_height, color, 1.));
+            }
         }
 
         if let Some(DragState { item: DragItem::Tab(tab_id), dragging: true, .. }) =
@@ -535,7 +592,7 @@ impl TabPanel {
                     if let Some(line) = self.drag_ghost_line(position, &panel_size_info, &layout) {
                         let y = start_y + line as f32 * line_height;
                         rects.push(RenderRect::new(
-                            0.,
+                            self.x,
                             y,
                             self.width_px,
                             line_height,
@@ -671,7 +728,16 @@ impl TabPanel {
                     let indicator_cols = if tab.activity.is_some() { ACTIVITY_INDICATOR_COLS } else { 0 };
                     let text_col = indent + indicator_cols;
                     let close_col = self.width_cols.saturating_sub(1);
-                    let max_cols = self.width_cols.saturating_sub(text_col + 1);
+                    let duration = tab
+                        .command_start
+                        .map(|start| format_command_duration(now.saturating_duration_since(start)))
+                        .or_else(|| {
+                            let interval = tab.auto_reload?;
+                            Some(format!("↻{}", format_command_duration(interval)))
+                        });
+                    let duration_cols =
+                        duration.as_ref().map_or(0, |duration| duration.chars().count() + 1);
+                    let max_cols = self.width_cols.saturating_sub(text_col + duration_cols + 1);
                     let title = match &self.edit {
                         Some(edit) if edit.target == TabPanelEditTarget::Tab(tab.tab_id) => {
                             render_edit_text(&edit.text, edit.cursor)
@@ -734,6 +800,21 @@ impl TabPanel {
                         glyph_cache,
                     );
 
+                    if let Some(duration) = duration {
+                        let duration_col = close_col.saturating_sub(duration.chars().count());
+                        if duration_col > text_col {
+                            let point = Point::new(item.line, Column(duration_col));
+                            renderer.draw_string(
+                                point,
+                                mix(text_fg, base, 0.4),
+                                bg,
+                                duration.chars(),
+                                &panel_size_info,
+                                glyph_cache,
+                            );
+                        }
+                    }
+
                     if show_trailing_close && close_col > text_col
                     {
                         let point = Point::new(item.line, Column(close_col));
@@ -844,11 +925,11 @@ impl TabPanel {
 
     fn panel_size_info(&self, size_info: &SizeInfo) -> SizeInfo {
         SizeInfo::new(
-            self.width_px,
+            self.x + self.width_px,
             size_info.height(),
             size_info.cell_width(),
             self.panel_cell_height(size_info),
-            0.,
+            self.x,
             0.,
             size_info.padding_y(),
             false,
@@ -912,11 +993,21 @@ impl TabPanel {
             return false;
         }
 
-        let left = (self.width_px as f64 - RESIZE_HANDLE_WIDTH_PX).max(0.0);
-        let right = self.width_px as f64 + RESIZE_HANDLE_WIDTH_PX;
+        let width = self.width_px as f64;
+        let (left, right) = match self.position {
+            TabPanelPosition::Left => {
+                ((width - RESIZE_HANDLE_WIDTH_PX).max(0.0), width + RESIZE_HANDLE_WIDTH_PX)
+            },
+            TabPanelPosition::Right => (-RESIZE_HANDLE_WIDTH_PX, RESIZE_HANDLE_WIDTH_PX.min(width)),
+        };
         position.x >= left && position.x <= right
     }
 
+    /// Convert a window position to panel coordinates.
+    fn panel_position(&self, position: PhysicalPosition<f64>) -> PhysicalPosition<f64> {
+        PhysicalPosition::new(position.x - self.x as f64, position.y)
+    }
+
     fn update_drop_target(&mut self, position: PhysicalPosition<f64>, size_info: &SizeInfo) -> bool {
         let Some(drag) = self.drag.as_ref().filter(|drag| drag.dragging) else {
             if self.drop_target.take().is_some() {
@@ -1660,15 +1751,28 @@ struct GroupDropTarget {
 
 struct ResizeState {
     offset: f64,
+    panel_position: TabPanelPosition,
 }
 
 impl ResizeState {
-    fn new(width_px: f32, position: PhysicalPosition<f64>) -> Self {
-        Self { offset: width_px as f64 - position.x }
+    fn new(
+        width_px: f32,
+        panel_position: TabPanelPosition,
+        position: PhysicalPosition<f64>,
+    ) -> Self {
+        let offset = match panel_position {
+            TabPanelPosition::Left => width_px as f64 - position.x,
+            TabPanelPosition::Right => width_px as f64 + position.x,
+        };
+        Self { offset, panel_position }
     }
 
     fn width(&self, position: PhysicalPosition<f64>) -> f32 {
-        (position.x + self.offset).max(0.0) as f32
+        let width = match self.panel_position {
+            TabPanelPosition::Left => self.offset + position.x,
+            TabPanelPosition::Right => self.offset - position.x,
+        };
+        width.max(0.0) as f32
     }
 }
 
@@ -1792,6 +1896,22 @@ fn tab_activity_indicator(
 ) -> Option<ActivityIndicator> {
     let activity = tab.activity.as_ref()?;
 
+    match activity.unseen() {
+        Some(TabActivityKind::Bell) => {
+            return Some(ActivityIndicator {
+                glyph: ACTIVITY_INDICATOR_BELL,
+                color: config.colors.normal.yellow,
+            });
+        },
+        Some(TabActivityKind::Title) => {
+            return Some(ActivityIndicator {
+                glyph: ACTIVITY_INDICATOR_TITLE,
+                color: config.colors.normal.magenta,
+            });
+        },
+        _ => (),
+    }
+
     if activity.is_active(now) {
         return Some(ActivityIndicator {
             glyph: ACTIVITY_INDICATOR_FILLED,
@@ -1818,6 +1938,16 @@ fn tab_activity_indicator(
     })
 }
 
+/// Format the elapsed time of a running command, like `42s`, `3m07s` or `1h05m`.
+fn format_command_duration(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    match secs {
+        0..60 => format!("{secs}s"),
+        60..3600 => format!("{}m{:02}s", secs / 60, secs % 60),
+        _ => format!("{}h{:02}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
 fn mix(a: Rgb, b: Rgb, t: f32) -> Rgb {
     let mix_channel = |a: u8, b: u8| -> u8 {
         let a = a as f32;
@@ -1829,3 +1959,82 @@ fn mix(a: Rgb, b: Rgb, t: f32) -> Rgb {
 }
 
 const DRAG_THRESHOLD_PX: f64 = 4.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn panel_config(position: TabPanelPosition) -> UiConfig {
+        let mut config = UiConfig::default();
+        config.window.tab_panel.width = 100;
+        config.window.tab_panel.position = position;
+        config
+    }
+
+    fn panel(position: TabPanelPosition) -> TabPanel {
+        TabPanel { enabled: true, width_cols: 10, width_px: 100., position, ..Default::default() }
+    }
+
+    #[test]
+    fn left_panel_dimensions() {
+        let config = panel_config(TabPanelPosition::Left);
+        let dimensions = compute_panel_dimensions(&config, 10., 800., 5., 1.);
+        assert_eq!(dimensions.columns, 10);
+        assert_eq!(dimensions.width, 100.);
+        assert_eq!(dimensions.x, 0.);
+        assert_eq!(dimensions.padding(5.), (105., 5.));
+
+        let dimensions = compute_panel_dimensions(&config, 10., 800., 5., 2.);
+        assert_eq!(dimensions.columns, 20);
+        assert_eq!(dimensions.padding(5.), (205., 5.));
+    }
+
+    #[test]
+    fn right_panel_dimensions() {
+        let config = panel_config(TabPanelPosition::Right);
+        let dimensions = compute_panel_dimensions(&config, 10., 800., 5., 1.);
+        assert_eq!(dimensions.columns, 10);
+        assert_eq!(dimensions.x, 700.);
+        assert_eq!(dimensions.padding(5.), (5., 105.));
+
+        // Narrow windows keep room for the terminal.
+        let dimensions = compute_panel_dimensions(&config, 10., 100., 5., 1.);
+        assert_eq!(dimensions.columns, 7);
+        assert_eq!(dimensions.width, 70.);
+        assert_eq!(dimensions.x, 30.);
+    }
+
+    #[test]
+    fn disabled_panel_takes_no_space() {
+        let mut config = panel_config(TabPanelPosition::Right);
+        config.window.tab_panel.enabled = false;
+        let dimensions = compute_panel_dimensions(&config, 10., 800., 5., 1.);
+        assert_eq!(dimensions.columns, 0);
+        assert_eq!(dimensions.padding(5.), (5., 5.));
+    }
+
+    #[test]
+    fn resize_handle_faces_the_terminal() {
+        let left = panel(TabPanelPosition::Left);
+        assert!(left.is_on_resize_handle(PhysicalPosition::new(100., 0.)));
+        assert!(left.is_on_resize_handle(PhysicalPosition::new(105., 0.)));
+        assert!(!left.is_on_resize_handle(PhysicalPosition::new(0., 0.)));
+
+        let right = panel(TabPanelPosition::Right);
+        assert!(right.is_on_resize_handle(PhysicalPosition::new(0., 0.)));
+        assert!(right.is_on_resize_handle(PhysicalPosition::new(-5., 0.)));
+        assert!(!right.is_on_resize_handle(PhysicalPosition::new(100., 0.)));
+    }
+
+    #[test]
+    fn resize_follows_the_panel_edge() {
+        let position = PhysicalPosition::new(100., 0.);
+        let resize = ResizeState::new(100., TabPanelPosition::Left, position);
+        assert_eq!(resize.width(PhysicalPosition::new(120., 0.)), 120.);
+
+        let position = PhysicalPosition::new(0., 0.);
+        let resize = ResizeState::new(100., TabPanelPosition::Right, position);
+        assert_eq!(resize.width(PhysicalPosition::new(-20., 0.)), 120.);
+        assert_eq!(resize.width(PhysicalPosition::new(150., 0.)), 0.);
+    }
+}
@@ -9,11 +9,13 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use std::error::Error;
 use std::ffi::OsStr;
 use std::fmt::Debug;
+use std::fs;
+use std::io::{self, Write};
 #[cfg(not(windows))]
 use std::os::unix::io::RawFd;
 #[cfg(unix)]
 use std::os::unix::net::UnixStream;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 #[cfg(unix)]
 use std::sync::Arc;
@@ -25,6 +27,7 @@ use crossfont::Size as FontSize;
 use glutin::config::Config as GlutinConfig;
 use glutin::display::GetGlDisplay;
 use log::{debug, error, info, warn};
+use parking_lot::Mutex;
 use winit::application::ApplicationHandler;
 use winit::dpi::PhysicalPosition;
 use winit::event::{
@@ -35,7 +38,7 @@ use winit::event_loop::{ActiveEventLoop, ControlFlow, DeviceEvents, EventLoop, E
 #[cfg(target_os = "macos")]
 use winit::platform::macos::ActiveEventLoopExtMacOS;
 use winit::raw_window_handle::HasDisplayHandle;
-use winit::keyboard::{Key, ModifiersState, NamedKey};
+use winit::keyboard::{Key, ModifiersState, NamedKey, PhysicalKey};
 #[cfg(target_os = "macos")]
 use winit::window::CursorIcon;
 use winit::window::WindowId;
@@ -48,6 +51,8 @@ use tabor_terminal::selection::{Selection, SelectionType};
 use tabor_terminal::term::cell::Flags;
 use tabor_terminal::term::search::{Match, RegexSearch};
 use tabor_terminal::term::{self, ClipboardType, Term, TermMode};
+#[cfg(not(windows))]
+use tabor_terminal::tty;
 use tabor_terminal::vte::ansi::NamedColor;
 
 #[cfg(unix)]
@@ -55,15 +60,16 @@ use crate::cli::ParsedOptions;
 use crate::cli::{Options as CliOptions, WindowOptions};
 use crate::clipboard::Clipboard;
 use crate::config::Action;
-use crate::config::ui_config::{HintAction, HintInternalAction};
+use crate::config::ui_config::{HintAction, HintInternalAction, Program};
 use crate::config::{self, UiConfig};
 #[cfg(not(windows))]
 use crate::daemon::foreground_process_path;
-use crate::daemon::spawn_daemon;
+use crate::daemon::{self, FilterOutput, spawn_daemon};
 use crate::display::color::Rgb;
 use crate::display::hint::HintMatch;
 use crate::display::window::{ImeInhibitor, Window};
 use crate::display::{Display, Preedit, SizeInfo};
+use crate::input::macros::{MacroKey, Macros};
 use crate::input::{self, ActionContext as _, FONT_SIZE_STEP};
 #[cfg(unix)]
 use crate::ipc::{self, IpcRequest, SocketReply};
@@ -71,8 +77,12 @@ use crate::logging::{LOG_TARGET_CONFIG, LOG_TARGET_WINIT};
 use crate::message_bar::{Message, MessageBuffer};
 use crate::scheduler::{Scheduler, TimerId, Topic};
 use crate::tab_panel::TAB_ACTIVITY_TICK_INTERVAL;
-use crate::tabs::{TabCommand, TabId};
-use crate::web_url::normalize_web_url;
+use crate::tabs::{BroadcastCommand, TabCommand, TabId};
+use crate::web_url::{
+    WebNavigationTrigger, log_web_navigation, normalize_web_url, strip_query_params,
+};
+#[cfg(target_os = "macos")]
+use crate::web_url::url_shell_command;
 use crate::window_kind::WindowKind;
 use crate::window_context::WindowContext;
 #[cfg(target_os = "macos")]
@@ -80,12 +90,18 @@ use objc2_app_kit::NSEventModifierFlags;
 #[cfg(target_os = "macos")]
 use crate::macos::web_commands::{self, WebActions, WebCommandState, WebHintAction, WebKey};
 #[cfg(target_os = "macos")]
-use crate::macos::web_cursor::{web_cursor_from_css, web_cursor_script, WEB_CURSOR_BOOTSTRAP};
+use crate::macos::web_cursor::{
+    web_cursor_from_css, web_cursor_script, web_link_script, WEB_CURSOR_BOOTSTRAP,
+};
 #[cfg(target_os = "macos")]
 use crate::macos::favicon::FaviconImage;
 #[cfg(target_os = "macos")]
 use crate::macos::webview::WebView;
 #[cfg(target_os = "macos")]
+use crate::macos::site_settings::{site_host, site_settings, update_site_settings};
+#[cfg(target_os = "macos")]
+use crate::config::web::{WebAction, user_agent_preset};
+#[cfg(target_os = "macos")]
 use url::Url;
 
 /// Duration after the last user input until an unlimited search is performed.
@@ -94,6 +110,15 @@ pub const TYPING_SEARCH_DELAY: Duration = Duration::from_millis(500);
 /// Minimum delay between foreground process name refreshes.
 const FOREGROUND_PROCESS_REFRESH: Duration = Duration::from_millis(500);
 
+/// Maximum number of lines of `:pipe` output shown in the message bar.
+const PIPE_MESSAGE_LINES: usize = 10;
+
+/// Script running a command with a temporary file as its input, removing the file once opened.
+///
+/// Expects the file as first argument, followed by the command.
+#[cfg(not(windows))]
+const TEMP_FILE_INPUT_SCRIPT: &str = r#"exec < "$1" && rm -f -- "$1" && shift && exec "$@""#;
+
 #[cfg(target_os = "macos")]
 const WEB_HINTS_BOOTSTRAP: &str = r##"
 (function() {
@@ -127,9 +152,45 @@ const WEB_HINTS_BOOTSTRAP: &str = r##"
     }
     window.__taborHintsState = null;
   }
-  function start() {
+  function selectorFor(el) {
+    const parts = [];
+    while (el && el.nodeType === 1 && el !== document.documentElement) {
+      if (el.id) {
+        parts.unshift("#" + CSS.escape(el.id));
+        break;
+      }
+      let part = el.tagName.toLowerCase();
+      const parent = el.parentElement;
+      if (parent) {
+        const siblings = Array.from(parent.children).filter((s) => s.tagName === el.tagName);
+        if (siblings.length > 1) {
+          part += ":nth-of-type(" + (siblings.indexOf(el) + 1) + ")";
+        }
+      }
+      parts.unshift(part);
+      el = parent;
+    }
+    return parts.join(" > ");
+  }
+  function describe(el) {
+    return JSON.stringify({
+      tag: el.tagName.toLowerCase(),
+      id: el.id || "",
+      classes: Array.from(el.classList),
+      selector: selectorFor(el),
+    });
+  }
+  function start(inspect, linkPattern) {
     clearState();
-    const links = Array.from(document.querySelectorAll("a[href]"));
+    const query = inspect
+      ? "a, button, input, select, textarea, img, label, h1, h2, h3, h4, h5, h6, [id], [role], [onclick]"
+      : "a[href]";
+    let pattern = null;
+    try {
+      pattern = linkPattern ? new RegExp(linkPattern) : null;
+    } catch (_) {}
+    const links = Array.from(document.querySelectorAll(query))
+      .filter((el) => !pattern || pattern.test(el.href));
     const container = document.createElement("div");
     container.id = "__tabor_hint_container";
     container.style.position = "absolute";
@@ -156,10 +217,40 @@ const WEB_HINTS_BOOTSTRAP: &str = r##"
       marker.style.borderRadius = "2px";
       marker.style.boxShadow = "0 1px 2px rgba(0,0,0,0.35)";
       container.appendChild(marker);
-      hints.push({ label: label, href: el.href, marker: marker });
+      hints.push({ label: label, href: el.href, el: el, marker: marker });
     }
     document.body.appendChild(container);
-    window.__taborHintsState = { container: container, hints: hints };
+    window.__taborHintsState = { container: container, hints: hints, inspect: !!inspect };
+    return hints.length;
+  }
+  function startHeadings() {
+    clearState();
+    const headings = Array.from(document.querySelectorAll("h1, h2, h3, h4, h5, h6"))
+      .filter((el) => el.getClientRects().length > 0 && el.textContent.trim());
+    if (headings.length === 0) return 0;
+    const container = document.createElement("div");
+    container.id = "__tabor_hint_container";
+    container.style.cssText = "position:fixed;top:10%;left:10%;right:10%;max-height:80%;" +
+      "overflow:auto;background:rgba(20,20,20,0.92);color:#f2f2f2;padding:16px;" +
+      "border-radius:8px;box-shadow:0 12px 40px rgba(0,0,0,0.45);z-index:2147483647;" +
+      "font:12px/1.6 Menlo,Monaco,monospace;";
+    const hints = [];
+    headings.forEach((el, index) => {
+      const label = makeLabel(index);
+      const row = document.createElement("div");
+      row.style.cssText = "white-space:nowrap;overflow:hidden;text-overflow:ellipsis;";
+      row.style.paddingLeft = (Number(el.tagName[1]) - 1) * 16 + "px";
+      const marker = document.createElement("span");
+      marker.textContent = label;
+      marker.style.cssText = "background:#ffd24d;color:#000;padding:0 2px;border-radius:2px;" +
+        "margin-right:8px;";
+      row.appendChild(marker);
+      row.appendChild(document.createTextNode(el.textContent.trim().replace(/\s+/g, " ")));
+      container.appendChild(row);
+      hints.push({ label: label, el: el, marker: row });
+    });
+    document.body.appendChild(container);
+    window.__taborHintsState = { container: container, hints: hints, headings: true };
     return hints.length;
   }
   function update(keys) {
@@ -178,14 +269,196 @@ const WEB_HINTS_BOOTSTRAP: &str = r##"
     }
     if (matched) {
       clearState();
-      return matched.href || "";
+      if (state.headings) {
+        matched.el.scrollIntoView(true);
+        return "heading";
+      }
+      return state.inspect ? describe(matched.el) : matched.href || "";
     }
     return "";
   }
   function cancel() {
     clearState();
   }
-  window.__taborHints = { start: start, update: update, cancel: cancel };
+  window.__taborHints = {
+    start: start,
+    startHeadings: startHeadings,
+    update: update,
+    cancel: cancel,
+  };
+})();
+"##;
+
+/// Show link hints, only for links with URLs matching the JavaScript regex `links`.
+#[cfg(target_os = "macos")]
+pub(crate) fn web_hints_start_script(inspect: bool, links: Option<&str>) -> String {
+    let links = serde_json::to_string(&links).unwrap_or_else(|_| String::from("null"));
+    format!("{WEB_HINTS_BOOTSTRAP}\nwindow.__taborHints.start({inspect}, {links});")
+}
+
+/// Remember the point of the page at viewport coordinates, to center it after zooming.
+#[cfg(target_os = "macos")]
+fn web_smart_zoom_mark_script(x: f64, y: f64) -> String {
+    format!(
+        r#"(() => {{
+  const el = document.elementFromPoint({x}, {y});
+  if (!el) return;
+  const rect = el.getBoundingClientRect();
+  const fx = rect.width ? ({x} - rect.left) / rect.width : 0;
+  const fy = rect.height ? ({y} - rect.top) / rect.height : 0;
+  window.__taborSmartZoom = {{ el, fx, fy }};
+}})();"#
+    )
+}
+
+/// Scroll the point remembered by [`web_smart_zoom_mark_script`] into the center of the view.
+#[cfg(target_os = "macos")]
+const WEB_SMART_ZOOM_CENTER_SCRIPT: &str = r#"(() => {
+  const target = window.__taborSmartZoom;
+  window.__taborSmartZoom = null;
+  if (!target || !target.el.isConnected) return;
+  const rect = target.el.getBoundingClientRect();
+  const x = rect.left + rect.width * target.fx;
+  const y = rect.top + rect.height * target.fy;
+  window.scrollTo(window.scrollX + x - innerWidth / 2, window.scrollY + y - innerHeight / 2);
+})();"#;
+
+/// Insert a line break, or submit the focused form once `confirmed`.
+///
+/// Returns `confirm` when Enter would submit a form which wasn't confirmed yet.
+#[cfg(target_os = "macos")]
+const WEB_FORM_SUBMIT_SCRIPT: &str = r#"function(confirmed) {
+  const el = document.activeElement;
+  let form = null;
+  for (let node = el; node; node = node.parentElement) {
+    if (node.tagName === "FORM") {
+      form = node;
+      break;
+    }
+  }
+  const textTypes = ["text", "search", "email", "url", "tel", "password", "number", "date",
+    "datetime-local", "month", "time", "week"];
+  let submits = false;
+  if (form && el.tagName === "INPUT" && textTypes.includes(el.type)) {
+    const button = form.querySelector(
+      "button:not([type]), button[type=submit], input[type=submit], input[type=image]");
+    const fields = Array.from(form.elements).filter(field =>
+      field.tagName === "INPUT" && textTypes.includes(field.type));
+    submits = !!button || fields.length === 1;
+  }
+  if (!submits) {
+    document.execCommand("insertParagraph");
+    return "";
+  }
+  if (!confirmed) return "confirm";
+  form.requestSubmit();
+  return "";
+}"#;
+
+/// Focus the visible form field after the focused one, or before it when going `backwards`.
+///
+/// Focus wraps around at the first and last field.
+#[cfg(target_os = "macos")]
+const WEB_FOCUS_NEXT_INPUT_SCRIPT: &str = r#"function(backwards) {
+  const fields = Array.from(document.querySelectorAll(
+    "input:not([type=hidden]), textarea, select, [contenteditable='true']"))
+    .filter(field => !field.disabled && field.getClientRects().length > 0);
+  if (fields.length === 0) return;
+  const index = fields.indexOf(document.activeElement);
+  let next;
+  if (index < 0) {
+    next = backwards ? fields.length - 1 : 0;
+  } else {
+    next = (index + (backwards ? fields.length - 1 : 1)) % fields.length;
+  }
+  fields[next].focus();
+  fields[next].scrollIntoView({ block: "nearest" });
+}"#;
+
+/// Text opened by `:edit`, the selection or all text of the page.
+#[cfg(target_os = "macos")]
+const WEB_EDIT_TEXT_SCRIPT: &str =
+    "window.getSelection().toString() || (document.body ? document.body.innerText : \"\");";
+
+/// Highlight overlay of all find matches, with one focused match cycled by `n`/`N`.
+#[cfg(target_os = "macos")]
+const WEB_FIND_BOOTSTRAP: &str = r##"
+(function() {
+  if (window.__taborFind) {
+    return;
+  }
+  const state = { query: null, marks: [], index: -1 };
+  const style = document.createElement("style");
+  style.textContent =
+    "mark.__tabor-find{background:#f3d36b;color:#000;border-radius:2px;padding:0}" +
+    "mark.__tabor-find-focus{background:#f08c2b}";
+  function clear() {
+    for (const mark of state.marks) {
+      const parent = mark.parentNode;
+      if (!parent) continue;
+      parent.replaceChild(document.createTextNode(mark.textContent), mark);
+      parent.normalize();
+    }
+    state.query = null;
+    state.marks = [];
+    state.index = -1;
+  }
+  function highlight(query) {
+    clear();
+    state.query = query;
+    if (!style.isConnected) {
+      (document.head || document.documentElement).appendChild(style);
+    }
+    const needle = query.toLowerCase();
+    const walker = document.createTreeWalker(document.body, NodeFilter.SHOW_TEXT, {
+      acceptNode(node) {
+        const parent = node.parentElement;
+        if (!parent || parent.closest("script, style, noscript, textarea")) {
+          return NodeFilter.FILTER_REJECT;
+        }
+        return node.nodeValue.toLowerCase().includes(needle)
+          ? NodeFilter.FILTER_ACCEPT : NodeFilter.FILTER_REJECT;
+      },
+    });
+    const nodes = [];
+    while (walker.nextNode()) nodes.push(walker.currentNode);
+    for (let node of nodes) {
+      let offset = node.nodeValue.toLowerCase().indexOf(needle);
+      while (offset !== -1) {
+        const match = node.splitText(offset);
+        node = match.splitText(query.length);
+        const mark = document.createElement("mark");
+        mark.className = "__tabor-find";
+        match.parentNode.replaceChild(mark, match);
+        mark.appendChild(match);
+        state.marks.push(mark);
+        offset = node.nodeValue.toLowerCase().indexOf(needle);
+      }
+    }
+  }
+  function focus(backwards) {
+    if (state.marks.length === 0) return 0;
+    if (state.index >= 0) {
+      state.marks[state.index].classList.remove("__tabor-find-focus");
+    }
+    const count = state.marks.length;
+    if (state.index < 0) {
+      state.index = backwards ? count - 1 : 0;
+    } else {
+      state.index = (state.index + (backwards ? count - 1 : 1)) % count;
+    }
+    const mark = state.marks[state.index];
+    mark.classList.add("__tabor-find-focus");
+    mark.scrollIntoView({ block: "center", inline: "nearest" });
+    return count;
+  }
+  function search(query, backwards) {
+    if (query !== state.query || !state.marks.every((mark) => mark.isConnected)) {
+      highlight(query);
+    }
+    return focus(backwards);
+  }
+  window.__taborFind = { search: search, clear: clear };
 })();
 "##;
 
@@ -199,7 +472,11 @@ Navigation:
 Links & inputs:
   f/F        open link / open in new tab
   yf         copy link URL
+  yi         inspect element
+  yL         copy all link URLs
+  gf         dump form fields
   gi         focus input (insert mode)
+  gj/gk      focus next/previous input
 Find & visual:
   /          find
   n/N        next/previous match
@@ -208,6 +485,7 @@ Find & visual:
 History & URL:
   H/L        back/forward
   yy         copy URL
+  yt         copy title
   p/P        open clipboard URL / new tab
   gu/gU      up one level / root
 Tabs & omnibar:
@@ -217,30 +495,63 @@ Tabs & omnibar:
   g0/g$      first/last tab
   o/O        omnibar / new tab
   b/B        bookmarks / new tab
+  gO         private tab
   T          tab search
 Misc:
   r          reload
+  gz         reset zoom and scroll
   gs         view source
+  gc         console
+  gC         open URL in terminal
+  gS         connection security
+  gI         toggle images
+  gJ         toggle JavaScript
+  gd         toggle dark mode
+  gF         toggle focus mode
+  gp         pass keys to page (Esc Esc exits)
   [[/]]      previous/next link
   m/`        set/jump mark
+  ``         previous URL
   ?          help
 </pre>"#;
 
+/// Message bar target of the passthrough mode indicator.
+#[cfg(target_os = "macos")]
+pub(crate) const WEB_PASSTHROUGH_MESSAGE_TARGET: &str = "tabor_web_passthrough";
+
+/// Message bar target of the hovered link preview.
+#[cfg(target_os = "macos")]
+pub(crate) const WEB_HOVER_LINK_MESSAGE_TARGET: &str = "tabor_web_hover_link";
+
 #[cfg(target_os = "macos")]
 const WEB_CURSOR_THROTTLE: Duration = Duration::from_millis(100);
 
+/// Interval at which repeated scrolling is flushed while smooth scrolling.
+#[cfg(target_os = "macos")]
+const WEB_SMOOTH_SCROLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Time to wait for a web page to report its scroll offset to an IPC client.
+#[cfg(unix)]
+const IPC_SCROLL_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Time to wait for a web page to report its text to an IPC client.
+#[cfg(unix)]
+const IPC_PAGE_TEXT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Page zoom change of each font size step in web tabs.
+#[cfg(target_os = "macos")]
+const WEB_ZOOM_STEP: f64 = 0.1;
+
+/// Page zoom limits of web tabs.
+#[cfg(target_os = "macos")]
+const WEB_ZOOM_RANGE: (f64, f64) = (0.3, 5.);
+
 /// Maximum number of lines for the blocking search while still typing the search regex.
 const MAX_SEARCH_WHILE_TYPING: Option<usize> = Some(1000);
 
 /// Maximum number of search terms stored in the history.
 const MAX_SEARCH_HISTORY_SIZE: usize = 255;
 
-/// Touch zoom speed.
-const TOUCH_ZOOM_FACTOR: f32 = 0.01;
-
-/// Cooldown between invocations of the bell command.
-const BELL_CMD_COOLDOWN: Duration = Duration::from_millis(100);
-
 /// The event processor.
 ///
 /// Stores some state from received events and dispatches actions when they are
@@ -259,6 +570,8 @@ pub struct Processor {
     gl_config: Option<GlutinConfig>,
     #[cfg(unix)]
     global_ipc_options: ParsedOptions,
+    #[cfg(unix)]
+    ipc_subscribers: Vec<ipc::IpcSubscriber>,
     cli_options: CliOptions,
     config: Rc<UiConfig>,
 }
@@ -290,6 +603,10 @@ impl ipc::IpcContext for IpcWindowContext<'_> {
         self.window.ipc_tab_kind(tab_id)
     }
 
+    fn tab_cwd(&self, tab_id: TabId) -> Option<PathBuf> {
+        self.window.ipc_tab_cwd(tab_id)
+    }
+
     fn create_tab(
         &mut self,
         options: WindowOptions,
@@ -304,7 +621,7 @@ impl ipc::IpcContext for IpcWindowContext<'_> {
     }
 
     fn close_tab(&mut self, tab_id: TabId) -> Result<bool, ipc::IpcError> {
-        self.window.ipc_close_tab(tab_id)
+        self.window.ipc_close_tab(tab_id, self.scheduler)
     }
 
     fn select_tab(&mut self, selection: ipc::TabSelection) -> Result<(), ipc::IpcError> {
@@ -329,10 +646,19 @@ impl ipc::IpcContext for IpcWindowContext<'_> {
         self.window.ipc_set_group_name(group_id, name)
     }
 
+    fn set_window_title(&mut self, title: Option<String>) -> Result<(), ipc::IpcError> {
+        self.window.ipc_set_window_title(title);
+        Ok(())
+    }
+
     fn restore_closed_tab(&mut self) -> Result<(), ipc::IpcError> {
         self.window.ipc_restore_closed_tab(self.event_proxy)
     }
 
+    fn closed_tab_count(&self) -> usize {
+        self.window.ipc_closed_tab_count()
+    }
+
     fn open_url_in_tab(&mut self, tab_id: TabId, url: String) -> Result<(), ipc::IpcError> {
         self.window.ipc_open_url_in_tab(tab_id, url, self.event_proxy)
     }
@@ -346,6 +672,26 @@ impl ipc::IpcContext for IpcWindowContext<'_> {
             .ipc_reload_web(tab_id, self.event_loop, self.event_proxy, self.clipboard, self.scheduler)
     }
 
+    fn set_scroll(&mut self, tab_id: TabId, x: f64, y: f64) -> Result<(), ipc::IpcError> {
+        self.window.ipc_set_scroll(
+            tab_id,
+            x,
+            y,
+            self.event_loop,
+            self.event_proxy,
+            self.clipboard,
+            self.scheduler,
+        )
+    }
+
+    fn inject_css(&mut self, tab_id: TabId, css: String) -> Result<u64, ipc::IpcError> {
+        self.window.ipc_inject_css(tab_id, css)
+    }
+
+    fn remove_css(&mut self, tab_id: TabId, handle: u64) -> Result<(), ipc::IpcError> {
+        self.window.ipc_remove_css(tab_id, handle)
+    }
+
     fn open_inspector(&mut self, tab_id: TabId) -> Result<(), ipc::IpcError> {
         self.window.ipc_open_inspector(
             tab_id,
@@ -379,6 +725,32 @@ impl ipc::IpcContext for IpcWindowContext<'_> {
         self.window.ipc_send_input(tab_id, text)
     }
 
+    fn send_input_typed(
+        &mut self,
+        tab_id: TabId,
+        text: String,
+        delay: Duration,
+    ) -> Result<u64, ipc::IpcError> {
+        self.window.ipc_send_input_typed(tab_id, text, delay, self.scheduler)
+    }
+
+    fn send_key(
+        &mut self,
+        tab_id: TabId,
+        key: String,
+        modifiers: Vec<String>,
+    ) -> Result<(), ipc::IpcError> {
+        self.window.ipc_send_key(
+            tab_id,
+            key,
+            modifiers,
+            self.event_loop,
+            self.event_proxy,
+            self.clipboard,
+            self.scheduler,
+        )
+    }
+
     fn run_command_bar(&mut self, tab_id: TabId, input: String) -> Result<(), ipc::IpcError> {
         self.window.ipc_run_command_bar(
             tab_id,
@@ -418,7 +790,7 @@ impl ipc::IpcContext for IpcWindowContext<'_> {
         &mut self,
         session_id: String,
         max: Option<usize>,
-    ) -> Result<Vec<ipc::IpcInspectorMessage>, ipc::IpcError> {
+    ) -> Result<(Vec<ipc::IpcInspectorMessage>, bool), ipc::IpcError> {
         self.window.ipc_poll_inspector_messages(session_id, max)
     }
 }
@@ -465,6 +837,8 @@ impl Processor {
             windows: Default::default(),
             #[cfg(unix)]
             global_ipc_options: Default::default(),
+            #[cfg(unix)]
+            ipc_subscribers: Default::default(),
             config_monitor,
         }
     }
@@ -488,6 +862,7 @@ impl Processor {
         self.gl_config = Some(window_context.display.gl_context().config());
         let window_id = window_context.id();
         self.windows.insert(window_id, window_context);
+        self.update_clipboard_poll(window_id);
 
         Ok(())
     }
@@ -497,7 +872,7 @@ impl Processor {
         &mut self,
         event_loop: &ActiveEventLoop,
         options: WindowOptions,
-    ) -> Result<(), Box<dyn Error>> {
+    ) -> Result<WindowId, Box<dyn Error>> {
         let gl_config = self.gl_config.as_ref().unwrap();
 
         // Override config with CLI/IPC options.
@@ -518,14 +893,117 @@ impl Processor {
 
         let window_id = window_context.id();
         self.windows.insert(window_id, window_context);
+        Ok(window_id)
+    }
+
+    /// Write the windows and tabs of this instance to a session file.
+    #[cfg(unix)]
+    fn save_session(&self, path: &Path) -> Result<(), String> {
+        let windows = self.windows.values().map(WindowContext::ipc_session_window).collect();
+        let session = ipc::IpcSession { windows };
+        let json = serde_json::to_string_pretty(&session)
+            .map_err(|err| format!("Failed session serialization: {err}"))?;
+        fs::write(path, json).map_err(|err| format!("Unable to write {}: {err}", path.display()))
+    }
+
+    /// Open new windows with the tabs of a session file.
+    ///
+    /// Each window is created with its first tab, before the remaining tabs are added to it.
+    #[cfg(unix)]
+    fn restore_session(&mut self, event_loop: &ActiveEventLoop, path: &Path) -> Result<(), String> {
+        let json = fs::read_to_string(path)
+            .map_err(|err| format!("Unable to read {}: {err}", path.display()))?;
+        let session: ipc::IpcSession =
+            serde_json::from_str(&json).map_err(|err| format!("Invalid session: {err}"))?;
+
+        for window in session.windows {
+            let first_tab = window.groups.iter().flat_map(|group| &group.tabs).next();
+            let Some(options) = first_tab.map(ipc::IpcSessionTab::window_options) else {
+                continue;
+            };
+
+            if self.gl_config.is_none() {
+                return Err(String::from("No window has been created yet"));
+            }
+
+            // Ensure no context is current, see `EventType::CreateWindow`.
+            for window_context in self.windows.values_mut() {
+                window_context.display.make_not_current();
+            }
+
+            let window_id = self
+                .create_window(event_loop, options)
+                .map_err(|err| format!("Could not create window: {err}"))?;
+
+            if let Some(window_context) = self.windows.get_mut(&window_id) {
+                window_context
+                    .ipc_restore_session(window.groups, &self.proxy)
+                    .map_err(|err| err.message)?;
+            }
+        }
+
         Ok(())
     }
 
+    /// Move a tab into a new window, keeping its PTY and web view running.
+    ///
+    /// Returns the ID of the tab in the new window.
+    fn move_tab_to_new_window(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        window_id: WindowId,
+        tab_id: Option<TabId>,
+    ) -> Result<TabId, String> {
+        let gl_config = self.gl_config.as_ref().ok_or("No window has been created yet")?;
+        let source = self.windows.get_mut(&window_id).ok_or("Window not found")?;
+        let tab_id = tab_id.or_else(|| source.active_tab_id()).ok_or("No active tab")?;
+        if !source.has_tab(tab_id) {
+            return Err(String::from("Tab not found"));
+        }
+        let tab = source.detach_tab(tab_id).ok_or("Tab is the only tab of its window")?;
+
+        // Ensure no context is current, see `EventType::CreateWindow`.
+        for window_context in self.windows.values_mut() {
+            window_context.display.make_not_current();
+        }
+
+        let options = WindowOptions::default();
+        let mut config_overrides = options.config_overrides();
+        #[cfg(unix)]
+        config_overrides.extend_from_slice(&self.global_ipc_options);
+        let config = config_overrides.override_config_rc(self.config.clone());
+
+        match WindowContext::with_detached_tab(
+            gl_config,
+            event_loop,
+            &self.proxy,
+            config,
+            options,
+            config_overrides,
+            tab,
+        ) {
+            Ok((window_context, tab_id)) => {
+                window_context.display.window.focus_window();
+                self.windows.insert(window_context.id(), window_context);
+                Ok(tab_id)
+            },
+            Err((err, tab)) => {
+                // Put the tab back, rather than killing its process.
+                if let Some(source) = self.windows.get_mut(&window_id) {
+                    if let Err((err, _)) = source.attach_tab(tab, &self.proxy) {
+                        error!("Could not restore tab: {err}");
+                    }
+                }
+                Err(format!("Could not create window: {err}"))
+            },
+        }
+    }
+
     #[cfg(target_os = "macos")]
     fn handle_open_urls(&mut self, urls: Vec<String>) {
         let mut urls = urls
             .into_iter()
-            .map(|url| normalize_web_url(&url))
+            .map(|url| normalize_web_url(&url, &self.config.web.search_engines))
             .filter(|url| !url.is_empty())
             .collect::<Vec<_>>();
 
@@ -546,7 +1024,13 @@ impl Processor {
 
         if let Some(window_context) = self.windows.get_mut(&window_id) {
             for url in urls {
-                if let Err(err) = window_context.open_web_url_new_tab(url, &self.proxy) {
+                if let Err(err) = window_context.open_web_url_new_tab(
+                    url,
+                    &self.proxy,
+                    WebNavigationTrigger::External,
+                    false,
+                    false,
+                ) {
                     error!("Could not open URL: {err:?}");
                 }
             }
@@ -569,17 +1053,37 @@ impl Processor {
 
         let urls = mem::take(&mut self.pending_open_urls);
         for url in urls {
-            let url = normalize_web_url(&url);
+            let url = normalize_web_url(&url, &self.config.web.search_engines);
             if url.is_empty() {
                 continue;
             }
 
-            if let Err(err) = window_context.open_web_url_new_tab(url, &self.proxy) {
+            if let Err(err) = window_context.open_web_url_new_tab(
+                url,
+                &self.proxy,
+                WebNavigationTrigger::External,
+                false,
+                false,
+            ) {
                 error!("Could not open URL: {err:?}");
             }
         }
     }
 
+    /// Schedule clipboard history capture when it is enabled.
+    fn update_clipboard_poll(&mut self, window_id: WindowId) {
+        self.scheduler.unschedule_topic(Topic::ClipboardPoll);
+
+        let history = &self.config.selection.clipboard_history;
+        if !history.enabled {
+            return;
+        }
+
+        let timer_id = TimerId::new(Topic::ClipboardPoll, window_id);
+        let event = Event::new(EventType::ClipboardPoll, None);
+        self.scheduler.schedule(event, history.interval(), true, timer_id);
+    }
+
     fn ensure_tab_activity_tick(&mut self, window_id: WindowId) {
         let timer_id = TimerId::new(Topic::TabActivityTick, window_id);
         if self.scheduler.scheduled(timer_id) {
@@ -657,6 +1161,41 @@ impl Processor {
                     ),
                 }
             },
+            IpcRequest::GetTypingStatus { typing_id } => {
+                let remaining = self
+                    .windows
+                    .values()
+                    .find_map(|window_context| window_context.typed_input_remaining(typing_id));
+                match remaining {
+                    Some(remaining) => {
+                        SocketReply::TypingStatus { typing_id, remaining, done: false }
+                    },
+                    None if WindowContext::typed_input_issued(typing_id) => {
+                        SocketReply::TypingStatus { typing_id, remaining: 0, done: true }
+                    },
+                    None => ipc::reply_error(ipc::IpcErrorCode::NotFound, "Unknown typing id"),
+                }
+            },
+            IpcRequest::SaveSession { path } => match self.save_session(&path) {
+                Ok(()) => ipc::reply_ok(),
+                Err(err) => ipc::reply_error(ipc::IpcErrorCode::Internal, err),
+            },
+            IpcRequest::RestoreSession { path } => match self.restore_session(event_loop, &path) {
+                Ok(()) => ipc::reply_ok(),
+                Err(err) => ipc::reply_error(ipc::IpcErrorCode::InvalidRequest, err),
+            },
+            request @ IpcRequest::MoveTabToNewWindow { tab_id } => {
+                let window_id = match self.window_for_ipc_request(&request) {
+                    Ok(window_id) => window_id,
+                    Err(reply) => return reply,
+                };
+
+                let tab_id = tab_id.map(TabId::from);
+                match self.move_tab_to_new_window(event_loop, window_id, tab_id) {
+                    Ok(tab_id) => SocketReply::TabCreated { tab_id: tab_id.into() },
+                    Err(err) => ipc::reply_error(ipc::IpcErrorCode::InvalidRequest, err),
+                }
+            },
             request => {
                 let window_id = match self.window_for_ipc_request(&request) {
                     Ok(window_id) => window_id,
@@ -691,44 +1230,215 @@ impl Processor {
         }
     }
 
+    /// Reply to an inspector poll once messages are queued, or after `timeout_ms` without any.
+    ///
+    /// Waiting happens on a separate thread, which retries the poll on the event loop whenever
+    /// new messages arrive.
+    #[cfg(target_os = "macos")]
+    fn long_poll_inspector_messages(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        session_id: String,
+        max: Option<usize>,
+        timeout_ms: u64,
+        stream: Arc<UnixStream>,
+    ) {
+        let request = IpcRequest::PollInspectorMessages {
+            session_id: session_id.clone(),
+            max,
+            timeout_ms: None,
+        };
+        let signal = self
+            .window_for_ipc_request(&request)
+            .ok()
+            .and_then(|window_id| self.windows.get(&window_id))
+            .and_then(|window_context| window_context.inspector_message_signal())
+            .map(|signal| (signal.generation(), signal));
+
+        let reply = self.handle_ipc_request(event_loop, request);
+        let empty = matches!(
+            &reply,
+            SocketReply::InspectorMessages { messages, dropped: false } if messages.is_empty()
+        );
+
+        let (generation, signal) = match signal {
+            Some(signal) if empty && timeout_ms > 0 => signal,
+            _ => {
+                if let Ok(mut stream) = stream.try_clone() {
+                    ipc::send_reply(&mut stream, reply);
+                }
+                return;
+            },
+        };
+
+        let proxy = self.proxy.clone();
+        tabor_terminal::thread::spawn_named("inspector poll", move || {
+            let start = Instant::now();
+            signal.wait(generation, Duration::from_millis(timeout_ms));
+            let elapsed = start.elapsed().as_millis() as u64;
+
+            let timeout_ms = Some(timeout_ms.saturating_sub(elapsed));
+            let request = IpcRequest::PollInspectorMessages { session_id, max, timeout_ms };
+            let _ = proxy.send_event(Event::new(EventType::IpcRequest(request, stream), None));
+        });
+    }
+
+    /// Reply with the scroll offset of a web tab once the page reports it.
+    ///
+    /// Scripts complete asynchronously on the event loop, so the reply is sent from the script
+    /// callback, or as a timeout error when the page doesn't answer in time.
     #[cfg(unix)]
-    fn window_for_ipc_request(&self, request: &IpcRequest) -> Result<WindowId, SocketReply> {
-        if let Some(tab_id) = request.target_tab_id() {
-            let tab_id: TabId = tab_id.into();
-            let mut matches = self
-                .windows
-                .iter()
-                .filter_map(|(id, window)| window.has_tab(tab_id).then_some(*id))
-                .collect::<Vec<_>>();
+    fn get_web_scroll(&mut self, tab_id: Option<ipc::IpcTabId>, stream: Arc<UnixStream>) {
+        let pending = Arc::new(Mutex::new(Some(stream)));
 
-            matches.sort();
-            matches.dedup();
+        let window_id = match self.window_for_ipc_request(&IpcRequest::GetScroll { tab_id }) {
+            Ok(window_id) => window_id,
+            Err(reply) => return send_reply_once(&pending, reply),
+        };
+        let Some(window_context) = self.windows.get_mut(&window_id) else {
+            let reply = ipc::reply_error(ipc::IpcErrorCode::NotFound, "Target window not found");
+            return send_reply_once(&pending, reply);
+        };
+        let Some(tab_id) = tab_id.map(TabId::from).or_else(|| window_context.active_tab_id())
+        else {
+            let reply = ipc::reply_error(ipc::IpcErrorCode::NotFound, "No active tab");
+            return send_reply_once(&pending, reply);
+        };
 
-            return match matches.len() {
-                0 => Err(ipc::reply_error(ipc::IpcErrorCode::NotFound, "Tab not found")),
-                1 => Ok(matches[0]),
-                _ => Err(ipc::reply_error(
-                    ipc::IpcErrorCode::Ambiguous,
-                    "Tab exists in multiple windows",
-                )),
+        let callback_pending = pending.clone();
+        let result = window_context.ipc_get_scroll(tab_id, move |scroll| {
+            let reply = match scroll {
+                Some((x, y)) => SocketReply::Scroll { x, y },
+                None => {
+                    ipc::reply_error(ipc::IpcErrorCode::Internal, "Failed to read scroll position")
+                },
             };
+            send_reply_once(&callback_pending, reply);
+        });
+
+        match result {
+            Ok(()) => {
+                tabor_terminal::thread::spawn_named("scroll query timeout", move || {
+                    std::thread::sleep(IPC_SCROLL_TIMEOUT);
+                    let reply = ipc::reply_error(
+                        ipc::IpcErrorCode::Timeout,
+                        "Timed out reading scroll position",
+                    );
+                    send_reply_once(&pending, reply);
+                });
+            },
+            Err(error) => send_reply_once(&pending, SocketReply::Error { error }),
         }
+    }
 
-        if let Some(session_id) = request.target_inspector_session_id() {
-            let mut matches = self
-                .windows
-                .iter()
-                .filter_map(|(id, window)| window.has_inspector_session(session_id).then_some(*id))
-                .collect::<Vec<_>>();
+    /// Reply with the text of a web page once the page reports it.
+    #[cfg(unix)]
+    fn get_page_text(&mut self, tab_id: Option<ipc::IpcTabId>, stream: Arc<UnixStream>) {
+        let pending = Arc::new(Mutex::new(Some(stream)));
 
-            matches.sort();
-            matches.dedup();
+        let window_id = match self.window_for_ipc_request(&IpcRequest::GetPageText { tab_id }) {
+            Ok(window_id) => window_id,
+            Err(reply) => return send_reply_once(&pending, reply),
+        };
+        let Some(window_context) = self.windows.get_mut(&window_id) else {
+            let reply = ipc::reply_error(ipc::IpcErrorCode::NotFound, "Target window not found");
+            return send_reply_once(&pending, reply);
+        };
+        let Some(tab_id) = tab_id.map(TabId::from).or_else(|| window_context.active_tab_id())
+        else {
+            let reply = ipc::reply_error(ipc::IpcErrorCode::NotFound, "No active tab");
+            return send_reply_once(&pending, reply);
+        };
 
-            return match matches.len() {
-                0 => Err(ipc::reply_error(
-                    ipc::IpcErrorCode::NotFound,
-                    "Inspector session not found",
-                )),
+        let limit = self.config.web.page_text_limit;
+        let callback_pending = pending.clone();
+        let result = window_context.ipc_get_page_text(tab_id, move |text| {
+            let reply = match text {
+                Some(text) => ipc::page_text_reply(text, limit),
+                None => ipc::reply_error(ipc::IpcErrorCode::Internal, "Failed to read page text"),
+            };
+            send_reply_once(&callback_pending, reply);
+        });
+
+        match result {
+            Ok(()) => {
+                tabor_terminal::thread::spawn_named("page text timeout", move || {
+                    std::thread::sleep(IPC_PAGE_TEXT_TIMEOUT);
+                    let reply =
+                        ipc::reply_error(ipc::IpcErrorCode::Timeout, "Timed out reading page text");
+                    send_reply_once(&pending, reply);
+                });
+            },
+            Err(error) => send_reply_once(&pending, SocketReply::Error { error }),
+        }
+    }
+
+    /// Keep an IPC stream open to receive tab lifecycle events.
+    #[cfg(unix)]
+    fn add_ipc_subscriber(&mut self, stream: &UnixStream) {
+        let subscriber = match stream.try_clone().and_then(ipc::IpcSubscriber::new) {
+            Ok(subscriber) => subscriber,
+            Err(err) => {
+                error!("Failed to configure IPC subscriber: {err}");
+                return;
+            },
+        };
+
+        if subscriber.send(&ipc::reply_ok()) {
+            self.ipc_subscribers.push(subscriber);
+        }
+    }
+
+    /// Send queued tab lifecycle events to all IPC subscribers.
+    #[cfg(unix)]
+    fn publish_ipc_events(&mut self, events: Vec<ipc::IpcEvent>) {
+        if self.ipc_subscribers.is_empty() {
+            return;
+        }
+
+        for event in &events {
+            self.ipc_subscribers.retain(|subscriber| subscriber.send(event));
+        }
+    }
+
+    #[cfg(unix)]
+    fn window_for_ipc_request(&self, request: &IpcRequest) -> Result<WindowId, SocketReply> {
+        if let Some(tab_id) = request.target_tab_id() {
+            let tab_id: TabId = tab_id.into();
+            let mut matches = self
+                .windows
+                .iter()
+                .filter_map(|(id, window)| window.has_tab(tab_id).then_some(*id))
+                .collect::<Vec<_>>();
+
+            matches.sort();
+            matches.dedup();
+
+            return match matches.len() {
+                0 => Err(ipc::reply_error(ipc::IpcErrorCode::NotFound, "Tab not found")),
+                1 => Ok(matches[0]),
+                _ => Err(ipc::reply_error(
+                    ipc::IpcErrorCode::Ambiguous,
+                    "Tab exists in multiple windows",
+                )),
+            };
+        }
+
+        if let Some(session_id) = request.target_inspector_session_id() {
+            let mut matches = self
+                .windows
+                .iter()
+                .filter_map(|(id, window)| window.has_inspector_session(session_id).then_some(*id))
+                .collect::<Vec<_>>();
+
+            matches.sort();
+            matches.dedup();
+
+            return match matches.len() {
+                0 => Err(ipc::reply_error(
+                    ipc::IpcErrorCode::NotFound,
+                    "Inspector session not found",
+                )),
                 1 => Ok(matches[0]),
                 _ => Err(ipc::reply_error(
                     ipc::IpcErrorCode::Ambiguous,
@@ -756,11 +1466,16 @@ impl Processor {
     }
 
     fn close_window(&mut self, event_loop: &ActiveEventLoop, window_id: WindowId) {
-        let window_context = match self.windows.entry(window_id) {
+        let mut window_context = match self.windows.entry(window_id) {
             Entry::Occupied(window_context) => window_context.remove(),
             _ => return,
         };
 
+        #[cfg(unix)]
+        self.publish_ipc_events(window_context.take_ipc_events());
+
+        #[cfg(not(windows))]
+        window_context.schedule_shell_shutdown(&mut self.scheduler);
         self.scheduler.unschedule_window(window_context.id());
 
         if self.windows.is_empty() && !self.cli_options.daemon {
@@ -778,17 +1493,13 @@ impl Processor {
             event,
             WindowEvent::KeyboardInput { is_synthetic: true, .. }
                 | WindowEvent::ActivationTokenDone { .. }
-                | WindowEvent::DoubleTapGesture { .. }
                 | WindowEvent::TouchpadPressure { .. }
                 | WindowEvent::RotationGesture { .. }
                 | WindowEvent::CursorEntered { .. }
-                | WindowEvent::PinchGesture { .. }
                 | WindowEvent::AxisMotion { .. }
                 | WindowEvent::PanGesture { .. }
-                | WindowEvent::HoveredFileCancelled
                 | WindowEvent::Destroyed
                 | WindowEvent::ThemeChanged(_)
-                | WindowEvent::HoveredFile(_)
                 | WindowEvent::Moved(_)
         )
     }
@@ -866,6 +1577,32 @@ impl ApplicationHandler<Event> for Processor {
 
         // Handle events which don't mandate the WindowId.
         match (payload, window_id) {
+            #[cfg(unix)]
+            (EventType::IpcRequest(IpcRequest::Subscribe, stream), _) => {
+                self.add_ipc_subscriber(&stream);
+            },
+            #[cfg(target_os = "macos")]
+            (
+                EventType::IpcRequest(
+                    IpcRequest::PollInspectorMessages {
+                        session_id,
+                        max,
+                        timeout_ms: Some(timeout),
+                    },
+                    stream,
+                ),
+                _,
+            ) => {
+                self.long_poll_inspector_messages(event_loop, session_id, max, timeout, stream);
+            },
+            #[cfg(unix)]
+            (EventType::IpcRequest(IpcRequest::GetScroll { tab_id }, stream), _) => {
+                self.get_web_scroll(tab_id, stream);
+            },
+            #[cfg(unix)]
+            (EventType::IpcRequest(IpcRequest::GetPageText { tab_id }, stream), _) => {
+                self.get_page_text(tab_id, stream);
+            },
             #[cfg(unix)]
             (EventType::IpcRequest(request, stream), _) => {
                 let reply = self.handle_ipc_request(event_loop, request);
@@ -873,6 +1610,19 @@ impl ApplicationHandler<Event> for Processor {
                     ipc::send_reply(&mut stream, reply);
                 }
             },
+            #[cfg(unix)]
+            (EventType::SetWindowConfig(options), Some(window_id)) => {
+                if let Some(window_context) = self.windows.get_mut(&window_id) {
+                    let options = ParsedOptions::from_options(&options);
+                    window_context.add_window_config(self.config.clone(), &options);
+                }
+            },
+            #[cfg(unix)]
+            (EventType::SetWindowTheme(name), Some(window_id)) => {
+                if let Some(window_context) = self.windows.get_mut(&window_id) {
+                    window_context.set_theme(self.config.clone(), &name);
+                }
+            },
             (EventType::CreateTab(options), Some(window_id)) => {
                 if let Some(window_context) = self.windows.get_mut(&window_id) {
                     if let Err(err) = window_context.create_tab(options, &self.proxy) {
@@ -880,9 +1630,21 @@ impl ApplicationHandler<Event> for Processor {
                     }
                 }
             },
+            (EventType::OpenWebTab(request), Some(window_id)) => {
+                if let Some(window_context) = self.windows.get_mut(&window_id) {
+                    if let Err(err) = window_context.open_web_tab(request, &self.proxy) {
+                        error!("Could not create tab: {err:?}");
+                    }
+                }
+            },
+            (EventType::TabCommand(TabCommand::MoveToNewWindow(tab_id)), Some(window_id)) => {
+                if let Err(err) = self.move_tab_to_new_window(event_loop, window_id, tab_id) {
+                    warn!("Could not move tab to new window: {err}");
+                }
+            },
             (EventType::TabCommand(command), Some(window_id)) => {
                 if let Some(window_context) = self.windows.get_mut(&window_id) {
-                    window_context.handle_tab_command(command);
+                    window_context.handle_tab_command(command, &self.proxy);
                 }
             },
             #[cfg(target_os = "macos")]
@@ -891,7 +1653,7 @@ impl ApplicationHandler<Event> for Processor {
                     return;
                 };
 
-                let should_close_window = window_context.close_tab(tab_id);
+                let should_close_window = window_context.shutdown_tab(tab_id, &mut self.scheduler);
 
                 if should_close_window {
                     self.close_window(event_loop, window_id);
@@ -950,6 +1712,10 @@ impl ApplicationHandler<Event> for Processor {
                     for window_context in self.windows.values_mut() {
                         window_context.update_config(self.config.clone());
                     }
+
+                    if let Some(&window_id) = self.windows.keys().next() {
+                        self.update_clipboard_poll(window_id);
+                    }
                 }
             },
             // Create a new terminal window.
@@ -999,7 +1765,7 @@ impl ApplicationHandler<Event> for Processor {
                             window_context.note_terminal_output(tab_id, is_active);
                         }
                         if window_context.tab_panel_enabled()
-                            && window_context.has_active_terminal_output(Instant::now())
+                            && window_context.needs_tab_activity_tick(Instant::now())
                         {
                             schedule_tick = true;
                         }
@@ -1074,6 +1840,31 @@ impl ApplicationHandler<Event> for Processor {
                     }
                 }
             },
+            (EventType::ClipboardPoll, _) => {
+                let limit = self.config.selection.clipboard_history.size;
+                self.clipboard.capture_history(limit);
+            },
+            #[cfg(not(windows))]
+            (EventType::ShellShutdown, Some(window_id)) => {
+                // Check again once the next shell is due to be killed.
+                if let Some(deadline) = tty::reap_terminated_children() {
+                    let timer_id = TimerId::new(Topic::ShellShutdown, window_id);
+                    let event = Event::new(EventType::ShellShutdown, window_id);
+                    let delay = deadline.saturating_duration_since(Instant::now());
+                    self.scheduler.schedule(event, delay, false, timer_id);
+                }
+            },
+            #[cfg(unix)]
+            (EventType::TypedInput, Some(window_id)) => {
+                if let Some(window_context) = self.windows.get_mut(&window_id) {
+                    window_context.type_next_input(
+                        event_loop,
+                        &self.proxy,
+                        &mut self.clipboard,
+                        &mut self.scheduler,
+                    );
+                }
+            },
             (EventType::TabActivityTick, Some(window_id)) => {
                 let Some(window_context) = self.windows.get_mut(&window_id) else {
                     return;
@@ -1085,7 +1876,7 @@ impl ApplicationHandler<Event> for Processor {
                     return;
                 }
 
-                if !window_context.has_active_terminal_output(Instant::now()) {
+                if !window_context.needs_tab_activity_tick(Instant::now()) {
                     self.scheduler.unschedule(timer_id);
                 }
 
@@ -1135,6 +1926,13 @@ impl ApplicationHandler<Event> for Processor {
             );
         }
 
+        #[cfg(unix)]
+        {
+            let events =
+                self.windows.values_mut().flat_map(WindowContext::take_ipc_events).collect();
+            self.publish_ipc_events(events);
+        }
+
         // Update the scheduler after event processing to ensure
         // the event loop deadline is as accurate as possible.
         let control_flow = match self.scheduler.update() {
@@ -1217,7 +2015,32 @@ impl From<Event> for WinitEvent<Event> {
 #[derive(Debug, Clone)]
 pub enum WebCommand {
     OpenUrl { url: String, new_tab: bool },
-    CopyToClipboard { text: String },
+    /// Remember the scroll offset of a page which is navigated away from.
+    SaveScroll { url: String, scroll_x: f64, scroll_y: f64 },
+    /// Middle click in a page, on the link with URL `url`.
+    MiddleClick { url: Option<String> },
+    /// Copy text to the clipboard, with an optional HTML flavor of it.
+    CopyToClipboard { text: String, html: Option<String> },
+    /// A heading hint scrolled to its heading, `found` is `false` when the page has no headings.
+    JumpToHeading { found: bool },
+    /// Show an element picked by an inspect hint, described as JSON.
+    InspectElement {
+        description: String,
+    },
+    /// Show and copy the form controls of a page, described as a JSON array.
+    DumpForm {
+        description: String,
+    },
+    /// Ask for another Enter before submitting the focused form.
+    ConfirmFormSubmit,
+    /// Cookies, storage and caches were removed, the page is reloaded to show the result.
+    SiteDataCleared { result: Result<String, String> },
+    /// Reload the page every `interval`, or stop reloading it without an interval.
+    SetAutoReload { interval: Option<Duration> },
+    /// Auto reload timer of the tab fired.
+    AutoReload,
+    /// Write the favicon of the tab to a PNG file.
+    SaveFavicon { path: PathBuf },
     SetMark {
         name: char,
         url: String,
@@ -1234,6 +2057,8 @@ pub enum EventType {
     Scroll(Scroll),
     CreateWindow(WindowOptions),
     CreateTab(WindowOptions),
+    /// Open a web page in a new tab, or switch to a tab already showing it.
+    OpenWebTab(WebTabRequest),
     TabCommand(TabCommand),
     #[cfg(target_os = "macos")]
     WebCommand(WebCommand),
@@ -1245,6 +2070,26 @@ pub enum EventType {
     WebCursor { cursor: Option<CursorIcon> },
     #[cfg(target_os = "macos")]
     WebCursorRequest,
+    /// Target of the link under the mouse cursor, `None` once the cursor left it.
+    #[cfg(target_os = "macos")]
+    WebHoverLink { url: Option<String> },
+    /// Notification requested by a page through the Notifications API.
+    #[cfg(target_os = "macos")]
+    WebNotification { title: String, body: String },
+    /// A page requested permission to post notifications.
+    #[cfg(target_os = "macos")]
+    WebNotificationPermission,
+    /// A notification of a web tab was clicked.
+    #[cfg(target_os = "macos")]
+    WebNotificationClicked,
+    /// Web page load progress, from `0.` when navigation starts to `1.` once it's done.
+    ///
+    /// Failed navigations also report `1.`, only pages which loaded successfully are `finished`.
+    #[cfg(target_os = "macos")]
+    WebLoadProgress { fraction: f64, finished: bool },
+    /// Flush the scrolling accumulated while smooth scrolling.
+    #[cfg(target_os = "macos")]
+    WebScroll,
     #[cfg(target_os = "macos")]
     CloseTab(TabId),
     #[cfg(target_os = "macos")]
@@ -1255,14 +2100,61 @@ pub enum EventType {
     OpenUrls(Vec<String>),
     #[cfg(unix)]
     IpcRequest(IpcRequest, Arc<UnixStream>),
+    /// Type the next character of input queued by `send_input_typed`.
+    #[cfg(unix)]
+    TypedInput,
+    /// Reap the shells of closed tabs, killing the ones past their shutdown grace period.
+    #[cfg(not(windows))]
+    ShellShutdown,
+    /// Override config options of a window, like `--option`.
+    #[cfg(unix)]
+    SetWindowConfig(Vec<String>),
+    /// Override the colors of a window with one of the configured themes.
+    #[cfg(unix)]
+    SetWindowTheme(String),
     BlinkCursor,
     BlinkCursorTimeout,
     TabActivityTick,
+    ClipboardPoll,
     SearchNext,
     UpdateTabProgramName,
+    PipeOutput(PipeOutput),
     Frame,
 }
 
+/// Web page to open in a new tab.
+#[derive(Debug, Clone)]
+pub struct WebTabRequest {
+    pub url: String,
+
+    /// Page of the tab which opened the URL.
+    pub from: Option<String>,
+    pub trigger: WebNavigationTrigger,
+    pub private: bool,
+    pub working_directory: Option<PathBuf>,
+}
+
+/// Result of a `:pipe` command.
+#[derive(Debug, Clone)]
+pub struct PipeOutput {
+    pub command: String,
+    pub result: Result<FilterOutput, String>,
+    pub target: PipeTarget,
+}
+
+/// Destination for the output of a `:pipe` command.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PipeTarget {
+    /// Show the output in the message bar.
+    Message,
+
+    /// Paste the output into the tab which ran the command.
+    Paste,
+
+    /// Open the output in a new tab.
+    NewTab,
+}
+
 impl From<TerminalEvent> for EventType {
     fn from(event: TerminalEvent) -> Self {
         Self::Terminal(event)
@@ -1492,8 +2384,42 @@ impl Default for InlineSearchState {
     }
 }
 
+/// Vi mode marks, keyed by their name.
+#[derive(Default)]
+pub struct ViMarks {
+    /// Mark action waiting for the mark name.
+    pub pending: Option<ViMarkAction>,
+    marks: HashMap<char, ViMark>,
+}
+
+/// Action applied to the next mark name.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ViMarkAction {
+    Set,
+    Jump,
+}
+
+/// Scrollback position stored by a vi mode mark.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ViMark {
+    pub display_offset: usize,
+    pub point: Point,
+}
+
+impl ViMark {
+    /// Clamp the mark to a grid whose scrollback might have shrunk since it was set.
+    fn clamp<D: Dimensions>(self, dimensions: &D) -> Self {
+        let display_offset = min(self.display_offset, dimensions.history_size());
+        let line = self.point.line.grid_clamp(dimensions, Boundary::Grid);
+        let column = min(self.point.column, dimensions.last_column());
+        Self { display_offset, point: Point::new(line, column) }
+    }
+}
+
 pub struct ActionContext<'a, N, T> {
     pub notifier: &'a mut N,
+    /// Notifiers of the other tabs receiving typed input.
+    pub broadcast: Vec<N>,
     pub terminal: &'a mut Term<T>,
     pub clipboard: &'a mut Clipboard,
     pub mouse: &'a mut Mouse,
@@ -1503,6 +2429,7 @@ pub struct ActionContext<'a, N, T> {
     pub message_buffer: &'a mut MessageBuffer,
     pub config: &'a UiConfig,
     pub cursor_blink_timed_out: &'a mut bool,
+    pub cursor_color: &'a mut Option<Rgb>,
     pub prev_bell_cmd: &'a mut Option<Instant>,
     pub command_state: &'a mut CommandState,
     pub command_history: &'a mut CommandHistory,
@@ -1518,6 +2445,8 @@ pub struct ActionContext<'a, N, T> {
     pub scheduler: &'a mut Scheduler,
     pub search_state: &'a mut SearchState,
     pub inline_search_state: &'a mut InlineSearchState,
+    pub vi_marks: &'a mut ViMarks,
+    pub macros: &'a mut Macros,
     pub dirty: &'a mut bool,
     pub occluded: &'a mut bool,
     pub preserve_title: bool,
@@ -1527,19 +2456,92 @@ pub struct ActionContext<'a, N, T> {
     pub shell_pid: u32,
 }
 
-#[cfg(target_os = "macos")]
-pub(crate) fn request_web_cursor_update(
-    web_view: &mut WebView,
-    web_command_state: &mut WebCommandState,
-    display: &Display,
-    position: PhysicalPosition<f64>,
-    event_proxy: &EventLoopProxy<Event>,
-    scheduler: &mut Scheduler,
-    window_id: WindowId,
-    tab_id: TabId,
-) {
-    web_command_state.set_last_cursor_pos(position);
+/// Text of the scrollback history and screen, limited to the most recent `limit` lines unless
+/// it's zero.
+fn scrollback_text<T>(terminal: &Term<T>, limit: usize) -> String {
+    let bottommost_line = terminal.bottommost_line();
+    let mut topmost_line = terminal.topmost_line();
+    if limit != 0 && terminal.total_lines() > limit {
+        topmost_line = bottommost_line - (limit - 1);
+    }
+
+    let start = Point::new(topmost_line, Column(0));
+    let end = Point::new(bottommost_line, terminal.last_column());
+    let text = terminal.bounds_to_string(start, end);
+    let text = text.lines().map(str::trim_end).collect::<Vec<_>>().join("\n");
+    text.trim_end().to_owned()
+}
+
+/// Write `text` to a new file in the temporary directory, which is only accessible by the user.
+///
+/// The file is kept once it's written, removing it is left to the caller.
+fn write_temp_file(prefix: &str, text: &str) -> io::Result<PathBuf> {
+    let mut file = tempfile::Builder::new().prefix(prefix).suffix(".txt").tempfile()?;
+    file.write_all(text.as_bytes())?;
+    let (_, path) = file.keep()?;
+    Ok(path)
+}
+
+/// Command reading a temporary file written by [`write_temp_file`] through `program`.
+///
+/// The file is removed as soon as it's opened.
+#[cfg(not(windows))]
+fn temp_file_command(path: &Path, program: &[&str]) -> Vec<String> {
+    let mut command = vec![
+        String::from("sh"),
+        String::from("-c"),
+        String::from(TEMP_FILE_INPUT_SCRIPT),
+        String::from("sh"),
+        path.display().to_string(),
+    ];
+    command.extend(program.iter().map(|arg| String::from(*arg)));
+    command
+}
+
+/// Write text opened by `:edit` to a new file in the temporary directory.
+///
+/// The files are left for the editor, they're never removed by Tabor.
+fn write_edit_file(text: &str) -> io::Result<PathBuf> {
+    write_temp_file("tabor-edit-", text)
+}
+
+/// Program and arguments opening `path` in an editor.
+///
+/// Without `general.editor`, `$VISUAL` or `$EDITOR` is run in a new Tabor instance, since these
+/// are usually terminal programs.
+fn editor_command(editor: Option<&Program>, path: &Path) -> Option<(String, Vec<String>)> {
+    let path = path.display().to_string();
+    if let Some(editor) = editor {
+        let mut args = editor.args().to_vec();
+        args.push(path);
+        return Some((editor.program().to_owned(), args));
+    }
+
+    let editor = env::var("VISUAL").or_else(|_| env::var("EDITOR")).ok()?;
+    let mut editor_args = editor.split_whitespace().map(str::to_owned).peekable();
+    editor_args.peek()?;
+
+    let tabor = env::current_exe().ok()?.display().to_string();
+    let mut args = vec![String::from("-e")];
+    args.extend(editor_args);
+    args.push(path);
+    Some((tabor, args))
+}
 
+/// Send an IPC reply, unless another reply was already sent for the same request.
+#[cfg(unix)]
+fn send_reply_once(pending: &Mutex<Option<Arc<UnixStream>>>, reply: SocketReply) {
+    let Some(stream) = pending.lock().take() else {
+        return;
+    };
+    if let Ok(mut stream) = stream.try_clone() {
+        ipc::send_reply(&mut stream, reply);
+    }
+}
+
+/// Convert a window position to a point of the web view, `None` if it's outside of the view.
+#[cfg(target_os = "macos")]
+fn web_local_position(display: &Display, position: PhysicalPosition<f64>) -> Option<(f64, f64)> {
     let scale_factor = display.window.scale_factor as f64;
     let size_info = display.size_info;
     let origin_x = f64::from(size_info.padding_x()) / scale_factor;
@@ -1553,9 +2555,33 @@ pub(crate) fn request_web_cursor_update(
     let local_y = position.y / scale_factor - origin_y;
 
     if local_x < 0.0 || local_y < 0.0 || local_x >= width || local_y >= height {
-        return;
+        return None;
     }
 
+    Some((local_x, local_y))
+}
+
+#[cfg(target_os = "macos")]
+pub(crate) fn request_web_cursor_update(
+    web_view: &mut WebView,
+    web_command_state: &mut WebCommandState,
+    display: &Display,
+    position: PhysicalPosition<f64>,
+    event_proxy: &EventLoopProxy<Event>,
+    scheduler: &mut Scheduler,
+    window_id: WindowId,
+    tab_id: TabId,
+) {
+    web_command_state.set_last_cursor_pos(position);
+
+    let Some((local_x, local_y)) = web_local_position(display, position) else {
+        if web_command_state.hover_link().is_some() {
+            let event = Event::for_tab(EventType::WebHoverLink { url: None }, window_id, tab_id);
+            let _ = event_proxy.send_event(event);
+        }
+        return;
+    };
+
     if web_command_state.cursor_pending() {
         return;
     }
@@ -1591,6 +2617,13 @@ pub(crate) fn request_web_cursor_update(
         let event = Event::for_tab(EventType::WebCursor { cursor }, window_id, tab_id);
         let _ = proxy.send_event(event);
     });
+
+    let proxy = event_proxy.clone();
+    web_view.eval_js_string(&web_link_script(local_x, local_y), move |result| {
+        let url = result.filter(|url| !url.is_empty());
+        let event = Event::for_tab(EventType::WebHoverLink { url }, window_id, tab_id);
+        let _ = proxy.send_event(event);
+    });
 }
 
 impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionContext<'a, N, T> {
@@ -1599,6 +2632,14 @@ impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionCon
         self.notifier.notify(val);
     }
 
+    fn write_input_to_pty<B: Into<Cow<'static, [u8]>>>(&self, val: B) {
+        let val = val.into();
+        for notifier in &self.broadcast {
+            notifier.notify(val.clone());
+        }
+        self.notifier.notify(val);
+    }
+
     /// Request a redraw.
     #[inline]
     fn mark_dirty(&mut self) {
@@ -1659,6 +2700,23 @@ impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionCon
         self.clipboard.store(ty, text);
     }
 
+    // Copy the entire scrollback history and screen.
+    fn copy_scrollback(&mut self) {
+        // Only copy the most recent lines of large buffers.
+        let limit = self.config.selection.scrollback_copy_limit as usize;
+        let total_lines = self.terminal.total_lines();
+        let truncated = limit != 0 && total_lines > limit;
+        let text = scrollback_text(self.terminal, limit);
+
+        if truncated {
+            let message = format!("Copied the last {limit} of {total_lines} scrollback lines");
+            self.message_buffer.push(Message::new(message, crate::message_bar::MessageType::Info));
+            self.display.pending_update.dirty = true;
+        }
+
+        self.clipboard.store(ClipboardType::Clipboard, text);
+    }
+
     fn selection_is_empty(&self) -> bool {
         self.terminal.selection.as_ref().is_none_or(Selection::is_empty)
     }
@@ -1765,6 +2823,28 @@ impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionCon
         self.tab_kind
     }
 
+    /// Toggle the page zoom between 100% and `web.double_tap_zoom`, centered on the mouse cursor.
+    #[cfg(target_os = "macos")]
+    fn web_smart_zoom(&mut self) {
+        let Some(web_view) = self.web_view.as_mut() else {
+            return;
+        };
+
+        let zoom = web_view.page_zoom();
+        let target = if zoom == 1. { self.config.web.double_tap_zoom } else { 1. };
+
+        // Page coordinates are scaled by the zoom, unlike the view's.
+        let position = self.web_command_state.last_cursor_pos();
+        let position = position.and_then(|position| web_local_position(self.display, position));
+        if let Some((x, y)) = position {
+            web_view.exec_js(&web_smart_zoom_mark_script(x / zoom, y / zoom));
+        }
+
+        web_view.set_page_zoom(target.clamp(WEB_ZOOM_RANGE.0, WEB_ZOOM_RANGE.1));
+        web_view.exec_js(WEB_SMART_ZOOM_CENTER_SCRIPT);
+        *self.dirty = true;
+    }
+
     #[cfg(target_os = "macos")]
     fn web_request_cursor_update(&mut self, position: PhysicalPosition<f64>) {
         if !self.tab_kind.is_web() {
@@ -1841,6 +2921,38 @@ impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionCon
         let _ = self.event_proxy.send_event(event);
     }
 
+    fn split_tab(&mut self) {
+        self.send_tab_command(TabCommand::Split(None));
+    }
+
+    fn close_split(&mut self) {
+        self.send_tab_command(TabCommand::CloseSplit);
+    }
+
+    fn focus_next_pane(&mut self) {
+        self.send_tab_command(TabCommand::FocusNextPane);
+    }
+
+    fn swap_panes(&mut self) {
+        self.send_tab_command(TabCommand::SwapPanes);
+    }
+
+    fn resize_pane(&mut self, percent: i8) {
+        self.send_tab_command(TabCommand::ResizePane(percent));
+    }
+
+    fn move_tab_to_new_window(&mut self) {
+        self.send_tab_command(TabCommand::MoveToNewWindow(None));
+    }
+
+    fn toggle_tab_panel(&mut self) {
+        self.send_tab_command(TabCommand::ToggleTabPanel);
+    }
+
+    fn select_recent_tab(&mut self, step: isize) {
+        self.send_tab_command(TabCommand::SelectMru(step));
+    }
+
     #[cfg(target_os = "macos")]
     fn select_next_tab(&mut self) {
         let event =
@@ -1888,14 +3000,34 @@ impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionCon
     }
 
     fn change_font_size(&mut self, delta: f32) {
-        // Round to pick integral px steps, since fonts look better on them.
-        let new_size = self.display.font_size.as_px().round() + delta;
+        // Zoom the page instead of the terminal font in web tabs.
+        #[cfg(target_os = "macos")]
+        if let Some(web_view) = self.web_view.as_ref() {
+            let zoom = web_view.page_zoom() + f64::from(delta) * WEB_ZOOM_STEP;
+            self.web_set_zoom(zoom);
+            return;
+        }
+
+        let scale_factor = self.display.window.scale_factor as f32;
+        let min_size = self.config.font.min_size().scale(scale_factor).as_px();
+        let max_size = self.config.font.max_size().scale(scale_factor).as_px();
+        let size = self.display.font_size.as_px();
+        let Some(new_size) = changed_font_size(size, delta, min_size, max_size) else {
+            return;
+        };
+
         self.display.font_size = FontSize::from_px(new_size);
         let font = self.config.font.clone().with_size(self.display.font_size);
         self.display.pending_update.set_font(font);
     }
 
     fn reset_font_size(&mut self) {
+        #[cfg(target_os = "macos")]
+        if self.web_view.is_some() {
+            self.web_set_zoom(1.);
+            return;
+        }
+
         let scale_factor = self.display.window.scale_factor as f32;
         self.display.font_size = self.config.font.size().scale(scale_factor);
         self.display
@@ -2177,6 +3309,7 @@ impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionCon
                 let mut options = WindowOptions::default();
                 options.window_kind = WindowKind::Web { url: String::new() };
                 options.command_input = Some(String::from("o "));
+                options.private = self.private_tab();
                 #[cfg(not(windows))]
                 {
                     options.terminal_options.working_directory =
@@ -2220,6 +3353,11 @@ impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionCon
         }
 
         let input_snapshot = self.command_state.input.clone();
+        if let Some((start, query)) = command_palette_prefix(&input_snapshot) {
+            self.palette_autocomplete(start, query);
+            return;
+        }
+
         let Some((start, prefix)) = command_url_prefix(&input_snapshot) else {
             return;
         };
@@ -2254,10 +3392,38 @@ impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionCon
         *self.dirty = true;
     }
 
-    fn command_input(&mut self, c: char) {
-        if !self.command_state.is_active() {
-            return;
-        }
+    /// Complete the `:cmd` query to the next action containing it.
+    ///
+    /// Repeated completions cycle through the actions matching the query typed originally.
+    fn palette_autocomplete(&mut self, start: usize, query: &str) {
+        let (query, last_index) = match &self.command_state.completion {
+            Some(completion) => (completion.prefix.clone(), Some(completion.index)),
+            None => (query.to_string(), None),
+        };
+
+        let names = self.palette_names();
+        let Some((name, index)) = palette_complete(&names, &query, last_index) else {
+            return;
+        };
+
+        let mut input = self.command_state.input[..start].to_string();
+        if !input.ends_with(' ') {
+            input.push(' ');
+        }
+        input.push_str(&name);
+
+        self.command_state.input = input;
+        self.command_state.completion = Some(CommandCompletion { prefix: query, index });
+
+        self.display.pending_update.dirty = true;
+        self.display.damage_tracker.frame().mark_fully_damaged();
+        *self.dirty = true;
+    }
+
+    fn command_input(&mut self, c: char) {
+        if !self.command_state.is_active() {
+            return;
+        }
 
         let prompt_len = self.command_state.prompt_len();
         match c {
@@ -2568,6 +3734,29 @@ impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionCon
         self.inline_search_state
     }
 
+    fn macros(&mut self) -> &mut Macros {
+        self.macros
+    }
+
+    fn toggle_macro_recording(&mut self) {
+        self.macros.toggle_recording();
+        self.update_macro_indicator();
+    }
+
+    fn replay_macro(&mut self) {
+        self.macros.request_replay();
+    }
+
+    fn macro_register_input(
+        &mut self,
+        register: char,
+        key: PhysicalKey,
+    ) -> Option<Vec<MacroKey>> {
+        let keys = self.macros.select_register(register, key);
+        self.update_macro_indicator();
+        keys
+    }
+
     /// Start vi mode inline search.
     fn start_inline_search(&mut self, direction: Direction, stop_short: bool) {
         self.inline_search_state.stop_short = stop_short;
@@ -2604,6 +3793,50 @@ impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionCon
         self.inline_search_next();
     }
 
+    fn vi_mark_pending(&self) -> bool {
+        self.vi_marks.pending.is_some()
+    }
+
+    /// Wait for the name of a mark to set or jump to.
+    fn start_vi_mark(&mut self, action: ViMarkAction) {
+        self.vi_marks.pending = Some(action);
+    }
+
+    /// Process the mark name following a mark action.
+    fn vi_mark_input(&mut self, text: &str) {
+        // Ignore input with empty text, like modifier keys.
+        let name = match text.chars().next() {
+            Some(name) => name,
+            None => return,
+        };
+
+        // Control characters like escape cancel the pending action.
+        let action = self.vi_marks.pending.take();
+        if name.is_control() {
+            return;
+        }
+
+        match action {
+            Some(ViMarkAction::Set) => {
+                let display_offset = self.terminal.grid().display_offset();
+                let point = self.terminal.vi_mode_cursor.point;
+                self.vi_marks.marks.insert(name, ViMark { display_offset, point });
+            },
+            Some(ViMarkAction::Jump) => {
+                let Some(mark) = self.vi_marks.marks.get(&name) else {
+                    return;
+                };
+                let mark = mark.clamp(self.terminal);
+
+                let display_offset = self.terminal.grid().display_offset();
+                self.scroll(Scroll::Delta(mark.display_offset as i32 - display_offset as i32));
+                self.terminal.vi_mode_cursor.point = mark.point;
+                *self.dirty = true;
+            },
+            None => (),
+        }
+    }
+
     fn message(&self) -> Option<&Message> {
         self.message_buffer.message()
     }
@@ -2639,14 +3872,7 @@ impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionCon
         }
 
         let web_key = web_key_from_event(key);
-        self.with_web_command_state(|state, ctx| {
-            let before = state.status_label();
-            let handled = web_commands::handle_key(state, ctx, web_key, text);
-            if handled && before != state.status_label() {
-                ctx.mark_dirty();
-            }
-            handled
-        })
+        self.web_dispatch_key(web_key, text)
     }
 
     #[cfg(target_os = "macos")]
@@ -2659,6 +3885,14 @@ impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionCon
             return;
         };
 
+        // Middle clicks are only passed on to the page once they're known to miss links.
+        if button == MouseButton::Middle {
+            if state == ElementState::Pressed {
+                ActionContext::web_middle_click(self, position);
+            }
+            return;
+        }
+
         let modifiers = web_modifier_flags(self.modifiers().state());
         let Some(web_view) = self.web_view.as_mut() else {
             return;
@@ -2684,6 +3918,20 @@ impl<'a, N: Notify + 'a, T: EventListener> input::ActionContext<T> for ActionCon
             format!("document.execCommand('insertText', false, {});", Self::js_string(text));
         self.web_exec_js(&script);
     }
+
+    #[cfg(target_os = "macos")]
+    fn web_ime_commit(&mut self, text: &str) {
+        if let Some(web_view) = self.web_view.as_mut() {
+            web_view.insert_text(text);
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    fn web_ime_preedit(&mut self, text: &str, cursor: Option<(usize, usize)>) {
+        if let Some(web_view) = self.web_view.as_mut() {
+            web_view.set_marked_text(text, cursor);
+        }
+    }
 }
 
 #[cfg(target_os = "macos")]
@@ -2728,7 +3976,8 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
     ) -> R {
         let state_ptr = self.web_command_state as *mut WebCommandState;
         // SAFETY: WebCommandState is stored outside ActionContext; WebActions implementations
-        // do not access web_command_state directly, so we can split the mutable borrow here.
+        // receive the state explicitly and do not access web_command_state directly, so we can
+        // split the mutable borrow here.
         unsafe { f(&mut *state_ptr, self) }
     }
 
@@ -2974,13 +4223,24 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
                     return;
                 }
 
-                let url = normalize_web_url(&url);
+                let url = normalize_web_url(&url, &self.config.web.search_engines);
+                let url = strip_query_params(url, &self.config.web.strip_query_params);
                 if matches!(command, "O" | "B") {
-                    self.open_web_url_new_tab(url);
+                    self.open_web_url_new_tab(url, WebNavigationTrigger::Omnibar);
                 } else {
-                    self.open_web_url(url);
+                    self.open_web_url(url, WebNavigationTrigger::Omnibar);
                 }
             },
+            "private" => {
+                let url = parts.collect::<Vec<_>>().join(" ");
+                let url = if url.is_empty() {
+                    url
+                } else {
+                    let url = normalize_web_url(&url, &self.config.web.search_engines);
+                    strip_query_params(url, &self.config.web.strip_query_params)
+                };
+                self.open_private_web_tab(url);
+            },
             "T" => {
                 let query = parts.collect::<Vec<_>>().join(" ");
                 if query.is_empty() {
@@ -3004,30 +4264,302 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
             "r" => {
                 self.reload_web();
             },
+            "clearsite" => self.clear_web_site_data(false),
+            "clearall" => {
+                let message = "This removes the data of all sites, use :clearall! to confirm";
+                self.push_command_error(String::from(message));
+            },
+            "clearall!" => self.clear_web_site_data(true),
+            "reload-every" => {
+                let interval = match parts.next() {
+                    Some("off") => None,
+                    Some(value) => match value.parse::<u64>() {
+                        Ok(seconds) if seconds > 0 => Some(Duration::from_secs(seconds)),
+                        _ => {
+                            let message = format!("Invalid interval for :reload-every: {value}");
+                            self.push_command_error(message);
+                            return;
+                        },
+                    },
+                    None => {
+                        self.push_command_error(String::from("Missing interval for :reload-every"));
+                        return;
+                    },
+                };
+
+                self.set_web_auto_reload(interval);
+            },
             "inspect" | "inspector" | "devtools" => {
                 self.open_web_inspector();
             },
+            "console" => {
+                self.open_web_console();
+            },
+            "useragent" => {
+                if !self.tab_kind.is_web() {
+                    self.push_command_error(String::from("No active web tab"));
+                    return;
+                }
+
+                #[cfg(target_os = "macos")]
+                self.web_set_site_user_agent(trimmed[command.len()..].trim());
+                #[cfg(not(target_os = "macos"))]
+                self.push_command_error(String::from("Web tabs are only available on macOS"));
+            },
+            "ssh" => {
+                let Some(host) = parts.next() else {
+                    self.push_command_error(String::from("Missing host for :ssh"));
+                    return;
+                };
+
+                // Reject options, which would be passed to `ssh` before the destination.
+                if host.starts_with('-') || parts.next().is_some() {
+                    self.push_command_error(format!("Invalid host for :ssh: {host}"));
+                    return;
+                }
+
+                self.open_ssh_tab(host.to_owned());
+            },
+            "clip" => {
+                let Some(index) = parts.next() else {
+                    self.show_clipboard_history();
+                    return;
+                };
+
+                let entry = index
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| index.checked_sub(1))
+                    .and_then(|index| self.clipboard.history().get(index).cloned());
+                let Some(text) = entry else {
+                    self.push_command_error(format!("No clipboard history entry: {index}"));
+                    return;
+                };
+
+                #[cfg(target_os = "macos")]
+                if self.tab_kind.is_web() {
+                    input::ActionContext::web_paste_text(self, &text);
+                    return;
+                }
+
+                input::ActionContext::paste(self, &text, true);
+            },
+            "pdf" => {
+                let path = parts.collect::<Vec<_>>().join(" ");
+                self.print_web_pdf(path);
+            },
+            "savefavicon" => {
+                let path = parts.collect::<Vec<_>>().join(" ");
+                self.save_web_favicon(path);
+            },
+            "yank" => {
+                if self.tab_kind.is_web() {
+                    self.push_command_error(String::from("Nothing to yank in web tabs"));
+                    return;
+                }
+
+                input::ActionContext::copy_scrollback(self);
+            },
+            "cursorcolor" => {
+                let color = match parts.next() {
+                    None | Some("default") => None,
+                    Some(value) => match value.parse::<Rgb>() {
+                        Ok(color) => Some(color),
+                        Err(()) => {
+                            self.push_command_error(format!("Invalid cursor color: {value}"));
+                            return;
+                        },
+                    },
+                };
+
+                *self.cursor_color = color;
+                self.display.damage_tracker.frame().mark_fully_damaged();
+                *self.dirty = true;
+            },
+            "tabcolor" => {
+                let color = match parts.next() {
+                    None | Some("default") => None,
+                    Some(value) => match value.parse::<Rgb>() {
+                        Ok(color) => Some(color),
+                        Err(()) => {
+                            self.push_command_error(format!("Invalid tab color: {value}"));
+                            return;
+                        },
+                    },
+                };
+
+                self.send_tab_command(TabCommand::SetColor(self.tab_id, color));
+            },
+            "split" => {
+                let index = match parts.next().map(str::parse::<usize>) {
+                    None => None,
+                    Some(Ok(index)) if index > 0 => Some(index - 1),
+                    Some(_) => {
+                        self.push_command_error(String::from("Invalid tab index for :split"));
+                        return;
+                    },
+                };
+
+                self.send_tab_command(TabCommand::Split(index));
+            },
+            "unsplit" => self.send_tab_command(TabCommand::CloseSplit),
+            "pager" => {
+                if self.tab_kind.is_web() {
+                    self.push_command_error(String::from("No scrollback in web tabs"));
+                    return;
+                }
+
+                #[cfg(not(windows))]
+                self.open_scrollback_pager();
+                #[cfg(windows)]
+                self.push_command_error(String::from(":pager requires a Unix shell"));
+            },
+            "edit" => self.open_in_editor(),
+            "set" => {
+                let option = match set_command_option(trimmed[command.len()..].trim()) {
+                    Ok(option) => option,
+                    Err(err) => {
+                        self.push_command_error(err);
+                        return;
+                    },
+                };
+
+                #[cfg(unix)]
+                {
+                    let event = Event::new(
+                        EventType::SetWindowConfig(vec![option]),
+                        self.display.window.id(),
+                    );
+                    let _ = self.event_proxy.send_event(event);
+                }
+
+                #[cfg(not(unix))]
+                {
+                    let _ = option;
+                    self.push_command_error(String::from(":set is not supported on this platform"));
+                }
+            },
+            "theme" => {
+                let name = trimmed[command.len()..].trim();
+                if !self.config.themes.contains_key(name) {
+                    let mut themes: Vec<_> = self.config.themes.keys().cloned().collect();
+                    themes.sort_unstable();
+                    let themes = themes.join(", ");
+                    let message = if themes.is_empty() {
+                        String::from("No themes configured")
+                    } else if name.is_empty() {
+                        format!("Usage: :theme name, with one of {themes}")
+                    } else {
+                        format!("Unknown theme {name}, expected one of {themes}")
+                    };
+                    self.push_command_error(message);
+                    return;
+                }
+
+                #[cfg(unix)]
+                {
+                    let event = Event::new(
+                        EventType::SetWindowTheme(name.to_owned()),
+                        self.display.window.id(),
+                    );
+                    let _ = self.event_proxy.send_event(event);
+                }
+
+                #[cfg(not(unix))]
+                self.push_command_error(String::from(":theme is not supported on this platform"));
+            },
+            "scrollto" => {
+                let selector = trimmed[command.len()..].trim();
+                if selector.is_empty() {
+                    self.push_command_error(String::from("Missing selector for :scrollto"));
+                    return;
+                }
+
+                if !self.tab_kind.is_web() {
+                    self.push_command_error(String::from("No active web tab to scroll"));
+                    return;
+                }
+
+                #[cfg(target_os = "macos")]
+                self.web_scroll_to_selector(selector);
+                #[cfg(not(target_os = "macos"))]
+                self.push_command_error(String::from("Web tabs are only available on macOS"));
+            },
+            "pipe" | "pipe!" | "Pipe" => {
+                let shell_command = trimmed[command.len()..].trim();
+                if shell_command.is_empty() {
+                    self.push_command_error(format!("Missing command for :{command}"));
+                    return;
+                }
+
+                let target = match command {
+                    "pipe!" => PipeTarget::Paste,
+                    "Pipe" => PipeTarget::NewTab,
+                    _ => PipeTarget::Message,
+                };
+                self.pipe(shell_command.to_owned(), target);
+            },
+            "broadcast" => {
+                let command = match parts.next() {
+                    None | Some("on") => BroadcastCommand::All,
+                    Some("group") => BroadcastCommand::Group,
+                    Some("off") => BroadcastCommand::Off,
+                    Some(value) => match value.parse::<usize>() {
+                        Ok(index) if index > 0 => BroadcastCommand::Toggle(index - 1),
+                        _ => {
+                            self.push_command_error(format!("Invalid tab for :broadcast: {value}"));
+                            return;
+                        },
+                    },
+                };
+
+                self.send_tab_command(TabCommand::Broadcast(command));
+            },
+            "web" => self.open_in_web_tab(trimmed[command.len()..].trim()),
+            "toc" => {
+                if !self.tab_kind.is_web() {
+                    self.push_command_error(String::from("No active web tab to list headings of"));
+                    return;
+                }
+
+                #[cfg(target_os = "macos")]
+                self.with_web_command_state(|state, ctx| {
+                    web_commands::perform_action(state, ctx, WebAction::HintHeading);
+                });
+            },
+            "term" => {
+                if !self.tab_kind.is_web() {
+                    self.push_command_error(String::from("No active web tab to open"));
+                    return;
+                }
+
+                #[cfg(target_os = "macos")]
+                self.web_open_in_terminal();
+            },
+            "cmd" => self.run_palette_command(trimmed[command.len()..].trim()),
             _ => {
                 self.push_command_error(format!("Unknown command: {command}"));
             },
         }
     }
 
-    fn open_web_url(&mut self, url: String) {
-        match &mut *self.tab_kind {
-            WindowKind::Web { url: current_url } => {
-                *current_url = url.clone();
+    fn open_web_url(&mut self, url: String, trigger: WebNavigationTrigger) {
+        match &*self.tab_kind {
+            WindowKind::Web { .. } => {
                 #[cfg(target_os = "macos")]
-                if let Some(web_view) = self.web_view.as_mut() {
-                    if web_view.load_url(&url) {
-                        self.command_history.record_url(url);
-                        return;
+                self.with_web_command_state(|state, ctx| ctx.web_load_url(state, url, trigger));
+
+                #[cfg(not(target_os = "macos"))]
+                {
+                    if let WindowKind::Web { url: current_url } = &mut *self.tab_kind {
+                        *current_url = url;
                     }
+                    self.push_command_error(String::from("Failed to load URL"));
                 }
-
-                self.push_command_error(String::from("Failed to load URL"));
             },
             WindowKind::Terminal => {
+                log_web_navigation(self.tab_id, None, &url, trigger);
+
                 let mut options = WindowOptions::default();
                 options.window_kind = WindowKind::Web { url };
                 #[cfg(not(windows))]
@@ -3048,65 +4580,608 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
         }
     }
 
-    fn open_web_url_new_tab(&mut self, url: String) {
-        let mut options = WindowOptions::default();
-        options.window_kind = WindowKind::Web { url: url.clone() };
-        #[cfg(not(windows))]
-        {
-            options.terminal_options.working_directory =
-                foreground_process_path(self.master_fd, self.shell_pid).ok();
+    /// Navigate the web tab to a file or URL dropped onto the window.
+    ///
+    /// Winit reports every dropped file separately, only the first one of a drop is opened.
+    fn open_dropped_file(&mut self, path: PathBuf) {
+        if self.mouse.file_drop_navigated {
+            let message = format!("Ignoring dropped file {}", path.display());
+            self.message_buffer
+                .push(Message::new(message, crate::message_bar::MessageType::Warning));
+            self.display.pending_update.dirty = true;
+            return;
         }
 
-        let event = Event::new(EventType::CreateTab(options), self.display.window.id());
-        self.command_history.record_url(url);
-        let _ = self.event_proxy.send_event(event);
+        let url = match Url::from_file_path(&path) {
+            Ok(url) if path.exists() => url.to_string(),
+            _ => normalize_web_url(&path.to_string_lossy(), &self.config.web.search_engines),
+        };
+        if url.is_empty() {
+            return;
+        }
+
+        self.mouse.file_drop_navigated = true;
+        self.open_web_url(url, WebNavigationTrigger::Drop);
     }
 
-    pub(crate) fn reload_web(&mut self) {
-        match &*self.tab_kind {
-            WindowKind::Web { .. } => {
-                #[cfg(target_os = "macos")]
-                if let Some(web_view) = self.web_view.as_mut() {
-                    web_view.reload();
-                    self.web_command_state.set_cursor_bootstrapped(false);
-                    self.web_command_state.clear_last_cursor_request();
-                    self.display.pending_update.dirty = true;
-                    self.display.damage_tracker.frame().mark_fully_damaged();
-                    *self.dirty = true;
+    /// Open a URL of a terminal tab in a new web tab.
+    ///
+    /// Without an explicit URL, the selection or the hint under the vi mode cursor is opened.
+    fn open_in_web_tab(&mut self, url: &str) {
+        let url = if !url.is_empty() {
+            url.to_owned()
+        } else if self.tab_kind.is_web() {
+            self.push_command_error(String::from("Missing URL for :web"));
+            return;
+        } else {
+            let selection = self.terminal.selection_to_string();
+            let text = selection.as_deref().map(str::trim).filter(|text| !text.is_empty());
+            let hint = self.display.vi_highlighted_hint.as_ref();
+            match text.map(str::to_owned).or_else(|| {
+                hint.and_then(|hint| hint.text(self.terminal)).map(|text| text.into_owned())
+            }) {
+                Some(url) => url,
+                None => {
+                    self.push_command_error(String::from("No selection or URL at the vi cursor"));
                     return;
-                }
+                },
+            }
+        };
 
-                self.push_command_error(String::from("Web view is unavailable"));
-            },
-            WindowKind::Terminal => {
-                self.push_command_error(String::from("No active web tab to reload"));
-            },
-        }
+        let url = normalize_web_url(&url, &self.config.web.search_engines);
+        let url = strip_query_params(url, &self.config.web.strip_query_params);
+        self.open_web_url_new_tab(url, WebNavigationTrigger::Omnibar);
     }
 
-    pub(crate) fn open_web_inspector(&mut self) {
-        match &*self.tab_kind {
-            WindowKind::Web { .. } => {
-                #[cfg(target_os = "macos")]
-                if let Some(web_view) = self.web_view.as_mut() {
-                    if web_view.show_inspector() {
-                        return;
-                    }
-                }
+    fn open_web_url_new_tab(&mut self, url: String, trigger: WebNavigationTrigger) {
+        let from = match &*self.tab_kind {
+            WindowKind::Web { url } if !url.is_empty() => Some(url.clone()),
+            _ => None,
+        };
 
-                self.push_command_error(String::from("Web inspector is unavailable"));
-            },
-            WindowKind::Terminal => {
+        #[cfg(not(windows))]
+        let working_directory = foreground_process_path(self.master_fd, self.shell_pid).ok();
+        #[cfg(windows)]
+        let working_directory = None;
+
+        let request =
+            WebTabRequest { url, from, trigger, private: self.private_tab(), working_directory };
+        let event = Event::new(EventType::OpenWebTab(request), self.display.window.id());
+        let _ = self.event_proxy.send_event(event);
+    }
+
+    /// Whether the active tab is private, leaving no trace of the pages it visits.
+    fn private_tab(&self) -> bool {
+        #[cfg(target_os = "macos")]
+        return self.web_view.as_ref().is_some_and(|web_view| web_view.is_private());
+
+        #[cfg(not(target_os = "macos"))]
+        false
+    }
+
+    /// Remove the cookies, storage and caches of the site in the active web tab, or of all sites.
+    ///
+    /// The page is reloaded once its data was removed.
+    fn clear_web_site_data(&mut self, all: bool) {
+        if !self.tab_kind.is_web() {
+            self.push_command_error(String::from("No active web tab"));
+            return;
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            let host = self.current_web_url().as_deref().and_then(site_host).filter(|_| !all);
+            if host.is_none() && !all {
+                self.push_command_error(String::from("No site data for this page"));
+                return;
+            }
+
+            let Some(web_view) = self.web_view.as_ref() else {
+                self.push_command_error(String::from("Web view is unavailable"));
+                return;
+            };
+
+            let proxy = self.event_proxy.clone();
+            let window_id = self.display.window.id();
+            let tab_id = self.tab_id;
+            web_view.clear_site_data(host.clone(), move |result| {
+                let result = result.map(|sites| match host {
+                    Some(host) => format!("Cleared site data of {host}"),
+                    None => format!("Cleared site data of {sites} sites"),
+                });
+                let command = WebCommand::SiteDataCleared { result };
+                let event = Event::for_tab(EventType::WebCommand(command), window_id, tab_id);
+                let _ = proxy.send_event(event);
+            });
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = all;
+            self.push_command_error(String::from("Web tabs are only available on macOS"));
+        }
+    }
+
+    /// Reload the active web tab every `interval`, or stop reloading it without an interval.
+    fn set_web_auto_reload(&mut self, interval: Option<Duration>) {
+        if !self.tab_kind.is_web() {
+            self.push_command_error(String::from("No active web tab to reload"));
+            return;
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            let command = WebCommand::SetAutoReload { interval };
+            let window_id = self.display.window.id();
+            let event = Event::for_tab(EventType::WebCommand(command), window_id, self.tab_id);
+            let _ = self.event_proxy.send_event(event);
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = interval;
+            self.push_command_error(String::from("Web tabs are only available on macOS"));
+        }
+    }
+
+    /// Open a URL in a new private web tab, which isn't recorded in the command history.
+    fn open_private_web_tab(&mut self, url: String) {
+        let mut options = WindowOptions::default();
+        options.window_kind = WindowKind::Web { url };
+        options.private = true;
+
+        let event = Event::new(EventType::CreateTab(options), self.display.window.id());
+        let _ = self.event_proxy.send_event(event);
+    }
+
+    pub(crate) fn reload_web(&mut self) {
+        match &*self.tab_kind {
+            WindowKind::Web { .. } => {
+                #[cfg(target_os = "macos")]
+                self.with_web_command_state(|state, ctx| ctx.web_reload(state));
+
+                #[cfg(not(target_os = "macos"))]
+                self.push_command_error(String::from("Web view is unavailable"));
+            },
+            WindowKind::Terminal => {
+                self.push_command_error(String::from("No active web tab to reload"));
+            },
+        }
+    }
+
+    pub(crate) fn open_web_inspector(&mut self) {
+        match &*self.tab_kind {
+            WindowKind::Web { .. } => {
+                #[cfg(target_os = "macos")]
+                if let Some(web_view) = self.web_view.as_mut() {
+                    if web_view.show_inspector() {
+                        return;
+                    }
+                }
+
+                self.push_command_error(String::from("Web inspector is unavailable"));
+            },
+            WindowKind::Terminal => {
+                self.push_command_error(String::from("No active web tab to inspect"));
+            },
+        }
+    }
+
+    /// Open the web inspector on its console.
+    fn open_web_console(&mut self) {
+        match &*self.tab_kind {
+            WindowKind::Web { .. } => {
+                #[cfg(target_os = "macos")]
+                if let Some(web_view) = self.web_view.as_mut() {
+                    if !web_view.show_inspector_console() {
+                        let message = "Web inspector has no console, showing console overlay";
+                        self.message_buffer.push(Message::new(
+                            String::from(message),
+                            crate::message_bar::MessageType::Info,
+                        ));
+                        self.display.pending_update.dirty = true;
+                    }
+                    return;
+                }
+
+                self.push_command_error(String::from("Web inspector is unavailable"));
+            },
+            WindowKind::Terminal => {
                 self.push_command_error(String::from("No active web tab to inspect"));
             },
         }
     }
 
+    /// Run the selection, or the cursor line without one, through a shell command.
+    ///
+    /// The command runs in the background, its output is delivered as [`EventType::PipeOutput`].
+    /// The event loop is never blocked waiting for the command.
+    fn pipe(&mut self, command: String, target: PipeTarget) {
+        if self.tab_kind.is_web() {
+            self.push_command_error(String::from("Pipe is only available in terminal tabs"));
+            return;
+        }
+
+        let input = match self.terminal.selection_to_string().filter(|text| !text.is_empty()) {
+            Some(text) => text,
+            None => {
+                let line = self.terminal.grid().cursor.point.line;
+                let start = Point::new(line, Column(0));
+                let end = Point::new(line, self.terminal.last_column());
+                self.terminal.bounds_to_string(start, end).trim_end().to_owned()
+            },
+        };
+
+        #[cfg(not(windows))]
+        let working_directory = foreground_process_path(self.master_fd, self.shell_pid).ok();
+        #[cfg(windows)]
+        let working_directory: Option<PathBuf> = None;
+
+        let event_proxy = self.event_proxy.clone();
+        let window_id = self.display.window.id();
+        let tab_id = self.tab_id;
+        tabor_terminal::thread::spawn_named("pipe", move || {
+            let result = daemon::run_filter(&command, input, working_directory.as_deref());
+            let output = PipeOutput { command, result, target };
+            let event = Event::for_tab(EventType::PipeOutput(output), window_id, tab_id);
+            let _ = event_proxy.send_event(event);
+        });
+    }
+
+    /// Deliver the output of a `:pipe` command to its [`PipeTarget`].
+    pub(crate) fn handle_pipe_output(&mut self, output: PipeOutput) {
+        let FilterOutput { text, truncated } = match output.result {
+            Ok(filter_output) => filter_output,
+            Err(err) => {
+                self.push_command_error(err);
+                return;
+            },
+        };
+
+        if truncated {
+            let message = format!(
+                "Output of {} truncated to {} bytes",
+                output.command,
+                daemon::FILTER_OUTPUT_LIMIT
+            );
+            self.message_buffer.push(Message::new(message, crate::message_bar::MessageType::Info));
+            self.display.pending_update.dirty = true;
+        }
+
+        match output.target {
+            PipeTarget::Message => {
+                let text = text.trim_end_matches(['\r', '\n']);
+                let mut message = if text.is_empty() {
+                    format!("{} finished without output", output.command)
+                } else {
+                    text.lines().take(PIPE_MESSAGE_LINES).collect::<Vec<_>>().join("\n")
+                };
+                if text.lines().count() > PIPE_MESSAGE_LINES {
+                    message.push_str("\n[...]");
+                }
+                self.message_buffer
+                    .push(Message::new(message, crate::message_bar::MessageType::Info));
+                self.display.pending_update.dirty = true;
+                return;
+            },
+            PipeTarget::Paste => {
+                // Avoid executing the last line with its trailing newline.
+                let text = text.trim_end_matches(['\r', '\n']);
+                if !text.is_empty() {
+                    input::ActionContext::paste(self, text, true);
+                }
+                return;
+            },
+            PipeTarget::NewTab => (),
+        }
+
+        #[cfg(windows)]
+        {
+            let _ = text;
+            self.push_command_error(String::from("Piping into a new tab requires a Unix shell"));
+        }
+
+        #[cfg(not(windows))]
+        self.open_pipe_output_tab(text);
+    }
+
+    /// Show the output of a `:pipe` command in a new tab.
+    #[cfg(not(windows))]
+    fn open_pipe_output_tab(&mut self, text: String) {
+        let path = match write_temp_file("tabor-pipe-", &text) {
+            Ok(path) => path,
+            Err(err) => {
+                self.push_command_error(format!("Unable to write pipe output: {err}"));
+                return;
+            },
+        };
+
+        let mut options = WindowOptions::default();
+        options.terminal_options.set_command(temp_file_command(&path, &["cat"]));
+        options.terminal_options.working_directory =
+            foreground_process_path(self.master_fd, self.shell_pid).ok();
+
+        let event = Event::new(EventType::CreateTab(options), self.display.window.id());
+        let _ = self.event_proxy.send_event(event);
+    }
+
+    /// Open a snapshot of the scrollback history and screen in a pager tab.
+    #[cfg(not(windows))]
+    fn open_scrollback_pager(&mut self) {
+        let path = match write_temp_file("tabor-pager-", &scrollback_text(self.terminal, 0)) {
+            Ok(path) => path,
+            Err(err) => {
+                self.push_command_error(format!("Unable to write scrollback: {err}"));
+                return;
+            },
+        };
+
+        let mut options = WindowOptions::default();
+        options.terminal_options.set_command(temp_file_command(&path, &["less", "+G"]));
+        options.return_focus = true;
+        options.terminal_options.working_directory =
+            foreground_process_path(self.master_fd, self.shell_pid).ok();
+
+        let event = Event::new(EventType::CreateTab(options), self.display.window.id());
+        let _ = self.event_proxy.send_event(event);
+    }
+
+    /// Open the selection, or the scrollback history and screen, in the configured editor.
+    ///
+    /// Web tabs open the selected text of the page, or all of its text.
+    fn open_in_editor(&mut self) {
+        let editor = self.config.general.editor.clone();
+
+        #[cfg(target_os = "macos")]
+        if self.tab_kind.is_web() {
+            let Some(web_view) = self.web_view.as_mut() else {
+                self.push_command_error(String::from("Web view is unavailable"));
+                return;
+            };
+
+            let (master_fd, shell_pid) = (self.master_fd, self.shell_pid);
+            web_view.eval_js_string(WEB_EDIT_TEXT_SCRIPT, move |text| {
+                let text = text.unwrap_or_default();
+                let command = write_edit_file(&text).and_then(|path| {
+                    editor_command(editor.as_ref(), &path).ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::NotFound, "no editor configured")
+                    })
+                });
+                let result = command.and_then(|(program, args)| {
+                    spawn_daemon(&program, &args, master_fd, shell_pid)
+                });
+                if let Err(err) = result {
+                    warn!("Unable to open page text in editor: {err}");
+                }
+            });
+            return;
+        }
+
+        let text = match self.terminal.selection_to_string().filter(|text| !text.is_empty()) {
+            Some(text) => text,
+            None => scrollback_text(self.terminal, 0),
+        };
+
+        let path = match write_edit_file(&text) {
+            Ok(path) => path,
+            Err(err) => {
+                self.push_command_error(format!("Unable to write scrollback: {err}"));
+                return;
+            },
+        };
+
+        match editor_command(editor.as_ref(), &path) {
+            Some((program, args)) => self.spawn_daemon(&program, &args),
+            None => self.push_command_error(String::from(
+                "No editor configured, set general.editor or $EDITOR",
+            )),
+        }
+    }
+
+    /// Open a new terminal tab connected to `host`.
+    fn open_ssh_tab(&mut self, host: String) {
+        let mut options = WindowOptions::default();
+        options.terminal_options.set_command(vec![String::from("ssh"), host.clone()]);
+        options.ssh_host = Some(host);
+        #[cfg(not(windows))]
+        {
+            options.terminal_options.working_directory =
+                foreground_process_path(self.master_fd, self.shell_pid).ok();
+        }
+
+        let event = Event::new(EventType::CreateTab(options), self.display.window.id());
+        let _ = self.event_proxy.send_event(event);
+    }
+
+    /// List the clipboard history entries which can be pasted with `:clip <index>`.
+    fn show_clipboard_history(&mut self) {
+        let history = self.clipboard.history();
+        if history.is_empty() {
+            self.push_command_error(String::from("Clipboard history is empty"));
+            return;
+        }
+
+        let entries: Vec<_> = history
+            .iter()
+            .enumerate()
+            .map(|(index, text)| {
+                let preview: String =
+                    text.lines().next().unwrap_or_default().chars().take(60).collect();
+                format!("{}: {preview}", index + 1)
+            })
+            .collect();
+
+        let message_type = crate::message_bar::MessageType::Info;
+        self.message_buffer.push(Message::new(entries.join("\n"), message_type));
+        self.display.pending_update.dirty = true;
+    }
+
+    /// Run the bound action matching `query`, or pick one of several matches.
+    fn run_palette_command(&mut self, query: &str) {
+        #[cfg(target_os = "macos")]
+        if self.tab_kind.is_web() {
+            let entries = self.web_palette_entries();
+            if let Some(action) = self.show_palette(entries, query) {
+                self.with_web_command_state(|state, ctx| {
+                    web_commands::perform_action(state, ctx, action);
+                });
+            }
+            return;
+        }
+
+        let entries = self.terminal_palette_entries();
+        if let Some(action) = self.show_palette(entries, query) {
+            input::execute_action(self, &action);
+        }
+    }
+
+    /// Palette entries of the terminal key bindings.
+    fn terminal_palette_entries(&self) -> Vec<PaletteEntry<Action>> {
+        let bindings = self
+            .config
+            .key_bindings()
+            .iter()
+            .filter(|binding| {
+                !matches!(binding.action, Action::None | Action::ReceiveChar | Action::Hint(_))
+            })
+            .map(|binding| {
+                (binding.to_string(), binding.action.to_string(), binding.action.clone())
+            });
+        palette_entries(bindings)
+    }
+
+    /// Palette entries of the normal mode bindings of web tabs.
+    #[cfg(target_os = "macos")]
+    fn web_palette_entries(&self) -> Vec<PaletteEntry<WebAction>> {
+        let bound = web_commands::bound_actions(self);
+        let bindings =
+            bound.into_iter().map(|(keys, action)| (keys, format!("{action:?}"), action));
+        palette_entries(bindings)
+    }
+
+    /// Names of the actions listed by the palette of the active tab.
+    fn palette_names(&self) -> Vec<String> {
+        #[cfg(target_os = "macos")]
+        if self.tab_kind.is_web() {
+            return self.web_palette_entries().into_iter().map(|entry| entry.name).collect();
+        }
+
+        self.terminal_palette_entries().into_iter().map(|entry| entry.name).collect()
+    }
+
+    /// Return the action of the palette entry matching `query`.
+    ///
+    /// With several matches, the command bar is opened again to pick one of them with Tab.
+    fn show_palette<A>(&mut self, entries: Vec<PaletteEntry<A>>, query: &str) -> Option<A> {
+        let mut matches = palette_search(entries, query);
+        if matches.is_empty() {
+            self.push_command_error(format!("No action matching {query}"));
+            return None;
+        }
+
+        if !query.is_empty() && matches.len() == 1 {
+            return matches.pop().map(|entry| entry.action);
+        }
+
+        self.command_state.start_with_input(':', &format!("cmd {query}"));
+        self.command_autocomplete();
+        None
+    }
+
+    /// Save the page of the active web tab as a PDF.
+    ///
+    /// Without a path, the PDF is saved to `~/Downloads` named after the page title.
+    pub(crate) fn print_web_pdf(&mut self, path: String) {
+        match &*self.tab_kind {
+            WindowKind::Web { .. } => {
+                #[cfg(target_os = "macos")]
+                if let Some(web_view) = self.web_view.as_mut() {
+                    let path = download_path(&path, web_view.title(), "pdf");
+                    let proxy = self.event_proxy.clone();
+                    let window_id = self.display.window.id();
+
+                    web_view.print_to_pdf(move |result| {
+                        let result = result
+                            .and_then(|pdf| fs::write(&path, pdf).map_err(|err| err.to_string()));
+                        let message = match result {
+                            Ok(()) => Message::new(
+                                format!("Saved PDF to {}", path.display()),
+                                crate::message_bar::MessageType::Info,
+                            ),
+                            Err(err) => Message::new(
+                                format!("Could not save PDF to {}: {err}", path.display()),
+                                crate::message_bar::MessageType::Error,
+                            ),
+                        };
+
+                        let event = Event::new(EventType::Message(message), window_id);
+                        let _ = proxy.send_event(event);
+                    });
+                    return;
+                }
+
+                #[cfg(not(target_os = "macos"))]
+                let _ = path;
+                self.push_command_error(String::from("Web view is unavailable"));
+            },
+            WindowKind::Terminal => {
+                self.push_command_error(String::from("No active web tab to print"));
+            },
+        }
+    }
+
+    /// Save the favicon of the active web tab as a PNG.
+    ///
+    /// Without a path, the image is saved to `~/Downloads` named after the page title.
+    fn save_web_favicon(&mut self, path: String) {
+        if !self.tab_kind.is_web() {
+            self.push_command_error(String::from("No active web tab"));
+            return;
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            let Some(web_view) = self.web_view.as_ref() else {
+                self.push_command_error(String::from("Web view is unavailable"));
+                return;
+            };
+
+            let path = download_path(&path, web_view.title(), "png");
+            let command = WebCommand::SaveFavicon { path };
+            let window_id = self.display.window.id();
+            let event = Event::for_tab(EventType::WebCommand(command), window_id, self.tab_id);
+            let _ = self.event_proxy.send_event(event);
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = path;
+            self.push_command_error(String::from("Web tabs are only available on macOS"));
+        }
+    }
+
+    /// Show the register of the active macro recording.
+    fn update_macro_indicator(&mut self) {
+        let recording = self.macros.recording();
+        if self.display.macro_recording == recording {
+            return;
+        }
+
+        self.display.macro_recording = recording;
+        self.display.damage_tracker.frame().mark_fully_damaged();
+        *self.dirty = true;
+    }
+
     fn push_command_error(&mut self, message: String) {
         self.message_buffer
             .push(Message::new(message, crate::message_bar::MessageType::Error));
         self.display.pending_update.dirty = true;
     }
+
+    fn send_tab_command(&mut self, command: TabCommand) {
+        let event = Event::new(EventType::TabCommand(command), self.display.window.id());
+        let _ = self.event_proxy.send_event(event);
+    }
 }
 
 #[cfg(target_os = "macos")]
@@ -3144,6 +5219,54 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
         }
     }
 
+    /// Send a synthetic key press to a web tab, like it was typed by the user.
+    ///
+    /// Keys which aren't handled by web commands are passed on to the page.
+    pub(crate) fn web_send_key(&mut self, key: WebKey, text: &str, mods: ModifiersState) -> bool {
+        if self.web_view.is_none() {
+            return false;
+        }
+
+        // Allow Shift+Enter to reach the page unchanged (used for newlines in chat inputs).
+        let shift_enter = mods.shift_key() && key == WebKey::Enter;
+        if !shift_enter && self.web_dispatch_key(key, text) {
+            return true;
+        }
+
+        let modifiers = web_modifier_flags(mods);
+        match self.web_view.as_mut() {
+            Some(web_view) => web_view.send_key(&self.display.window, key, text, modifiers),
+            None => false,
+        }
+    }
+
+    fn web_dispatch_key(&mut self, key: WebKey, text: &str) -> bool {
+        self.with_web_command_state(|state, ctx| {
+            let before = state.status_label();
+            let passthrough = state.is_passthrough();
+            let handled = web_commands::handle_key(state, ctx, key, text);
+            if handled && before != state.status_label() {
+                ctx.mark_dirty();
+            }
+            if passthrough != state.is_passthrough() {
+                ctx.show_web_passthrough(!passthrough);
+            }
+            handled
+        })
+    }
+
+    /// Indicate that keys are passed on to the page while passthrough mode is active.
+    fn show_web_passthrough(&mut self, active: bool) {
+        self.message_buffer.remove_target(WEB_PASSTHROUGH_MESSAGE_TARGET);
+        if active {
+            let text = String::from("-- PASSTHROUGH -- press Escape twice to exit");
+            let mut message = Message::new(text, crate::message_bar::MessageType::Warning);
+            message.set_target(String::from(WEB_PASSTHROUGH_MESSAGE_TARGET));
+            self.message_buffer.push(message);
+        }
+        self.display.pending_update.dirty = true;
+    }
+
     fn current_web_url(&mut self) -> Option<String> {
         if let Some(view) = self.web_view.as_ref() {
             if let Some(url) = view.current_url() {
@@ -3165,8 +5288,26 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
         self.web_exec_js("if (document.activeElement) { document.activeElement.blur(); }");
     }
 
-    fn web_hints_start(&mut self, _action: WebHintAction) {
-        self.web_exec_js(&format!("{WEB_HINTS_BOOTSTRAP}\nwindow.__taborHints.start();"));
+    fn web_hints_start(&mut self, action: WebHintAction) {
+        if action != WebHintAction::Heading {
+            self.web_exec_js(&web_hints_start_script(action == WebHintAction::Inspect, None));
+            return;
+        }
+
+        let script = format!("{WEB_HINTS_BOOTSTRAP}\nwindow.__taborHints.startHeadings();");
+        let proxy = self.event_proxy.clone();
+        let window_id = self.display.window.id();
+        let tab_id = self.tab_id;
+
+        self.web_eval_js_string(&script, move |result| {
+            if result.as_deref().is_some_and(|count| count != "0") {
+                return;
+            }
+
+            let command = WebCommand::JumpToHeading { found: false };
+            let event = Event::for_tab(EventType::WebCommand(command), window_id, tab_id);
+            let _ = proxy.send_event(event);
+        });
     }
 
     fn web_hints_update(&mut self, keys: &str, action: WebHintAction) {
@@ -3186,7 +5327,9 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
             let command = match action {
                 WebHintAction::Open => WebCommand::OpenUrl { url, new_tab: false },
                 WebHintAction::OpenNewTab => WebCommand::OpenUrl { url, new_tab: true },
-                WebHintAction::CopyLink => WebCommand::CopyToClipboard { text: url },
+                WebHintAction::CopyLink => WebCommand::CopyToClipboard { text: url, html: None },
+                WebHintAction::Inspect => WebCommand::InspectElement { description: url },
+                WebHintAction::Heading => WebCommand::JumpToHeading { found: true },
             };
 
             let event = Event::for_tab(EventType::WebCommand(command), window_id, tab_id);
@@ -3278,72 +5421,359 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
       const start = active.selectionStart;
       const end = active.selectionEnd;
       if (typeof value === "string" && typeof start === "number" && typeof end === "number" && start !== end) {
-        return value.substring(start, end);
+        return JSON.stringify({ text: value.substring(start, end) });
       }
     }
   }
   const sel = window.getSelection();
-  return sel ? sel.toString() : "";
+  if (!sel || sel.rangeCount === 0) return JSON.stringify({ text: "" });
+  const container = document.createElement("div");
+  container.appendChild(sel.getRangeAt(0).cloneContents());
+  return JSON.stringify({ text: sel.toString(), html: container.innerHTML });
 })();"#;
         self.web_eval_js_string(script, move |result| {
-            let Some(text) = result.filter(|text| !text.is_empty()) else {
+            let Some(value) = result
+                .and_then(|result| serde_json::from_str::<serde_json::Value>(&result).ok())
+            else {
+                return;
+            };
+            let Some(text) = value["text"].as_str().filter(|text| !text.is_empty()) else {
+                return;
+            };
+
+            let html = value["html"].as_str().filter(|html| !html.is_empty()).map(str::to_owned);
+            let command = WebCommand::CopyToClipboard { text: text.to_owned(), html };
+            let event = Event::for_tab(EventType::WebCommand(command), window_id, tab_id);
+            let _ = proxy.send_event(event);
+        });
+    }
+
+    /// Zoom the page, remembering the zoom for the current site.
+    fn web_set_zoom(&mut self, zoom: f64) {
+        let Some(web_view) = self.web_view.as_mut() else {
+            return;
+        };
+
+        // Round away the error accumulated by repeated steps.
+        let zoom = ((zoom * 100.).round() / 100.).clamp(WEB_ZOOM_RANGE.0, WEB_ZOOM_RANGE.1);
+        web_view.set_page_zoom(zoom);
+
+        if self.private_tab() {
+            return;
+        }
+
+        if let Some(host) = self.current_web_url().as_deref().and_then(site_host) {
+            let zoom = Some(zoom).filter(|zoom| *zoom != 1.);
+            update_site_settings(&host, |settings| settings.zoom = zoom);
+        }
+    }
+
+    /// Reset the page zoom, only forgetting a zoom remembered for the site once `confirmed`.
+    fn web_reset_zoom(&mut self, confirmed: bool) -> bool {
+        let host = self.current_web_url().as_deref().and_then(site_host);
+        let site_zoom = host.as_deref().and_then(|host| site_settings(host).zoom);
+        // Private tabs never change the remembered zoom.
+        let site_zoom = site_zoom.filter(|_| !self.private_tab());
+        let (Some(host), Some(_), false) = (host, site_zoom, confirmed) else {
+            self.web_set_zoom(1.);
+            return true;
+        };
+
+        if let Some(web_view) = self.web_view.as_mut() {
+            web_view.set_page_zoom(1.);
+        }
+
+        let message = format!("Reset the view again to forget the zoom of {host}");
+        self.message_buffer.push(Message::new(message, crate::message_bar::MessageType::Warning));
+        self.display.pending_update.dirty = true;
+
+        false
+    }
+
+    /// Remember a user agent for the current site, resetting it without one.
+    fn web_set_site_user_agent(&mut self, user_agent: &str) {
+        let Some(host) = self.current_web_url().as_deref().and_then(site_host) else {
+            self.push_command_error(String::from("No site settings for this page"));
+            return;
+        };
+
+        let user_agent = Some(user_agent.to_owned()).filter(|user_agent| !user_agent.is_empty());
+        let agent = match &user_agent {
+            Some(user_agent) => user_agent_preset(user_agent),
+            None => self.config.web.user_agent(),
+        };
+        if let Some(web_view) = self.web_view.as_mut() {
+            if web_view.set_user_agent(agent) {
+                web_view.reload();
+            }
+        }
+
+        if !self.private_tab() {
+            update_site_settings(&host, |settings| settings.user_agent = user_agent);
+        }
+    }
+
+    /// Copy the URLs of all visible links on the page, one per line.
+    fn web_copy_all_links(&mut self, same_origin: bool) {
+        let proxy = self.event_proxy.clone();
+        let window_id = self.display.window.id();
+        let tab_id = self.tab_id;
+        let script = format!(
+            r#"(function() {{
+  const urls = new Set();
+  for (const link of document.querySelectorAll("a[href]")) {{
+    if (link.getClientRects().length === 0) continue;
+    const url = link.href;
+    if (!url || ({same_origin} && new URL(url).origin !== location.origin)) continue;
+    urls.add(url);
+  }}
+  return Array.from(urls).join("\n");
+}})();"#
+        );
+        self.web_eval_js_string(&script, move |result| {
+            let event = match result.filter(|text| !text.is_empty()) {
+                Some(text) => {
+                    let command = WebCommand::CopyToClipboard { text, html: None };
+                    Event::for_tab(EventType::WebCommand(command), window_id, tab_id)
+                },
+                None => {
+                    let message = Message::new(
+                        String::from("No links on the page"),
+                        crate::message_bar::MessageType::Warning,
+                    );
+                    Event::new(EventType::Message(message), window_id)
+                },
+            };
+            let _ = proxy.send_event(event);
+        });
+    }
+
+    /// Collect the name, type and value of every form control on the page.
+    fn web_dump_form(&mut self) {
+        let proxy = self.event_proxy.clone();
+        let window_id = self.display.window.id();
+        let tab_id = self.tab_id;
+        let reveal_passwords = self.config.web.reveal_form_passwords;
+        let script = format!(
+            r#"(function() {{
+  const fields = [];
+  for (const el of document.querySelectorAll("input, select, textarea")) {{
+    const type = el.type || el.tagName.toLowerCase();
+    if (type === "hidden" && !el.name) continue;
+    let value = el.value || "";
+    if (type === "checkbox" || type === "radio") value = el.checked ? value || "on" : "";
+    if (type === "password" && !{reveal_passwords} && value) value = "<redacted>";
+    fields.push({{ name: el.name || el.id || "", type: type, value: value }});
+  }}
+  return JSON.stringify(fields);
+}})();"#
+        );
+        self.web_eval_js_string(&script, move |result| {
+            let Some(description) = result else {
+                return;
+            };
+            let command = WebCommand::DumpForm { description };
+            let event = Event::for_tab(EventType::WebCommand(command), window_id, tab_id);
+            let _ = proxy.send_event(event);
+        });
+    }
+
+    /// Script expression evaluating to the element scrolled along the `x` or `y` axis.
+    ///
+    /// Containers under the mouse cursor are preferred over the focused element.
+    fn web_scroll_target(&self, state: &WebCommandState, axis: char) -> String {
+        let point = state
+            .last_cursor_pos()
+            .and_then(|position| web_local_position(&*self.display, position));
+        web_commands::scroll_target_script(point, axis)
+    }
+
+    fn web_scroll_by(&mut self, state: &WebCommandState, dx: f64, dy: f64) {
+        let target = self.web_scroll_target(state, if dy != 0. { 'y' } else { 'x' });
+        let script = format!("{target}.scrollBy({dx}, {dy});");
+        self.web_exec_js(&script);
+    }
+
+    /// Flush the accumulated smooth scroll, unless a flush is already scheduled.
+    fn schedule_web_scroll(&mut self) {
+        let window_id = self.display.window.id();
+        let timer_id = TimerId::new(Topic::WebScroll, window_id);
+        if !self.scheduler.scheduled(timer_id) {
+            let event = Event::for_tab(EventType::WebScroll, window_id, self.tab_id);
+            self.scheduler.schedule(event, WEB_SMOOTH_SCROLL_INTERVAL, false, timer_id);
+        }
+    }
+
+    /// Start animating the scrolling accumulated since the last flush.
+    fn flush_web_scroll(&mut self, state: &mut WebCommandState) {
+        let Some((dx, dy)) = state.take_smooth_scroll() else {
+            return;
+        };
+
+        // Scroll relative to the target of a running animation, so no distance is lost.
+        let target = self.web_scroll_target(state, if dy != 0. { 'y' } else { 'x' });
+        let script = format!(
+            r#"(function() {{
+  const el = {target};
+  const now = performance.now();
+  const last = el.__taborSmoothScroll;
+  const running = last && last.until > now;
+  const x = (running ? last.x : el.scrollLeft) + {dx};
+  const y = (running ? last.y : el.scrollTop) + {dy};
+  el.__taborSmoothScroll = {{ x: x, y: y, until: now + 500 }};
+  el.scrollTo({{ left: x, top: y, behavior: "smooth" }});
+}})();"#
+        );
+        self.web_exec_js(&script);
+    }
+
+    pub(crate) fn web_scroll_to(&mut self, x: f64, y: f64) {
+        let script = format!("window.scrollTo({x}, {y});");
+        self.web_exec_js(&script);
+    }
+
+    /// Scroll the first element matching a CSS selector into the center of the view.
+    fn web_scroll_to_selector(&mut self, selector: &str) {
+        let script = format!(
+            r#"(function() {{
+  try {{
+    const el = document.querySelector({0});
+    if (!el) return "No element matches " + {0};
+    el.scrollIntoView({{ behavior: "smooth", block: "center" }});
+    return "";
+  }} catch (err) {{
+    return "Invalid selector " + {0};
+  }}
+}})();"#,
+            Self::js_string(selector)
+        );
+        let proxy = self.event_proxy.clone();
+        let window_id = self.display.window.id();
+
+        self.web_eval_js_string(&script, move |result| {
+            let Some(error) = result.filter(|error| !error.is_empty()) else {
                 return;
             };
-            let command = WebCommand::CopyToClipboard { text };
-            let event = Event::for_tab(EventType::WebCommand(command), window_id, tab_id);
-            let _ = proxy.send_event(event);
+
+            let message = Message::new(error, crate::message_bar::MessageType::Error);
+            let _ = proxy.send_event(Event::new(EventType::Message(message), window_id));
         });
     }
 
-    fn web_scroll_by(&mut self, dx: f64, dy: f64) {
-        let script = format!("window.scrollBy({dx}, {dy});");
+    fn web_scroll_half_page(&mut self, state: &WebCommandState, down: bool) {
+        let fraction = self.config.web.scroll.half_page();
+        let distance = if down { fraction } else { -fraction };
+        let behavior = if self.config.web.smooth_scroll { "smooth" } else { "auto" };
+        let target = self.web_scroll_target(state, 'y');
+        let script = format!(
+            r#"(function() {{
+  const el = {target};
+  const root = el === (document.scrollingElement || document.documentElement);
+  const height = root ? window.innerHeight : el.clientHeight;
+  el.scrollBy({{ top: height * {distance}, behavior: "{behavior}" }});
+}})();"#
+        );
         self.web_exec_js(&script);
     }
 
-    fn web_scroll_to(&mut self, x: f64, y: f64) {
-        let script = format!("window.scrollTo({x}, {y});");
+    fn web_scroll_top(&mut self, state: &WebCommandState) {
+        let target = self.web_scroll_target(state, 'y');
+        let script = format!("(function(el) {{ el.scrollTo(el.scrollLeft, 0); }})({target});");
         self.web_exec_js(&script);
     }
 
-    fn web_scroll_half_page(&mut self, down: bool) {
-        let direction = if down { 1.0 } else { -1.0 };
-        let script = format!("window.scrollBy(0, window.innerHeight / 2 * {direction});");
+    fn web_scroll_bottom(&mut self, state: &WebCommandState) {
+        let target = self.web_scroll_target(state, 'y');
+        let script =
+            format!("(function(el) {{ el.scrollTo(el.scrollLeft, el.scrollHeight); }})({target});");
         self.web_exec_js(&script);
     }
 
-    fn web_scroll_top(&mut self) {
-        self.web_exec_js("window.scrollTo(window.scrollX, 0);");
+    fn web_scroll_far_left(&mut self, state: &WebCommandState) {
+        let target = self.web_scroll_target(state, 'x');
+        self.web_exec_js(&format!("(function(el) {{ el.scrollTo(0, el.scrollTop); }})({target});"));
     }
 
-    fn web_scroll_bottom(&mut self) {
-        self.web_exec_js(
-            "window.scrollTo(window.scrollX, Math.max(document.body.scrollHeight, document.documentElement.scrollHeight));",
-        );
+    fn web_scroll_far_right(&mut self, state: &WebCommandState) {
+        let target = self.web_scroll_target(state, 'x');
+        let script =
+            format!("(function(el) {{ el.scrollTo(el.scrollWidth, el.scrollTop); }})({target});");
+        self.web_exec_js(&script);
     }
 
-    fn web_scroll_far_left(&mut self) {
-        self.web_exec_js("window.scrollTo(0, window.scrollY);");
+    /// Load `url` in the current web tab, remembering the page it replaces.
+    fn web_load_url(
+        &mut self,
+        state: &mut WebCommandState,
+        url: String,
+        trigger: WebNavigationTrigger,
+    ) {
+        let previous_url = self.current_web_url();
+
+        if let WindowKind::Web { url: current_url } = &mut *self.tab_kind {
+            *current_url = url.clone();
+        }
+
+        if let Some(web_view) = self.web_view.as_mut() {
+            if web_view.load_url(&url) {
+                log_web_navigation(self.tab_id, previous_url.as_deref(), &url, trigger);
+                if let Some(previous_url) = previous_url.filter(|prev| *prev != url) {
+                    state.set_last_url(previous_url);
+                }
+                if !self.private_tab() {
+                    self.command_history.record_url(url);
+                }
+                return;
+            }
+        }
+
+        self.push_command_error(String::from("Failed to load URL"));
     }
 
-    fn web_scroll_far_right(&mut self) {
-        self.web_exec_js(
-            "window.scrollTo(Math.max(document.body.scrollWidth, document.documentElement.scrollWidth), window.scrollY);",
-        );
+    fn web_reload(&mut self, state: &mut WebCommandState) {
+        let Some(web_view) = self.web_view.as_mut() else {
+            self.push_command_error(String::from("Web view is unavailable"));
+            return;
+        };
+
+        web_view.reload();
+        state.set_cursor_bootstrapped(false);
+        state.clear_last_cursor_request();
+        self.display.pending_update.dirty = true;
+        self.display.damage_tracker.frame().mark_fully_damaged();
+        *self.dirty = true;
     }
 
     fn web_go_back(&mut self) {
+        let from = self.current_web_url();
         if let Some(web_view) = self.web_view.as_mut() {
+            if let Some(to) = web_view.back_url() {
+                log_web_navigation(self.tab_id, from.as_deref(), &to, WebNavigationTrigger::Back);
+            }
             web_view.go_back();
         }
     }
 
     fn web_go_forward(&mut self) {
+        let from = self.current_web_url();
         if let Some(web_view) = self.web_view.as_mut() {
+            if let Some(to) = web_view.forward_url() {
+                let trigger = WebNavigationTrigger::Forward;
+                log_web_navigation(self.tab_id, from.as_deref(), &to, trigger);
+            }
             web_view.go_forward();
         }
     }
 
     fn web_open_command_bar(&mut self, input: &str) {
+        // Edit the URL of the page like in the address bar of a browser.
+        if input == "o " && self.config.web.prefill_omnibar {
+            if let Some(url) = self.current_web_url() {
+                self.start_command_prompt(':', &format!("o {url}"));
+                return;
+            }
+        }
+
         self.start_command_prompt(':', input);
     }
 
@@ -3352,6 +5782,15 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
     }
 
     fn web_find(&mut self, query: &str, backwards: bool) {
+        if self.config.web.highlight_matches {
+            let script = format!(
+                "{WEB_FIND_BOOTSTRAP}\nwindow.__taborFind.search({}, {backwards});",
+                Self::js_string(query)
+            );
+            self.web_exec_js(&script);
+            return;
+        }
+
         let script = format!(
             "window.find({}, false, {}, true, false, true, false);",
             Self::js_string(query),
@@ -3360,6 +5799,38 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
         self.web_exec_js(&script);
     }
 
+    /// Show whether the page was loaded securely, with its server certificate.
+    fn web_show_security_info(&mut self) {
+        let Some(web_view) = self.web_view.as_ref() else {
+            self.push_command_error(String::from("Web view is unavailable"));
+            return;
+        };
+
+        let info = web_view.security_info();
+        let message_type = if info.https && info.only_secure_content {
+            crate::message_bar::MessageType::Info
+        } else {
+            crate::message_bar::MessageType::Error
+        };
+        self.message_buffer.push(Message::new(info.to_string(), message_type));
+        self.display.pending_update.dirty = true;
+    }
+
+    /// Open a terminal tab running `web.terminal_command` for the current URL.
+    fn web_open_in_terminal(&mut self) {
+        let Some(url) = self.current_web_url() else {
+            self.push_command_error(String::from("No active URL"));
+            return;
+        };
+
+        let command = url_shell_command(&self.config.web.terminal_command, &url);
+        let mut options = WindowOptions::default();
+        options.terminal_options.set_command(vec![String::from("sh"), String::from("-c"), command]);
+
+        let event = Event::new(EventType::CreateTab(options), self.display.window.id());
+        let _ = self.event_proxy.send_event(event);
+    }
+
     fn web_focus_input(&mut self) {
         let script = r#"(function() {
   const el = document.querySelector("input, textarea, select, [contenteditable='true']");
@@ -3371,7 +5842,7 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
         self.web_exec_js(script);
     }
 
-    fn web_view_source(&mut self) {
+    fn web_view_source(&mut self, state: &mut WebCommandState) {
         let Some(current) = self.current_web_url() else {
             self.push_command_error(String::from("No active URL"));
             return;
@@ -3381,30 +5852,47 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
         } else {
             format!("view-source:{current}")
         };
-        self.open_web_url(url);
+        self.web_load_url(state, url, WebNavigationTrigger::ViewSource);
     }
 
     fn web_follow_rel(&mut self, rel: &str) {
         let rel = Self::js_string(rel);
+        let same_origin = self.config.web.same_origin_pages;
         let script = format!(
             "(function() {{
   const rel = {rel};
+  const follow = (href) => {{
+    if ({same_origin} && new URL(href, location.href).origin !== location.origin) {{
+      return `Not following ${{rel}} link to another site: ${{href}}`;
+    }}
+    window.location.href = href;
+    return \"\";
+  }};
   const link = document.querySelector(`link[rel~=\"${{rel}}\"], a[rel~=\"${{rel}}\"]`);
   if (link && link.href) {{
-    window.location.href = link.href;
-    return;
+    return follow(link.href);
   }}
   const pattern = rel === \"prev\" ? /(prev|previous)/i : /(next)/i;
   for (const a of Array.from(document.querySelectorAll(\"a[href]\"))) {{
     const text = (a.textContent || \"\").trim();
     if (pattern.test(text)) {{
-      window.location.href = a.href;
-      return;
+      return follow(a.href);
     }}
   }}
+  return \"\";
 }})();"
         );
-        self.web_exec_js(&script);
+        let proxy = self.event_proxy.clone();
+        let window_id = self.display.window.id();
+
+        self.web_eval_js_string(&script, move |result| {
+            let Some(error) = result.filter(|error| !error.is_empty()) else {
+                return;
+            };
+
+            let message = Message::new(error, crate::message_bar::MessageType::Error);
+            let _ = proxy.send_event(Event::new(EventType::Message(message), window_id));
+        });
     }
 
     fn web_copy_url(&mut self) {
@@ -3415,7 +5903,59 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
         self.clipboard.store(ClipboardType::Clipboard, url);
     }
 
-    fn web_open_clipboard(&mut self, new_tab: bool) {
+    /// Open the link under the cursor in a new tab.
+    fn web_middle_click(&mut self, position: PhysicalPosition<f64>) {
+        let Some((x, y)) = web_local_position(&*self.display, position) else {
+            return;
+        };
+
+        let proxy = self.event_proxy.clone();
+        let window_id = self.display.window.id();
+        let tab_id = self.tab_id;
+        let script = format!(
+            "(() => {{ const link = document.elementFromPoint({x}, {y})?.closest('a[href], \
+             area[href]'); return link ? link.href : ''; }})()"
+        );
+        self.web_eval_js_string(&script, move |result| {
+            let url = result.filter(|url| !url.is_empty());
+            let command = WebCommand::MiddleClick { url };
+            let event = Event::for_tab(EventType::WebCommand(command), window_id, tab_id);
+            let _ = proxy.send_event(event);
+        });
+    }
+
+    /// Copy the page title, falling back to the host for pages without one.
+    fn web_copy_title(&mut self) {
+        let proxy = self.event_proxy.clone();
+        let window_id = self.display.window.id();
+        let tab_id = self.tab_id;
+        let script = "document.title.trim() || location.hostname";
+        self.web_eval_js_string(script, move |result| {
+            let Some(text) = result.filter(|text| !text.is_empty()) else {
+                return;
+            };
+
+            let command = WebCommand::CopyToClipboard { text, html: None };
+            let event = Event::for_tab(EventType::WebCommand(command), window_id, tab_id);
+            let _ = proxy.send_event(event);
+        });
+    }
+
+    /// Block or allow loading images in the current tab, reloading the page to apply it.
+    fn web_toggle_images(&mut self, blocked: bool) -> bool {
+        let Some(web_view) = self.web_view.as_mut() else {
+            return false;
+        };
+
+        if !web_view.set_images_blocked(blocked) {
+            self.push_command_error(String::from("Image blocking is unavailable"));
+            return false;
+        }
+        web_view.reload();
+        true
+    }
+
+    fn web_open_clipboard(&mut self, state: &mut WebCommandState, new_tab: bool) {
         let raw = self.clipboard.load(ClipboardType::Clipboard);
         let trimmed = raw.trim();
         if trimmed.is_empty() {
@@ -3423,16 +5963,17 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
             return;
         }
 
-        let url = normalize_web_url(trimmed);
+        let url = normalize_web_url(trimmed, &self.config.web.search_engines);
+        let url = strip_query_params(url, &self.config.web.strip_query_params);
         if new_tab {
-            self.open_web_url_new_tab(url);
+            self.open_web_url_new_tab(url, WebNavigationTrigger::Clipboard);
         } else {
-            self.open_web_url(url);
+            self.web_load_url(state, url, WebNavigationTrigger::Clipboard);
         }
     }
 
     fn web_new_tab(&mut self) {
-        self.open_web_url_new_tab(String::from("about:blank"));
+        self.open_web_url_new_tab(String::from("about:blank"), WebNavigationTrigger::NewTab);
     }
 
     fn web_close_tab(&mut self) {
@@ -3445,8 +5986,14 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
         let _ = self.event_proxy.send_event(event);
     }
 
-    fn web_show_help(&mut self) {
-        let html = Self::js_string(WEB_HELP_HTML);
+    fn web_show_help(&mut self, settings: &[&str]) {
+        let html = if settings.is_empty() {
+            Self::js_string(WEB_HELP_HTML)
+        } else {
+            let settings = settings.join(", ");
+            let settings = format!("<p style=\"margin:0 0 8px;\">This tab: {settings}</p>");
+            Self::js_string(&(settings + WEB_HELP_HTML))
+        };
         let script = format!(
             "(function() {{
   const existing = document.getElementById(\"__tabor_help\");
@@ -3478,7 +6025,7 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
         );
     }
 
-    fn web_up_url(&mut self, root: bool) {
+    fn web_up_url(&mut self, state: &mut WebCommandState, root: bool) {
         let Some(current) = self.current_web_url() else {
             self.push_command_error(String::from("No active URL"));
             return;
@@ -3513,40 +6060,64 @@ impl<'a, N: Notify + 'a, T: EventListener> ActionContext<'a, N, T> {
             parsed.set_path(&new_path);
         }
 
-        self.open_web_url(parsed.to_string());
+        self.web_load_url(state, parsed.to_string(), WebNavigationTrigger::ParentUrl);
     }
 }
 
 #[cfg(target_os = "macos")]
 impl<'a, N: Notify + 'a, T: EventListener> WebActions for ActionContext<'a, N, T> {
-    fn scroll_by(&mut self, dx: f64, dy: f64) {
-        self.web_scroll_by(dx, dy);
+    fn bindings(&self) -> &HashMap<String, WebAction> {
+        &self.config.web.bindings
+    }
+
+    fn scroll_step(&self) -> f64 {
+        self.config.web.scroll.step()
+    }
+
+    fn smooth_scroll(&self) -> bool {
+        self.config.web.smooth_scroll
+    }
+
+    fn scroll_by(&mut self, state: &WebCommandState, dx: f64, dy: f64) {
+        self.web_scroll_by(state, dx, dy);
+    }
+
+    fn schedule_smooth_scroll(&mut self) {
+        self.schedule_web_scroll();
     }
 
-    fn scroll_half_page(&mut self, down: bool) {
-        self.web_scroll_half_page(down);
+    fn scroll_half_page(&mut self, state: &WebCommandState, down: bool) {
+        self.web_scroll_half_page(state, down);
     }
 
-    fn scroll_top(&mut self) {
-        self.web_scroll_top();
+    fn scroll_top(&mut self, state: &WebCommandState) {
+        self.web_scroll_top(state);
     }
 
-    fn scroll_bottom(&mut self) {
-        self.web_scroll_bottom();
+    fn scroll_bottom(&mut self, state: &WebCommandState) {
+        self.web_scroll_bottom(state);
     }
 
-    fn scroll_far_left(&mut self) {
-        self.web_scroll_far_left();
+    fn scroll_far_left(&mut self, state: &WebCommandState) {
+        self.web_scroll_far_left(state);
     }
 
-    fn scroll_far_right(&mut self) {
-        self.web_scroll_far_right();
+    fn scroll_far_right(&mut self, state: &WebCommandState) {
+        self.web_scroll_far_right(state);
     }
 
     fn scroll_to(&mut self, x: f64, y: f64) {
         self.web_scroll_to(x, y);
     }
 
+    fn back_url(&self) -> Option<String> {
+        self.web_view.as_ref()?.back_url()
+    }
+
+    fn forward_url(&self) -> Option<String> {
+        self.web_view.as_ref()?.forward_url()
+    }
+
     fn go_back(&mut self) {
         self.web_go_back();
     }
@@ -3567,6 +6138,10 @@ impl<'a, N: Notify + 'a, T: EventListener> WebActions for ActionContext<'a, N, T
         self.web_find(query, backwards);
     }
 
+    fn clear_find(&mut self) {
+        self.web_exec_js("if (window.__taborFind) { window.__taborFind.clear(); }");
+    }
+
     fn hints_start(&mut self, action: WebHintAction) {
         self.web_hints_start(action);
     }
@@ -3599,6 +6174,10 @@ impl<'a, N: Notify + 'a, T: EventListener> WebActions for ActionContext<'a, N, T
         self.web_focus_input();
     }
 
+    fn focus_next_input(&mut self, backwards: bool) {
+        self.web_exec_js(&format!("({WEB_FOCUS_NEXT_INPUT_SCRIPT})({backwards});"));
+    }
+
     fn blur_active_element(&mut self) {
         self.web_blur_active_element();
     }
@@ -3617,8 +6196,27 @@ impl<'a, N: Notify + 'a, T: EventListener> WebActions for ActionContext<'a, N, T
         self.web_exec_js("document.execCommand('deleteForward');");
     }
 
-    fn insert_paragraph(&mut self) {
-        self.web_exec_js("document.execCommand('insertParagraph');");
+    fn insert_paragraph(&mut self, confirmed: bool) {
+        if !self.config.web.confirm_form_submit {
+            self.web_exec_js("document.execCommand('insertParagraph');");
+            return;
+        }
+
+        let proxy = self.event_proxy.clone();
+        let window_id = self.display.window.id();
+        let tab_id = self.tab_id;
+        let script = format!("({WEB_FORM_SUBMIT_SCRIPT})({confirmed});");
+        self.web_eval_js_string(&script, move |result| {
+            if result.as_deref() != Some("confirm") {
+                return;
+            }
+            let event = Event::for_tab(
+                EventType::WebCommand(WebCommand::ConfirmFormSubmit),
+                window_id,
+                tab_id,
+            );
+            let _ = proxy.send_event(event);
+        });
     }
 
     fn insert_tab(&mut self) {
@@ -3631,8 +6229,20 @@ impl<'a, N: Notify + 'a, T: EventListener> WebActions for ActionContext<'a, N, T
         self.web_caret_move(direction, granularity);
     }
 
-    fn view_source(&mut self) {
-        self.web_view_source();
+    fn view_source(&mut self, state: &mut WebCommandState) {
+        self.web_view_source(state);
+    }
+
+    fn open_console(&mut self) {
+        self.open_web_console();
+    }
+
+    fn open_in_terminal(&mut self) {
+        self.web_open_in_terminal();
+    }
+
+    fn show_security_info(&mut self) {
+        self.web_show_security_info();
     }
 
     fn follow_rel(&mut self, rel: &str) {
@@ -3643,12 +6253,67 @@ impl<'a, N: Notify + 'a, T: EventListener> WebActions for ActionContext<'a, N, T
         self.web_copy_url();
     }
 
-    fn open_clipboard(&mut self, new_tab: bool) {
-        self.web_open_clipboard(new_tab);
+    fn copy_title(&mut self) {
+        self.web_copy_title();
+    }
+
+    fn copy_all_links(&mut self, same_origin: bool) {
+        self.web_copy_all_links(same_origin);
+    }
+
+    fn dump_form(&mut self) {
+        self.web_dump_form();
+    }
+
+    fn block_images(&mut self, blocked: bool) -> bool {
+        self.web_toggle_images(blocked)
+    }
+
+    fn set_javascript_enabled(&mut self, enabled: bool) -> bool {
+        let Some(web_view) = self.web_view.as_mut() else {
+            return false;
+        };
+
+        web_view.set_javascript_enabled(enabled);
+        web_view.reload();
+
+        let text = if enabled { "JavaScript enabled" } else { "JavaScript disabled for this tab" };
+        let message = Message::new(String::from(text), crate::message_bar::MessageType::Info);
+        self.message_buffer.push(message);
+        self.display.pending_update.dirty = true;
+        true
+    }
+
+    fn set_dark_mode(&mut self, enabled: bool) -> bool {
+        let Some(web_view) = self.web_view.as_mut() else {
+            return false;
+        };
+
+        if !web_view.set_dark_mode(enabled) {
+            self.push_command_error(String::from("Dark mode is unavailable"));
+            return false;
+        }
+        true
+    }
+
+    fn set_focus_mode(&mut self, enabled: bool) -> bool {
+        let Some(web_view) = self.web_view.as_mut() else {
+            return false;
+        };
+
+        if !web_view.set_focus_mode(enabled, &self.config.web.focus_mode_selectors) {
+            self.push_command_error(String::from("Focus mode is unavailable"));
+            return false;
+        }
+        true
+    }
+
+    fn open_clipboard(&mut self, state: &mut WebCommandState, new_tab: bool) {
+        self.web_open_clipboard(state, new_tab);
     }
 
-    fn up_url(&mut self, root: bool) {
-        self.web_up_url(root);
+    fn up_url(&mut self, state: &mut WebCommandState, root: bool) {
+        self.web_up_url(state, root);
     }
 
     fn new_tab(&mut self) {
@@ -3679,12 +6344,16 @@ impl<'a, N: Notify + 'a, T: EventListener> WebActions for ActionContext<'a, N, T
         input::ActionContext::select_last_tab(self);
     }
 
-    fn reload(&mut self) {
-        self.reload_web();
+    fn reload(&mut self, state: &mut WebCommandState) {
+        self.web_reload(state);
+    }
+
+    fn reset_zoom(&mut self, confirmed: bool) -> bool {
+        self.web_reset_zoom(confirmed)
     }
 
-    fn show_help(&mut self) {
-        self.web_show_help();
+    fn show_help(&mut self, settings: &[&str]) {
+        self.web_show_help(settings);
     }
 
     fn hide_help(&mut self) {
@@ -3699,8 +6368,13 @@ impl<'a, N: Notify + 'a, T: EventListener> WebActions for ActionContext<'a, N, T
         self.current_web_url()
     }
 
-    fn open_url(&mut self, url: String) {
-        self.open_web_url(url);
+    fn open_url(
+        &mut self,
+        state: &mut WebCommandState,
+        url: String,
+        trigger: WebNavigationTrigger,
+    ) {
+        self.web_load_url(state, url, trigger);
     }
 
     fn push_error(&mut self, message: String) {
@@ -3708,6 +6382,164 @@ impl<'a, N: Notify + 'a, T: EventListener> WebActions for ActionContext<'a, N, T
     }
 }
 
+/// Destination of a file saved from a page, like a PDF saved with `:pdf`.
+///
+/// Directories, including the `~/Downloads` default, receive a file named after the page title.
+#[cfg(target_os = "macos")]
+fn download_path(path: &str, title: Option<String>, extension: &str) -> PathBuf {
+    let home = home::home_dir().unwrap_or_default();
+    let path = match path.trim() {
+        "" => home.join("Downloads"),
+        path => match path.strip_prefix("~/") {
+            Some(stripped) => home.join(stripped),
+            None => PathBuf::from(path),
+        },
+    };
+
+    if !path.is_dir() {
+        return path;
+    }
+
+    let title = title.unwrap_or_default();
+    let name: String =
+        title.trim().chars().map(|c| if matches!(c, '/' | ':') { '-' } else { c }).collect();
+    let name = if name.is_empty() { String::from("page") } else { name };
+    path.join(format!("{name}.{extension}"))
+}
+
+/// Type of a config option value changed with `:set`.
+#[derive(Copy, Clone)]
+enum SetValue {
+    Bool,
+    Float,
+    /// Float which must be above zero.
+    PositiveFloat,
+    Integer,
+}
+
+/// Config options which can be changed for a window with `:set`.
+const SETTABLE_OPTIONS: [(&str, SetValue); 7] = [
+    ("font.size", SetValue::PositiveFloat),
+    ("window.opacity", SetValue::Float),
+    ("window.tab_panel.enabled", SetValue::Bool),
+    ("window.tab_panel.width", SetValue::Integer),
+    ("scrolling.multiplier", SetValue::Integer),
+    ("web.smooth_scroll", SetValue::Bool),
+    ("web.highlight_matches", SetValue::Bool),
+];
+
+/// Action listed by the `:cmd` command palette.
+struct PaletteEntry<A> {
+    name: String,
+    keys: Vec<String>,
+    action: A,
+}
+
+/// Group key bindings by the name of their action.
+fn palette_entries<A>(
+    bindings: impl IntoIterator<Item = (String, String, A)>,
+) -> Vec<PaletteEntry<A>> {
+    let mut entries: Vec<PaletteEntry<A>> = Vec::new();
+    for (keys, name, action) in bindings {
+        match entries.iter_mut().find(|entry| entry.name == name) {
+            Some(entry) if !entry.keys.contains(&keys) => entry.keys.push(keys),
+            Some(_) => (),
+            None => entries.push(PaletteEntry { name, keys: vec![keys], action }),
+        }
+    }
+    entries
+}
+
+/// Font size in px after changing it by `delta`, `None` when a bound is already reached.
+///
+/// The size is rounded to integral px first, since fonts look better on them. Sizes beyond a bound
+/// aren't moved towards it.
+fn changed_font_size(size: f32, delta: f32, min_size: f32, max_size: f32) -> Option<f32> {
+    let size = size.round();
+    let new_size = if delta < 0. {
+        (size + delta).max(min_size.ceil().min(size))
+    } else {
+        (size + delta).min(max_size.floor().max(size))
+    };
+
+    (new_size != size).then_some(new_size)
+}
+
+/// Palette name or query, ignoring case and `_`.
+fn palette_normalize(name: &str) -> String {
+    name.chars().filter(|ch| *ch != '_').map(|ch| ch.to_ascii_lowercase()).collect()
+}
+
+/// Palette entries with a name containing `query`, ignoring case and `_`.
+///
+/// An exact match is returned on its own, even when other names contain it.
+fn palette_search<A>(entries: Vec<PaletteEntry<A>>, query: &str) -> Vec<PaletteEntry<A>> {
+    let query = palette_normalize(query);
+
+    let mut matches: Vec<_> = entries
+        .into_iter()
+        .filter(|entry| palette_normalize(&entry.name).contains(&query))
+        .collect();
+    if let Some(index) = matches.iter().position(|entry| palette_normalize(&entry.name) == query) {
+        return vec![matches.swap_remove(index)];
+    }
+    matches
+}
+
+/// Next palette name containing `query` after the one at `last_index`, wrapping around.
+fn palette_complete(
+    names: &[String],
+    query: &str,
+    last_index: Option<usize>,
+) -> Option<(String, usize)> {
+    let query = palette_normalize(query);
+    let start = last_index.map_or(0, |index| index + 1).min(names.len());
+    (start..names.len())
+        .chain(0..start)
+        .find(|index| palette_normalize(&names[*index]).contains(&query))
+        .map(|index| (names[index].clone(), index))
+}
+
+/// Start and text of the action query of `:cmd`.
+fn command_palette_prefix(input: &str) -> Option<(usize, &str)> {
+    let rest = input.strip_prefix(":cmd")?;
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+
+    let query = rest.trim_start();
+    Some((input.len() - query.len(), query))
+}
+
+/// Parse the `key=value` argument of `:set` into a config override.
+fn set_command_option(argument: &str) -> Result<String, String> {
+    let valid_keys = || SETTABLE_OPTIONS.map(|(key, _)| key).join(", ");
+    let Some((key, value)) = argument.split_once('=') else {
+        return Err(format!("Usage: :set key=value, with one of {}", valid_keys()));
+    };
+
+    let (key, value) = (key.trim(), value.trim());
+    let Some((_, kind)) = SETTABLE_OPTIONS.iter().find(|(option, _)| *option == key) else {
+        return Err(format!("Unknown option {key}, expected one of {}", valid_keys()));
+    };
+
+    let value = match kind {
+        SetValue::Bool => value.parse::<bool>().ok().map(|value| value.to_string()),
+        SetValue::Float | SetValue::PositiveFloat => value
+            .parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .filter(|value| !matches!(kind, SetValue::PositiveFloat) || *value > 0.)
+            .map(|value| format!("{value:?}")),
+        SetValue::Integer => value.parse::<u32>().ok().map(|value| value.to_string()),
+    };
+
+    match value {
+        Some(value) => Ok(format!("{key}={value}")),
+        None => Err(format!("Invalid value for {key}")),
+    }
+}
+
 fn command_url_prefix(input: &str) -> Option<(usize, &str)> {
     let bytes = input.as_bytes();
     if bytes.len() < 2 || bytes[0] != b':' {
@@ -3731,7 +6563,16 @@ fn command_url_prefix(input: &str) -> Option<(usize, &str)> {
 
 #[cfg(test)]
 mod tests {
-    use super::{CommandHistory, command_url_prefix};
+    use std::path::Path;
+
+    use tabor_terminal::index::{Column, Line, Point};
+    use tabor_terminal::term::test::TermSize;
+
+    use super::{
+        CommandHistory, Program, ViMark, changed_font_size, command_palette_prefix,
+        command_url_prefix, editor_command, palette_complete, palette_entries, palette_search,
+        set_command_option,
+    };
 
     #[test]
     fn command_url_prefix_parses_basic() {
@@ -3750,6 +6591,92 @@ mod tests {
         assert_eq!(command_url_prefix(":t"), None);
     }
 
+    #[test]
+    fn set_command_parses_allowed_options() {
+        assert_eq!(set_command_option("font.size = 14"), Ok(String::from("font.size=14.0")));
+        assert_eq!(
+            set_command_option("window.tab_panel.enabled=false"),
+            Ok(String::from("window.tab_panel.enabled=false"))
+        );
+        assert_eq!(
+            set_command_option("window.tab_panel.width=300"),
+            Ok(String::from("window.tab_panel.width=300"))
+        );
+
+        assert!(set_command_option("font.size").is_err());
+        assert!(set_command_option("font.size=large").is_err());
+        assert!(set_command_option("font.size=0").is_err());
+        assert!(set_command_option("font.size=-3").is_err());
+        assert!(set_command_option("font.size=inf").is_err());
+        assert!(set_command_option("window.opacity=NaN").is_err());
+        assert!(set_command_option("window.opacity=-inf").is_err());
+        assert_eq!(set_command_option("window.opacity=0"), Ok(String::from("window.opacity=0.0")));
+        assert!(set_command_option("shell=sh").unwrap_err().contains("font.size"));
+    }
+
+    #[test]
+    fn editor_command_appends_path() {
+        let editor = Program::WithArgs {
+            program: String::from("code"),
+            args: vec![String::from("--wait")],
+        };
+        let command = editor_command(Some(&editor), Path::new("/tmp/tabor-edit.txt"));
+        let args = vec![String::from("--wait"), String::from("/tmp/tabor-edit.txt")];
+        assert_eq!(command, Some((String::from("code"), args)));
+    }
+
+    #[test]
+    fn palette_groups_and_searches_actions() {
+        let bindings = [("y", "CopyUrl"), ("gy", "CopyUrl"), ("yt", "CopyTitle"), ("r", "Reload")]
+            .map(|(keys, name)| (keys.to_owned(), name.to_owned(), name));
+        let entries = palette_entries(bindings.clone());
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].keys, ["y", "gy"]);
+
+        let names = |query: &str| {
+            let matches = palette_search(palette_entries(bindings.clone()), query);
+            matches.into_iter().map(|entry| entry.action).collect::<Vec<_>>()
+        };
+        assert_eq!(names("copy"), ["CopyUrl", "CopyTitle"]);
+        assert_eq!(names("copy_url"), ["CopyUrl"]);
+        assert_eq!(names(""), ["CopyUrl", "CopyTitle", "Reload"]);
+        assert!(names("paste").is_empty());
+    }
+
+    #[test]
+    fn font_size_changes_stop_at_bounds() {
+        // Sizes are rounded before the delta is applied.
+        assert_eq!(changed_font_size(14.4, 1., 4., 72.), Some(15.));
+        assert_eq!(changed_font_size(4.6, -1., 4., 72.), Some(4.));
+
+        // Bounds are rounded towards the inside.
+        assert_eq!(changed_font_size(6., -2., 4.5, 72.), Some(5.));
+        assert_eq!(changed_font_size(70., 2., 4., 71.5), Some(71.));
+
+        // Reached bounds and sizes beyond them don't change.
+        assert_eq!(changed_font_size(72., 1., 4., 72.), None);
+        assert_eq!(changed_font_size(4., -1., 4., 72.), None);
+        assert_eq!(changed_font_size(80., 1., 4., 72.), None);
+        assert_eq!(changed_font_size(80., -1., 4., 72.), Some(79.));
+        assert_eq!(changed_font_size(3., 1., 4., 72.), Some(4.));
+    }
+
+    #[test]
+    fn palette_completion_cycles_matches() {
+        let names = ["CopyUrl", "Reload", "CopyTitle"].map(String::from);
+        assert_eq!(palette_complete(&names, "copy", None), Some((String::from("CopyUrl"), 0)));
+        assert_eq!(palette_complete(&names, "copy", Some(0)), Some((String::from("CopyTitle"), 2)));
+        assert_eq!(palette_complete(&names, "copy", Some(2)), Some((String::from("CopyUrl"), 0)));
+        assert_eq!(palette_complete(&names, "", Some(0)), Some((String::from("Reload"), 1)));
+        assert_eq!(palette_complete(&names, "paste", None), None);
+        assert_eq!(palette_complete(&[], "", None), None);
+
+        assert_eq!(command_palette_prefix(":cmd"), Some((4, "")));
+        assert_eq!(command_palette_prefix(":cmd  copy"), Some((6, "copy")));
+        assert_eq!(command_palette_prefix(":cmdx"), None);
+        assert_eq!(command_palette_prefix(":o copy"), None);
+    }
+
     #[test]
     fn command_history_records_most_recent() {
         let mut history = CommandHistory::default();
@@ -3774,6 +6701,18 @@ mod tests {
         assert_eq!(second, "https://example.com");
     }
 
+    #[test]
+    fn vi_mark_clamps_to_shrunk_scrollback() {
+        let size = TermSize::new(10, 5);
+        let mark = ViMark { display_offset: 40, point: Point::new(Line(-40), Column(20)) };
+
+        let mark = mark.clamp(&size);
+        assert_eq!(mark.display_offset, 0);
+        assert_eq!(mark.point, Point::new(Line(0), Column(9)));
+
+        let mark = ViMark { display_offset: 0, point: Point::new(Line(3), Column(2)) };
+        assert_eq!(mark.clamp(&size), mark);
+    }
 }
 
 /// Identified purpose of the touch input.
@@ -3801,8 +6740,8 @@ impl TouchZoom {
         Self { slots, fractions: Default::default() }
     }
 
-    /// Get slot distance change since last update.
-    pub fn font_delta(&mut self, slot: TouchEvent) -> f32 {
+    /// Get slot distance change since last update, scaled by the zoom sensitivity.
+    pub fn font_delta(&mut self, slot: TouchEvent, sensitivity: f32) -> f32 {
         let old_distance = self.distance();
 
         // Update touch slots.
@@ -3813,7 +6752,7 @@ impl TouchZoom {
         }
 
         // Calculate font change in `FONT_SIZE_STEP` increments.
-        let delta = (self.distance() - old_distance) * TOUCH_ZOOM_FACTOR + self.fractions;
+        let delta = (self.distance() - old_distance) * sensitivity + self.fractions;
         let font_delta = (delta.abs() / FONT_SIZE_STEP).floor() * FONT_SIZE_STEP * delta.signum();
         self.fractions = delta - font_delta;
 
@@ -3843,10 +6782,14 @@ pub struct Mouse {
     pub last_click_button: MouseButton,
     pub click_state: ClickState,
     pub accumulated_scroll: AccumulatedScroll,
+    /// Trackpad pinch magnification not yet applied as a zoom step.
+    pub accumulated_pinch: f64,
     pub cell_side: Side,
     pub block_hint_launcher: bool,
     pub hint_highlight_dirty: bool,
     pub inside_text_area: bool,
+    /// Whether the current drag and drop already navigated the web tab.
+    pub file_drop_navigated: bool,
     pub x: usize,
     pub y: usize,
 }
@@ -3864,7 +6807,9 @@ impl Default for Mouse {
             hint_highlight_dirty: Default::default(),
             block_hint_launcher: Default::default(),
             inside_text_area: Default::default(),
+            file_drop_navigated: Default::default(),
             accumulated_scroll: Default::default(),
+            accumulated_pinch: Default::default(),
             x: Default::default(),
             y: Default::default(),
         }
@@ -3913,6 +6858,7 @@ impl input::Processor<EventProxy, ActionContext<'_, Notifier, EventProxy>> {
             WinitEvent::UserEvent(Event { payload, .. }) => match payload {
                 EventType::SearchNext => self.ctx.goto_match(None),
                 EventType::Scroll(scroll) => self.ctx.scroll(scroll),
+                EventType::PipeOutput(output) => self.ctx.handle_pipe_output(output),
                 EventType::BlinkCursor => {
                     // Only change state when timeout isn't reached, since we could get
                     // BlinkCursor and BlinkCursorTimeout events at the same time.
@@ -3930,6 +6876,10 @@ impl input::Processor<EventProxy, ActionContext<'_, Notifier, EventProxy>> {
                     *self.ctx.dirty = true;
                 },
                 #[cfg(target_os = "macos")]
+                EventType::WebScroll => {
+                    self.ctx.with_web_command_state(|state, ctx| ctx.flush_web_scroll(state));
+                },
+                #[cfg(target_os = "macos")]
                 EventType::WebCommand(command) => {
                     self.ctx.handle_web_command(command);
                 },
@@ -3961,16 +6911,9 @@ impl input::Processor<EventProxy, ActionContext<'_, Notifier, EventProxy>> {
                         self.ctx.display.visual_bell.ring();
 
                         // Execute bell command.
-                        if let Some(bell_command) = &self.ctx.config.bell.command {
-                            if self
-                                .ctx
-                                .prev_bell_cmd
-                                .is_none_or(|i| i.elapsed() >= BELL_CMD_COOLDOWN)
-                            {
-                                self.ctx.spawn_daemon(bell_command.program(), bell_command.args());
-
-                                *self.ctx.prev_bell_cmd = Some(Instant::now());
-                            }
+                        let bell = &self.ctx.config.bell;
+                        if let Some(bell_command) = bell.take_command(self.ctx.prev_bell_cmd) {
+                            self.ctx.spawn_daemon(bell_command.program(), bell_command.args());
                         }
                     },
                     TerminalEvent::ClipboardStore(clipboard_type, content) => {
@@ -4000,24 +6943,40 @@ impl input::Processor<EventProxy, ActionContext<'_, Notifier, EventProxy>> {
                     TerminalEvent::PtyWrite(text) => self.ctx.write_to_pty(text.into_bytes()),
                     TerminalEvent::MouseCursorDirty => self.reset_mouse_cursor(),
                     TerminalEvent::CursorBlinkingChange => self.ctx.update_cursor_blinking(),
-                    TerminalEvent::Exit | TerminalEvent::ChildExit(_) | TerminalEvent::Wakeup => (),
+                    TerminalEvent::Exit
+                    | TerminalEvent::ChildExit(_)
+                    | TerminalEvent::Wakeup
+                    | TerminalEvent::CommandExecuted(_)
+                    | TerminalEvent::CommandFinished(_) => (),
                 },
                 #[cfg(unix)]
-                EventType::IpcRequest(..) => (),
+                EventType::IpcRequest(..)
+                | EventType::TypedInput
+                | EventType::SetWindowConfig(_)
+                | EventType::SetWindowTheme(_) => (),
+                #[cfg(not(windows))]
+                EventType::ShellShutdown => (),
                 #[cfg(target_os = "macos")]
                 EventType::Message(_)
                 | EventType::ConfigReload(_)
                 | EventType::CreateWindow(_)
                 | EventType::CreateTab(_)
+                | EventType::OpenWebTab(_)
                 | EventType::TabCommand(_)
                 | EventType::UpdateTabProgramName
                 | EventType::TabActivityTick
+                | EventType::ClipboardPoll
                 | EventType::CloseTab(_)
                 | EventType::WebPopup { .. }
                 | EventType::RestoreTab
                 | EventType::WebFavicon { .. }
                 | EventType::WebCursor { .. }
                 | EventType::WebCursorRequest
+                | EventType::WebHoverLink { .. }
+                | EventType::WebNotification { .. }
+                | EventType::WebNotificationPermission
+                | EventType::WebNotificationClicked
+                | EventType::WebLoadProgress { .. }
                 | EventType::TabSearch(_)
                 | EventType::OpenUrls(_)
                 | EventType::Frame => (),
@@ -4026,9 +6985,11 @@ impl input::Processor<EventProxy, ActionContext<'_, Notifier, EventProxy>> {
                 | EventType::ConfigReload(_)
                 | EventType::CreateWindow(_)
                 | EventType::CreateTab(_)
+                | EventType::OpenWebTab(_)
                 | EventType::TabCommand(_)
                 | EventType::UpdateTabProgramName
                 | EventType::TabActivityTick
+                | EventType::ClipboardPoll
                 | EventType::Frame => (),
             },
             WinitEvent::WindowEvent { event, .. } => {
@@ -4089,6 +7050,10 @@ impl input::Processor<EventProxy, ActionContext<'_, Notifier, EventProxy>> {
                         self.mouse_wheel_input(delta, phase);
                     },
                     WindowEvent::Touch(touch) => self.touch(touch),
+                    WindowEvent::PinchGesture { delta, phase, .. } => {
+                        self.pinch_gesture(delta, phase);
+                    },
+                    WindowEvent::DoubleTapGesture { .. } => self.double_tap_gesture(),
                     WindowEvent::Focused(is_focused) => {
                         self.ctx.terminal.is_focused = is_focused;
 
@@ -4111,6 +7076,12 @@ impl input::Processor<EventProxy, ActionContext<'_, Notifier, EventProxy>> {
                     WindowEvent::Occluded(occluded) => {
                         *self.ctx.occluded = occluded;
                     },
+                    WindowEvent::HoveredFile(_) | WindowEvent::HoveredFileCancelled => {
+                        self.ctx.mouse.file_drop_navigated = false;
+                    },
+                    WindowEvent::DroppedFile(path) if self.ctx.window_kind().is_web() => {
+                        self.ctx.open_dropped_file(path);
+                    },
                     WindowEvent::DroppedFile(path) => {
                         let path: String = path.to_string_lossy().into();
                         self.ctx.paste(&(path + " "), true);
@@ -4122,6 +7093,10 @@ impl input::Processor<EventProxy, ActionContext<'_, Notifier, EventProxy>> {
                             *self.ctx.dirty = true;
                         }
                     },
+                    #[cfg(target_os = "macos")]
+                    WindowEvent::Ime(ime) if self.ctx.window_kind().is_web() => {
+                        self.web_ime_input(ime);
+                    },
                     WindowEvent::Ime(ime) => match ime {
                         Ime::Commit(text) => {
                             *self.ctx.dirty = true;
@@ -4150,17 +7125,13 @@ impl input::Processor<EventProxy, ActionContext<'_, Notifier, EventProxy>> {
                     },
                     WindowEvent::KeyboardInput { is_synthetic: true, .. }
                     | WindowEvent::ActivationTokenDone { .. }
-                    | WindowEvent::DoubleTapGesture { .. }
                     | WindowEvent::TouchpadPressure { .. }
                     | WindowEvent::RotationGesture { .. }
                     | WindowEvent::CursorEntered { .. }
-                    | WindowEvent::PinchGesture { .. }
                     | WindowEvent::AxisMotion { .. }
                     | WindowEvent::PanGesture { .. }
-                    | WindowEvent::HoveredFileCancelled
                     | WindowEvent::Destroyed
                     | WindowEvent::ThemeChanged(_)
-                    | WindowEvent::HoveredFile(_)
                     | WindowEvent::RedrawRequested
                     | WindowEvent::Moved(_) => (),
                 }
@@ -4179,23 +7150,31 @@ impl input::Processor<EventProxy, ActionContext<'_, Notifier, EventProxy>> {
 #[derive(Debug, Clone)]
 pub struct EventProxy {
     proxy: EventLoopProxy<Event>,
-    window_id: WindowId,
-    tab_id: TabId,
+    /// Window and tab receiving the events, shared by all clones of the proxy.
+    target: Arc<Mutex<(WindowId, TabId)>>,
 }
 
 impl EventProxy {
     pub fn new(proxy: EventLoopProxy<Event>, window_id: WindowId, tab_id: TabId) -> Self {
-        Self { proxy, window_id, tab_id }
+        Self { proxy, target: Arc::new(Mutex::new((window_id, tab_id))) }
     }
 
     /// Send an event to the event loop.
     pub fn send_event(&self, event: EventType) {
-        let _ = self.proxy.send_event(Event::for_tab(event, self.window_id, self.tab_id));
+        let (window_id, tab_id) = *self.target.lock();
+        let _ = self.proxy.send_event(Event::for_tab(event, window_id, tab_id));
+    }
+
+    /// Route events of this proxy and all its clones to a different tab.
+    ///
+    /// This allows moving a tab between windows without restarting its PTY event loop.
+    pub fn retarget(&self, window_id: WindowId, tab_id: TabId) {
+        *self.target.lock() = (window_id, tab_id);
     }
 }
 
 impl EventListener for EventProxy {
     fn send_event(&self, event: TerminalEvent) {
-        let _ = self.proxy.send_event(Event::for_tab(event.into(), self.window_id, self.tab_id));
+        EventProxy::send_event(self, event.into());
     }
 }
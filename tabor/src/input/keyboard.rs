@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use winit::event::{ElementState, KeyEvent};
 #[cfg(target_os = "macos")]
 use winit::keyboard::ModifiersKeyState;
-use winit::keyboard::{Key, KeyLocation, ModifiersState, NamedKey};
+use winit::keyboard::{Key, KeyLocation, ModifiersState, NamedKey, PhysicalKey};
 #[cfg(target_os = "macos")]
 use winit::platform::macos::OptionAsAlt;
 
@@ -14,6 +14,7 @@ use winit::platform::modifier_supplement::KeyEventExtModifierSupplement;
 use crate::config::{Action, BindingKey, BindingMode, KeyBinding};
 use crate::display::window::ImeInhibitor;
 use crate::event::TYPING_SEARCH_DELAY;
+use crate::input::macros::MacroKey;
 use crate::input::{ActionContext, Execute, Processor};
 use crate::scheduler::{TimerId, Topic};
 
@@ -32,6 +33,10 @@ impl<T: EventListener, A: ActionContext<T>> Processor<T, A> {
             if self.ctx.inline_search_state().char_pending {
                 self.ctx.window().set_ime_inhibitor(ImeInhibitor::VI, false);
             }
+            if self.ctx.macros().skip_release(key.physical_key) {
+                return;
+            }
+            self.record_macro_key(&key);
             self.key_release(key, mode, mods);
             return;
         }
@@ -56,6 +61,22 @@ impl<T: EventListener, A: ActionContext<T>> Processor<T, A> {
             return;
         }
 
+        // First key after a mark action names the mark.
+        if self.ctx.vi_mark_pending() {
+            self.ctx.vi_mark_input(text);
+            return;
+        }
+
+        // First character after a macro action selects the register.
+        if self.ctx.macros().register_pending() {
+            if let Some(register) = text.chars().next() {
+                self.select_macro_register(register, key.physical_key);
+            }
+            return;
+        }
+
+        self.record_macro_key(&key);
+
         if self.ctx.command_active() {
             self.handle_command_key(&key, &text);
             return;
@@ -114,8 +135,34 @@ impl<T: EventListener, A: ActionContext<T>> Processor<T, A> {
             if !is_modifier_key {
                 self.ctx.on_terminal_input_start();
             }
-            self.ctx.write_to_pty(bytes);
+            self.ctx.write_input_to_pty(bytes);
+        }
+    }
+
+    /// Add a key event to the active macro recording.
+    fn record_macro_key(&mut self, key: &KeyEvent) {
+        if self.ctx.macros().recording().is_none() {
+            return;
+        }
+
+        let modifiers = *self.ctx.modifiers();
+        self.ctx.macros().record(MacroKey { key: key.clone(), modifiers });
+    }
+
+    /// Start recording or replay the macro stored in a register.
+    fn select_macro_register(&mut self, register: char, key: PhysicalKey) {
+        let Some(keys) = self.ctx.macro_register_input(register, key) else {
+            return;
+        };
+
+        let modifiers = *self.ctx.modifiers();
+        self.ctx.macros().set_replaying(true);
+        for MacroKey { key, modifiers } in keys {
+            *self.ctx.modifiers() = modifiers;
+            self.key_input(key);
         }
+        self.ctx.macros().set_replaying(false);
+        *self.ctx.modifiers() = modifiers;
     }
 
     fn handle_command_key(&mut self, key: &KeyEvent, text: &str) {
@@ -349,7 +396,7 @@ impl<T: EventListener, A: ActionContext<T>> Processor<T, A> {
             _ => build_sequence(key, mods, mode),
         };
 
-        self.ctx.write_to_pty(bytes);
+        self.ctx.write_input_to_pty(bytes);
     }
 
     /// Reset search delay.
@@ -0,0 +1,229 @@
+//! Recording and replay of key input.
+
+use std::collections::HashMap;
+
+use winit::event::{KeyEvent, Modifiers};
+use winit::keyboard::PhysicalKey;
+
+/// Key event recorded into a macro register.
+#[derive(Clone, Debug)]
+pub struct MacroKey {
+    pub key: KeyEvent,
+    pub modifiers: Modifiers,
+}
+
+/// Macro action waiting for its register.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MacroPending {
+    Record,
+    Replay,
+}
+
+/// Macro registers of a window.
+///
+/// Like in vim, the key following a record or replay action selects the register.
+#[derive(Debug)]
+pub struct Macros<K = MacroKey> {
+    registers: HashMap<char, Vec<K>>,
+    recording: Option<(char, Vec<K>)>,
+    pending: Option<MacroPending>,
+    last_replayed: Option<char>,
+    replaying: bool,
+    /// Key which selected the last register, its release is part of the macro action.
+    register_key: Option<PhysicalKey>,
+}
+
+impl<K> Default for Macros<K> {
+    fn default() -> Self {
+        Self {
+            registers: Default::default(),
+            recording: Default::default(),
+            pending: Default::default(),
+            last_replayed: Default::default(),
+            replaying: Default::default(),
+            register_key: Default::default(),
+        }
+    }
+}
+
+impl<K: Clone> Macros<K> {
+    /// Register currently being recorded.
+    pub fn recording(&self) -> Option<char> {
+        self.recording.as_ref().map(|(register, _)| *register)
+    }
+
+    /// Whether the next key selects a register.
+    pub fn register_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    pub fn set_replaying(&mut self, replaying: bool) {
+        self.replaying = replaying;
+    }
+
+    /// Stop the active recording, or wait for the register of a new one.
+    pub fn toggle_recording(&mut self) {
+        match self.recording.take() {
+            Some((register, mut keys)) => {
+                // Drop the key which stopped the recording.
+                keys.pop();
+                self.registers.insert(register, keys);
+            },
+            None => self.pending = Some(MacroPending::Record),
+        }
+    }
+
+    /// Wait for the register to replay.
+    pub fn request_replay(&mut self) {
+        self.pending = Some(MacroPending::Replay);
+    }
+
+    /// Complete the pending action with the register selected by `key`.
+    ///
+    /// Returns the keys to replay, `@` selects the register replayed last.
+    pub fn select_register(&mut self, register: char, key: PhysicalKey) -> Option<Vec<K>> {
+        let pending = self.pending.take()?;
+        self.register_key = Some(key);
+
+        match pending {
+            MacroPending::Record if register.is_ascii_alphanumeric() => {
+                self.recording = Some((register, Vec::new()));
+                None
+            },
+            // Nested replays could expand indefinitely.
+            MacroPending::Replay if !self.replaying => {
+                let register = if register == '@' { self.last_replayed? } else { register };
+                let keys = self.registers.get(&register)?.clone();
+                self.last_replayed = Some(register);
+                Some(keys)
+            },
+            _ => None,
+        }
+    }
+
+    /// Check if a key release belongs to the key which selected the last register.
+    ///
+    /// These releases should neither be recorded nor passed on.
+    pub fn skip_release(&mut self, key: PhysicalKey) -> bool {
+        if self.replaying || self.register_key != Some(key) {
+            return false;
+        }
+
+        self.register_key = None;
+        true
+    }
+
+    /// Add a key event to the active recording.
+    pub fn record(&mut self, key: K) {
+        if self.replaying {
+            return;
+        }
+
+        if let Some((_, keys)) = &mut self.recording {
+            keys.push(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use winit::keyboard::KeyCode;
+
+    const KEY_A: PhysicalKey = PhysicalKey::Code(KeyCode::KeyA);
+    const KEY_2: PhysicalKey = PhysicalKey::Code(KeyCode::Digit2);
+
+    /// Record `keys` into `register`, followed by the key which stops the recording.
+    fn record(macros: &mut Macros<char>, register: char, keys: &str) {
+        macros.toggle_recording();
+        assert!(macros.register_pending());
+        assert_eq!(macros.select_register(register, KEY_A), None);
+        assert!(macros.skip_release(KEY_A));
+
+        for key in keys.chars() {
+            macros.record(key);
+        }
+
+        macros.record('q');
+        macros.toggle_recording();
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let mut macros = Macros::<char>::default();
+        record(&mut macros, 'a', "ls\r");
+        assert_eq!(macros.recording(), None);
+
+        macros.request_replay();
+        assert_eq!(macros.select_register('a', KEY_A), Some(vec!['l', 's', '\r']));
+    }
+
+    #[test]
+    fn register_key_release_is_skipped() {
+        let mut macros = Macros::<char>::default();
+        macros.toggle_recording();
+        macros.select_register('a', KEY_A);
+        assert_eq!(macros.recording(), Some('a'));
+
+        assert!(!macros.skip_release(KEY_2));
+        assert!(macros.skip_release(KEY_A));
+        assert!(!macros.skip_release(KEY_A));
+
+        // Replayed releases of the register key are kept.
+        macros.record('q');
+        macros.toggle_recording();
+        macros.request_replay();
+        macros.select_register('a', KEY_A);
+        macros.set_replaying(true);
+        assert!(!macros.skip_release(KEY_A));
+        macros.set_replaying(false);
+        assert!(macros.skip_release(KEY_A));
+    }
+
+    #[test]
+    fn invalid_register_cancels_recording() {
+        let mut macros = Macros::<char>::default();
+        macros.toggle_recording();
+        assert_eq!(macros.select_register('@', KEY_2), None);
+        assert!(!macros.register_pending());
+        assert_eq!(macros.recording(), None);
+    }
+
+    #[test]
+    fn replay_last_register() {
+        let mut macros = Macros::<char>::default();
+        record(&mut macros, 'a', "x");
+        record(&mut macros, 'b', "y");
+
+        macros.request_replay();
+        assert_eq!(macros.select_register('@', KEY_2), None);
+
+        macros.request_replay();
+        assert_eq!(macros.select_register('b', KEY_A), Some(vec!['y']));
+        macros.request_replay();
+        assert_eq!(macros.select_register('@', KEY_2), Some(vec!['y']));
+    }
+
+    #[test]
+    fn nested_replay_is_ignored() {
+        let mut macros = Macros::<char>::default();
+        record(&mut macros, 'a', "x");
+
+        macros.set_replaying(true);
+        macros.request_replay();
+        assert_eq!(macros.select_register('a', KEY_A), None);
+        assert!(!macros.register_pending());
+
+        // Replayed keys are not recorded again.
+        macros.toggle_recording();
+        macros.select_register('b', KEY_A);
+        macros.record('x');
+        macros.set_replaying(false);
+        macros.record('q');
+        macros.toggle_recording();
+
+        macros.request_replay();
+        assert_eq!(macros.select_register('b', KEY_A), Some(Vec::new()));
+    }
+}
@@ -21,8 +21,10 @@ use winit::event::{
     TouchPhase,
 };
 #[cfg(target_os = "macos")]
+use winit::event::Ime;
+#[cfg(target_os = "macos")]
 use winit::event_loop::ActiveEventLoop;
-use winit::keyboard::ModifiersState;
+use winit::keyboard::{ModifiersState, PhysicalKey};
 #[cfg(target_os = "macos")]
 use winit::platform::macos::ActiveEventLoopExtMacOS;
 use winit::window::CursorIcon;
@@ -44,19 +46,24 @@ use crate::display::hint::HintMatch;
 use crate::display::window::{ImeInhibitor, Window};
 use crate::display::{Display, SizeInfo};
 use crate::event::{
-    ClickState, Event, EventType, InlineSearchState, Mouse, TouchPurpose, TouchZoom,
+    ClickState, Event, EventType, InlineSearchState, Mouse, TouchPurpose, TouchZoom, ViMarkAction,
 };
 #[cfg(target_os = "macos")]
 use crate::event::WebCommand;
+use crate::input::macros::{MacroKey, Macros};
 use crate::message_bar::{self, Message};
 use crate::scheduler::{Scheduler, TimerId, Topic};
 use crate::window_kind::WindowKind;
 
 pub mod keyboard;
+pub mod macros;
 
 /// Font size change interval in px.
 pub const FONT_SIZE_STEP: f32 = 1.;
 
+/// Split pane size change interval in percent of the window width.
+const PANE_RESIZE_STEP: i8 = 5;
+
 /// Interval for mouse scrolling during selection outside of the boundaries.
 const SELECTION_SCROLLING_INTERVAL: Duration = Duration::from_millis(15);
 
@@ -69,6 +76,11 @@ const SELECTION_SCROLLING_STEP: f64 = 20.;
 /// Distance before a touch input is considered a drag.
 const MAX_TAP_DISTANCE: f64 = 20.;
 
+/// Web zoom steps per unit of trackpad pinch magnification and of `touch.zoom_sensitivity`.
+///
+/// With the default sensitivity, doubling the distance between the fingers zooms by 10 steps.
+const PINCH_ZOOM_STEPS: f64 = 1000.;
+
 /// Threshold used for double_click/triple_click.
 const CLICK_THRESHOLD: Duration = Duration::from_millis(400);
 
@@ -83,9 +95,14 @@ pub struct Processor<T: EventListener, A: ActionContext<T>> {
 
 pub trait ActionContext<T: EventListener> {
     fn write_to_pty<B: Into<Cow<'static, [u8]>>>(&self, _data: B) {}
+    /// Write typed input to the PTY and to all tabs receiving broadcast input.
+    fn write_input_to_pty<B: Into<Cow<'static, [u8]>>>(&self, data: B) {
+        self.write_to_pty(data);
+    }
     fn mark_dirty(&mut self) {}
     fn size_info(&self) -> SizeInfo;
     fn copy_selection(&mut self, _ty: ClipboardType) {}
+    fn copy_scrollback(&mut self) {}
     fn start_selection(&mut self, _ty: SelectionType, _point: Point, _side: Side) {}
     fn toggle_selection(&mut self, _ty: SelectionType, _point: Point, _side: Side) {}
     fn update_selection(&mut self, _point: Point, _side: Side) {}
@@ -104,6 +121,14 @@ pub trait ActionContext<T: EventListener> {
     fn spawn_new_instance(&mut self) {}
     fn create_new_window(&mut self) {}
     fn create_new_tab(&mut self) {}
+    fn split_tab(&mut self) {}
+    fn close_split(&mut self) {}
+    fn focus_next_pane(&mut self) {}
+    fn swap_panes(&mut self) {}
+    fn resize_pane(&mut self, _percent: i8) {}
+    fn move_tab_to_new_window(&mut self) {}
+    fn toggle_tab_panel(&mut self) {}
+    fn select_recent_tab(&mut self, _step: isize) {}
     fn change_font_size(&mut self, _delta: f32) {}
     fn reset_font_size(&mut self) {}
     fn pop_message(&mut self) {}
@@ -117,12 +142,18 @@ pub trait ActionContext<T: EventListener> {
     #[cfg(target_os = "macos")]
     fn web_request_cursor_update(&mut self, _position: PhysicalPosition<f64>) {}
     #[cfg(target_os = "macos")]
+    fn web_smart_zoom(&mut self) {}
+    #[cfg(target_os = "macos")]
     fn web_mouse_input(&mut self, _state: ElementState, _button: MouseButton) {}
     #[cfg(target_os = "macos")]
     fn web_copy_selection(&mut self) {}
     #[cfg(target_os = "macos")]
     fn web_paste_text(&mut self, _text: &str) {}
     #[cfg(target_os = "macos")]
+    fn web_ime_commit(&mut self, _text: &str) {}
+    #[cfg(target_os = "macos")]
+    fn web_ime_preedit(&mut self, _text: &str, _cursor: Option<(usize, usize)>) {}
+    #[cfg(target_os = "macos")]
     fn select_next_tab(&mut self) {}
     #[cfg(target_os = "macos")]
     fn select_previous_tab(&mut self) {}
@@ -154,10 +185,25 @@ pub trait ActionContext<T: EventListener> {
     fn on_typing_start(&mut self) {}
     fn toggle_vi_mode(&mut self) {}
     fn inline_search_state(&mut self) -> &mut InlineSearchState;
+    fn macros(&mut self) -> &mut Macros;
+    fn toggle_macro_recording(&mut self) {}
+    fn replay_macro(&mut self) {}
+    fn macro_register_input(
+        &mut self,
+        _register: char,
+        _key: PhysicalKey,
+    ) -> Option<Vec<MacroKey>> {
+        None
+    }
     fn start_inline_search(&mut self, _direction: Direction, _stop_short: bool) {}
     fn inline_search_next(&mut self) {}
     fn inline_search_input(&mut self, _text: &str) {}
     fn inline_search_previous(&mut self) {}
+    fn vi_mark_pending(&self) -> bool {
+        false
+    }
+    fn start_vi_mark(&mut self, _action: ViMarkAction) {}
+    fn vi_mark_input(&mut self, _text: &str) {}
     fn hint_input(&mut self, _character: char) {}
     fn trigger_hint(&mut self, _hint: &HintMatch) {}
     fn expand_selection(&mut self) {}
@@ -211,6 +257,8 @@ impl<T: EventListener> Execute<T> for Action {
                 ctx.on_typing_start();
                 ctx.toggle_vi_mode()
             },
+            Action::RecordMacro => ctx.toggle_macro_recording(),
+            Action::ReplayMacro => ctx.replay_macro(),
             Action::ToggleCommandBar => {
                 ctx.on_typing_start();
                 ctx.toggle_command_bar();
@@ -318,6 +366,8 @@ impl<T: EventListener> Execute<T> for Action {
             },
             Action::Vi(ViAction::InlineSearchNext) => ctx.inline_search_next(),
             Action::Vi(ViAction::InlineSearchPrevious) => ctx.inline_search_previous(),
+            Action::Vi(ViAction::SetMark) => ctx.start_vi_mark(ViMarkAction::Set),
+            Action::Vi(ViAction::JumpToMark) => ctx.start_vi_mark(ViMarkAction::Jump),
             Action::Vi(ViAction::SemanticSearchForward | ViAction::SemanticSearchBackward) => {
                 let seed_text = match ctx.terminal().selection_to_string() {
                     Some(selection) if !selection.is_empty() => selection,
@@ -455,6 +505,16 @@ impl<T: EventListener> Execute<T> for Action {
             Action::CreateNewWindow => ctx.create_new_window(),
             Action::SpawnNewInstance => ctx.spawn_new_instance(),
             Action::CreateNewTab => ctx.create_new_tab(),
+            Action::SplitTab => ctx.split_tab(),
+            Action::CloseSplit => ctx.close_split(),
+            Action::FocusNextPane => ctx.focus_next_pane(),
+            Action::SwapPanes => ctx.swap_panes(),
+            Action::MoveTabToNewWindow => ctx.move_tab_to_new_window(),
+            Action::ToggleTabPanel => ctx.toggle_tab_panel(),
+            Action::SelectRecentTab => ctx.select_recent_tab(1),
+            Action::SelectRecentTabReverse => ctx.select_recent_tab(-1),
+            Action::GrowPane => ctx.resize_pane(PANE_RESIZE_STEP),
+            Action::ShrinkPane => ctx.resize_pane(-PANE_RESIZE_STEP),
             #[cfg(target_os = "macos")]
             Action::SelectNextTab => ctx.select_next_tab(),
             #[cfg(target_os = "macos")]
@@ -493,6 +553,18 @@ impl<T: EventListener, A: ActionContext<T>> Processor<T, A> {
         Self { ctx, _phantom: Default::default() }
     }
 
+    /// Pass input method composition and committed text on to the focused element of a web tab.
+    #[cfg(target_os = "macos")]
+    pub fn web_ime_input(&mut self, ime: Ime) {
+        match ime {
+            Ime::Commit(text) => self.ctx.web_ime_commit(&text),
+            Ime::Preedit(text, cursor_offset) => self.ctx.web_ime_preedit(&text, cursor_offset),
+            // Drop any composition left behind once the input method is turned off.
+            Ime::Disabled => self.ctx.web_ime_preedit("", None),
+            Ime::Enabled => (),
+        }
+    }
+
     #[inline]
     pub fn mouse_moved(&mut self, position: PhysicalPosition<f64>) {
         if self.ctx.window_kind().is_web() {
@@ -904,6 +976,35 @@ impl<T: EventListener, A: ActionContext<T>> Processor<T, A> {
         }
     }
 
+    /// Zoom the page of web tabs with trackpad pinch gestures.
+    pub fn pinch_gesture(&mut self, delta: f64, phase: TouchPhase) {
+        let touch = self.ctx.config().touch;
+        if !touch.zoom_enabled || !self.ctx.window_kind().is_web() {
+            return;
+        }
+
+        let accumulated = &mut self.ctx.mouse_mut().accumulated_pinch;
+        if phase == TouchPhase::Started {
+            *accumulated = 0.;
+        }
+
+        *accumulated += delta * f64::from(touch.zoom_sensitivity()) * PINCH_ZOOM_STEPS;
+        let steps = accumulated.trunc();
+        *accumulated -= steps;
+
+        if steps != 0. {
+            self.ctx.change_font_size(steps as f32);
+        }
+    }
+
+    /// Toggle the page zoom of web tabs with a trackpad double tap.
+    pub fn double_tap_gesture(&mut self) {
+        #[cfg(target_os = "macos")]
+        if self.ctx.window_kind().is_web() {
+            self.ctx.web_smart_zoom();
+        }
+    }
+
     /// Handle beginning of touch input.
     pub fn on_touch_start(&mut self, touch: TouchEvent) {
         // Inhibit IME on touch while not focused, forcing a touch tap while focused to enable IME.
@@ -911,9 +1012,14 @@ impl<T: EventListener, A: ActionContext<T>> Processor<T, A> {
             self.ctx.window().set_ime_inhibitor(ImeInhibitor::TOUCH, true);
         }
 
+        let zoom_enabled = self.ctx.config().touch.zoom_enabled;
         let touch_purpose = self.ctx.touch_purpose();
         *touch_purpose = match mem::take(touch_purpose) {
             TouchPurpose::None => TouchPurpose::Tap(touch),
+            // Ignore pinch gestures while zooming is disabled.
+            TouchPurpose::Tap(start) | TouchPurpose::ZoomPendingSlot(start) if !zoom_enabled => {
+                TouchPurpose::Invalid(HashSet::from_iter([start.id, touch.id]))
+            },
             TouchPurpose::Tap(start) => TouchPurpose::Zoom(TouchZoom::new((start, touch))),
             TouchPurpose::ZoomPendingSlot(slot) => {
                 TouchPurpose::Zoom(TouchZoom::new((slot, touch)))
@@ -939,6 +1045,7 @@ impl<T: EventListener, A: ActionContext<T>> Processor<T, A> {
 
     /// Handle touch input movement.
     pub fn on_touch_motion(&mut self, touch: TouchEvent) {
+        let zoom_sensitivity = self.ctx.config().touch.zoom_sensitivity();
         let touch_purpose = self.ctx.touch_purpose();
         match touch_purpose {
             TouchPurpose::None => (),
@@ -966,7 +1073,7 @@ impl<T: EventListener, A: ActionContext<T>> Processor<T, A> {
                 }
             },
             TouchPurpose::Zoom(zoom) => {
-                let font_delta = zoom.font_delta(touch);
+                let font_delta = zoom.font_delta(touch, zoom_sensitivity);
                 self.ctx.change_font_size(font_delta);
             },
             TouchPurpose::Scroll(last_touch) => {
@@ -1245,7 +1352,19 @@ mod tests {
         pub modifiers: Modifiers,
         config: &'a UiConfig,
         inline_search_state: &'a mut InlineSearchState,
+        macros: &'a mut Macros,
         window_kind: WindowKind,
+        text_input: Vec<TextInput>,
+    }
+
+    /// Text sent to the terminal or to the page of a web tab.
+    #[derive(Debug, PartialEq, Eq)]
+    enum TextInput {
+        Paste(String),
+        #[cfg(target_os = "macos")]
+        WebCommit(String),
+        #[cfg(target_os = "macos")]
+        WebPreedit(String),
     }
 
     impl<T: EventListener> super::ActionContext<T> for ActionContext<'_, T> {
@@ -1266,6 +1385,10 @@ mod tests {
             self.inline_search_state
         }
 
+        fn macros(&mut self) -> &mut Macros {
+            self.macros
+        }
+
         fn search_active(&self) -> bool {
             false
         }
@@ -1357,6 +1480,20 @@ mod tests {
         fn semantic_word(&self, _point: Point) -> String {
             unimplemented!();
         }
+
+        fn paste(&mut self, text: &str, _bracketed: bool) {
+            self.text_input.push(TextInput::Paste(text.to_owned()));
+        }
+
+        #[cfg(target_os = "macos")]
+        fn web_ime_commit(&mut self, text: &str) {
+            self.text_input.push(TextInput::WebCommit(text.to_owned()));
+        }
+
+        #[cfg(target_os = "macos")]
+        fn web_ime_preedit(&mut self, text: &str, _cursor: Option<(usize, usize)>) {
+            self.text_input.push(TextInput::WebPreedit(text.to_owned()));
+        }
     }
 
     macro_rules! test_clickstate {
@@ -1403,8 +1540,10 @@ mod tests {
                     modifiers: Default::default(),
                     message_buffer: &mut message_buffer,
                     inline_search_state: &mut inline_search_state,
+                    macros: &mut Macros::default(),
                     config: &cfg,
                     window_kind: WindowKind::Terminal,
+                    text_input: Vec::new(),
                 };
 
                 let mut processor = Processor::new(context);
@@ -1464,8 +1603,10 @@ mod tests {
             modifiers: Default::default(),
             message_buffer: &mut message_buffer,
             inline_search_state: &mut inline_search_state,
+            macros: &mut Macros::default(),
             config: &cfg,
             window_kind: WindowKind::Web { url: String::from("about:blank") },
+            text_input: Vec::new(),
         };
 
         let mut processor = Processor::new(context);
@@ -1492,8 +1633,10 @@ mod tests {
             modifiers: Default::default(),
             message_buffer: &mut message_buffer,
             inline_search_state: &mut inline_search_state,
+            macros: &mut Macros::default(),
             config: &cfg,
             window_kind: WindowKind::Web { url: String::from("about:blank") },
+            text_input: Vec::new(),
         };
 
         let mut processor = Processor::new(context);
@@ -1520,8 +1663,10 @@ mod tests {
             modifiers: Default::default(),
             message_buffer: &mut message_buffer,
             inline_search_state: &mut inline_search_state,
+            macros: &mut Macros::default(),
             config: &cfg,
             window_kind: WindowKind::Web { url: String::from("about:blank") },
+            text_input: Vec::new(),
         };
 
         let mut processor = Processor::new(context);
@@ -1531,6 +1676,42 @@ mod tests {
         assert_eq!(processor.ctx.mouse.y, 0);
     }
 
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn web_tab_ime_input_reaches_page() {
+        let mut clipboard = Clipboard::new_nop();
+        let cfg = UiConfig::default();
+        let size = SizeInfo::new(21.0, 51.0, 3.0, 3.0, 0., 0., 0., false);
+        let mut terminal = Term::new(cfg.term_options(), &size, MockEventProxy);
+        let mut mouse = Mouse::default();
+        let mut inline_search_state = InlineSearchState::default();
+        let mut message_buffer = MessageBuffer::default();
+
+        let context = ActionContext {
+            terminal: &mut terminal,
+            mouse: &mut mouse,
+            size_info: &size,
+            clipboard: &mut clipboard,
+            modifiers: Default::default(),
+            message_buffer: &mut message_buffer,
+            inline_search_state: &mut inline_search_state,
+            macros: &mut Macros::default(),
+            config: &cfg,
+            window_kind: WindowKind::Web { url: String::from("about:blank") },
+            text_input: Vec::new(),
+        };
+
+        let mut processor = Processor::new(context);
+        processor.web_ime_input(Ime::Preedit(String::from("かん"), Some((6, 6))));
+        processor.web_ime_input(Ime::Commit(String::from("漢")));
+
+        let expected = vec![
+            TextInput::WebPreedit(String::from("かん")),
+            TextInput::WebCommit(String::from("漢")),
+        ];
+        assert_eq!(processor.ctx.text_input, expected);
+    }
+
     test_clickstate! {
         name: single_click,
         initial_state: ClickState::None,
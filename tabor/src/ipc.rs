@@ -8,7 +8,8 @@ use std::net::Shutdown;
 use std::os::unix::net::{UnixListener, UnixStream};
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Instant;
+use std::sync::mpsc::{self, SyncSender};
+use std::time::{Duration, Instant};
 use std::{env, fs, process};
 
 use log::{error, warn};
@@ -30,6 +31,12 @@ const TABOR_SOCKET_ENV: &str = "TABOR_SOCKET";
 
 const IPC_PROTOCOL_VERSION: u32 = 1;
 
+/// First command id used for the domains enabled by `attach_inspector`.
+const INSPECTOR_ENABLE_ID_BASE: u64 = 1 << 30;
+
+/// Marker appended to page text which was cut off.
+const PAGE_TEXT_TRUNCATION_MARKER: &str = "\n[truncated]";
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct IpcTabId {
     pub index: u32,
@@ -67,9 +74,21 @@ impl From<&WindowKind> for IpcTabKind {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct IpcTabActivity {
     pub has_unseen_output: bool,
+    #[serde(default)]
+    pub has_unseen_bell: bool,
+    #[serde(default)]
+    pub has_unseen_title: bool,
     pub last_output_ms_ago: Option<u64>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpcScrollback {
+    /// Approximate memory used by the scrollback history, in bytes.
+    pub bytes: usize,
+    /// Lines dropped from the history to stay within `terminal.max_scrollback_bytes`.
+    pub evicted_lines: usize,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct IpcTabState {
     pub tab_id: IpcTabId,
@@ -78,9 +97,15 @@ pub struct IpcTabState {
     pub is_active: bool,
     pub title: String,
     pub custom_title: Option<String>,
+    pub color: Option<String>,
     pub program_name: String,
     pub kind: IpcTabKind,
     pub activity: Option<IpcTabActivity>,
+    /// Working directory of the shell, `None` for web tabs.
+    pub cwd: Option<PathBuf>,
+    /// Scrollback memory usage, `None` for web tabs.
+    #[serde(default)]
+    pub scrollback: Option<IpcScrollback>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -96,6 +121,46 @@ pub struct IpcTabPanelState {
     pub width: usize,
 }
 
+/// Windows and tabs written by `save_session`.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct IpcSession {
+    pub windows: Vec<IpcSessionWindow>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct IpcSessionWindow {
+    pub groups: Vec<IpcSessionGroup>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct IpcSessionGroup {
+    pub name: Option<String>,
+    pub tabs: Vec<IpcSessionTab>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct IpcSessionTab {
+    pub kind: IpcTabKind,
+    #[serde(default)]
+    pub custom_title: Option<String>,
+    #[serde(default)]
+    pub cwd: Option<PathBuf>,
+    #[serde(default)]
+    pub is_active: bool,
+}
+
+impl IpcSessionTab {
+    /// Options recreating the tab.
+    pub fn window_options(&self) -> WindowOptions {
+        let mut options = WindowOptions::default();
+        match &self.kind {
+            IpcTabKind::Terminal => options.terminal_options.working_directory = self.cwd.clone(),
+            IpcTabKind::Web { url } => options.window_kind = WindowKind::Web { url: url.clone() },
+        }
+        options
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct IpcInspectorTarget {
     pub target_id: u64,
@@ -177,7 +242,7 @@ pub enum IpcAction {
     Command { program: Program },
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum IpcRequest {
     Ping,
@@ -193,25 +258,66 @@ pub enum IpcRequest {
         target_group_id: Option<usize>,
         target_index: Option<usize>,
     },
+    MoveTabToNewWindow { tab_id: Option<IpcTabId> },
     SetTabTitle { tab_id: Option<IpcTabId>, title: Option<String> },
     SetGroupName { group_id: usize, name: Option<String> },
+    SetWindowTitle { title: Option<String> },
     RestoreClosedTab,
+    GetClosedTabs,
     OpenUrl { url: String, target: UrlTarget },
     SetWebUrl { tab_id: Option<IpcTabId>, url: String },
     ReloadWeb { tab_id: Option<IpcTabId> },
     OpenInspector { tab_id: Option<IpcTabId> },
+    GetScroll { tab_id: Option<IpcTabId> },
+    GetPageText { tab_id: Option<IpcTabId> },
+    SetScroll { tab_id: Option<IpcTabId>, x: f64, y: f64 },
+    /// Add a stylesheet to a web tab, which stays applied to pages loaded later on.
+    InjectCss { tab_id: Option<IpcTabId>, css: String },
+    /// Remove a stylesheet added by `InjectCss`.
+    RemoveCss { tab_id: Option<IpcTabId>, handle: u64 },
     GetTabPanel,
     SetTabPanel { enabled: Option<bool>, width: Option<usize> },
     DispatchAction { tab_id: Option<IpcTabId>, action: IpcAction },
     SendInput { tab_id: Option<IpcTabId>, text: String },
+    /// Type text one character at a time, waiting `delay_ms` before each character.
+    SendInputTyped { tab_id: Option<IpcTabId>, text: String, delay_ms: u64 },
+    GetTypingStatus { typing_id: u64 },
+    SendKey {
+        tab_id: Option<IpcTabId>,
+        key: String,
+        #[serde(default)]
+        modifiers: Vec<String>,
+    },
     RunCommandBar { tab_id: Option<IpcTabId>, input: String },
     ListInspectorTargets,
-    AttachInspector { tab_id: Option<IpcTabId>, target_id: Option<u64> },
+    AttachInspector {
+        tab_id: Option<IpcTabId>,
+        target_id: Option<u64>,
+        /// Protocol domains like `Page` or `Runtime` to enable right after attaching.
+        #[serde(default)]
+        domains: Vec<String>,
+    },
     DetachInspector { session_id: String },
     SendInspectorMessage { session_id: String, message: String },
-    PollInspectorMessages { session_id: String, max: Option<usize> },
+    PollInspectorMessages { session_id: String, max: Option<usize>, timeout_ms: Option<u64> },
     SetConfig(IpcConfig),
     GetConfig(IpcGetConfig),
+    Subscribe,
+    SaveSession { path: PathBuf },
+    RestoreSession { path: PathBuf },
+}
+
+/// Tab lifecycle notification pushed to subscribed clients.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "event", rename_all = "snake_case")]
+#[allow(clippy::enum_variant_names)]
+pub enum IpcEvent {
+    TabCreated { tab_id: IpcTabId },
+    TabClosed { tab_id: IpcTabId },
+    TabSelected { tab_id: IpcTabId },
+    TabRenamed { tab_id: IpcTabId, name: Option<String> },
+    TabTitleChanged { tab_id: IpcTabId, title: String },
+    TabFaviconChanged { tab_id: IpcTabId },
 }
 
 pub struct IpcRequestHelp {
@@ -257,6 +363,10 @@ pub fn ipc_request_help() -> &'static [IpcRequestHelp] {
             name: "move_tab",
             summary: "Move a tab to a group/index.",
         },
+        IpcRequestHelp {
+            name: "move_tab_to_new_window",
+            summary: "Move a tab into a new window.",
+        },
         IpcRequestHelp {
             name: "set_tab_title",
             summary: "Set or clear a tab custom title.",
@@ -265,10 +375,18 @@ pub fn ipc_request_help() -> &'static [IpcRequestHelp] {
             name: "set_group_name",
             summary: "Set a tab group name.",
         },
+        IpcRequestHelp {
+            name: "set_window_title",
+            summary: "Set or clear a window title override.",
+        },
         IpcRequestHelp {
             name: "restore_closed_tab",
             summary: "Restore the most recently closed tab.",
         },
+        IpcRequestHelp {
+            name: "get_closed_tabs",
+            summary: "Get the number of closed tabs which can be restored.",
+        },
         IpcRequestHelp {
             name: "open_url",
             summary: "Open URL in current or new tab.",
@@ -285,6 +403,26 @@ pub fn ipc_request_help() -> &'static [IpcRequestHelp] {
             name: "open_inspector",
             summary: "Open Web Inspector for a web tab.",
         },
+        IpcRequestHelp {
+            name: "get_scroll",
+            summary: "Read the scroll offset of a web tab.",
+        },
+        IpcRequestHelp {
+            name: "get_page_text",
+            summary: "Read the visible text of a web page.",
+        },
+        IpcRequestHelp {
+            name: "set_scroll",
+            summary: "Scroll a web tab to an offset.",
+        },
+        IpcRequestHelp {
+            name: "inject_css",
+            summary: "Add a stylesheet to a web tab.",
+        },
+        IpcRequestHelp {
+            name: "remove_css",
+            summary: "Remove a stylesheet added to a web tab.",
+        },
         IpcRequestHelp {
             name: "get_tab_panel",
             summary: "Get tab panel state.",
@@ -301,6 +439,18 @@ pub fn ipc_request_help() -> &'static [IpcRequestHelp] {
             name: "send_input",
             summary: "Send literal input text to a tab.",
         },
+        IpcRequestHelp {
+            name: "send_input_typed",
+            summary: "Type input text with a delay per character.",
+        },
+        IpcRequestHelp {
+            name: "get_typing_status",
+            summary: "Check if typed input has finished.",
+        },
+        IpcRequestHelp {
+            name: "send_key",
+            summary: "Send a key press to a web tab.",
+        },
         IpcRequestHelp {
             name: "run_command_bar",
             summary: "Open the command bar with input.",
@@ -333,6 +483,18 @@ pub fn ipc_request_help() -> &'static [IpcRequestHelp] {
             name: "get_config",
             summary: "Read runtime config.",
         },
+        IpcRequestHelp {
+            name: "subscribe",
+            summary: "Stream tab lifecycle events.",
+        },
+        IpcRequestHelp {
+            name: "save_session",
+            summary: "Save all windows and tabs to a file.",
+        },
+        IpcRequestHelp {
+            name: "restore_session",
+            summary: "Open the windows and tabs of a saved session.",
+        },
     ]
 }
 
@@ -342,13 +504,21 @@ impl IpcRequest {
             IpcRequest::GetTabState { tab_id } => Some(*tab_id),
             IpcRequest::CloseTab { tab_id } => *tab_id,
             IpcRequest::MoveTab { tab_id, .. } => Some(*tab_id),
+            IpcRequest::MoveTabToNewWindow { tab_id } => *tab_id,
             IpcRequest::SetTabTitle { tab_id, .. } => *tab_id,
             IpcRequest::DispatchAction { tab_id, .. } => *tab_id,
             IpcRequest::SendInput { tab_id, .. } => *tab_id,
+            IpcRequest::SendInputTyped { tab_id, .. } => *tab_id,
+            IpcRequest::SendKey { tab_id, .. } => *tab_id,
             IpcRequest::RunCommandBar { tab_id, .. } => *tab_id,
             IpcRequest::AttachInspector { tab_id, .. } => *tab_id,
             IpcRequest::OpenInspector { tab_id }
             | IpcRequest::ReloadWeb { tab_id }
+            | IpcRequest::GetScroll { tab_id }
+            | IpcRequest::GetPageText { tab_id }
+            | IpcRequest::SetScroll { tab_id, .. }
+            | IpcRequest::InjectCss { tab_id, .. }
+            | IpcRequest::RemoveCss { tab_id, .. }
             | IpcRequest::SetWebUrl { tab_id, .. } => *tab_id,
             IpcRequest::OpenUrl { target, .. } => match target {
                 UrlTarget::TabId { tab_id } => Some(*tab_id),
@@ -382,10 +552,24 @@ pub enum SocketReply {
     TabState { tab: IpcTabState },
     TabCreated { tab_id: IpcTabId },
     GroupCreated { group_id: usize },
+    ClosedTabs { count: usize },
     TabPanel { panel: IpcTabPanelState },
     InspectorTargets { targets: Vec<IpcInspectorTarget> },
     InspectorAttached { session: IpcInspectorSession },
-    InspectorMessages { messages: Vec<IpcInspectorMessage> },
+    InspectorMessages {
+        messages: Vec<IpcInspectorMessage>,
+        /// Messages were dropped since the last poll because the queue was full.
+        #[serde(default)]
+        dropped: bool,
+    },
+    Scroll { x: f64, y: f64 },
+    /// Handle of a stylesheet added by `inject_css`.
+    CssInjected { handle: u64 },
+    /// Text of a web page, cut off at `web.page_text_limit` bytes when `truncated`.
+    PageText { text: String, truncated: bool },
+    TypingStarted { typing_id: u64 },
+    /// Progress of typed input, `remaining` counting the characters not typed yet.
+    TypingStatus { typing_id: u64, remaining: usize, done: bool },
     Config { config: serde_json::Value },
     Error { error: IpcError },
 }
@@ -411,6 +595,21 @@ pub fn reply_error(code: IpcErrorCode, message: impl Into<String>) -> SocketRepl
     SocketReply::Error { error: IpcError::new(code, message) }
 }
 
+/// Reply with page text, cut down to `limit` bytes on a character boundary.
+pub fn page_text_reply(mut text: String, limit: usize) -> SocketReply {
+    if text.len() <= limit {
+        return SocketReply::PageText { text, truncated: false };
+    }
+
+    let mut end = limit;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text.truncate(end);
+    text.push_str(PAGE_TEXT_TRUNCATION_MARKER);
+    SocketReply::PageText { text, truncated: true }
+}
+
 pub fn reply_ok() -> SocketReply {
     SocketReply::Ok
 }
@@ -458,6 +657,7 @@ pub trait IpcContext {
     fn list_tabs(&self, now: Instant) -> Vec<IpcTabGroup>;
     fn tab_state(&self, tab_id: TabId, now: Instant) -> Option<IpcTabState>;
     fn tab_kind(&self, tab_id: TabId) -> Option<IpcTabKind>;
+    fn tab_cwd(&self, tab_id: TabId) -> Option<PathBuf>;
     fn create_tab(
         &mut self,
         options: WindowOptions,
@@ -475,15 +675,36 @@ pub trait IpcContext {
     ) -> Result<(), IpcError>;
     fn set_tab_title(&mut self, tab_id: TabId, title: Option<String>) -> Result<(), IpcError>;
     fn set_group_name(&mut self, group_id: usize, name: Option<String>) -> Result<(), IpcError>;
+    /// Override the window title, `None` restores dynamic titles.
+    fn set_window_title(&mut self, title: Option<String>) -> Result<(), IpcError>;
     fn restore_closed_tab(&mut self) -> Result<(), IpcError>;
+    /// Number of closed tabs which can be restored.
+    fn closed_tab_count(&self) -> usize;
     fn open_url_in_tab(&mut self, tab_id: TabId, url: String) -> Result<(), IpcError>;
     fn open_url_new_tab(&mut self, url: String) -> Result<TabId, IpcError>;
     fn reload_web(&mut self, tab_id: TabId) -> Result<(), IpcError>;
+    fn set_scroll(&mut self, tab_id: TabId, x: f64, y: f64) -> Result<(), IpcError>;
+    /// Add a stylesheet to a web tab, returning the handle used to remove it.
+    fn inject_css(&mut self, tab_id: TabId, css: String) -> Result<u64, IpcError>;
+    fn remove_css(&mut self, tab_id: TabId, handle: u64) -> Result<(), IpcError>;
     fn open_inspector(&mut self, tab_id: TabId) -> Result<(), IpcError>;
     fn tab_panel_state(&self) -> IpcTabPanelState;
     fn set_tab_panel(&mut self, enabled: Option<bool>, width: Option<usize>) -> Result<(), IpcError>;
     fn dispatch_action(&mut self, tab_id: TabId, action: Action) -> Result<(), IpcError>;
     fn send_input(&mut self, tab_id: TabId, text: String) -> Result<(), IpcError>;
+    /// Start typing text into a tab, returning the id used to query its progress.
+    fn send_input_typed(
+        &mut self,
+        tab_id: TabId,
+        text: String,
+        delay: Duration,
+    ) -> Result<u64, IpcError>;
+    fn send_key(
+        &mut self,
+        tab_id: TabId,
+        key: String,
+        modifiers: Vec<String>,
+    ) -> Result<(), IpcError>;
     fn run_command_bar(&mut self, tab_id: TabId, input: String) -> Result<(), IpcError>;
     fn list_inspector_targets(&mut self) -> Result<Vec<IpcInspectorTarget>, IpcError>;
     fn attach_inspector(
@@ -497,11 +718,12 @@ pub trait IpcContext {
         session_id: String,
         message: String,
     ) -> Result<(), IpcError>;
+    /// Drain queued inspector messages, reporting if any were dropped since the last poll.
     fn poll_inspector_messages(
         &mut self,
         session_id: String,
         max: Option<usize>,
-    ) -> Result<Vec<IpcInspectorMessage>, IpcError>;
+    ) -> Result<(Vec<IpcInspectorMessage>, bool), IpcError>;
 }
 
 pub fn handle_request<C: IpcContext>(ctx: &mut C, request: IpcRequest) -> IpcResponse {
@@ -513,14 +735,17 @@ pub fn handle_request<C: IpcContext>(ctx: &mut C, request: IpcRequest) -> IpcRes
             reply: SocketReply::Capabilities { capabilities: IpcCapabilities::current() },
             close_window: false,
         },
-        IpcRequest::ListTabs => IpcResponse {
-            reply: SocketReply::TabList { groups: ctx.list_tabs(now) },
-            close_window: false,
+        IpcRequest::ListTabs => {
+            let mut groups = ctx.list_tabs(now);
+            for tab in groups.iter_mut().flat_map(|group| &mut group.tabs) {
+                tab.cwd = ctx.tab_cwd(tab.tab_id.into());
+            }
+            IpcResponse { reply: SocketReply::TabList { groups }, close_window: false }
         },
         IpcRequest::GetTabState { tab_id } => match ctx.tab_state(tab_id.into(), now) {
-            Some(tab) => IpcResponse {
-                reply: SocketReply::TabState { tab },
-                close_window: false,
+            Some(mut tab) => {
+                tab.cwd = ctx.tab_cwd(tab_id.into());
+                IpcResponse { reply: SocketReply::TabState { tab }, close_window: false }
             },
             None => IpcResponse {
                 reply: reply_error(IpcErrorCode::NotFound, "Tab not found"),
@@ -603,10 +828,18 @@ pub fn handle_request<C: IpcContext>(ctx: &mut C, request: IpcRequest) -> IpcRes
             Ok(()) => IpcResponse { reply: reply_ok(), close_window: false },
             Err(err) => IpcResponse { reply: SocketReply::Error { error: err }, close_window: false },
         },
+        IpcRequest::SetWindowTitle { title } => match ctx.set_window_title(title) {
+            Ok(()) => IpcResponse { reply: reply_ok(), close_window: false },
+            Err(err) => IpcResponse { reply: SocketReply::Error { error: err }, close_window: false },
+        },
         IpcRequest::RestoreClosedTab => match ctx.restore_closed_tab() {
             Ok(()) => IpcResponse { reply: reply_ok(), close_window: false },
             Err(err) => IpcResponse { reply: SocketReply::Error { error: err }, close_window: false },
         },
+        IpcRequest::GetClosedTabs => IpcResponse {
+            reply: SocketReply::ClosedTabs { count: ctx.closed_tab_count() },
+            close_window: false,
+        },
         IpcRequest::OpenUrl { url, target } => {
             let result = match target {
                 UrlTarget::NewTab => ctx.open_url_new_tab(url).map(|id| Some(id)),
@@ -675,6 +908,68 @@ pub fn handle_request<C: IpcContext>(ctx: &mut C, request: IpcRequest) -> IpcRes
                 Err(err) => IpcResponse { reply: SocketReply::Error { error: err }, close_window: false },
             }
         },
+        IpcRequest::SetScroll { tab_id, x, y } => {
+            let tab_id = match tab_id.or_else(|| ctx.active_tab_id().map(IpcTabId::from)) {
+                Some(tab_id) => tab_id.into(),
+                None => {
+                    return IpcResponse {
+                        reply: reply_error(IpcErrorCode::NotFound, "No active tab"),
+                        close_window: false,
+                    };
+                },
+            };
+            match ctx.set_scroll(tab_id, x, y) {
+                Ok(()) => IpcResponse { reply: reply_ok(), close_window: false },
+                Err(err) => IpcResponse { reply: SocketReply::Error { error: err }, close_window: false },
+            }
+        },
+        IpcRequest::InjectCss { tab_id, css } => {
+            let tab_id = match tab_id.or_else(|| ctx.active_tab_id().map(IpcTabId::from)) {
+                Some(tab_id) => tab_id.into(),
+                None => {
+                    return IpcResponse {
+                        reply: reply_error(IpcErrorCode::NotFound, "No active tab"),
+                        close_window: false,
+                    };
+                },
+            };
+            match ctx.inject_css(tab_id, css) {
+                Ok(handle) => IpcResponse {
+                    reply: SocketReply::CssInjected { handle },
+                    close_window: false,
+                },
+                Err(err) => IpcResponse { reply: SocketReply::Error { error: err }, close_window: false },
+            }
+        },
+        IpcRequest::RemoveCss { tab_id, handle } => {
+            let tab_id = match tab_id.or_else(|| ctx.active_tab_id().map(IpcTabId::from)) {
+                Some(tab_id) => tab_id.into(),
+                None => {
+                    return IpcResponse {
+                        reply: reply_error(IpcErrorCode::NotFound, "No active tab"),
+                        close_window: false,
+                    };
+                },
+            };
+            match ctx.remove_css(tab_id, handle) {
+                Ok(()) => IpcResponse { reply: reply_ok(), close_window: false },
+                Err(err) => IpcResponse { reply: SocketReply::Error { error: err }, close_window: false },
+            }
+        },
+        IpcRequest::GetScroll { .. } => IpcResponse {
+            reply: reply_error(
+                IpcErrorCode::InvalidRequest,
+                "Scroll queries must be handled at the IPC router",
+            ),
+            close_window: false,
+        },
+        IpcRequest::GetPageText { .. } => IpcResponse {
+            reply: reply_error(
+                IpcErrorCode::InvalidRequest,
+                "Page text queries must be handled at the IPC router",
+            ),
+            close_window: false,
+        },
         IpcRequest::GetTabPanel => IpcResponse {
             reply: SocketReply::TabPanel { panel: ctx.tab_panel_state() },
             close_window: false,
@@ -719,6 +1014,39 @@ pub fn handle_request<C: IpcContext>(ctx: &mut C, request: IpcRequest) -> IpcRes
                 Err(err) => IpcResponse { reply: SocketReply::Error { error: err }, close_window: false },
             }
         },
+        IpcRequest::SendInputTyped { tab_id, text, delay_ms } => {
+            let tab_id = match tab_id.or_else(|| ctx.active_tab_id().map(IpcTabId::from)) {
+                Some(tab_id) => tab_id.into(),
+                None => {
+                    return IpcResponse {
+                        reply: reply_error(IpcErrorCode::NotFound, "No active tab"),
+                        close_window: false,
+                    };
+                },
+            };
+            match ctx.send_input_typed(tab_id, text, Duration::from_millis(delay_ms)) {
+                Ok(typing_id) => IpcResponse {
+                    reply: SocketReply::TypingStarted { typing_id },
+                    close_window: false,
+                },
+                Err(err) => IpcResponse { reply: SocketReply::Error { error: err }, close_window: false },
+            }
+        },
+        IpcRequest::SendKey { tab_id, key, modifiers } => {
+            let tab_id = match tab_id.or_else(|| ctx.active_tab_id().map(IpcTabId::from)) {
+                Some(tab_id) => tab_id.into(),
+                None => {
+                    return IpcResponse {
+                        reply: reply_error(IpcErrorCode::NotFound, "No active tab"),
+                        close_window: false,
+                    };
+                },
+            };
+            match ctx.send_key(tab_id, key, modifiers) {
+                Ok(()) => IpcResponse { reply: reply_ok(), close_window: false },
+                Err(err) => IpcResponse { reply: SocketReply::Error { error: err }, close_window: false },
+            }
+        },
         IpcRequest::RunCommandBar { tab_id, input } => {
             let tab_id = match tab_id.or_else(|| ctx.active_tab_id().map(IpcTabId::from)) {
                 Some(tab_id) => tab_id.into(),
@@ -741,9 +1069,18 @@ pub fn handle_request<C: IpcContext>(ctx: &mut C, request: IpcRequest) -> IpcRes
             },
             Err(err) => IpcResponse { reply: SocketReply::Error { error: err }, close_window: false },
         },
-        IpcRequest::AttachInspector { tab_id, target_id } => {
+        IpcRequest::AttachInspector { tab_id, target_id, domains } => {
             let tab_id = tab_id.map(Into::into);
-            match ctx.attach_inspector(tab_id, target_id) {
+            let attached = ctx.attach_inspector(tab_id, target_id).and_then(|session| {
+                match enable_inspector_domains(ctx, &session.session_id, &domains) {
+                    Ok(()) => Ok(session),
+                    Err(err) => {
+                        let _ = ctx.detach_inspector(session.session_id);
+                        Err(err)
+                    },
+                }
+            });
+            match attached {
                 Ok(session) => IpcResponse {
                     reply: SocketReply::InspectorAttached { session },
                     close_window: false,
@@ -765,10 +1102,10 @@ pub fn handle_request<C: IpcContext>(ctx: &mut C, request: IpcRequest) -> IpcRes
                 },
             }
         },
-        IpcRequest::PollInspectorMessages { session_id, max } => {
+        IpcRequest::PollInspectorMessages { session_id, max, .. } => {
             match ctx.poll_inspector_messages(session_id, max) {
-                Ok(messages) => IpcResponse {
-                    reply: SocketReply::InspectorMessages { messages },
+                Ok((messages, dropped)) => IpcResponse {
+                    reply: SocketReply::InspectorMessages { messages, dropped },
                     close_window: false,
                 },
                 Err(err) => {
@@ -780,11 +1117,55 @@ pub fn handle_request<C: IpcContext>(ctx: &mut C, request: IpcRequest) -> IpcRes
             reply: reply_error(IpcErrorCode::InvalidRequest, "Config requests must be handled at the IPC router"),
             close_window: false,
         },
+        IpcRequest::Subscribe => IpcResponse {
+            reply: reply_error(
+                IpcErrorCode::InvalidRequest,
+                "Subscriptions must be handled at the IPC router",
+            ),
+            close_window: false,
+        },
+        IpcRequest::MoveTabToNewWindow { .. } | IpcRequest::RestoreSession { .. } => IpcResponse {
+            reply: reply_error(
+                IpcErrorCode::InvalidRequest,
+                "Window creation must be handled at the IPC router",
+            ),
+            close_window: false,
+        },
+        IpcRequest::SaveSession { .. } => IpcResponse {
+            reply: reply_error(
+                IpcErrorCode::InvalidRequest,
+                "Sessions must be handled at the IPC router",
+            ),
+            close_window: false,
+        },
+        IpcRequest::GetTypingStatus { .. } => IpcResponse {
+            reply: reply_error(
+                IpcErrorCode::InvalidRequest,
+                "Typing status must be handled at the IPC router",
+            ),
+            close_window: false,
+        },
     };
 
     response
 }
 
+/// Send `<domain>.enable` commands for a new inspector session.
+///
+/// Command ids start at [`INSPECTOR_ENABLE_ID_BASE`], so their replies can be told apart from
+/// the replies to the client's own commands.
+fn enable_inspector_domains<C: IpcContext>(
+    ctx: &mut C,
+    session_id: &str,
+    domains: &[String],
+) -> Result<(), IpcError> {
+    for (id, domain) in (INSPECTOR_ENABLE_ID_BASE..).zip(domains) {
+        let message = serde_json::json!({ "id": id, "method": format!("{domain}.enable") });
+        ctx.send_inspector_message(session_id.to_owned(), message.to_string())?;
+    }
+    Ok(())
+}
+
 /// Create an IPC socket.
 pub fn spawn_ipc_socket(
     options: &Options,
@@ -851,6 +1232,31 @@ pub fn send_raw_message(socket: Option<PathBuf>, message_json: &str) -> IoResult
     read_reply(&socket)
 }
 
+/// Subscribe to tab lifecycle events, returning an iterator over the event lines.
+pub fn subscribe(socket: Option<PathBuf>) -> IoResult<impl Iterator<Item = IoResult<String>>> {
+    let mut socket = find_socket(socket)?;
+
+    let message_json = serde_json::to_string(&IpcRequest::Subscribe)?;
+    socket.write_all(message_json.as_bytes())?;
+    socket.write_all(b"\n")?;
+    let _ = socket.flush();
+    socket.shutdown(Shutdown::Write)?;
+
+    let mut reader = BufReader::new(socket);
+    let mut buffer = String::new();
+    if reader.read_line(&mut buffer)? == 0 {
+        return Err(IoError::other("IPC socket closed before subscription"));
+    }
+
+    let reply: SocketReply = serde_json::from_str(&buffer)
+        .map_err(|err| IoError::other(format!("Invalid IPC format: {err}")))?;
+    match reply {
+        SocketReply::Ok => Ok(reader.lines()),
+        SocketReply::Error { error } => Err(IoError::other(error.message)),
+        reply => Err(IoError::other(format!("Unexpected IPC reply: {reply:?}"))),
+    }
+}
+
 /// Read IPC responses.
 fn read_reply(stream: &UnixStream) -> IoResult<Option<SocketReply>> {
     let mut buffer = String::new();
@@ -871,6 +1277,53 @@ pub fn send_reply(stream: &mut UnixStream, message: SocketReply) {
     }
 }
 
+/// Maximum number of messages queued for a subscriber before it is dropped.
+const SUBSCRIBER_QUEUE_SIZE: usize = 1024;
+
+/// Time a subscriber may block a write before it is disconnected.
+const SUBSCRIBER_WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Client subscribed to tab lifecycle events.
+///
+/// Messages are written by a dedicated thread, so slow readers can't stall the event loop and
+/// always receive complete lines.
+pub struct IpcSubscriber {
+    sender: SyncSender<String>,
+}
+
+impl IpcSubscriber {
+    pub fn new(mut stream: UnixStream) -> IoResult<Self> {
+        stream.set_write_timeout(Some(SUBSCRIBER_WRITE_TIMEOUT))?;
+
+        let (sender, receiver) = mpsc::sync_channel::<String>(SUBSCRIBER_QUEUE_SIZE);
+        thread::spawn_named("ipc subscriber", move || {
+            for line in receiver {
+                if let Err(err) = stream.write_all(line.as_bytes()).and_then(|_| stream.flush()) {
+                    warn!("Dropping IPC subscriber: {err}");
+                    break;
+                }
+            }
+        });
+
+        Ok(Self { sender })
+    }
+
+    /// Queue a newline-delimited JSON message.
+    ///
+    /// Returns `false` once the client disconnected or fell too far behind.
+    pub fn send<T: Serialize>(&self, message: &T) -> bool {
+        let mut json = match serde_json::to_string(message) {
+            Ok(json) => json,
+            Err(err) => {
+                error!("Failed to serialize IPC event: {err}");
+                return true;
+            },
+        };
+        json.push('\n');
+        self.sender.try_send(json).is_ok()
+    }
+}
+
 /// Send IPC message reply, returning possible errors.
 fn send_reply_fallible(stream: &mut UnixStream, message: SocketReply) -> IoResult<()> {
     let json = serde_json::to_string(&message).map_err(IoError::other)?;
@@ -991,7 +1444,13 @@ mod tests {
         tab_panel: IpcTabPanelState,
         last_action: Option<Action>,
         last_input: Option<String>,
+        last_typed_input: Option<(String, Duration)>,
+        last_key: Option<(String, Vec<String>)>,
         last_command: Option<String>,
+        last_scroll: Option<(f64, f64)>,
+        injected_css: Vec<(TabId, u64, String)>,
+        closed_tabs: usize,
+        window_title: Option<String>,
         web_supported: bool,
         inspector_targets: Vec<IpcInspectorTarget>,
         inspector_sessions: HashMap<String, IpcInspectorSession>,
@@ -1009,7 +1468,13 @@ mod tests {
                 tab_panel: IpcTabPanelState { enabled: true, width: 240 },
                 last_action: None,
                 last_input: None,
+                last_typed_input: None,
+                last_key: None,
                 last_command: None,
+                last_scroll: None,
+                injected_css: Vec::new(),
+                closed_tabs: 0,
+                window_title: None,
                 web_supported,
                 inspector_targets: Vec::new(),
                 inspector_sessions: HashMap::new(),
@@ -1019,6 +1484,19 @@ mod tests {
             context
         }
 
+        fn web_tab(&self, tab_id: TabId) -> Result<&MockTab, IpcError> {
+            let tab = self
+                .tabs
+                .get(&tab_id)
+                .ok_or_else(|| IpcError::new(IpcErrorCode::NotFound, "Tab not found"))?;
+            match tab.kind {
+                IpcTabKind::Web { .. } => Ok(tab),
+                IpcTabKind::Terminal => {
+                    Err(IpcError::new(IpcErrorCode::InvalidRequest, "Not a web tab"))
+                },
+            }
+        }
+
         fn add_tab(
             &mut self,
             kind: IpcTabKind,
@@ -1109,9 +1587,12 @@ mod tests {
                                 is_active: Some(tab.id) == active,
                                 title: tab.title.clone(),
                                 custom_title: tab.custom_title.clone(),
+                                color: None,
                                 program_name: tab.program_name.clone(),
                                 kind: tab.kind.clone(),
                                 activity: None,
+                                cwd: None,
+                                scrollback: None,
                             })
                         })
                         .collect();
@@ -1130,9 +1611,12 @@ mod tests {
                 is_active: Some(tab.id) == self.active,
                 title: tab.title.clone(),
                 custom_title: tab.custom_title.clone(),
+                color: None,
                 program_name: tab.program_name.clone(),
                 kind: tab.kind.clone(),
                 activity: None,
+                cwd: None,
+                scrollback: None,
             })
         }
 
@@ -1140,6 +1624,13 @@ mod tests {
             self.tabs.get(&tab_id).map(|tab| tab.kind.clone())
         }
 
+        fn tab_cwd(&self, tab_id: TabId) -> Option<PathBuf> {
+            match self.tabs.get(&tab_id)?.kind {
+                IpcTabKind::Terminal => Some(PathBuf::from("/tmp")),
+                IpcTabKind::Web { .. } => None,
+            }
+        }
+
         fn create_tab(
             &mut self,
             options: WindowOptions,
@@ -1178,6 +1669,7 @@ mod tests {
             if self.active == Some(tab_id) {
                 self.active = self.tabs_ordered().first().copied();
             }
+            self.closed_tabs += 1;
             Ok(self.tabs.is_empty())
         }
 
@@ -1280,10 +1772,20 @@ mod tests {
             Ok(())
         }
 
+        fn set_window_title(&mut self, title: Option<String>) -> Result<(), IpcError> {
+            self.window_title = title;
+            Ok(())
+        }
+
         fn restore_closed_tab(&mut self) -> Result<(), IpcError> {
+            self.closed_tabs = self.closed_tabs.saturating_sub(1);
             Ok(())
         }
 
+        fn closed_tab_count(&self) -> usize {
+            self.closed_tabs
+        }
+
         fn open_url_in_tab(&mut self, tab_id: TabId, url: String) -> Result<(), IpcError> {
             let tab = self
                 .tabs
@@ -1312,21 +1814,34 @@ mod tests {
         }
 
         fn reload_web(&mut self, tab_id: TabId) -> Result<(), IpcError> {
-            let tab = self
-                .tabs
-                .get(&tab_id)
-                .ok_or_else(|| IpcError::new(IpcErrorCode::NotFound, "Tab not found"))?;
-            match tab.kind {
-                IpcTabKind::Web { .. } => Ok(()),
-                IpcTabKind::Terminal => Err(IpcError::new(
-                    IpcErrorCode::InvalidRequest,
-                    "Not a web tab",
-                )),
-            }
+            self.web_tab(tab_id).map(|_| ())
         }
 
         fn open_inspector(&mut self, tab_id: TabId) -> Result<(), IpcError> {
-            self.reload_web(tab_id)
+            self.web_tab(tab_id).map(|_| ())
+        }
+
+        fn set_scroll(&mut self, tab_id: TabId, x: f64, y: f64) -> Result<(), IpcError> {
+            self.web_tab(tab_id)?;
+            self.last_scroll = Some((x, y));
+            Ok(())
+        }
+
+        fn inject_css(&mut self, tab_id: TabId, css: String) -> Result<u64, IpcError> {
+            self.web_tab(tab_id)?;
+            let handle = self.injected_css.last().map_or(1, |(_, handle, _)| handle + 1);
+            self.injected_css.push((tab_id, handle, css));
+            Ok(handle)
+        }
+
+        fn remove_css(&mut self, tab_id: TabId, handle: u64) -> Result<(), IpcError> {
+            self.web_tab(tab_id)?;
+            let len = self.injected_css.len();
+            self.injected_css.retain(|(id, css_handle, _)| (*id, *css_handle) != (tab_id, handle));
+            if self.injected_css.len() == len {
+                return Err(IpcError::new(IpcErrorCode::NotFound, "Stylesheet not found"));
+            }
+            Ok(())
         }
 
         fn tab_panel_state(&self) -> IpcTabPanelState {
@@ -1359,6 +1874,32 @@ mod tests {
             Ok(())
         }
 
+        fn send_input_typed(
+            &mut self,
+            _tab_id: TabId,
+            text: String,
+            delay: Duration,
+        ) -> Result<u64, IpcError> {
+            self.last_typed_input = Some((text, delay));
+            Ok(1)
+        }
+
+        fn send_key(
+            &mut self,
+            _tab_id: TabId,
+            key: String,
+            modifiers: Vec<String>,
+        ) -> Result<(), IpcError> {
+            if !self.web_supported {
+                return Err(IpcError::new(
+                    IpcErrorCode::Unsupported,
+                    "Web tabs are not supported",
+                ));
+            }
+            self.last_key = Some((key, modifiers));
+            Ok(())
+        }
+
         fn run_command_bar(&mut self, _tab_id: TabId, input: String) -> Result<(), IpcError> {
             self.last_command = Some(input);
             Ok(())
@@ -1425,7 +1966,7 @@ mod tests {
             &mut self,
             session_id: String,
             max: Option<usize>,
-        ) -> Result<Vec<IpcInspectorMessage>, IpcError> {
+        ) -> Result<(Vec<IpcInspectorMessage>, bool), IpcError> {
             let Some(messages) = self.inspector_messages.get_mut(&session_id) else {
                 return Err(IpcError::new(
                     IpcErrorCode::NotFound,
@@ -1444,7 +1985,7 @@ mod tests {
                     payload,
                 });
             }
-            Ok(drained)
+            Ok((drained, false))
         }
     }
 
@@ -1489,6 +2030,15 @@ mod tests {
             Some("renamed")
         );
 
+        let title = Some(String::from("project"));
+        let response = handle_request(&mut ctx, IpcRequest::SetWindowTitle { title });
+        assert!(matches!(response.reply, SocketReply::Ok));
+        assert_eq!(ctx.window_title.as_deref(), Some("project"));
+
+        let response = handle_request(&mut ctx, IpcRequest::SetWindowTitle { title: None });
+        assert!(matches!(response.reply, SocketReply::Ok));
+        assert_eq!(ctx.window_title, None);
+
         let response = handle_request(
             &mut ctx,
             IpcRequest::MoveTab {
@@ -1509,6 +2059,9 @@ mod tests {
         assert!(matches!(response.reply, SocketReply::Ok));
         assert!(!response.close_window);
 
+        let response = handle_request(&mut ctx, IpcRequest::GetClosedTabs);
+        assert_eq!(response.reply, SocketReply::ClosedTabs { count: 1 });
+
         let response = handle_request(&mut ctx, IpcRequest::CloseTab { tab_id: None });
         assert!(matches!(response.reply, SocketReply::Ok));
         assert!(response.close_window);
@@ -1544,6 +2097,7 @@ mod tests {
         };
         assert_eq!(groups.len(), 1);
         assert_eq!(groups[0].tabs.len(), 2);
+        assert_eq!(groups[0].tabs[0].cwd, Some(PathBuf::from("/tmp")));
 
         let response = handle_request(
             &mut ctx,
@@ -1555,6 +2109,7 @@ mod tests {
             panic!("expected tab_state reply");
         };
         assert_eq!(tab.tab_id, web_id.into());
+        assert_eq!(tab.cwd, None);
     }
 
     #[test]
@@ -1587,6 +2142,12 @@ mod tests {
         let response = handle_request(&mut ctx, IpcRequest::OpenInspector { tab_id: Some(tab_id) });
         assert!(matches!(response.reply, SocketReply::Ok));
 
+        let request: IpcRequest =
+            serde_json::from_str(r#"{"type":"send_key","tab_id":null,"key":"Tab"}"#).unwrap();
+        let response = handle_request(&mut ctx, request);
+        assert!(matches!(response.reply, SocketReply::Ok));
+        assert_eq!(ctx.last_key, Some((String::from("Tab"), Vec::new())));
+
         let response = handle_request(
             &mut ctx,
             IpcRequest::SetTabPanel {
@@ -1641,6 +2202,33 @@ mod tests {
         assert!(matches!(response.reply, SocketReply::Ok));
         assert_eq!(ctx.last_input.as_deref(), Some("ls\n"));
 
+        let response = handle_request(
+            &mut ctx,
+            IpcRequest::SendInputTyped {
+                tab_id: None,
+                text: String::from("query"),
+                delay_ms: 80,
+            },
+        );
+        assert!(matches!(response.reply, SocketReply::TypingStarted { typing_id: 1 }));
+        assert_eq!(
+            ctx.last_typed_input,
+            Some((String::from("query"), Duration::from_millis(80)))
+        );
+
+        let response = handle_request(
+            &mut ctx,
+            IpcRequest::SendKey {
+                tab_id: Some(tab_id.into()),
+                key: String::from("Escape"),
+                modifiers: Vec::new(),
+            },
+        );
+        let SocketReply::Error { error } = response.reply else {
+            panic!("expected error reply");
+        };
+        assert_eq!(error.code, IpcErrorCode::Unsupported);
+
         let response = handle_request(
             &mut ctx,
             IpcRequest::RunCommandBar {
@@ -1674,7 +2262,11 @@ mod tests {
 
         let response = handle_request(
             &mut ctx,
-            IpcRequest::AttachInspector { tab_id: Some(tab_id.into()), target_id: Some(42) },
+            IpcRequest::AttachInspector {
+                tab_id: Some(tab_id.into()),
+                target_id: Some(42),
+                domains: Vec::new(),
+            },
         );
         let SocketReply::InspectorAttached { session } = response.reply else {
             panic!("expected inspector_attached reply");
@@ -1695,12 +2287,14 @@ mod tests {
             IpcRequest::PollInspectorMessages {
                 session_id: session.session_id.clone(),
                 max: Some(10),
+                timeout_ms: None,
             },
         );
-        let SocketReply::InspectorMessages { messages } = response.reply else {
+        let SocketReply::InspectorMessages { messages, dropped } = response.reply else {
             panic!("expected inspector_messages reply");
         };
         assert_eq!(messages.len(), 1);
+        assert!(!dropped);
 
         let response = handle_request(
             &mut ctx,
@@ -1708,4 +2302,158 @@ mod tests {
         );
         assert!(matches!(response.reply, SocketReply::Ok));
     }
+
+    #[test]
+    fn ipc_attach_inspector_enables_domains() {
+        let mut ctx = MockContext::new(false);
+        let tab_id = ctx.active_tab_id().unwrap();
+        ctx.inspector_targets.push(IpcInspectorTarget {
+            target_id: 42,
+            target_type: Some(String::from("WIRTypeWebPage")),
+            url: Some(String::from("https://example.com")),
+            title: Some(String::from("Example")),
+            override_name: None,
+            host_app_identifier: None,
+            tab_id: Some(tab_id.into()),
+        });
+
+        let request: IpcRequest = serde_json::from_str(
+            r#"{"type":"attach_inspector","target_id":42,"domains":["Page","Runtime"]}"#,
+        )
+        .unwrap();
+        let response = handle_request(&mut ctx, request);
+        let SocketReply::InspectorAttached { session } = response.reply else {
+            panic!("expected inspector_attached reply");
+        };
+
+        let response = handle_request(
+            &mut ctx,
+            IpcRequest::PollInspectorMessages {
+                session_id: session.session_id,
+                max: None,
+                timeout_ms: None,
+            },
+        );
+        let SocketReply::InspectorMessages { messages, .. } = response.reply else {
+            panic!("expected inspector_messages reply");
+        };
+        let methods: Vec<_> = messages
+            .iter()
+            .map(|message| serde_json::from_str::<serde_json::Value>(&message.payload).unwrap())
+            .map(|message| message["method"].as_str().unwrap().to_owned())
+            .collect();
+        assert_eq!(methods, ["Page.enable", "Runtime.enable"]);
+    }
+
+    #[test]
+    fn ipc_subscribe_is_routed_and_events_serialize() {
+        let mut ctx = MockContext::new(false);
+        let tab_id = ctx.active_tab_id().unwrap();
+
+        let request: IpcRequest = serde_json::from_str(r#"{"type":"subscribe"}"#).unwrap();
+        let response = handle_request(&mut ctx, request);
+        let SocketReply::Error { error } = response.reply else {
+            panic!("expected error reply");
+        };
+        assert_eq!(error.code, IpcErrorCode::InvalidRequest);
+
+        let event =
+            IpcEvent::TabRenamed { tab_id: tab_id.into(), name: Some(String::from("logs")) };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["event"], "tab_renamed");
+        assert_eq!(json["name"], "logs");
+        assert_eq!(json["tab_id"]["index"], tab_id.index);
+    }
+
+    #[test]
+    fn ipc_session_tabs_restore_options() {
+        let json = r#"{"windows":[{"groups":[{"name":"work","tabs":[
+            {"kind":"terminal","cwd":"/tmp","custom_title":"shell"},
+            {"kind":{"web":{"url":"https://example.com"}},"is_active":true}
+        ]}]}]}"#;
+        let session: IpcSession = serde_json::from_str(json).unwrap();
+        let tabs = &session.windows[0].groups[0].tabs;
+
+        let options = tabs[0].window_options();
+        assert_eq!(options.window_kind, WindowKind::Terminal);
+        assert_eq!(options.terminal_options.working_directory, Some(PathBuf::from("/tmp")));
+
+        let options = tabs[1].window_options();
+        assert_eq!(
+            options.window_kind,
+            WindowKind::Web { url: String::from("https://example.com") }
+        );
+        assert_eq!(options.terminal_options.working_directory, None);
+        assert!(tabs[1].is_active);
+
+        let mut ctx = MockContext::new(false);
+        let request = IpcRequest::SaveSession { path: PathBuf::from("/tmp/session.json") };
+        let SocketReply::Error { error } = handle_request(&mut ctx, request).reply else {
+            panic!("expected error reply");
+        };
+        assert_eq!(error.code, IpcErrorCode::InvalidRequest);
+    }
+
+    #[test]
+    fn ipc_set_scroll_requires_web_tab() {
+        let mut ctx = MockContext::new(true);
+
+        let request: IpcRequest =
+            serde_json::from_str(r#"{"type":"set_scroll","tab_id":null,"x":0.0,"y":120.5}"#)
+                .unwrap();
+        let response = handle_request(&mut ctx, request);
+        let SocketReply::Error { error } = response.reply else {
+            panic!("expected error reply");
+        };
+        assert_eq!(error.code, IpcErrorCode::InvalidRequest);
+        assert_eq!(ctx.last_scroll, None);
+
+        let tab_id = ctx.open_url_new_tab(String::from("https://example.com")).unwrap();
+        let request = IpcRequest::SetScroll { tab_id: Some(tab_id.into()), x: 0., y: 120.5 };
+        let response = handle_request(&mut ctx, request);
+        assert!(matches!(response.reply, SocketReply::Ok));
+        assert_eq!(ctx.last_scroll, Some((0., 120.5)));
+    }
+
+    #[test]
+    fn ipc_inject_and_remove_css() {
+        let mut ctx = MockContext::new(true);
+        let terminal_id = ctx.active_tab_id().unwrap();
+        let tab_id = ctx.open_url_new_tab(String::from("https://example.com")).unwrap();
+
+        let request: IpcRequest =
+            serde_json::from_str(r#"{"type":"inject_css","tab_id":null,"css":"body { zoom: 2 }"}"#)
+                .unwrap();
+        let response = handle_request(&mut ctx, request);
+        assert_eq!(response.reply, SocketReply::CssInjected { handle: 1 });
+
+        let request = IpcRequest::RemoveCss { tab_id: Some(terminal_id.into()), handle: 1 };
+        let response = handle_request(&mut ctx, request);
+        let SocketReply::Error { error } = response.reply else {
+            panic!("expected error reply");
+        };
+        assert_eq!(error.code, IpcErrorCode::InvalidRequest);
+        assert_eq!(ctx.injected_css.len(), 1);
+
+        let request = IpcRequest::RemoveCss { tab_id: Some(tab_id.into()), handle: 1 };
+        let response = handle_request(&mut ctx, request.clone());
+        assert!(matches!(response.reply, SocketReply::Ok));
+        assert!(ctx.injected_css.is_empty());
+
+        let response = handle_request(&mut ctx, request);
+        let SocketReply::Error { error } = response.reply else {
+            panic!("expected error reply");
+        };
+        assert_eq!(error.code, IpcErrorCode::NotFound);
+    }
+
+    #[test]
+    fn page_text_is_truncated_on_char_boundary() {
+        let reply = page_text_reply(String::from("short"), 5);
+        assert_eq!(reply, SocketReply::PageText { text: String::from("short"), truncated: false });
+
+        let reply = page_text_reply(String::from("añb"), 2);
+        let text = format!("a{PAGE_TEXT_TRUNCATION_MARKER}");
+        assert_eq!(reply, SocketReply::PageText { text, truncated: true });
+    }
 }
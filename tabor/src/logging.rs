@@ -31,6 +31,9 @@ pub const LOG_TARGET_CONFIG: &str = "tabor_config_derive";
 /// Logging target for winit events.
 pub const LOG_TARGET_WINIT: &str = "tabor_winit_event";
 
+/// Logging target for web navigation.
+pub const LOG_TARGET_WEB: &str = "tabor_web_navigation";
+
 /// Name for the environment variable containing extra logging targets.
 ///
 /// The targets are semicolon separated.
@@ -51,6 +54,7 @@ const ALLOWED_TARGETS: &[&str] = &[
     LOG_TARGET_IPC_CONFIG,
     LOG_TARGET_CONFIG,
     LOG_TARGET_WINIT,
+    LOG_TARGET_WEB,
     "tabor_config_derive",
     "tabor_terminal",
     "tabor",
@@ -1,10 +1,11 @@
 use std::io::Read;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
 use crossfont::{BitmapBuffer, Metrics, RasterizedGlyph};
 use image::imageops::{self, FilterType};
-use image::{Rgba, RgbaImage};
+use image::{ImageFormat, ImageResult, Rgba, RgbaImage};
 use url::Url;
 
 use crate::display::SizeInfo;
@@ -65,6 +66,11 @@ impl FaviconImage {
         }
     }
 
+    /// Write the icon to a PNG file.
+    pub fn save_png(&self, path: &Path) -> ImageResult<()> {
+        self.to_image().save_with_format(path, ImageFormat::Png)
+    }
+
     fn to_image(&self) -> RgbaImage {
         RgbaImage::from_raw(self.width, self.height, self.rgba.to_vec())
             .unwrap_or_else(|| RgbaImage::from_pixel(1, 1, Rgba([0, 0, 0, 0])))
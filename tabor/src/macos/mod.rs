@@ -1,33 +1,68 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::ffi::CStr;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
-use block2::RcBlock;
-use objc2::ffi::NSInteger;
+use block2::{Block, RcBlock};
+use objc2::ffi::{NSInteger, NSUInteger};
 use objc2::rc::Retained;
 use objc2::runtime::{AnyClass, AnyObject, Bool};
 use objc2::{msg_send, sel, MainThreadMarker};
-use objc2_foundation::{NSDictionary, NSString, NSUserDefaults, ns_string};
+use objc2_foundation::{NSDictionary, NSNumber, NSString, NSUserDefaults, ns_string};
+use winit::window::WindowId;
+
+use crate::tabs::TabId;
 
 #[link(name = "AuthenticationServices", kind = "framework")]
 unsafe extern "C" {}
 
+#[link(name = "UserNotifications", kind = "framework")]
+unsafe extern "C" {}
+
 pub mod favicon;
 pub mod locale;
+pub mod notifications;
 pub mod open_documents;
 pub mod proc;
 pub mod remote_inspector;
+pub mod site_settings;
 pub mod web_commands;
 pub mod web_cursor;
 pub mod webview;
 
+pub(crate) use notifications::register_notification_handler;
 pub(crate) use open_documents::register_open_documents_handler;
 
 static WEBVIEW_COUNT: AtomicUsize = AtomicUsize::new(0);
 static PASSKEY_AUTH_REQUESTED: AtomicBool = AtomicBool::new(false);
+static NOTIFICATION_AUTH_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Minimum time between two bell notifications.
+const BELL_NOTIFICATION_COOLDOWN: Duration = Duration::from_secs(5);
+
+/// Identifier shared by all bell notifications, so a new one replaces the previous.
+const BELL_NOTIFICATION_ID: &str = "org.tabor.bell";
+
+/// Identifier for command completion notifications.
+const COMMAND_NOTIFICATION_ID: &str = "org.tabor.command";
+
+/// Identifier prefix of notifications posted by web pages.
+const WEB_NOTIFICATION_ID: &str = "org.tabor.web";
+
+/// Keys of the user info identifying the tab which posted a web notification.
+const NOTIFICATION_WINDOW_KEY: &str = "window";
+const NOTIFICATION_TAB_INDEX_KEY: &str = "tab_index";
+const NOTIFICATION_TAB_GENERATION_KEY: &str = "tab_generation";
+
+/// `UNAuthorizationOptionSound | UNAuthorizationOptionAlert`.
+const NOTIFICATION_AUTH_OPTIONS: NSUInteger = (1 << 1) | (1 << 2);
+
+/// `UNNotificationInterruptionLevelActive`.
+const NOTIFICATION_INTERRUPTION_ACTIVE: NSUInteger = 1;
 
 thread_local! {
     static PASSKEY_AUTH_BLOCK: RefCell<Option<RcBlock<dyn Fn(NSInteger)>>> = RefCell::new(None);
+    static LAST_BELL_NOTIFICATION: Cell<Option<Instant>> = const { Cell::new(None) };
 }
 
 pub fn disable_autofill() {
@@ -63,6 +98,13 @@ pub(crate) fn unregister_webview() {
     }
 }
 
+/// Force autofill on or off for the page of the focused web tab.
+///
+/// Without an override, autofill is enabled while any web view exists.
+pub(crate) fn set_site_autofill(enabled: Option<bool>) {
+    set_autofill_override(enabled.unwrap_or_else(|| WEBVIEW_COUNT.load(Ordering::SeqCst) > 0));
+}
+
 fn set_autofill_override(enabled: bool) {
     let defaults = NSUserDefaults::standardUserDefaults();
     if enabled {
@@ -121,3 +163,179 @@ fn request_passkey_authorization() {
         let _: () = msg_send![&*manager, requestAuthorizationForPublicKeyCredentials: &*block];
     }
 }
+
+/// Post a native notification for a bell rung in a background tab or window.
+///
+/// Notifications are throttled and use the active interruption level, so they are held back
+/// while Do Not Disturb or a Focus mode is enabled.
+pub(crate) fn post_bell_notification(tab_title: &str) {
+    let now = Instant::now();
+    let throttled = LAST_BELL_NOTIFICATION.with(|last| {
+        if last.get().is_some_and(|last| now.duration_since(last) < BELL_NOTIFICATION_COOLDOWN) {
+            return true;
+        }
+        last.set(Some(now));
+        false
+    });
+    if throttled {
+        return;
+    }
+
+    post_notification(BELL_NOTIFICATION_ID, "Bell", tab_title, None);
+}
+
+/// Post a notification for a long-running command which finished in the background.
+pub(crate) fn post_command_notification(tab_title: &str, command: &str, exit_code: Option<i32>) {
+    let title = match exit_code {
+        Some(0) | None => String::from("Command finished"),
+        Some(code) => format!("Command failed with exit code {code}"),
+    };
+    let body =
+        if command.is_empty() { tab_title.to_owned() } else { format!("{command}\n{tab_title}") };
+
+    post_notification(COMMAND_NOTIFICATION_ID, &title, &body, None);
+}
+
+/// Post a notification requested by the page of a web tab.
+///
+/// Each tab only shows its latest notification, clicking it selects the tab.
+pub(crate) fn post_web_notification(window_id: WindowId, tab_id: TabId, title: &str, body: &str) {
+    let identifier = format!("{WEB_NOTIFICATION_ID}.{}.{}", u64::from(window_id), tab_id.index);
+    let keys = [
+        NSString::from_str(NOTIFICATION_WINDOW_KEY),
+        NSString::from_str(NOTIFICATION_TAB_INDEX_KEY),
+        NSString::from_str(NOTIFICATION_TAB_GENERATION_KEY),
+    ];
+    let values = [
+        NSNumber::new_u64(u64::from(window_id)),
+        NSNumber::new_u32(tab_id.index),
+        NSNumber::new_u32(tab_id.generation),
+    ];
+    let keys: Vec<&NSString> = keys.iter().map(|key| &**key).collect();
+    let values: Vec<&NSNumber> = values.iter().map(|value| &**value).collect();
+    let user_info = NSDictionary::from_slices(&keys, &values);
+
+    post_notification(&identifier, title, body, Some(&user_info));
+}
+
+/// Post a native notification.
+///
+/// Notifications with the same identifier replace each other.
+fn post_notification(
+    identifier: &str,
+    title: &str,
+    body: &str,
+    user_info: Option<&NSDictionary<NSString, NSNumber>>,
+) {
+    let Some(center) = notification_center() else {
+        return;
+    };
+    request_notification_authorization(&center);
+
+    let (Some(content_class), Some(request_class)) =
+        (AnyClass::get(c"UNMutableNotificationContent"), AnyClass::get(c"UNNotificationRequest"))
+    else {
+        return;
+    };
+
+    let content: Option<Retained<AnyObject>> = unsafe { msg_send![content_class, new] };
+    let Some(content) = content else {
+        return;
+    };
+
+    let title = NSString::from_str(title);
+    let body = NSString::from_str(body);
+    unsafe {
+        let _: () = msg_send![&*content, setTitle: &*title];
+        let _: () = msg_send![&*content, setBody: &*body];
+        if let Some(user_info) = user_info {
+            let _: () = msg_send![&*content, setUserInfo: user_info];
+        }
+    }
+
+    let level_sel = sel!(setInterruptionLevel:);
+    let responds: Bool = unsafe { msg_send![&*content, respondsToSelector: level_sel] };
+    if responds.as_bool() {
+        unsafe {
+            let _: () =
+                msg_send![&*content, setInterruptionLevel: NOTIFICATION_INTERRUPTION_ACTIVE];
+        }
+    }
+
+    let identifier = NSString::from_str(identifier);
+    let request: Option<Retained<AnyObject>> = unsafe {
+        msg_send![
+            request_class,
+            requestWithIdentifier: &*identifier,
+            content: &*content,
+            trigger: None::<&AnyObject>
+        ]
+    };
+    let Some(request) = request else {
+        return;
+    };
+
+    unsafe {
+        let _: () = msg_send![
+            &*center,
+            addNotificationRequest: &*request,
+            withCompletionHandler: None::<&Block<dyn Fn(*mut AnyObject)>>
+        ];
+    }
+}
+
+/// Get the user notification center.
+///
+/// The notification center raises an exception for processes without a bundle identifier, so
+/// this returns `None` when Tabor is not running from an app bundle.
+fn notification_center() -> Option<Retained<AnyObject>> {
+    let bundle_class = AnyClass::get(c"NSBundle")?;
+    let bundle: Option<Retained<AnyObject>> = unsafe { msg_send![bundle_class, mainBundle] };
+    let identifier: Option<Retained<NSString>> = unsafe { msg_send![&*bundle?, bundleIdentifier] };
+    identifier?;
+
+    let center_class = AnyClass::get(c"UNUserNotificationCenter")?;
+    unsafe { msg_send![center_class, currentNotificationCenter] }
+}
+
+fn request_notification_authorization(center: &AnyObject) {
+    if NOTIFICATION_AUTH_REQUESTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    let block = RcBlock::new(|_granted: Bool, _error: *mut AnyObject| {});
+    unsafe {
+        let _: () = msg_send![
+            center,
+            requestAuthorizationWithOptions: NOTIFICATION_AUTH_OPTIONS,
+            completionHandler: &*block
+        ];
+    }
+}
+
+/// Store plain text together with an HTML flavor on the general pasteboard.
+///
+/// Returns `false` when the pasteboard could not be written.
+pub(crate) fn store_rich_text(text: &str, html: &str) -> bool {
+    let Some(pasteboard_class) = AnyClass::get(c"NSPasteboard") else {
+        return false;
+    };
+    let pasteboard: Option<Retained<AnyObject>> =
+        unsafe { msg_send![pasteboard_class, generalPasteboard] };
+    let Some(pasteboard) = pasteboard else {
+        return false;
+    };
+
+    let text = NSString::from_str(text);
+    let html = NSString::from_str(html);
+    unsafe {
+        let _: NSInteger = msg_send![&*pasteboard, clearContents];
+        let text_stored: Bool = msg_send![
+            &*pasteboard,
+            setString: &*text,
+            forType: ns_string!("public.utf8-plain-text")
+        ];
+        let _: Bool = msg_send![&*pasteboard, setString: &*html, forType: ns_string!("public.html")];
+        text_stored.as_bool()
+    }
+}
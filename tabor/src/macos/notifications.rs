@@ -0,0 +1,118 @@
+use std::cell::RefCell;
+
+use block2::Block;
+use objc2::ffi::NSUInteger;
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, NSObject};
+use objc2::{define_class, msg_send, DefinedClass, MainThreadMarker, MainThreadOnly};
+use objc2_foundation::{NSDictionary, NSNumber, NSObjectProtocol, NSString};
+use winit::event_loop::EventLoopProxy;
+use winit::window::WindowId;
+
+use crate::event::{Event, EventType};
+use crate::tabs::TabId;
+
+use super::{NOTIFICATION_TAB_GENERATION_KEY, NOTIFICATION_TAB_INDEX_KEY, NOTIFICATION_WINDOW_KEY};
+
+/// `UNNotificationPresentationOptionList | UNNotificationPresentationOptionBanner`.
+const WEB_PRESENTATION_OPTIONS: NSUInteger = (1 << 3) | (1 << 4);
+
+struct NotificationDelegateIvars {
+    proxy: EventLoopProxy<Event>,
+}
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = NotificationDelegateIvars]
+    struct NotificationDelegate;
+
+    impl NotificationDelegate {
+        #[unsafe(method(userNotificationCenter:willPresentNotification:withCompletionHandler:))]
+        fn will_present_notification(
+            &self,
+            _center: *mut AnyObject,
+            notification: *mut AnyObject,
+            completion: *mut Block<dyn Fn(NSUInteger)>,
+        ) {
+            // Like in browsers, notifications of pages are shown even while Tabor is focused.
+            let options = match web_notification_tab(notification) {
+                Some(_) => WEB_PRESENTATION_OPTIONS,
+                None => 0,
+            };
+
+            if let Some(completion) = unsafe { completion.as_ref() } {
+                completion.call((options,));
+            }
+        }
+
+        #[unsafe(method(userNotificationCenter:didReceiveNotificationResponse:withCompletionHandler:))]
+        fn did_receive_notification_response(
+            &self,
+            _center: *mut AnyObject,
+            response: *mut AnyObject,
+            completion: *mut Block<dyn Fn()>,
+        ) {
+            let notification: *mut AnyObject = unsafe { msg_send![response, notification] };
+            if let Some((window_id, tab_id)) = web_notification_tab(notification) {
+                let event = Event::for_tab(EventType::WebNotificationClicked, window_id, tab_id);
+                let _ = self.ivars().proxy.send_event(event);
+            }
+
+            if let Some(completion) = unsafe { completion.as_ref() } {
+                completion.call(());
+            }
+        }
+    }
+);
+
+unsafe impl NSObjectProtocol for NotificationDelegate {}
+
+thread_local! {
+    static NOTIFICATION_DELEGATE: RefCell<Option<Retained<NotificationDelegate>>> =
+        const { RefCell::new(None) };
+}
+
+impl NotificationDelegate {
+    fn new(proxy: EventLoopProxy<Event>, mtm: MainThreadMarker) -> Retained<Self> {
+        let this = NotificationDelegate::alloc(mtm).set_ivars(NotificationDelegateIvars { proxy });
+        unsafe { msg_send![super(this), init] }
+    }
+}
+
+/// Window and tab which posted a web notification, from the notification's user info.
+fn web_notification_tab(notification: *mut AnyObject) -> Option<(WindowId, TabId)> {
+    let notification = unsafe { notification.as_ref() }?;
+    let request: *mut AnyObject = unsafe { msg_send![notification, request] };
+    let content: *mut AnyObject = unsafe { msg_send![unsafe { request.as_ref() }?, content] };
+    let user_info: *mut AnyObject = unsafe { msg_send![unsafe { content.as_ref() }?, userInfo] };
+
+    // Only web notifications have a user info, which holds numbers exclusively.
+    let user_info = unsafe { (user_info as *const NSDictionary<NSString, NSNumber>).as_ref() }?;
+    let value = |key: &str| user_info.objectForKey(&NSString::from_str(key));
+    let window_id = WindowId::from(value(NOTIFICATION_WINDOW_KEY)?.as_u64());
+    let index = value(NOTIFICATION_TAB_INDEX_KEY)?.as_u32();
+    let generation = value(NOTIFICATION_TAB_GENERATION_KEY)?.as_u32();
+    Some((window_id, TabId::new(index, generation)))
+}
+
+/// Select the tab of a web notification when it's clicked.
+pub(crate) fn register_notification_handler(proxy: EventLoopProxy<Event>) {
+    let mtm = MainThreadMarker::new().expect("notification handler must be on the main thread");
+    let Some(center) = super::notification_center() else {
+        return;
+    };
+
+    NOTIFICATION_DELEGATE.with(|cell| {
+        if cell.borrow().is_some() {
+            return;
+        }
+
+        // The notification center only keeps a weak reference to its delegate.
+        let delegate = NotificationDelegate::new(proxy, mtm);
+        unsafe {
+            let _: () = msg_send![&*center, setDelegate: &*delegate];
+        }
+        *cell.borrow_mut() = Some(delegate);
+    });
+}
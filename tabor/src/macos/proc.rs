@@ -5,6 +5,7 @@ use std::mem::{self, MaybeUninit};
 use std::os::raw::{c_int, c_void};
 use std::os::unix::ffi::OsStringExt;
 use std::path::PathBuf;
+use std::ptr;
 
 /// Error during working directory retrieval.
 #[derive(Debug)]
@@ -84,6 +85,47 @@ pub fn executable_path(pid: c_int) -> io::Result<PathBuf> {
     Ok(PathBuf::from(OsString::from_vec(c_str.to_bytes().to_vec())))
 }
 
+/// Command line arguments of a process.
+pub fn arguments(pid: c_int) -> io::Result<Vec<String>> {
+    let mut name = [libc::CTL_KERN, libc::KERN_PROCARGS2, pid];
+    let mut size = 0;
+    let result = unsafe {
+        libc::sysctl(name.as_mut_ptr(), 3, ptr::null_mut(), &mut size, ptr::null_mut(), 0)
+    };
+    if result != 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let mut buf = vec![0u8; size];
+    let result = unsafe {
+        libc::sysctl(name.as_mut_ptr(), 3, buf.as_mut_ptr().cast(), &mut size, ptr::null_mut(), 0)
+    };
+    if result != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    buf.truncate(size);
+
+    parse_procargs(&buf)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid process arguments"))
+}
+
+/// Parse the `KERN_PROCARGS2` layout: argc, the executable path, NUL padding, then the arguments.
+fn parse_procargs(buf: &[u8]) -> Option<Vec<String>> {
+    let argc = c_int::from_ne_bytes(buf.get(..4)?.try_into().ok()?);
+    let rest = &buf[4..];
+
+    let path_end = rest.iter().position(|&byte| byte == 0)?;
+    let rest = &rest[path_end..];
+    let args_start = rest.iter().position(|&byte| byte != 0)?;
+
+    let args = rest[args_start..]
+        .split(|&byte| byte == 0)
+        .take(usize::try_from(argc).ok()?)
+        .map(|arg| String::from_utf8_lossy(arg).into_owned())
+        .collect();
+    Some(args)
+}
+
 /// Bindings for libproc.
 #[allow(non_camel_case_types)]
 mod sys {
@@ -174,4 +216,10 @@ mod tests {
     fn cwd_matches_current_dir() {
         assert_eq!(cwd(process::id() as i32).ok(), env::current_dir().ok());
     }
+
+    #[test]
+    fn arguments_match_current_args() {
+        let args: Vec<String> = env::args().collect();
+        assert_eq!(arguments(process::id() as i32).ok(), Some(args));
+    }
 }
@@ -60,6 +60,44 @@ pub struct InspectorMessage {
     pub payload: String,
 }
 
+/// Messages drained from a session's queue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectorPoll {
+    pub messages: Vec<InspectorMessage>,
+    /// Messages were dropped since the last poll because the queue was full.
+    pub dropped: bool,
+}
+
+/// Wakes up long polls once new inspector messages are queued.
+#[derive(Default)]
+pub struct InspectorMessageSignal {
+    generation: Mutex<u64>,
+    cv: Condvar,
+}
+
+impl InspectorMessageSignal {
+    pub fn generation(&self) -> u64 {
+        *self.generation.lock()
+    }
+
+    /// Wait for messages queued after `generation`, returning `false` on timeout.
+    pub fn wait(&self, generation: u64, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        let mut current = self.generation.lock();
+        while *current == generation {
+            if self.cv.wait_until(&mut current, deadline).timed_out() {
+                return *current != generation;
+            }
+        }
+        true
+    }
+
+    fn notify(&self) {
+        *self.generation.lock() += 1;
+        self.cv.notify_all();
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InspectorTabInfo {
     pub tab_id: TabId,
@@ -116,10 +154,54 @@ impl MessageDataType {
     }
 }
 
+/// Protocol message waiting to be polled.
+struct QueuedMessage {
+    payload: String,
+    /// Whether the message is an event, rather than a reply to a command.
+    event: bool,
+}
+
 struct InspectorSessionState {
     target_id: u64,
-    pending_messages: VecDeque<String>,
+    pending_messages: VecDeque<QueuedMessage>,
     pending_chunk: Vec<u8>,
+    dropped: bool,
+}
+
+impl InspectorSessionState {
+    fn new(target_id: u64) -> Self {
+        Self {
+            target_id,
+            pending_messages: VecDeque::new(),
+            pending_chunk: Vec::new(),
+            dropped: false,
+        }
+    }
+
+    /// Queue a message, dropping the oldest one once `limit` messages are queued.
+    ///
+    /// Events are dropped before command replies, since clients are usually waiting on those.
+    fn push_message(&mut self, message: String, limit: usize) {
+        if limit == 0 {
+            self.dropped = true;
+            return;
+        }
+
+        if self.pending_messages.len() >= limit {
+            let index = self.pending_messages.iter().position(|message| message.event);
+            self.pending_messages.remove(index.unwrap_or(0));
+            self.dropped = true;
+        }
+
+        let event = is_event(&message);
+        self.pending_messages.push_back(QueuedMessage { payload: message, event });
+    }
+}
+
+/// Check if a protocol message is an event, rather than a reply to a command.
+fn is_event(message: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(message)
+        .is_ok_and(|message| message.get("id").is_none())
 }
 
 #[derive(Default)]
@@ -129,6 +211,8 @@ struct RemoteInspectorState {
     listing_generation: u64,
     targets: Vec<InspectorTarget>,
     sessions: HashMap<String, InspectorSessionState>,
+    /// Maximum number of messages queued per session.
+    queue_limit: usize,
 }
 
 pub struct RemoteInspectorClient {
@@ -142,11 +226,12 @@ struct RemoteInspectorInner {
     sender: String,
     state: Mutex<RemoteInspectorState>,
     listing_cv: Condvar,
+    message_signal: Arc<InspectorMessageSignal>,
     connection_seq: AtomicU64,
 }
 
 impl RemoteInspectorClient {
-    pub fn connect() -> Result<Self, InspectorError> {
+    pub fn connect(queue_limit: usize) -> Result<Self, InspectorError> {
         let queue_label =
             CString::new("tabor.remote_inspector").map_err(|_| InspectorError::invalid("Bad label"))?;
         let queue = unsafe { xpc::dispatch_queue_create(queue_label.as_ptr(), std::ptr::null()) };
@@ -183,8 +268,9 @@ impl RemoteInspectorClient {
                 queue,
                 handler: handler_ptr,
                 sender,
-                state: Mutex::new(RemoteInspectorState::default()),
+                state: Mutex::new(RemoteInspectorState { queue_limit, ..Default::default() }),
                 listing_cv: Condvar::new(),
+                message_signal: Default::default(),
                 connection_seq: AtomicU64::new(1),
             }
         });
@@ -227,14 +313,7 @@ impl RemoteInspectorClient {
         if let Some(error) = state.connection_error.clone() {
             return Err(InspectorError::ConnectionFailed(error));
         }
-        state.sessions.insert(
-            session_id.clone(),
-            InspectorSessionState {
-                target_id,
-                pending_messages: VecDeque::new(),
-                pending_chunk: Vec::new(),
-            },
-        );
+        state.sessions.insert(session_id.clone(), InspectorSessionState::new(target_id));
         drop(state);
 
         self.send_socket_setup(target_id, &session_id)?;
@@ -271,7 +350,7 @@ impl RemoteInspectorClient {
         &self,
         session_id: &str,
         max: Option<usize>,
-    ) -> Result<Vec<InspectorMessage>, InspectorError> {
+    ) -> Result<InspectorPoll, InspectorError> {
         let mut state = self.inner.state.lock();
         let Some(session) = state.sessions.get_mut(session_id) else {
             return Err(InspectorError::not_found("Inspector session not found"));
@@ -280,16 +359,17 @@ impl RemoteInspectorClient {
         let take = max.unwrap_or(session.pending_messages.len());
         let mut messages = Vec::with_capacity(take);
         for _ in 0..take {
-            let Some(payload) = session.pending_messages.pop_front() else {
+            let Some(message) = session.pending_messages.pop_front() else {
                 break;
             };
             messages.push(InspectorMessage {
                 session_id: session_id.to_string(),
-                payload,
+                payload: message.payload,
             });
         }
 
-        Ok(messages)
+        let dropped = std::mem::take(&mut session.dropped);
+        Ok(InspectorPoll { messages, dropped })
     }
 
     pub fn has_session(&self, session_id: &str) -> bool {
@@ -297,6 +377,15 @@ impl RemoteInspectorClient {
         state.sessions.contains_key(session_id)
     }
 
+    /// Limit the number of messages queued per session until they are polled.
+    pub fn set_queue_limit(&self, limit: usize) {
+        self.inner.state.lock().queue_limit = limit;
+    }
+
+    pub fn message_signal(&self) -> Arc<InspectorMessageSignal> {
+        self.inner.message_signal.clone()
+    }
+
     fn next_connection_id(&self) -> u64 {
         self.inner.connection_seq.fetch_add(1, Ordering::Relaxed)
     }
@@ -416,26 +505,28 @@ impl RemoteInspectorInner {
             MessageDataType::parse(xpc::dictionary_string(message, WIR_MESSAGE_DATA_TYPE_KEY), xpc::dictionary_uint64(message, WIR_MESSAGE_DATA_TYPE_KEY));
 
         let mut state = self.state.lock();
+        let queue_limit = state.queue_limit;
         let Some(session) = state.sessions.get_mut(&session_id) else {
             return;
         };
 
-        match data_type {
-            MessageDataType::Full => {
-                if let Ok(text) = String::from_utf8(payload) {
-                    session.pending_messages.push_back(text);
-                }
-            },
+        let message = match data_type {
+            MessageDataType::Full => String::from_utf8(payload).ok(),
             MessageDataType::Chunk => {
                 session.pending_chunk.extend_from_slice(&payload);
+                None
             },
             MessageDataType::FinalChunk => {
                 session.pending_chunk.extend_from_slice(&payload);
                 let chunk = std::mem::take(&mut session.pending_chunk);
-                if let Ok(text) = String::from_utf8(chunk) {
-                    session.pending_messages.push_back(text);
-                }
+                String::from_utf8(chunk).ok()
             },
+        };
+
+        if let Some(message) = message {
+            session.push_message(message, queue_limit);
+            drop(state);
+            self.message_signal.notify();
         }
     }
 }
@@ -845,14 +936,7 @@ mod tests {
     #[test]
     fn message_chunks_are_reassembled() {
         let mut state = RemoteInspectorState::default();
-        state.sessions.insert(
-            String::from("sess-1"),
-            InspectorSessionState {
-                target_id: 10,
-                pending_messages: VecDeque::new(),
-                pending_chunk: Vec::new(),
-            },
-        );
+        state.sessions.insert(String::from("sess-1"), InspectorSessionState::new(10));
 
         {
             let session = state.sessions.get_mut("sess-1").unwrap();
@@ -862,14 +946,37 @@ mod tests {
             let session = state.sessions.get_mut("sess-1").unwrap();
             session.pending_chunk.extend_from_slice(b"\"result\":{}}");
             let payload = std::mem::take(&mut session.pending_chunk);
-            session
-                .pending_messages
-                .push_back(String::from_utf8(payload).expect("utf8"));
+            session.push_message(String::from_utf8(payload).expect("utf8"), 10);
         }
 
         let session = state.sessions.get_mut("sess-1").unwrap();
-        let payload = session.pending_messages.pop_front().unwrap();
-        assert_eq!(payload, "{\"id\":1,\"result\":{}}");
+        let message = session.pending_messages.pop_front().unwrap();
+        assert_eq!(message.payload, "{\"id\":1,\"result\":{}}");
+        assert!(!message.event);
+    }
+
+    #[test]
+    fn full_queue_drops_events_first() {
+        let mut session = InspectorSessionState::new(10);
+        session.push_message(String::from(r#"{"id":1,"result":{}}"#), 2);
+        session.push_message(String::from(r#"{"method":"Page.loadEventFired"}"#), 2);
+        assert!(!session.dropped);
+
+        session.push_message(String::from(r#"{"method":"Page.frameNavigated"}"#), 2);
+        assert!(session.dropped);
+        let payloads: Vec<_> =
+            session.pending_messages.iter().map(|message| message.payload.as_str()).collect();
+        assert_eq!(payloads, [r#"{"id":1,"result":{}}"#, r#"{"method":"Page.frameNavigated"}"#]);
+    }
+
+    #[test]
+    fn message_signal_wakes_on_new_messages() {
+        let signal = InspectorMessageSignal::default();
+        let generation = signal.generation();
+        assert!(!signal.wait(generation, Duration::from_millis(1)));
+
+        signal.notify();
+        assert!(signal.wait(generation, Duration::from_millis(1)));
     }
 
     #[test]
@@ -0,0 +1,189 @@
+use std::cell::RefCell;
+use std::fs;
+use std::path::PathBuf;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// File storing the settings, inside the data directory.
+const SITE_SETTINGS_FILE: &str = "site_settings.json";
+
+/// Maximum number of sites remembered, the least recently used ones are forgotten first.
+const MAX_SITES: usize = 500;
+
+thread_local! {
+    static SITE_SETTINGS: RefCell<Option<SiteSettingsStore>> = const { RefCell::new(None) };
+}
+
+/// Settings remembered for a single host.
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
+pub struct SiteSettings {
+    /// Page zoom factor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zoom: Option<f64>,
+
+    /// User agent overriding `web.user_agent`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+}
+
+impl SiteSettings {
+    fn is_empty(&self) -> bool {
+        self.zoom.is_none() && self.user_agent.is_none()
+    }
+}
+
+/// Site settings by host, ordered from least to most recently used.
+#[derive(Serialize, Deserialize, Default, Debug)]
+struct SiteSettingsStore {
+    sites: Vec<(String, SiteSettings)>,
+
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl SiteSettingsStore {
+    fn load() -> Self {
+        let path = xdg::BaseDirectories::with_prefix("tabor").place_data_file(SITE_SETTINGS_FILE);
+        let path = match path {
+            Ok(path) => path,
+            Err(err) => {
+                warn!("Unable to locate site settings: {err}");
+                return Self::default();
+            },
+        };
+
+        let mut store = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| match serde_json::from_str::<Self>(&json) {
+                Ok(store) => Some(store),
+                Err(err) => {
+                    warn!("Ignoring invalid site settings {}: {err}", path.display());
+                    None
+                },
+            })
+            .unwrap_or_default();
+        store.path = Some(path);
+        store
+    }
+
+    /// Get the settings of a host, marking it as recently used.
+    fn get(&mut self, host: &str) -> Option<SiteSettings> {
+        let index = self.sites.iter().position(|(site, _)| site == host)?;
+        let site = self.sites.remove(index);
+        let settings = site.1.clone();
+        self.sites.push(site);
+        Some(settings)
+    }
+
+    /// Modify the settings of a host, forgetting hosts without any settings left.
+    fn update(&mut self, host: &str, f: impl FnOnce(&mut SiteSettings)) {
+        let mut settings = match self.sites.iter().position(|(site, _)| site == host) {
+            Some(index) => self.sites.remove(index).1,
+            None => SiteSettings::default(),
+        };
+        f(&mut settings);
+
+        if !settings.is_empty() {
+            self.sites.push((host.to_owned(), settings));
+        }
+
+        let excess = self.sites.len().saturating_sub(MAX_SITES);
+        self.sites.drain(..excess);
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        let result = serde_json::to_string(self)
+            .map_err(|err| err.to_string())
+            .and_then(|json| fs::write(path, json).map_err(|err| err.to_string()));
+        if let Err(err) = result {
+            warn!("Unable to write site settings {}: {err}", path.display());
+        }
+    }
+}
+
+/// Host whose settings apply to a URL, only http(s) pages have site settings.
+pub fn site_host(url: &str) -> Option<String> {
+    let url = Url::parse(url).ok()?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    url.host_str().map(str::to_owned)
+}
+
+/// Check if `host` belongs to a site, like `www.example.com` does to `example.com`.
+pub fn host_in_site(host: &str, site: &str) -> bool {
+    host.strip_suffix(site)
+        .is_some_and(|subdomain| subdomain.is_empty() || subdomain.ends_with('.'))
+}
+
+/// Settings remembered for a host.
+pub fn site_settings(host: &str) -> SiteSettings {
+    with_store(|store| store.get(host).unwrap_or_default())
+}
+
+/// Modify and persist the settings of a host.
+pub fn update_site_settings(host: &str, f: impl FnOnce(&mut SiteSettings)) {
+    with_store(|store| {
+        store.update(host, f);
+        store.save();
+    })
+}
+
+fn with_store<T>(f: impl FnOnce(&mut SiteSettingsStore) -> T) -> T {
+    SITE_SETTINGS.with(|store| {
+        let mut store = store.borrow_mut();
+        f(store.get_or_insert_with(SiteSettingsStore::load))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hosts_of_web_pages() {
+        assert_eq!(site_host("https://docs.rs/url/latest"), Some(String::from("docs.rs")));
+        assert_eq!(site_host("http://localhost:8080/"), Some(String::from("localhost")));
+        assert_eq!(site_host("about:blank"), None);
+        assert_eq!(site_host("file:///tmp/index.html"), None);
+    }
+
+    #[test]
+    fn hosts_in_sites() {
+        assert!(host_in_site("example.com", "example.com"));
+        assert!(host_in_site("www.example.com", "example.com"));
+        assert!(!host_in_site("badexample.com", "example.com"));
+        assert!(!host_in_site("example.com", "www.example.com"));
+    }
+
+    #[test]
+    fn least_recently_used_sites_are_evicted() {
+        let mut store = SiteSettingsStore::default();
+        for index in 0..MAX_SITES {
+            store.update(&format!("{index}.example.com"), |settings| settings.zoom = Some(1.5));
+        }
+
+        // Using the oldest site keeps it around.
+        assert!(store.get("0.example.com").is_some());
+        store.update("new.example.com", |settings| settings.zoom = Some(2.));
+
+        assert_eq!(store.sites.len(), MAX_SITES);
+        assert!(store.get("0.example.com").is_some());
+        assert!(store.get("1.example.com").is_none());
+        assert_eq!(store.get("new.example.com").unwrap().zoom, Some(2.));
+    }
+
+    #[test]
+    fn empty_settings_are_forgotten() {
+        let mut store = SiteSettingsStore::default();
+        store.update("example.com", |settings| settings.zoom = Some(1.25));
+        store.update("example.com", |settings| settings.zoom = None);
+        assert!(store.sites.is_empty());
+    }
+}
@@ -1,9 +1,20 @@
-use std::collections::HashMap;
-use std::time::Instant;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Display, Formatter};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
 use winit::dpi::PhysicalPosition;
+use winit::keyboard::ModifiersState;
 use winit::window::CursorIcon;
 
-pub const WEB_SCROLL_STEP: f64 = 48.0;
+use crate::config::web::WebAction;
+use crate::web_url::WebNavigationTrigger;
+
+/// Minimum time between hints started automatically by page loads.
+pub(crate) const AUTO_HINTS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Maximum number of pages with a scroll offset remembered for back and forward navigation.
+const MAX_HISTORY_SCROLLS: usize = 50;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WebKey {
@@ -19,6 +30,38 @@ pub enum WebKey {
     Other,
 }
 
+/// Parse a key name like `Escape` or `ArrowUp`, or a single character, into a key and its text.
+pub fn parse_web_key(name: &str) -> Option<(WebKey, String)> {
+    let key = match name.to_ascii_lowercase().as_str() {
+        "escape" | "esc" => WebKey::Escape,
+        "enter" | "return" => WebKey::Enter,
+        "backspace" => WebKey::Backspace,
+        "delete" => WebKey::Delete,
+        "tab" => WebKey::Tab,
+        "arrowleft" | "left" => WebKey::ArrowLeft,
+        "arrowright" | "right" => WebKey::ArrowRight,
+        "arrowup" | "up" => WebKey::ArrowUp,
+        "arrowdown" | "down" => WebKey::ArrowDown,
+        "space" => return Some((WebKey::Other, String::from(" "))),
+        _ => return single_char(name).map(|ch| (WebKey::Other, ch.to_string())),
+    };
+    Some((key, String::new()))
+}
+
+/// Parse modifier names like `shift` or `control` into their modifier state.
+pub fn parse_web_modifiers(names: &[String]) -> Option<ModifiersState> {
+    names.iter().try_fold(ModifiersState::empty(), |mods, name| {
+        let modifier = match name.to_ascii_lowercase().as_str() {
+            "shift" => ModifiersState::SHIFT,
+            "control" | "ctrl" => ModifiersState::CONTROL,
+            "alt" | "option" => ModifiersState::ALT,
+            "super" | "command" | "cmd" => ModifiersState::SUPER,
+            _ => return None,
+        };
+        Some(mods | modifier)
+    })
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum WebMode {
     Normal,
@@ -28,6 +71,8 @@ pub(crate) enum WebMode {
     Hint,
     MarkSet,
     MarkJump,
+    /// All keys are passed on to the page.
+    Passthrough,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -35,6 +80,102 @@ pub enum WebHintAction {
     Open,
     OpenNewTab,
     CopyLink,
+    Inspect,
+    /// Scroll to a heading picked from the table of contents.
+    Heading,
+}
+
+/// Element picked by an inspect hint.
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WebElementInfo {
+    pub tag: String,
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub classes: Vec<String>,
+    /// CSS selector matching the element, anchored at the closest ancestor with an id.
+    pub selector: String,
+}
+
+impl WebElementInfo {
+    pub fn parse(description: &str) -> Option<Self> {
+        serde_json::from_str(description).ok()
+    }
+}
+
+impl Display for WebElementInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.tag)?;
+        if !self.id.is_empty() {
+            write!(f, "#{}", self.id)?;
+        }
+        for class in &self.classes {
+            write!(f, ".{class}")?;
+        }
+        write!(f, "  {}", self.selector)
+    }
+}
+
+/// Form control collected by a form dump.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct WebFormField {
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub value: String,
+}
+
+impl WebFormField {
+    /// Parse the form controls of a page, described as a JSON array.
+    pub fn parse_all(description: &str) -> Option<Vec<Self>> {
+        serde_json::from_str(description).ok()
+    }
+}
+
+impl Display for WebFormField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = if self.name.is_empty() { "(unnamed)" } else { &self.name };
+        write!(f, "{name} [{}] = {:?}", self.kind, self.value)
+    }
+}
+
+/// Connection security of a web page.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WebSecurityInfo {
+    pub host: Option<String>,
+    /// Page was loaded over HTTPS.
+    pub https: bool,
+    /// All resources of the page were loaded over secure connections.
+    pub only_secure_content: bool,
+    /// Subject summaries of the server certificate chain, leaf first.
+    pub certificates: Vec<String>,
+}
+
+impl Display for WebSecurityInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let host = self.host.as_deref().unwrap_or("(no host)");
+        if !self.https {
+            return write!(f, "Insecure connection to {host}: not using HTTPS");
+        }
+
+        if self.only_secure_content {
+            write!(f, "Secure connection to {host}")?;
+        } else {
+            write!(f, "Insecure connection to {host}: page has mixed content")?;
+        }
+
+        if let Some(certificate) = self.certificates.first() {
+            write!(f, " (certificate {certificate}")?;
+            if let Some(issuer) = self.certificates.get(1) {
+                write!(f, ", issued by {issuer}")?;
+            }
+            write!(f, ")")?;
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -43,14 +184,6 @@ struct WebHintState {
     keys: String,
 }
 
-#[derive(Default)]
-struct WebPending {
-    g: bool,
-    z: bool,
-    y: bool,
-    bracket: Option<char>,
-}
-
 #[derive(Clone, Debug)]
 struct WebMark {
     url: String,
@@ -67,27 +200,50 @@ struct WebPendingScroll {
 
 pub struct WebCommandState {
     mode: WebMode,
-    pending: WebPending,
+    /// First key of a two key binding.
+    pending: Option<char>,
     hint: Option<WebHintState>,
     last_find: Option<String>,
     last_find_backward: bool,
     marks: HashMap<char, WebMark>,
+    last_url: Option<String>,
     pending_scroll: Option<WebPendingScroll>,
+    /// Scroll offsets of pages navigated away from, least recently visited first.
+    history_scrolls: VecDeque<WebPendingScroll>,
     help_visible: bool,
     cursor_pending: bool,
     last_cursor: Option<CursorIcon>,
     last_cursor_pos: Option<PhysicalPosition<f64>>,
     cursor_bootstrapped: bool,
     last_cursor_request: Option<Instant>,
+    /// Link under the mouse cursor shown in the message bar.
+    hover_link: Option<String>,
+    smooth_scroll: Option<(f64, f64)>,
+    images_blocked: bool,
+    /// Scripts of pages were disabled for this tab.
+    javascript_disabled: bool,
+    /// Dark mode was toggled on for this tab.
+    dark_mode: bool,
+    /// Focus mode was toggled on for this tab.
+    focus_mode: bool,
+    submit_armed: bool,
+    /// Resetting the view again forgets the zoom of the site.
+    reset_view_armed: bool,
+    /// Escape was the last key pressed in passthrough mode.
+    passthrough_escape: bool,
+    last_auto_hints: Option<Instant>,
 }
 
 impl WebCommandState {
     fn reset_pending(&mut self) {
-        self.pending = WebPending::default();
+        self.pending = None;
     }
 
     fn set_mode(&mut self, mode: WebMode) {
         self.mode = mode;
+        self.submit_armed = false;
+        self.reset_view_armed = false;
+        self.passthrough_escape = false;
         if mode != WebMode::Hint {
             self.hint = None;
         }
@@ -109,13 +265,25 @@ impl WebCommandState {
             WebMode::Hint => "HINT",
             WebMode::MarkSet => "MARK SET",
             WebMode::MarkJump => "MARK JUMP",
+            WebMode::Passthrough => "PASSTHROUGH",
         }
     }
 
+    pub(crate) fn is_passthrough(&self) -> bool {
+        self.mode == WebMode::Passthrough
+    }
+
     pub(crate) fn set_mark(&mut self, name: char, url: String, scroll_x: f64, scroll_y: f64) {
         self.marks.insert(name, WebMark { url, scroll_x, scroll_y });
     }
 
+    /// Remember the URL that was visited before navigating away from it.
+    pub(crate) fn set_last_url(&mut self, url: String) {
+        if !url.is_empty() {
+            self.last_url = Some(url);
+        }
+    }
+
     pub(crate) fn take_pending_scroll(&mut self, url: &str) -> Option<(f64, f64)> {
         let pending = self.pending_scroll.take()?;
         if pending.url == url {
@@ -126,6 +294,23 @@ impl WebCommandState {
         }
     }
 
+    /// Remember the scroll offset of a page which is navigated away from.
+    pub(crate) fn remember_scroll(&mut self, url: String, scroll_x: f64, scroll_y: f64) {
+        self.history_scrolls.retain(|scroll| scroll.url != url);
+        if self.history_scrolls.len() >= MAX_HISTORY_SCROLLS {
+            self.history_scrolls.pop_front();
+        }
+        self.history_scrolls.push_back(WebPendingScroll { url, scroll_x, scroll_y });
+    }
+
+    /// Scroll to the offset remembered for `url` once it's loaded by going back or forward.
+    pub(crate) fn restore_history_scroll(&mut self, url: &str) {
+        let Some(index) = self.history_scrolls.iter().position(|scroll| scroll.url == url) else {
+            return;
+        };
+        self.pending_scroll = self.history_scrolls.remove(index);
+    }
+
     pub(crate) fn cursor_pending(&self) -> bool {
         self.cursor_pending
     }
@@ -169,43 +354,132 @@ impl WebCommandState {
     pub(crate) fn clear_last_cursor_request(&mut self) {
         self.last_cursor_request = None;
     }
+
+    pub(crate) fn hover_link(&self) -> Option<&str> {
+        self.hover_link.as_deref()
+    }
+
+    pub(crate) fn set_hover_link(&mut self, url: Option<String>) {
+        self.hover_link = url;
+    }
+
+    /// Add to the distance of the next smooth scroll.
+    pub(crate) fn add_smooth_scroll(&mut self, dx: f64, dy: f64) {
+        let (x, y) = self.smooth_scroll.get_or_insert((0., 0.));
+        *x += dx;
+        *y += dy;
+    }
+
+    pub(crate) fn take_smooth_scroll(&mut self) -> Option<(f64, f64)> {
+        self.smooth_scroll.take()
+    }
+
+    /// Toggles of the tab which differ from their defaults, listed by the help.
+    pub(crate) fn tab_settings(&self) -> Vec<&'static str> {
+        let settings = [
+            (self.javascript_disabled, "JavaScript disabled"),
+            (self.images_blocked, "images blocked"),
+            (self.dark_mode, "dark mode"),
+            (self.focus_mode, "focus mode"),
+        ];
+        settings.into_iter().filter(|(enabled, _)| *enabled).map(|(_, name)| name).collect()
+    }
+
+    /// Submit the form on the next Enter in insert mode, unless another key is pressed first.
+    pub(crate) fn arm_form_submit(&mut self) {
+        if self.mode == WebMode::Insert {
+            self.submit_armed = true;
+        }
+    }
+
+    /// Dark mode was toggled on for this tab.
+    pub(crate) fn dark_mode(&self) -> bool {
+        self.dark_mode
+    }
+
+    /// Enter hint mode for opening links after a page was loaded.
+    ///
+    /// Returns `false` outside of normal mode, or when hints were already started automatically
+    /// less than [`AUTO_HINTS_INTERVAL`] ago.
+    pub(crate) fn start_auto_hints(&mut self, now: Instant) -> bool {
+        let recent = self
+            .last_auto_hints
+            .is_some_and(|last| now.saturating_duration_since(last) < AUTO_HINTS_INTERVAL);
+        if self.mode != WebMode::Normal || recent {
+            return false;
+        }
+
+        self.last_auto_hints = Some(now);
+        self.set_mode(WebMode::Hint);
+        self.hint = Some(WebHintState { action: WebHintAction::Open, keys: String::new() });
+        true
+    }
 }
 
 impl Default for WebCommandState {
     fn default() -> Self {
         Self {
             mode: WebMode::Normal,
-            pending: WebPending::default(),
+            pending: None,
             hint: None,
             last_find: None,
             last_find_backward: false,
             marks: HashMap::default(),
+            last_url: None,
             pending_scroll: None,
+            history_scrolls: VecDeque::new(),
             help_visible: false,
             cursor_pending: false,
             last_cursor: None,
             last_cursor_pos: None,
             cursor_bootstrapped: false,
             last_cursor_request: None,
+            hover_link: None,
+            smooth_scroll: None,
+            images_blocked: false,
+            javascript_disabled: false,
+            dark_mode: false,
+            focus_mode: false,
+            submit_armed: false,
+            reset_view_armed: false,
+            passthrough_escape: false,
+            last_auto_hints: None,
         }
     }
 }
 
+/// Actions of the normal mode commands on a web tab.
+///
+/// Actions updating the [`WebCommandState`] of the tab receive it explicitly, since it's borrowed
+/// by the command while it runs.
 pub trait WebActions {
-    fn scroll_by(&mut self, dx: f64, dy: f64);
-    fn scroll_half_page(&mut self, down: bool);
-    fn scroll_top(&mut self);
-    fn scroll_bottom(&mut self);
-    fn scroll_far_left(&mut self);
-    fn scroll_far_right(&mut self);
+    /// Configured normal mode bindings, overriding the defaults.
+    fn bindings(&self) -> &HashMap<String, WebAction>;
+
+    /// Distance scrolled by each `hjkl` key press.
+    fn scroll_step(&self) -> f64;
+    /// Whether scrolling is animated.
+    fn smooth_scroll(&self) -> bool;
+    fn scroll_by(&mut self, state: &WebCommandState, dx: f64, dy: f64);
+    /// Animate the scrolling accumulated in the state once key repeats settle.
+    fn schedule_smooth_scroll(&mut self);
+    fn scroll_half_page(&mut self, state: &WebCommandState, down: bool);
+    fn scroll_top(&mut self, state: &WebCommandState);
+    fn scroll_bottom(&mut self, state: &WebCommandState);
+    fn scroll_far_left(&mut self, state: &WebCommandState);
+    fn scroll_far_right(&mut self, state: &WebCommandState);
     fn scroll_to(&mut self, x: f64, y: f64);
 
+    /// URLs of the previous and next pages in the history of the tab.
+    fn back_url(&self) -> Option<String>;
+    fn forward_url(&self) -> Option<String>;
     fn go_back(&mut self);
     fn go_forward(&mut self);
 
     fn open_command_bar(&mut self, input: &str);
     fn start_find_prompt(&mut self);
     fn find(&mut self, query: &str, backwards: bool);
+    fn clear_find(&mut self);
 
     fn hints_start(&mut self, action: WebHintAction);
     fn hints_update(&mut self, keys: &str, action: WebHintAction);
@@ -217,20 +491,37 @@ pub trait WebActions {
     fn visual_move(&mut self, direction: &str, granularity: &str);
 
     fn focus_input(&mut self);
+    /// Focus the form field after the focused one, or before it when going `backwards`.
+    fn focus_next_input(&mut self, backwards: bool);
     fn blur_active_element(&mut self);
 
     fn insert_text(&mut self, text: &str);
     fn delete_backward(&mut self);
     fn delete_forward(&mut self);
-    fn insert_paragraph(&mut self);
+    /// Insert a line break, `confirmed` being set by the Enter following a submit confirmation.
+    fn insert_paragraph(&mut self, confirmed: bool);
     fn insert_tab(&mut self);
     fn caret_move(&mut self, direction: &str, granularity: &str);
 
-    fn view_source(&mut self);
+    fn view_source(&mut self, state: &mut WebCommandState);
+    fn open_console(&mut self);
+    fn open_in_terminal(&mut self);
+    fn show_security_info(&mut self);
     fn follow_rel(&mut self, rel: &str);
     fn copy_url(&mut self);
-    fn open_clipboard(&mut self, new_tab: bool);
-    fn up_url(&mut self, root: bool);
+    fn copy_title(&mut self);
+    fn copy_all_links(&mut self, same_origin: bool);
+    fn dump_form(&mut self);
+    /// Block or allow loading images, returning `false` if it could not be changed.
+    fn block_images(&mut self, blocked: bool) -> bool;
+    /// Allow or disallow the scripts of pages, returning `false` if it could not be changed.
+    fn set_javascript_enabled(&mut self, enabled: bool) -> bool;
+    /// Force or stop forcing a dark page, returning `false` if it could not be changed.
+    fn set_dark_mode(&mut self, enabled: bool) -> bool;
+    /// Unpin fixed elements and hide banners, returning `false` if it could not be changed.
+    fn set_focus_mode(&mut self, enabled: bool) -> bool;
+    fn open_clipboard(&mut self, state: &mut WebCommandState, new_tab: bool);
+    fn up_url(&mut self, state: &mut WebCommandState, root: bool);
 
     fn new_tab(&mut self);
     fn close_tab(&mut self);
@@ -239,14 +530,19 @@ pub trait WebActions {
     fn select_next_tab(&mut self);
     fn select_tab_at_index(&mut self, index: usize);
     fn select_last_tab(&mut self);
-    fn reload(&mut self);
-
-    fn show_help(&mut self);
+    fn reload(&mut self, state: &mut WebCommandState);
+    /// Reset the page zoom, forgetting the zoom of the site only once `confirmed`.
+    ///
+    /// Returns `false` when the zoom of the site was kept.
+    fn reset_zoom(&mut self, confirmed: bool) -> bool;
+
+    /// Show the help, along with the toggles changed for the tab.
+    fn show_help(&mut self, settings: &[&str]);
     fn hide_help(&mut self);
 
     fn request_mark_set(&mut self, name: char, url: String);
     fn current_url(&mut self) -> Option<String>;
-    fn open_url(&mut self, url: String);
+    fn open_url(&mut self, state: &mut WebCommandState, url: String, trigger: WebNavigationTrigger);
     fn push_error(&mut self, message: String);
 }
 
@@ -256,6 +552,10 @@ pub fn handle_key(
     key: WebKey,
     text: &str,
 ) -> bool {
+    if state.mode == WebMode::Passthrough {
+        return handle_passthrough(state, key);
+    }
+
     if matches!(key, WebKey::Escape) {
         handle_escape(state, actions);
         return true;
@@ -267,249 +567,243 @@ pub fn handle_key(
         WebMode::MarkSet => return handle_mark_set(state, actions, text),
         WebMode::MarkJump => return handle_mark_jump(state, actions, text),
         WebMode::Visual | WebMode::VisualLine => return handle_visual(state, actions, text),
-        WebMode::Normal => (),
+        WebMode::Passthrough | WebMode::Normal => (),
     }
 
-    let mut chars = text.chars();
-    let Some(ch) = chars.next() else {
+    let Some(ch) = single_char(text) else {
         return false;
     };
-    if chars.next().is_some() {
-        return false;
-    }
 
-    let mut retry = true;
-    while retry {
-        retry = false;
-
-        if let Some(bracket) = state.pending.bracket {
-            state.pending.bracket = None;
-            if bracket == ch {
-                match bracket {
-                    '[' => {
-                        actions.follow_rel("prev");
-                        return true;
-                    },
-                    ']' => {
-                        actions.follow_rel("next");
-                        return true;
-                    },
-                    _ => (),
-                }
-            } else {
-                retry = true;
-                continue;
-            }
+    // Complete a pending prefix, or handle the key on its own without a matching binding.
+    if let Some(prefix) = state.pending.take() {
+        if let Some(action) = web_binding(actions, &format!("{prefix}{ch}")) {
+            perform_action(state, actions, action);
+            return true;
         }
+    }
 
-        if state.pending.g {
-            state.pending.g = false;
-            match ch {
-                'g' => {
-                    actions.scroll_top();
-                    return true;
-                },
-                '0' => {
-                    actions.select_tab_at_index(0);
-                    return true;
-                },
-                '$' => {
-                    actions.select_last_tab();
-                    return true;
-                },
-                'u' => {
-                    actions.up_url(false);
-                    return true;
-                },
-                'U' => {
-                    actions.up_url(true);
-                    return true;
-                },
-                's' => {
-                    actions.view_source();
-                    return true;
-                },
-                'i' => {
-                    actions.focus_input();
-                    state.set_mode(WebMode::Insert);
-                    return true;
-                },
-                _ => {
-                    retry = true;
-                    continue;
-                },
-            }
-        }
+    match web_binding(actions, &ch.to_string()) {
+        Some(action) => perform_action(state, actions, action),
+        None if is_binding_prefix(actions, ch) => state.pending = Some(ch),
+        None => (),
+    }
 
-        if state.pending.z {
-            state.pending.z = false;
-            match ch {
-                'H' | 'h' => {
-                    actions.scroll_far_left();
-                    return true;
-                },
-                'L' | 'l' => {
-                    actions.scroll_far_right();
-                    return true;
-                },
-                _ => {
-                    retry = true;
-                    continue;
-                },
-            }
-        }
+    true
+}
 
-        if state.pending.y {
-            state.pending.y = false;
-            match ch {
-                'y' => {
-                    actions.copy_url();
-                    return true;
-                },
-                'f' => {
-                    start_hints(state, actions, WebHintAction::CopyLink);
-                    return true;
-                },
-                _ => {
-                    retry = true;
-                    continue;
-                },
-            }
-        }
-    }
+/// Action bound to a key sequence, with configured bindings taking precedence over the defaults.
+fn web_binding(actions: &impl WebActions, keys: &str) -> Option<WebAction> {
+    actions.bindings().get(keys).copied().or_else(|| default_binding(keys))
+}
 
-    match ch {
-        'j' => actions.scroll_by(0.0, WEB_SCROLL_STEP),
-        'k' => actions.scroll_by(0.0, -WEB_SCROLL_STEP),
-        'h' => actions.scroll_by(-WEB_SCROLL_STEP, 0.0),
-        'l' => actions.scroll_by(WEB_SCROLL_STEP, 0.0),
-        'd' => actions.scroll_half_page(true),
-        'u' => actions.scroll_half_page(false),
-        'G' => actions.scroll_bottom(),
-        'g' => {
-            state.pending.g = true;
-            return true;
-        },
-        'z' => {
-            state.pending.z = true;
-            return true;
-        },
-        '[' => {
-            state.pending.bracket = Some('[');
-            return true;
-        },
-        ']' => {
-            state.pending.bracket = Some(']');
-            return true;
-        },
-        'f' => {
-            start_hints(state, actions, WebHintAction::Open);
-            return true;
-        },
-        'F' => {
-            start_hints(state, actions, WebHintAction::OpenNewTab);
-            return true;
-        },
-        'y' => {
-            state.pending.y = true;
-            return true;
-        },
-        'H' => {
-            actions.go_back();
-            return true;
-        },
-        'L' => {
-            actions.go_forward();
-            return true;
-        },
-        '/' => {
-            actions.start_find_prompt();
-            return true;
-        },
-        'n' => {
-            find_next(state, actions, false);
-            return true;
-        },
-        'N' => {
-            find_next(state, actions, true);
-            return true;
-        },
-        'v' => {
-            toggle_visual(state, actions, false);
-            return true;
-        },
-        'V' => {
-            toggle_visual(state, actions, true);
-            return true;
-        },
-        'p' => {
-            actions.open_clipboard(false);
-            return true;
-        },
-        'P' => {
-            actions.open_clipboard(true);
-            return true;
-        },
-        't' => {
-            actions.new_tab();
-            return true;
-        },
-        'x' => {
-            actions.close_tab();
-            return true;
-        },
-        'X' => {
-            actions.restore_tab();
-            return true;
-        },
-        'J' => {
-            actions.select_previous_tab();
-            return true;
-        },
-        'K' => {
-            actions.select_next_tab();
-            return true;
-        },
-        'o' => {
-            actions.open_command_bar("o ");
-            return true;
-        },
-        'O' => {
-            actions.open_command_bar("O ");
-            return true;
-        },
-        'b' => {
-            actions.open_command_bar("b ");
-            return true;
-        },
-        'B' => {
-            actions.open_command_bar("B ");
-            return true;
-        },
-        'T' => {
-            actions.open_command_bar("T ");
-            return true;
-        },
-        'r' => {
-            actions.reload();
-            return true;
-        },
-        'm' => {
-            state.set_mode(WebMode::MarkSet);
-            return true;
-        },
-        '`' => {
-            state.set_mode(WebMode::MarkJump);
-            return true;
+/// Check if a key starts any two key binding.
+fn is_binding_prefix(actions: &impl WebActions, ch: char) -> bool {
+    DEFAULT_PREFIXES.contains(&ch)
+        || actions.bindings().keys().any(|keys| {
+            let mut chars = keys.chars();
+            chars.next() == Some(ch) && chars.next().is_some()
+        })
+}
+
+/// Keys starting a two key default binding.
+const DEFAULT_PREFIXES: [char; 5] = ['g', 'z', 'y', '[', ']'];
+
+/// Default normal mode bindings.
+const DEFAULT_BINDINGS: [(&str, WebAction); 63] = [
+    ("j", WebAction::ScrollDown),
+    ("k", WebAction::ScrollUp),
+    ("h", WebAction::ScrollLeft),
+    ("l", WebAction::ScrollRight),
+    ("d", WebAction::ScrollHalfPageDown),
+    ("u", WebAction::ScrollHalfPageUp),
+    ("gg", WebAction::ScrollToTop),
+    ("G", WebAction::ScrollToBottom),
+    ("zh", WebAction::ScrollFarLeft),
+    ("zH", WebAction::ScrollFarLeft),
+    ("zl", WebAction::ScrollFarRight),
+    ("zL", WebAction::ScrollFarRight),
+    ("H", WebAction::Back),
+    ("L", WebAction::Forward),
+    ("f", WebAction::HintOpen),
+    ("F", WebAction::HintOpenNewTab),
+    ("yf", WebAction::HintCopyLink),
+    ("yi", WebAction::HintInspect),
+    ("yy", WebAction::CopyUrl),
+    ("yt", WebAction::CopyTitle),
+    ("yL", WebAction::CopyLinks),
+    ("gf", WebAction::DumpForm),
+    ("gi", WebAction::FocusInput),
+    ("gj", WebAction::FocusNextInput),
+    ("gk", WebAction::FocusPreviousInput),
+    ("/", WebAction::Find),
+    ("n", WebAction::FindNext),
+    ("N", WebAction::FindPrevious),
+    ("v", WebAction::ToggleVisual),
+    ("V", WebAction::ToggleVisualLine),
+    ("p", WebAction::OpenClipboard),
+    ("P", WebAction::OpenClipboardNewTab),
+    ("gu", WebAction::UpUrl),
+    ("gU", WebAction::RootUrl),
+    ("[[", WebAction::PreviousPage),
+    ("]]", WebAction::NextPage),
+    ("t", WebAction::NewTab),
+    ("x", WebAction::CloseTab),
+    ("X", WebAction::RestoreTab),
+    ("J", WebAction::PreviousTab),
+    ("K", WebAction::NextTab),
+    ("g0", WebAction::FirstTab),
+    ("g$", WebAction::LastTab),
+    ("o", WebAction::Omnibar),
+    ("O", WebAction::OmnibarNewTab),
+    ("b", WebAction::Bookmarks),
+    ("B", WebAction::BookmarksNewTab),
+    ("gO", WebAction::PrivateTab),
+    ("T", WebAction::TabSearch),
+    ("r", WebAction::Reload),
+    ("gz", WebAction::ResetView),
+    ("gs", WebAction::ViewSource),
+    ("gc", WebAction::Console),
+    ("gC", WebAction::OpenInTerminal),
+    ("gS", WebAction::SecurityInfo),
+    ("gI", WebAction::ToggleImages),
+    ("gJ", WebAction::ToggleJavaScript),
+    ("gd", WebAction::ToggleDarkMode),
+    ("gF", WebAction::ToggleFocusMode),
+    ("gp", WebAction::Passthrough),
+    ("m", WebAction::SetMark),
+    ("`", WebAction::JumpToMark),
+    ("?", WebAction::ToggleHelp),
+];
+
+/// Default normal mode binding of a key sequence.
+fn default_binding(keys: &str) -> Option<WebAction> {
+    DEFAULT_BINDINGS
+        .iter()
+        .find(|(default_keys, _)| *default_keys == keys)
+        .map(|(_, action)| *action)
+}
+
+/// All bound actions by key sequence, with configured bindings taking precedence over defaults.
+pub fn bound_actions(actions: &impl WebActions) -> Vec<(String, WebAction)> {
+    let bindings = actions.bindings();
+    let defaults = DEFAULT_BINDINGS
+        .iter()
+        .filter(|(keys, _)| !bindings.contains_key(*keys))
+        .map(|(keys, action)| ((*keys).to_owned(), *action));
+    let mut bound: Vec<_> = bindings
+        .iter()
+        .map(|(keys, action)| (keys.clone(), *action))
+        .chain(defaults)
+        .filter(|(_, action)| *action != WebAction::None)
+        .collect();
+    bound.sort_by(|a, b| a.0.cmp(&b.0));
+    bound
+}
+
+pub fn perform_action(
+    state: &mut WebCommandState,
+    actions: &mut impl WebActions,
+    action: WebAction,
+) {
+    let reset_view_confirmed = std::mem::take(&mut state.reset_view_armed);
+    match action {
+        WebAction::ScrollDown => scroll_by(state, actions, 0.0, actions.scroll_step()),
+        WebAction::ScrollUp => scroll_by(state, actions, 0.0, -actions.scroll_step()),
+        WebAction::ScrollLeft => scroll_by(state, actions, -actions.scroll_step(), 0.0),
+        WebAction::ScrollRight => scroll_by(state, actions, actions.scroll_step(), 0.0),
+        WebAction::ScrollHalfPageDown => actions.scroll_half_page(state, true),
+        WebAction::ScrollHalfPageUp => actions.scroll_half_page(state, false),
+        WebAction::ScrollToTop => actions.scroll_top(state),
+        WebAction::ScrollToBottom => actions.scroll_bottom(state),
+        WebAction::ScrollFarLeft => actions.scroll_far_left(state),
+        WebAction::ScrollFarRight => actions.scroll_far_right(state),
+        WebAction::Back => go_back(state, actions),
+        WebAction::Forward => go_forward(state, actions),
+        WebAction::HintOpen => start_hints(state, actions, WebHintAction::Open),
+        WebAction::HintOpenNewTab => start_hints(state, actions, WebHintAction::OpenNewTab),
+        WebAction::HintCopyLink => start_hints(state, actions, WebHintAction::CopyLink),
+        WebAction::HintInspect => start_hints(state, actions, WebHintAction::Inspect),
+        WebAction::HintHeading => start_hints(state, actions, WebHintAction::Heading),
+        WebAction::CopyUrl => actions.copy_url(),
+        WebAction::CopyTitle => actions.copy_title(),
+        WebAction::CopyLinks => actions.copy_all_links(false),
+        WebAction::DumpForm => actions.dump_form(),
+        WebAction::FocusInput => {
+            actions.focus_input();
+            state.set_mode(WebMode::Insert);
         },
-        '?' => {
-            toggle_help(state, actions);
-            return true;
+        WebAction::FocusNextInput => actions.focus_next_input(false),
+        WebAction::FocusPreviousInput => actions.focus_next_input(true),
+        WebAction::Find => actions.start_find_prompt(),
+        WebAction::FindNext => find_next(state, actions, false),
+        WebAction::FindPrevious => find_next(state, actions, true),
+        WebAction::ToggleVisual => toggle_visual(state, actions, false),
+        WebAction::ToggleVisualLine => toggle_visual(state, actions, true),
+        WebAction::OpenClipboard => actions.open_clipboard(state, false),
+        WebAction::OpenClipboardNewTab => actions.open_clipboard(state, true),
+        WebAction::UpUrl => actions.up_url(state, false),
+        WebAction::RootUrl => actions.up_url(state, true),
+        WebAction::PreviousPage => actions.follow_rel("prev"),
+        WebAction::NextPage => actions.follow_rel("next"),
+        WebAction::NewTab => actions.new_tab(),
+        WebAction::CloseTab => actions.close_tab(),
+        WebAction::RestoreTab => actions.restore_tab(),
+        WebAction::PreviousTab => actions.select_previous_tab(),
+        WebAction::NextTab => actions.select_next_tab(),
+        WebAction::FirstTab => actions.select_tab_at_index(0),
+        WebAction::LastTab => actions.select_last_tab(),
+        WebAction::Omnibar => actions.open_command_bar("o "),
+        WebAction::OmnibarNewTab => actions.open_command_bar("O "),
+        WebAction::Bookmarks => actions.open_command_bar("b "),
+        WebAction::BookmarksNewTab => actions.open_command_bar("B "),
+        WebAction::PrivateTab => actions.open_command_bar("private "),
+        WebAction::TabSearch => actions.open_command_bar("T "),
+        WebAction::Reload => actions.reload(state),
+        WebAction::ResetView => {
+            state.reset_view_armed = !actions.reset_zoom(reset_view_confirmed);
+            actions.scroll_to(0.0, 0.0);
         },
-        _ => (),
+        WebAction::ViewSource => actions.view_source(state),
+        WebAction::Console => actions.open_console(),
+        WebAction::OpenInTerminal => actions.open_in_terminal(),
+        WebAction::SecurityInfo => actions.show_security_info(),
+        WebAction::ToggleImages => toggle_images(state, actions),
+        WebAction::ToggleJavaScript => toggle_javascript(state, actions),
+        WebAction::ToggleDarkMode => toggle_dark_mode(state, actions),
+        WebAction::ToggleFocusMode => toggle_focus_mode(state, actions),
+        WebAction::Passthrough => state.set_mode(WebMode::Passthrough),
+        WebAction::SetMark => state.set_mode(WebMode::MarkSet),
+        WebAction::JumpToMark => state.set_mode(WebMode::MarkJump),
+        WebAction::ToggleHelp => toggle_help(state, actions),
+        WebAction::None => (),
+    }
+}
+
+fn scroll_by(state: &mut WebCommandState, actions: &mut impl WebActions, dx: f64, dy: f64) {
+    if !actions.smooth_scroll() {
+        actions.scroll_by(state, dx, dy);
+        return;
     }
 
-    true
+    // Coalesce key repeats, to avoid restarting the animation on every key press.
+    state.add_smooth_scroll(dx, dy);
+    actions.schedule_smooth_scroll();
+}
+
+/// Go back in history, scrolling to where the previous page was left once it's loaded.
+fn go_back(state: &mut WebCommandState, actions: &mut impl WebActions) {
+    if let Some(url) = actions.back_url() {
+        state.restore_history_scroll(&url);
+    }
+    actions.go_back();
+}
+
+/// Go forward in history, scrolling to where the next page was left once it's loaded.
+fn go_forward(state: &mut WebCommandState, actions: &mut impl WebActions) {
+    if let Some(url) = actions.forward_url() {
+        state.restore_history_scroll(&url);
+    }
+    actions.go_forward();
 }
 
 pub fn find(state: &mut WebCommandState, actions: &mut impl WebActions, query: &str, backwards: bool) {
@@ -537,18 +831,38 @@ fn handle_escape(state: &mut WebCommandState, actions: &mut impl WebActions) {
         WebMode::Hint => actions.hints_cancel(),
         WebMode::Visual | WebMode::VisualLine => actions.clear_selection(),
         WebMode::Insert => actions.blur_active_element(),
-        WebMode::Normal | WebMode::MarkSet | WebMode::MarkJump => (),
+        WebMode::Normal if state.last_find.is_some() => actions.clear_find(),
+        WebMode::Normal | WebMode::MarkSet | WebMode::MarkJump | WebMode::Passthrough => (),
     }
 
     state.set_mode(WebMode::Normal);
 }
 
+/// Pass keys on to the page, returning to normal mode when Escape is pressed twice in a row.
+///
+/// The first Escape still reaches the page, since web apps commonly use it on its own.
+fn handle_passthrough(state: &mut WebCommandState, key: WebKey) -> bool {
+    if key != WebKey::Escape {
+        state.passthrough_escape = false;
+        return false;
+    }
+
+    if state.passthrough_escape {
+        state.set_mode(WebMode::Normal);
+        return true;
+    }
+
+    state.passthrough_escape = true;
+    false
+}
+
 fn handle_insert(
     state: &mut WebCommandState,
     actions: &mut impl WebActions,
     key: WebKey,
     text: &str,
 ) -> bool {
+    let submit_confirmed = std::mem::take(&mut state.submit_armed);
     match key {
         WebKey::Escape => {
             handle_escape(state, actions);
@@ -563,7 +877,7 @@ fn handle_insert(
             return true;
         },
         WebKey::Enter => {
-            actions.insert_paragraph();
+            actions.insert_paragraph(submit_confirmed);
             return true;
         },
         WebKey::Tab => {
@@ -673,6 +987,11 @@ fn handle_mark_jump(
     };
     state.set_mode(WebMode::Normal);
 
+    if name == '`' {
+        web_jump_last(state, actions);
+        return true;
+    }
+
     let Some(mark) = state.marks.get(&name).cloned() else {
         actions.push_error(format!("Unknown mark: {name}"));
         return true;
@@ -686,12 +1005,20 @@ fn handle_mark_jump(
             scroll_x: mark.scroll_x,
             scroll_y: mark.scroll_y,
         });
-        actions.open_url(mark.url);
+        actions.open_url(state, mark.url, WebNavigationTrigger::Mark);
     }
 
     true
 }
 
+/// Swap back to the previously visited URL.
+fn web_jump_last(state: &mut WebCommandState, actions: &mut impl WebActions) {
+    match state.last_url.clone() {
+        Some(url) => actions.open_url(state, url, WebNavigationTrigger::LastUrl),
+        None => actions.push_error(String::from("No previous URL")),
+    }
+}
+
 fn handle_visual(
     state: &mut WebCommandState,
     actions: &mut impl WebActions,
@@ -732,6 +1059,34 @@ fn handle_visual(
     true
 }
 
+fn toggle_images(state: &mut WebCommandState, actions: &mut impl WebActions) {
+    let blocked = !state.images_blocked;
+    if actions.block_images(blocked) {
+        state.images_blocked = blocked;
+    }
+}
+
+fn toggle_javascript(state: &mut WebCommandState, actions: &mut impl WebActions) {
+    let disabled = !state.javascript_disabled;
+    if actions.set_javascript_enabled(!disabled) {
+        state.javascript_disabled = disabled;
+    }
+}
+
+fn toggle_dark_mode(state: &mut WebCommandState, actions: &mut impl WebActions) {
+    let enabled = !state.dark_mode;
+    if actions.set_dark_mode(enabled) {
+        state.dark_mode = enabled;
+    }
+}
+
+fn toggle_focus_mode(state: &mut WebCommandState, actions: &mut impl WebActions) {
+    let enabled = !state.focus_mode;
+    if actions.set_focus_mode(enabled) {
+        state.focus_mode = enabled;
+    }
+}
+
 fn toggle_visual(
     state: &mut WebCommandState,
     actions: &mut impl WebActions,
@@ -753,11 +1108,42 @@ fn toggle_help(state: &mut WebCommandState, actions: &mut impl WebActions) {
         actions.hide_help();
         state.help_visible = false;
     } else {
-        actions.show_help();
+        actions.show_help(&state.tab_settings());
         state.help_visible = true;
     }
 }
 
+/// Find the element scrolled by normal mode keys along the `x` or `y` axis.
+///
+/// This is the closest scrollable ancestor of the element at the point, or of the focused element
+/// without a point, falling back to the page itself.
+const WEB_SCROLL_TARGET: &str = r#"function(x, y, axis) {
+  const root = document.scrollingElement || document.documentElement;
+  const scrollable = (value) => value === "auto" || value === "scroll" || value === "overlay";
+  let el = x === null ? document.activeElement : document.elementFromPoint(x, y);
+  for (; el && el !== root && el !== document.body; el = el.parentElement) {
+    const style = window.getComputedStyle(el);
+    if (axis === "y" && scrollable(style.overflowY) && el.scrollHeight > el.clientHeight) {
+      return el;
+    }
+    if (axis === "x" && scrollable(style.overflowX) && el.scrollWidth > el.clientWidth) {
+      return el;
+    }
+  }
+  return root;
+}"#;
+
+/// Script expression evaluating to the element scrolled along the `x` or `y` axis.
+///
+/// Containers under `point`, the mouse cursor in page coordinates, are preferred over the focused
+/// element.
+pub(crate) fn scroll_target_script(point: Option<(f64, f64)>, axis: char) -> String {
+    match point {
+        Some((x, y)) => format!("({WEB_SCROLL_TARGET})({x}, {y}, \"{axis}\")"),
+        None => format!("({WEB_SCROLL_TARGET})(null, null, \"{axis}\")"),
+    }
+}
+
 fn single_char(text: &str) -> Option<char> {
     let mut chars = text.chars();
     let ch = chars.next()?;
@@ -774,6 +1160,7 @@ mod tests {
     #[derive(Debug, Clone, PartialEq)]
     enum ActionCall {
         ScrollBy(f64, f64),
+        ScheduleSmoothScroll,
         ScrollHalfPage(bool),
         ScrollTop,
         ScrollBottom,
@@ -785,6 +1172,7 @@ mod tests {
         OpenCommandBar(String),
         StartFindPrompt,
         Find(String, bool),
+        ClearFind,
         HintsStart(WebHintAction),
         HintsUpdate(String, WebHintAction),
         HintsCancel,
@@ -793,16 +1181,27 @@ mod tests {
         StartVisualSelection,
         VisualMove(String, String),
         FocusInput,
+        FocusNextInput(bool),
         BlurActiveElement,
         InsertText(String),
         DeleteBackward,
         DeleteForward,
-        InsertParagraph,
+        InsertParagraph(bool),
         InsertTab,
         CaretMove(String, String),
         ViewSource,
+        OpenConsole,
+        OpenInTerminal,
+        ShowSecurityInfo,
         FollowRel(String),
         CopyUrl,
+        CopyTitle,
+        CopyAllLinks(bool),
+        DumpForm,
+        BlockImages(bool),
+        JavaScript(bool),
+        DarkMode(bool),
+        FocusMode(bool),
         OpenClipboard(bool),
         UpUrl(bool),
         NewTab,
@@ -813,17 +1212,25 @@ mod tests {
         SelectTabAtIndex(usize),
         SelectLastTab,
         Reload,
-        ShowHelp,
+        ResetZoom(bool),
+        ShowHelp(Vec<String>),
         HideHelp,
         RequestMarkSet(char, String),
-        OpenUrl(String),
+        OpenUrl(String, WebNavigationTrigger),
         PushError(String),
     }
 
+    const WEB_SCROLL_STEP: f64 = 48.0;
+
     #[derive(Default)]
     struct MockActions {
         calls: Vec<ActionCall>,
         current_url: Option<String>,
+        back_url: Option<String>,
+        forward_url: Option<String>,
+        bindings: HashMap<String, WebAction>,
+        site_zoom: bool,
+        smooth_scroll: bool,
     }
 
     impl MockActions {
@@ -833,27 +1240,43 @@ mod tests {
     }
 
     impl WebActions for MockActions {
-        fn scroll_by(&mut self, dx: f64, dy: f64) {
+        fn bindings(&self) -> &HashMap<String, WebAction> {
+            &self.bindings
+        }
+
+        fn scroll_step(&self) -> f64 {
+            WEB_SCROLL_STEP
+        }
+
+        fn smooth_scroll(&self) -> bool {
+            self.smooth_scroll
+        }
+
+        fn scroll_by(&mut self, _state: &WebCommandState, dx: f64, dy: f64) {
             self.calls.push(ActionCall::ScrollBy(dx, dy));
         }
 
-        fn scroll_half_page(&mut self, down: bool) {
+        fn schedule_smooth_scroll(&mut self) {
+            self.calls.push(ActionCall::ScheduleSmoothScroll);
+        }
+
+        fn scroll_half_page(&mut self, _state: &WebCommandState, down: bool) {
             self.calls.push(ActionCall::ScrollHalfPage(down));
         }
 
-        fn scroll_top(&mut self) {
+        fn scroll_top(&mut self, _state: &WebCommandState) {
             self.calls.push(ActionCall::ScrollTop);
         }
 
-        fn scroll_bottom(&mut self) {
+        fn scroll_bottom(&mut self, _state: &WebCommandState) {
             self.calls.push(ActionCall::ScrollBottom);
         }
 
-        fn scroll_far_left(&mut self) {
+        fn scroll_far_left(&mut self, _state: &WebCommandState) {
             self.calls.push(ActionCall::ScrollFarLeft);
         }
 
-        fn scroll_far_right(&mut self) {
+        fn scroll_far_right(&mut self, _state: &WebCommandState) {
             self.calls.push(ActionCall::ScrollFarRight);
         }
 
@@ -861,6 +1284,14 @@ mod tests {
             self.calls.push(ActionCall::ScrollTo(x, y));
         }
 
+        fn back_url(&self) -> Option<String> {
+            self.back_url.clone()
+        }
+
+        fn forward_url(&self) -> Option<String> {
+            self.forward_url.clone()
+        }
+
         fn go_back(&mut self) {
             self.calls.push(ActionCall::GoBack);
         }
@@ -881,6 +1312,10 @@ mod tests {
             self.calls.push(ActionCall::Find(query.to_string(), backwards));
         }
 
+        fn clear_find(&mut self) {
+            self.calls.push(ActionCall::ClearFind);
+        }
+
         fn hints_start(&mut self, action: WebHintAction) {
             self.calls.push(ActionCall::HintsStart(action));
         }
@@ -913,6 +1348,10 @@ mod tests {
             self.calls.push(ActionCall::FocusInput);
         }
 
+        fn focus_next_input(&mut self, backwards: bool) {
+            self.calls.push(ActionCall::FocusNextInput(backwards));
+        }
+
         fn blur_active_element(&mut self) {
             self.calls.push(ActionCall::BlurActiveElement);
         }
@@ -929,8 +1368,8 @@ mod tests {
             self.calls.push(ActionCall::DeleteForward);
         }
 
-        fn insert_paragraph(&mut self) {
-            self.calls.push(ActionCall::InsertParagraph);
+        fn insert_paragraph(&mut self, confirmed: bool) {
+            self.calls.push(ActionCall::InsertParagraph(confirmed));
         }
 
         fn insert_tab(&mut self) {
@@ -941,10 +1380,22 @@ mod tests {
             self.calls.push(ActionCall::CaretMove(direction.to_string(), granularity.to_string()));
         }
 
-        fn view_source(&mut self) {
+        fn view_source(&mut self, _state: &mut WebCommandState) {
             self.calls.push(ActionCall::ViewSource);
         }
 
+        fn open_console(&mut self) {
+            self.calls.push(ActionCall::OpenConsole);
+        }
+
+        fn open_in_terminal(&mut self) {
+            self.calls.push(ActionCall::OpenInTerminal);
+        }
+
+        fn show_security_info(&mut self) {
+            self.calls.push(ActionCall::ShowSecurityInfo);
+        }
+
         fn follow_rel(&mut self, rel: &str) {
             self.calls.push(ActionCall::FollowRel(rel.to_string()));
         }
@@ -953,11 +1404,43 @@ mod tests {
             self.calls.push(ActionCall::CopyUrl);
         }
 
-        fn open_clipboard(&mut self, new_tab: bool) {
+        fn copy_title(&mut self) {
+            self.calls.push(ActionCall::CopyTitle);
+        }
+
+        fn copy_all_links(&mut self, same_origin: bool) {
+            self.calls.push(ActionCall::CopyAllLinks(same_origin));
+        }
+
+        fn dump_form(&mut self) {
+            self.calls.push(ActionCall::DumpForm);
+        }
+
+        fn block_images(&mut self, blocked: bool) -> bool {
+            self.calls.push(ActionCall::BlockImages(blocked));
+            true
+        }
+
+        fn set_javascript_enabled(&mut self, enabled: bool) -> bool {
+            self.calls.push(ActionCall::JavaScript(enabled));
+            true
+        }
+
+        fn set_focus_mode(&mut self, enabled: bool) -> bool {
+            self.calls.push(ActionCall::FocusMode(enabled));
+            true
+        }
+
+        fn set_dark_mode(&mut self, enabled: bool) -> bool {
+            self.calls.push(ActionCall::DarkMode(enabled));
+            true
+        }
+
+        fn open_clipboard(&mut self, _state: &mut WebCommandState, new_tab: bool) {
             self.calls.push(ActionCall::OpenClipboard(new_tab));
         }
 
-        fn up_url(&mut self, root: bool) {
+        fn up_url(&mut self, _state: &mut WebCommandState, root: bool) {
             self.calls.push(ActionCall::UpUrl(root));
         }
 
@@ -989,12 +1472,18 @@ mod tests {
             self.calls.push(ActionCall::SelectLastTab);
         }
 
-        fn reload(&mut self) {
+        fn reload(&mut self, _state: &mut WebCommandState) {
             self.calls.push(ActionCall::Reload);
         }
 
-        fn show_help(&mut self) {
-            self.calls.push(ActionCall::ShowHelp);
+        fn reset_zoom(&mut self, confirmed: bool) -> bool {
+            self.calls.push(ActionCall::ResetZoom(confirmed));
+            !self.site_zoom || confirmed
+        }
+
+        fn show_help(&mut self, settings: &[&str]) {
+            let settings = settings.iter().map(|setting| setting.to_string()).collect();
+            self.calls.push(ActionCall::ShowHelp(settings));
         }
 
         fn hide_help(&mut self) {
@@ -1009,8 +1498,13 @@ mod tests {
             self.current_url.clone()
         }
 
-        fn open_url(&mut self, url: String) {
-            self.calls.push(ActionCall::OpenUrl(url));
+        fn open_url(
+            &mut self,
+            _state: &mut WebCommandState,
+            url: String,
+            trigger: WebNavigationTrigger,
+        ) {
+            self.calls.push(ActionCall::OpenUrl(url, trigger));
         }
 
         fn push_error(&mut self, message: String) {
@@ -1062,6 +1556,24 @@ mod tests {
         assert_eq!(actions.last_call(), Some(&ActionCall::ScrollFarRight));
     }
 
+    #[test]
+    fn parse_key_names() {
+        assert_eq!(parse_web_key("Escape"), Some((WebKey::Escape, String::new())));
+        assert_eq!(parse_web_key("arrowdown"), Some((WebKey::ArrowDown, String::new())));
+        assert_eq!(parse_web_key("space"), Some((WebKey::Other, String::from(" "))));
+        assert_eq!(parse_web_key("f"), Some((WebKey::Other, String::from("f"))));
+        assert_eq!(parse_web_key("PageDown"), None);
+        assert_eq!(parse_web_key(""), None);
+
+        let names = [String::from("Shift"), String::from("cmd")];
+        assert_eq!(
+            parse_web_modifiers(&names),
+            Some(ModifiersState::SHIFT | ModifiersState::SUPER)
+        );
+        assert_eq!(parse_web_modifiers(&[]), Some(ModifiersState::empty()));
+        assert_eq!(parse_web_modifiers(&[String::from("hyper")]), None);
+    }
+
     #[test]
     fn link_and_input_commands() {
         let mut state = WebCommandState::default();
@@ -1082,11 +1594,81 @@ mod tests {
         assert_eq!(state.mode, WebMode::Hint);
         assert_eq!(actions.last_call(), Some(&ActionCall::HintsStart(WebHintAction::CopyLink)));
 
+        state = WebCommandState::default();
+        press(&mut state, &mut actions, 'y');
+        press(&mut state, &mut actions, 'i');
+        assert_eq!(state.mode, WebMode::Hint);
+        assert_eq!(actions.last_call(), Some(&ActionCall::HintsStart(WebHintAction::Inspect)));
+
+        state = WebCommandState::default();
+        press(&mut state, &mut actions, 'y');
+        press(&mut state, &mut actions, 'L');
+        assert_eq!(state.mode, WebMode::Normal);
+        assert_eq!(actions.last_call(), Some(&ActionCall::CopyAllLinks(false)));
+
+        state = WebCommandState::default();
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'j');
+        assert_eq!(actions.last_call(), Some(&ActionCall::FocusNextInput(false)));
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'k');
+        assert_eq!(actions.last_call(), Some(&ActionCall::FocusNextInput(true)));
+        assert_eq!(state.mode, WebMode::Normal);
+
         state = WebCommandState::default();
         press(&mut state, &mut actions, 'g');
         press(&mut state, &mut actions, 'i');
         assert_eq!(state.mode, WebMode::Insert);
         assert_eq!(actions.last_call(), Some(&ActionCall::FocusInput));
+
+        press_key(&mut state, &mut actions, WebKey::Enter);
+        assert_eq!(actions.last_call(), Some(&ActionCall::InsertParagraph(false)));
+        state.arm_form_submit();
+        press_key(&mut state, &mut actions, WebKey::Enter);
+        assert_eq!(actions.last_call(), Some(&ActionCall::InsertParagraph(true)));
+        state.arm_form_submit();
+        press(&mut state, &mut actions, 'a');
+        press_key(&mut state, &mut actions, WebKey::Enter);
+        assert_eq!(actions.last_call(), Some(&ActionCall::InsertParagraph(false)));
+
+        state = WebCommandState::default();
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'I');
+        assert_eq!(actions.last_call(), Some(&ActionCall::BlockImages(true)));
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'I');
+        assert_eq!(actions.last_call(), Some(&ActionCall::BlockImages(false)));
+
+        state = WebCommandState::default();
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'J');
+        assert_eq!(actions.last_call(), Some(&ActionCall::JavaScript(false)));
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'J');
+        assert_eq!(actions.last_call(), Some(&ActionCall::JavaScript(true)));
+
+        state = WebCommandState::default();
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'd');
+        assert_eq!(actions.last_call(), Some(&ActionCall::DarkMode(true)));
+        assert!(state.dark_mode());
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'd');
+        assert_eq!(actions.last_call(), Some(&ActionCall::DarkMode(false)));
+
+        state = WebCommandState::default();
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'F');
+        assert_eq!(actions.last_call(), Some(&ActionCall::FocusMode(true)));
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'F');
+        assert_eq!(actions.last_call(), Some(&ActionCall::FocusMode(false)));
+
+        state = WebCommandState::default();
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'f');
+        assert_eq!(state.mode, WebMode::Normal);
+        assert_eq!(actions.last_call(), Some(&ActionCall::DumpForm));
     }
 
     #[test]
@@ -1110,6 +1692,8 @@ mod tests {
         assert_eq!(actions.last_call(), Some(&ActionCall::Find(String::from("needle"), false)));
         press(&mut state, &mut actions, 'N');
         assert_eq!(actions.last_call(), Some(&ActionCall::Find(String::from("needle"), true)));
+        press_key(&mut state, &mut actions, WebKey::Escape);
+        assert_eq!(actions.last_call(), Some(&ActionCall::ClearFind));
 
         state = WebCommandState::default();
         press(&mut state, &mut actions, 'v');
@@ -1138,6 +1722,9 @@ mod tests {
         press(&mut state, &mut actions, 'y');
         press(&mut state, &mut actions, 'y');
         assert_eq!(actions.last_call(), Some(&ActionCall::CopyUrl));
+        press(&mut state, &mut actions, 'y');
+        press(&mut state, &mut actions, 't');
+        assert_eq!(actions.last_call(), Some(&ActionCall::CopyTitle));
 
         press(&mut state, &mut actions, 'p');
         assert_eq!(actions.last_call(), Some(&ActionCall::OpenClipboard(false)));
@@ -1186,6 +1773,134 @@ mod tests {
         assert_eq!(actions.last_call(), Some(&ActionCall::OpenCommandBar(String::from("B "))));
         press(&mut state, &mut actions, 'T');
         assert_eq!(actions.last_call(), Some(&ActionCall::OpenCommandBar(String::from("T "))));
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'O');
+        let private = ActionCall::OpenCommandBar(String::from("private "));
+        assert_eq!(actions.last_call(), Some(&private));
+    }
+
+    #[test]
+    fn passthrough_mode() {
+        let mut state = WebCommandState::default();
+        let mut actions = MockActions::default();
+
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'p');
+        assert!(state.is_passthrough());
+
+        let calls = actions.calls.len();
+        assert!(!handle_key(&mut state, &mut actions, WebKey::Other, "j"));
+        assert!(!handle_key(&mut state, &mut actions, WebKey::Escape, ""));
+        assert!(!handle_key(&mut state, &mut actions, WebKey::Other, "x"));
+        assert!(!handle_key(&mut state, &mut actions, WebKey::Escape, ""));
+        assert!(state.is_passthrough());
+        assert_eq!(actions.calls.len(), calls);
+
+        press_key(&mut state, &mut actions, WebKey::Escape);
+        assert!(!state.is_passthrough());
+        assert_eq!(state.mode, WebMode::Normal);
+        assert_eq!(actions.calls.len(), calls);
+    }
+
+    #[test]
+    fn auto_hints_are_throttled() {
+        let mut state = WebCommandState::default();
+        let mut actions = MockActions::default();
+        let now = Instant::now();
+
+        assert!(state.start_auto_hints(now));
+        assert_eq!(state.mode, WebMode::Hint);
+        press(&mut state, &mut actions, 'a');
+        assert_eq!(
+            actions.last_call(),
+            Some(&ActionCall::HintsUpdate(String::from("a"), WebHintAction::Open))
+        );
+
+        press_key(&mut state, &mut actions, WebKey::Escape);
+        assert!(!state.start_auto_hints(now + Duration::from_millis(500)));
+        assert_eq!(state.mode, WebMode::Normal);
+
+        press(&mut state, &mut actions, 'i');
+        assert!(!state.start_auto_hints(now + AUTO_HINTS_INTERVAL));
+        press_key(&mut state, &mut actions, WebKey::Escape);
+        assert!(state.start_auto_hints(now + AUTO_HINTS_INTERVAL));
+    }
+
+    #[test]
+    fn heading_hints() {
+        let mut state = WebCommandState::default();
+        let mut actions = MockActions::default();
+
+        perform_action(&mut state, &mut actions, WebAction::HintHeading);
+        assert_eq!(state.mode, WebMode::Hint);
+        assert_eq!(actions.last_call(), Some(&ActionCall::HintsStart(WebHintAction::Heading)));
+
+        press(&mut state, &mut actions, 's');
+        assert_eq!(
+            actions.last_call(),
+            Some(&ActionCall::HintsUpdate(String::from("s"), WebHintAction::Heading))
+        );
+    }
+
+    #[test]
+    fn reset_view_keeps_site_zoom_until_confirmed() {
+        let mut state = WebCommandState::default();
+        let mut actions = MockActions { site_zoom: true, ..MockActions::default() };
+
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'z');
+        assert_eq!(actions.calls, [ActionCall::ResetZoom(false), ActionCall::ScrollTo(0.0, 0.0)]);
+
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'z');
+        assert_eq!(actions.calls[2], ActionCall::ResetZoom(true));
+
+        // Other actions disarm the confirmation.
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'z');
+        press(&mut state, &mut actions, 'j');
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'z');
+        assert_eq!(actions.calls[7], ActionCall::ResetZoom(false));
+    }
+
+    #[test]
+    fn configured_bindings() {
+        let mut state = WebCommandState::default();
+        let mut actions = MockActions::default();
+        actions.bindings.insert(String::from("j"), WebAction::ScrollHalfPageDown);
+        actions.bindings.insert(String::from("qr"), WebAction::Reload);
+        actions.bindings.insert(String::from("x"), WebAction::None);
+
+        press(&mut state, &mut actions, 'j');
+        assert_eq!(actions.last_call(), Some(&ActionCall::ScrollHalfPage(true)));
+
+        press(&mut state, &mut actions, 'q');
+        press(&mut state, &mut actions, 'r');
+        assert_eq!(actions.last_call(), Some(&ActionCall::Reload));
+
+        let calls = actions.calls.len();
+        press(&mut state, &mut actions, 'x');
+        assert_eq!(actions.calls.len(), calls);
+
+        // Unbound sequences fall back to the defaults of their last key.
+        press(&mut state, &mut actions, 'q');
+        press(&mut state, &mut actions, 'k');
+        assert_eq!(actions.last_call(), Some(&ActionCall::ScrollBy(0.0, -WEB_SCROLL_STEP)));
+    }
+
+    #[test]
+    fn bound_actions_prefer_configured_bindings() {
+        let mut actions = MockActions::default();
+        actions.bindings.insert(String::from("j"), WebAction::ScrollHalfPageDown);
+        actions.bindings.insert(String::from("x"), WebAction::None);
+
+        let bound = bound_actions(&actions);
+        let action = |keys: &str| bound.iter().find(|(bound_keys, _)| bound_keys == keys);
+        assert_eq!(action("j"), Some(&(String::from("j"), WebAction::ScrollHalfPageDown)));
+        assert_eq!(action("gg"), Some(&(String::from("gg"), WebAction::ScrollToTop)));
+        assert_eq!(action("x"), None);
+        assert!(bound.iter().all(|(_, action)| *action != WebAction::None));
     }
 
     #[test]
@@ -1200,6 +1915,18 @@ mod tests {
         press(&mut state, &mut actions, 's');
         assert_eq!(actions.last_call(), Some(&ActionCall::ViewSource));
 
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'c');
+        assert_eq!(actions.last_call(), Some(&ActionCall::OpenConsole));
+
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'C');
+        assert_eq!(actions.last_call(), Some(&ActionCall::OpenInTerminal));
+
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'S');
+        assert_eq!(actions.last_call(), Some(&ActionCall::ShowSecurityInfo));
+
         press(&mut state, &mut actions, '[');
         press(&mut state, &mut actions, '[');
         assert_eq!(actions.last_call(), Some(&ActionCall::FollowRel(String::from("prev"))));
@@ -1230,16 +1957,180 @@ mod tests {
         press(&mut state, &mut actions, 'a');
         assert_eq!(
             actions.last_call(),
-            Some(&ActionCall::OpenUrl(String::from("https://example.com")))
+            Some(&ActionCall::OpenUrl(
+                String::from("https://example.com"),
+                WebNavigationTrigger::Mark
+            ))
         );
         assert!(state.pending_scroll.is_some());
 
+        state = WebCommandState::default();
+        press(&mut state, &mut actions, '`');
+        press(&mut state, &mut actions, '`');
+        assert_eq!(
+            actions.last_call(),
+            Some(&ActionCall::PushError(String::from("No previous URL")))
+        );
+
+        state.set_last_url(String::new());
+        state.set_last_url(String::from("https://previous.com"));
+        state.set_last_url(String::new());
+        press(&mut state, &mut actions, '`');
+        press(&mut state, &mut actions, '`');
+        assert_eq!(
+            actions.last_call(),
+            Some(&ActionCall::OpenUrl(
+                String::from("https://previous.com"),
+                WebNavigationTrigger::LastUrl
+            ))
+        );
+
         state = WebCommandState::default();
         press(&mut state, &mut actions, '?');
-        assert_eq!(actions.last_call(), Some(&ActionCall::ShowHelp));
+        assert_eq!(actions.last_call(), Some(&ActionCall::ShowHelp(Vec::new())));
         assert!(state.help_visible);
         press_key(&mut state, &mut actions, WebKey::Escape);
         assert_eq!(actions.last_call(), Some(&ActionCall::HideHelp));
         assert!(!state.help_visible);
+
+        // The help lists the toggles changed for the tab.
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'J');
+        press(&mut state, &mut actions, 'g');
+        press(&mut state, &mut actions, 'I');
+        press(&mut state, &mut actions, '?');
+        let settings = vec![String::from("JavaScript disabled"), String::from("images blocked")];
+        assert_eq!(actions.last_call(), Some(&ActionCall::ShowHelp(settings)));
+    }
+
+    #[test]
+    fn smooth_scroll_accumulates_until_taken() {
+        let mut state = WebCommandState::default();
+        assert_eq!(state.take_smooth_scroll(), None);
+
+        state.add_smooth_scroll(0.0, 48.0);
+        state.add_smooth_scroll(0.0, 48.0);
+        state.add_smooth_scroll(-48.0, 0.0);
+        assert_eq!(state.take_smooth_scroll(), Some((-48.0, 96.0)));
+        assert_eq!(state.take_smooth_scroll(), None);
+    }
+
+    #[test]
+    fn scroll_keys_accumulate_smooth_scroll() {
+        let mut state = WebCommandState::default();
+        let mut actions = MockActions::default();
+
+        press(&mut state, &mut actions, 'j');
+        assert_eq!(actions.last_call(), Some(&ActionCall::ScrollBy(0.0, WEB_SCROLL_STEP)));
+        assert_eq!(state.take_smooth_scroll(), None);
+
+        actions.smooth_scroll = true;
+        press(&mut state, &mut actions, 'j');
+        press(&mut state, &mut actions, 'j');
+        press(&mut state, &mut actions, 'h');
+        assert_eq!(actions.last_call(), Some(&ActionCall::ScheduleSmoothScroll));
+        assert_eq!(state.take_smooth_scroll(), Some((-WEB_SCROLL_STEP, 2. * WEB_SCROLL_STEP)));
+        assert_eq!(state.take_smooth_scroll(), None);
+    }
+
+    #[test]
+    fn scroll_target_prefers_the_cursor() {
+        let target = scroll_target_script(Some((120., 80.5)), 'y');
+        assert_eq!(target, format!("({WEB_SCROLL_TARGET})(120, 80.5, \"y\")"));
+
+        let target = scroll_target_script(None, 'x');
+        assert_eq!(target, format!("({WEB_SCROLL_TARGET})(null, null, \"x\")"));
+    }
+
+    #[test]
+    fn element_info_formatting() {
+        let description =
+            r##"{"tag":"a","id":"home","classes":["nav","active"],"selector":"#home"}"##;
+        let info = WebElementInfo::parse(description).unwrap();
+        assert_eq!(info.to_string(), "a#home.nav.active  #home");
+
+        let description = r##"{"tag":"li","selector":"#menu > li:nth-of-type(2)"}"##;
+        let info = WebElementInfo::parse(description).unwrap();
+        assert_eq!(info.to_string(), "li  #menu > li:nth-of-type(2)");
+
+        assert_eq!(WebElementInfo::parse("https://example.org"), None);
+    }
+
+    #[test]
+    fn form_field_formatting() {
+        let description =
+            r#"[{"name":"email","type":"email","value":"a@b.c"},{"type":"textarea"}]"#;
+        let fields = WebFormField::parse_all(description).unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].to_string(), r#"email [email] = "a@b.c""#);
+        assert_eq!(fields[1].to_string(), r#"(unnamed) [textarea] = """#);
+
+        assert_eq!(WebFormField::parse_all("{}"), None);
+    }
+
+    #[test]
+    fn security_info_formatting() {
+        let mut info = WebSecurityInfo {
+            host: Some(String::from("example.org")),
+            https: true,
+            only_secure_content: true,
+            certificates: vec![String::from("example.org"), String::from("R3")],
+        };
+        assert_eq!(
+            info.to_string(),
+            "Secure connection to example.org (certificate example.org, issued by R3)"
+        );
+
+        info.only_secure_content = false;
+        info.certificates.truncate(1);
+        assert_eq!(
+            info.to_string(),
+            "Insecure connection to example.org: page has mixed content (certificate example.org)"
+        );
+
+        info.https = false;
+        assert_eq!(info.to_string(), "Insecure connection to example.org: not using HTTPS");
+    }
+
+    #[test]
+    fn history_scrolls_are_restored() {
+        let mut state = WebCommandState::default();
+        state.remember_scroll(String::from("https://example.com/a"), 0.0, 120.0);
+        state.remember_scroll(String::from("https://example.com/b"), 0.0, 40.0);
+        state.remember_scroll(String::from("https://example.com/a"), 0.0, 300.0);
+
+        state.restore_history_scroll("https://example.com/c");
+        assert_eq!(state.take_pending_scroll("https://example.com/c"), None);
+
+        state.restore_history_scroll("https://example.com/a");
+        assert_eq!(state.take_pending_scroll("https://example.com/a"), Some((0.0, 300.0)));
+        assert_eq!(state.history_scrolls.len(), 1);
+
+        for i in 0..MAX_HISTORY_SCROLLS {
+            state.remember_scroll(format!("https://example.com/{i}"), 0.0, 1.0);
+        }
+        assert_eq!(state.history_scrolls.len(), MAX_HISTORY_SCROLLS);
+        state.restore_history_scroll("https://example.com/b");
+        assert_eq!(state.take_pending_scroll("https://example.com/b"), None);
+    }
+
+    #[test]
+    fn history_keys_restore_scroll() {
+        let mut state = WebCommandState::default();
+        let mut actions = MockActions {
+            back_url: Some(String::from("https://example.com/a")),
+            forward_url: Some(String::from("https://example.com/b")),
+            ..MockActions::default()
+        };
+        state.remember_scroll(String::from("https://example.com/a"), 0.0, 120.0);
+        state.remember_scroll(String::from("https://example.com/b"), 10.0, 40.0);
+
+        press(&mut state, &mut actions, 'H');
+        assert_eq!(actions.last_call(), Some(&ActionCall::GoBack));
+        assert_eq!(state.take_pending_scroll("https://example.com/a"), Some((0.0, 120.0)));
+
+        press(&mut state, &mut actions, 'L');
+        assert_eq!(actions.last_call(), Some(&ActionCall::GoForward));
+        assert_eq!(state.take_pending_scroll("https://example.com/b"), Some((10.0, 40.0)));
     }
 }
@@ -42,6 +42,11 @@ pub const WEB_CURSOR_BOOTSTRAP: &str = r#"(function() {
     }
     return "default";
   };
+  window.__taborLinkProbe = function(x, y) {
+    const el = document.elementFromPoint(x, y);
+    const link = el && el.closest ? el.closest("a[href], area[href]") : null;
+    return link ? link.href : "";
+  };
 })();"#;
 
 pub fn web_cursor_script(x: f64, y: f64) -> String {
@@ -55,6 +60,17 @@ pub fn web_cursor_script(x: f64, y: f64) -> String {
     )
 }
 
+pub fn web_link_script(x: f64, y: f64) -> String {
+    format!(
+        r#"(() => {{
+  const probe = window.__taborLinkProbe;
+  return probe ? probe({x}, {y}) : "";
+}})()"#,
+        x = x,
+        y = y
+    )
+}
+
 pub fn web_cursor_from_css(value: &str) -> Option<CursorIcon> {
     let value = value.trim().trim_matches('"');
     if value.is_empty() {
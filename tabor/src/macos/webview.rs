@@ -1,4 +1,4 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::error::Error;
 use std::rc::Rc;
@@ -6,9 +6,9 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::ptr;
 use std::ptr::NonNull;
 
-use block2::RcBlock;
+use block2::{Block, RcBlock};
 use log::debug;
-use objc2::encode::{Encode, Encoding};
+use objc2::encode::{Encode, Encoding, RefEncode};
 use objc2::ffi::NSInteger;
 use objc2::rc::Retained;
 use objc2::runtime::AnyObject;
@@ -16,7 +16,8 @@ use objc2::runtime::NSObject;
 use objc2::runtime::Bool;
 use objc2::{class, define_class, msg_send, sel, DefinedClass, MainThreadMarker, MainThreadOnly};
 use objc2_app_kit::{NSApplication, NSEvent, NSEventMask, NSEventModifierFlags, NSEventType};
-use objc2_foundation::{NSNumber, NSPoint, NSString};
+use objc2_foundation::{NSNotFound, NSNumber, NSPoint, NSRange, NSString};
+use url::Url;
 use winit::dpi::PhysicalPosition;
 use winit::event::{ElementState, MouseButton};
 use winit::event_loop::EventLoopProxy;
@@ -25,15 +26,43 @@ use winit::window::WindowId;
 
 use tabor_terminal::grid::Dimensions;
 
+use crate::cli::WindowOptions;
+use crate::config::web::WebNewWindowLinks;
 use crate::display::SizeInfo;
 use crate::display::window::Window;
-use crate::event::{Event, EventType};
+use crate::event::{Event, EventType, WebCommand};
+use crate::macos::site_settings::host_in_site;
+use crate::macos::web_commands::{WebKey, WebSecurityInfo};
 use crate::tabs::TabId;
+use crate::window_kind::WindowKind;
 use libc::{c_char, c_void};
 
 #[link(name = "WebKit", kind = "framework")]
 unsafe extern "C" {}
 
+/// Opaque `SecTrustRef` target.
+#[repr(C)]
+struct SecTrust {
+    _private: [u8; 0],
+}
+
+// SAFETY: `SecTrustRef` is a pointer to the opaque `__SecTrust` struct.
+unsafe impl RefEncode for SecTrust {
+    const ENCODING_REF: Encoding = Encoding::Pointer(&Encoding::Struct("__SecTrust", &[]));
+}
+
+#[link(name = "Security", kind = "framework")]
+unsafe extern "C" {
+    fn SecTrustGetCertificateCount(trust: *mut SecTrust) -> isize;
+    fn SecTrustGetCertificateAtIndex(trust: *mut SecTrust, index: isize) -> *const c_void;
+    fn SecCertificateCopySubjectSummary(certificate: *const c_void) -> *const c_void;
+}
+
+#[link(name = "CoreFoundation", kind = "framework")]
+unsafe extern "C" {
+    fn CFRelease(value: *const c_void);
+}
+
 #[cfg(target_pointer_width = "32")]
 type CGFloat = f32;
 #[cfg(target_pointer_width = "64")]
@@ -76,7 +105,14 @@ pub struct WebView {
     view: Retained<AnyObject>,
     last_title: Option<String>,
     last_url: Option<String>,
-    _delegate: Retained<AnyObject>,
+    progress_bar: Option<Retained<AnyObject>>,
+    delegate: Retained<AnyObject>,
+    dark_mode: bool,
+    /// Script of focus mode while it's enabled.
+    focus_mode: Option<String>,
+    /// Scripts of the stylesheets added with [`WebView::inject_css`], by handle.
+    user_css: Vec<(u64, String)>,
+    next_css_handle: u64,
 }
 
 pub(crate) struct PendingPopup {
@@ -88,6 +124,10 @@ pub(crate) struct PendingPopup {
 struct WebViewDelegateIvars {
     proxy: EventLoopProxy<Event>,
     window_id: WindowId,
+    /// Handling of links opening a new window, following the config of the window.
+    new_window_links: Cell<WebNewWindowLinks>,
+    /// Allow pages loaded by the web view to run scripts.
+    javascript_enabled: Cell<bool>,
 }
 
 define_class!(
@@ -105,6 +145,24 @@ define_class!(
             navigation_action: *mut AnyObject,
             _window_features: *mut AnyObject,
         ) -> *mut AnyObject {
+            // Scripted popups keep their opener, since pages like OAuth logins rely on it.
+            let links = self.ivars().new_window_links.get();
+            let navigation_type: NSInteger =
+                unsafe { msg_send![navigation_action, navigationType] };
+            if links != WebNewWindowLinks::Popup
+                && navigation_type == NAVIGATION_TYPE_LINK_ACTIVATED
+            {
+                if let Some(url) = navigation_action_url(navigation_action) {
+                    let mut options = WindowOptions::default();
+                    options.window_kind = WindowKind::Web { url };
+                    options.background = links == WebNewWindowLinks::BackgroundTab;
+                    options.private = unsafe { webview.as_ref() }.is_some_and(uses_private_store);
+                    let event = Event::new(EventType::CreateTab(options), self.ivars().window_id);
+                    let _ = self.ivars().proxy.send_event(event);
+                    return ptr::null_mut();
+                }
+            }
+
             let Some(config) = (unsafe { config.as_ref() }) else {
                 return ptr::null_mut();
             };
@@ -121,12 +179,15 @@ define_class!(
                 return ptr::null_mut();
             };
 
-            if let Err(err) = apply_safari_user_agent(&view) {
-                debug!("Failed to apply Safari user agent: {err}");
+            // Popups identify themselves like the page which opened them.
+            let user_agent = unsafe { webview.as_ref() }.and_then(custom_user_agent);
+            if let Err(err) = apply_user_agent(&view, user_agent.as_deref()) {
+                debug!("Failed to apply user agent: {err}");
                 return ptr::null_mut();
             }
 
-            let delegate = WebViewDelegate::new(self.ivars().proxy.clone(), self.ivars().window_id);
+            let ivars = self.ivars();
+            let delegate = WebViewDelegate::new(ivars.proxy.clone(), ivars.window_id, links);
             let delegate = unsafe { Retained::cast_unchecked(delegate) };
             set_webview_delegate(&view, &delegate);
 
@@ -157,6 +218,28 @@ define_class!(
             Retained::autorelease_return(view)
         }
 
+        #[unsafe(method(webView:decidePolicyForNavigationAction:preferences:decisionHandler:))]
+        fn decide_policy_for_navigation_action(
+            &self,
+            _webview: *mut AnyObject,
+            _navigation_action: *mut AnyObject,
+            preferences: *mut AnyObject,
+            decision_handler: *mut Block<dyn Fn(NSInteger, *mut AnyObject)>,
+        ) {
+            // Preferences of the configuration only apply to web views created afterwards, so
+            // the scripts of each page are allowed or disallowed when navigating to it.
+            if let Some(preferences) = unsafe { preferences.as_ref() } {
+                let enabled = self.ivars().javascript_enabled.get();
+                unsafe {
+                    let _: () = msg_send![preferences, setAllowsContentJavaScript: enabled];
+                }
+            }
+
+            if let Some(decision_handler) = unsafe { decision_handler.as_ref() } {
+                decision_handler.call((NAVIGATION_ACTION_POLICY_ALLOW, preferences));
+            }
+        }
+
         #[unsafe(method(webViewDidClose:))]
         fn web_view_did_close(&self, webview: *mut AnyObject) {
             let Some(webview) = (unsafe { webview.as_ref() }) else {
@@ -169,6 +252,113 @@ define_class!(
             let event = Event::new(EventType::CloseTab(tab_id), self.ivars().window_id);
             let _ = self.ivars().proxy.send_event(event);
         }
+
+        #[unsafe(method(webView:didStartProvisionalNavigation:))]
+        fn did_start_provisional_navigation(
+            &self,
+            webview: *mut AnyObject,
+            _navigation: *mut AnyObject,
+        ) {
+            let Some(webview) = (unsafe { webview.as_ref() }) else {
+                return;
+            };
+            let Some(tab_id) = webview_tab_id(webview) else {
+                return;
+            };
+
+            // The previous page is still loaded, so its scroll offset can be remembered for
+            // going back and forward to it.
+            let proxy = self.ivars().proxy.clone();
+            let window_id = self.ivars().window_id;
+            let script = "JSON.stringify([location.href, window.scrollX, window.scrollY]);";
+            eval_js_string(webview, script, move |result| {
+                let Some((url, scroll_x, scroll_y)) =
+                    result.and_then(|result| serde_json::from_str(&result).ok())
+                else {
+                    return;
+                };
+
+                let command = WebCommand::SaveScroll { url, scroll_x, scroll_y };
+                let event = Event::for_tab(EventType::WebCommand(command), window_id, tab_id);
+                let _ = proxy.send_event(event);
+            });
+        }
+
+        #[unsafe(method(webView:didFinishNavigation:))]
+        fn did_finish_navigation(&self, webview: *mut AnyObject, _navigation: *mut AnyObject) {
+            self.send_load_progress(webview, 1., true);
+        }
+
+        #[unsafe(method(webView:didFailNavigation:withError:))]
+        fn did_fail_navigation(
+            &self,
+            webview: *mut AnyObject,
+            _navigation: *mut AnyObject,
+            _error: *mut AnyObject,
+        ) {
+            self.send_load_progress(webview, 1., false);
+        }
+
+        #[unsafe(method(webView:didFailProvisionalNavigation:withError:))]
+        fn did_fail_provisional_navigation(
+            &self,
+            webview: *mut AnyObject,
+            _navigation: *mut AnyObject,
+            _error: *mut AnyObject,
+        ) {
+            self.send_load_progress(webview, 1., false);
+        }
+
+        #[unsafe(method(userContentController:didReceiveScriptMessage:))]
+        fn did_receive_script_message(&self, _controller: *mut AnyObject, message: *mut AnyObject) {
+            let Some(message) = (unsafe { message.as_ref() }) else {
+                return;
+            };
+            let webview: *mut AnyObject = unsafe { msg_send![message, webView] };
+            let Some(tab_id) = (unsafe { webview.as_ref() }).and_then(webview_tab_id) else {
+                return;
+            };
+
+            let body: *mut AnyObject = unsafe { msg_send![message, body] };
+            let Some(body) = (unsafe { body.as_ref() }) else {
+                return;
+            };
+            let is_string: Bool = unsafe { msg_send![body, isKindOfClass: class!(NSString)] };
+            if !is_string.as_bool() {
+                return;
+            }
+
+            let body = unsafe { &*(body as *const AnyObject as *const NSString) }.to_string();
+            let payload = match serde_json::from_str::<(String, String)>(&body) {
+                Ok((title, body)) => EventType::WebNotification { title, body },
+                Err(_) if serde_json::from_str::<String>(&body).is_ok_and(|request| {
+                    request == NOTIFICATION_PERMISSION_REQUEST
+                }) =>
+                {
+                    EventType::WebNotificationPermission
+                },
+                Err(_) => return,
+            };
+
+            let event = Event::for_tab(payload, self.ivars().window_id, tab_id);
+            let _ = self.ivars().proxy.send_event(event);
+        }
+
+        #[unsafe(method(observeValueForKeyPath:ofObject:change:context:))]
+        fn observe_value(
+            &self,
+            _key_path: *mut AnyObject,
+            object: *mut AnyObject,
+            _change: *mut AnyObject,
+            _context: *mut c_void,
+        ) {
+            if object.is_null() {
+                return;
+            }
+
+            let fraction: f64 = unsafe { msg_send![object, estimatedProgress] };
+            self.send_load_progress(object, fraction, false);
+        }
     }
 );
 
@@ -183,16 +373,263 @@ thread_local! {
     static WEBVIEW_TAB_IDS: RefCell<HashMap<usize, TabId>> = RefCell::new(HashMap::new());
     static MOUSE_MONITOR: RefCell<Option<MouseMonitor>> = RefCell::new(None);
     static LAST_MOUSE_EVENT: RefCell<Option<Retained<NSEvent>>> = RefCell::new(None);
+    static IMAGE_BLOCK_RULES: RefCell<Option<Retained<AnyObject>>> = const { RefCell::new(None) };
+    static IMAGE_BLOCK_RULES_REQUESTED: Cell<bool> = const { Cell::new(false) };
 }
 
+/// `WKNavigationTypeLinkActivated`.
+const NAVIGATION_TYPE_LINK_ACTIVATED: NSInteger = 0;
+
+/// `WKNavigationActionPolicyAllow`.
+const NAVIGATION_ACTION_POLICY_ALLOW: NSInteger = 1;
+
+/// `WKUserScriptInjectionTimeAtDocumentStart`.
+const USER_SCRIPT_INJECTION_AT_DOCUMENT_START: NSInteger = 0;
+
+/// Name of the script message handler receiving notifications of pages.
+const NOTIFICATION_MESSAGE_HANDLER: &str = "taborNotification";
+
+/// Message sent to [`NOTIFICATION_MESSAGE_HANDLER`] when a page requests permission to notify.
+const NOTIFICATION_PERMISSION_REQUEST: &str = "permission";
+
+/// Replacement for the Notifications API, which WKWebView doesn't implement.
+///
+/// Pages start without permission, Tabor answers their permission requests with
+/// [`notification_permission_script`].
+const NOTIFICATION_JS: &str = r#"(() => {
+  const handler = window.webkit?.messageHandlers?.taborNotification;
+  if (!handler || window.__taborNotification) return;
+  let permission = 'default';
+  const requests = [];
+  class TaborNotification extends EventTarget {
+    constructor(title, options = {}) {
+      super();
+      this.title = String(title);
+      this.body = options.body ? String(options.body) : '';
+      this.tag = options.tag || '';
+      this.onclick = this.onclose = this.onerror = this.onshow = null;
+      if (permission === 'granted') {
+        handler.postMessage(JSON.stringify([this.title, this.body]));
+      }
+    }
+    close() {}
+    static get permission() { return permission; }
+    static requestPermission(callback) {
+      const request = permission === 'default'
+        ? new Promise((resolve) => {
+            requests.push(resolve);
+            if (requests.length === 1) handler.postMessage(JSON.stringify('permission'));
+          })
+        : Promise.resolve(permission);
+      return request.then((result) => {
+        if (callback) callback(result);
+        return result;
+      });
+    }
+  }
+  window.__taborNotification = {
+    resolve(granted) {
+      permission = granted ? 'granted' : 'denied';
+      requests.splice(0).forEach((resolve) => resolve(permission));
+    },
+  };
+  window.Notification = TaborNotification;
+})();"#;
+
+/// Script answering the notification permission request of a page.
+pub fn notification_permission_script(granted: bool) -> String {
+    format!("window.__taborNotification?.resolve({granted});")
+}
+
+/// Overlay listing the console messages of the page, for inspectors without a console panel.
+///
+/// Only messages logged after the overlay was added are shown, running it again removes it.
+const CONSOLE_OVERLAY_JS: &str = r#"(() => {
+  const existing = document.getElementById('tabor-console');
+  if (existing) {
+    existing.remove();
+    return;
+  }
+  const overlay = document.createElement('pre');
+  overlay.id = 'tabor-console';
+  overlay.style.cssText = 'position:fixed;left:0;right:0;bottom:0;max-height:30vh;margin:0;'
+    + 'padding:4px 8px;overflow:auto;z-index:2147483647;background:rgba(0,0,0,.85);color:#ddd;'
+    + 'font:12px Menlo,Monaco,monospace;white-space:pre-wrap;';
+  overlay.textContent = 'Console messages are shown here, run :console again to close.\n';
+  document.documentElement.appendChild(overlay);
+  if (window.__taborConsole) return;
+  window.__taborConsole = true;
+  const colors = { error: '#f66', warn: '#fc6', info: '#6cf', log: '#ddd', debug: '#999' };
+  for (const level of Object.keys(colors)) {
+    const original = console[level];
+    console[level] = (...args) => {
+      const overlay = document.getElementById('tabor-console');
+      if (overlay) {
+        const line = document.createElement('div');
+        line.style.color = colors[level];
+        line.textContent = args.map((arg) => {
+          if (typeof arg === 'string') return arg;
+          try { return JSON.stringify(arg); } catch (_) { return String(arg); }
+        }).join(' ');
+        overlay.appendChild(line);
+        overlay.scrollTop = overlay.scrollHeight;
+      }
+      return original.apply(console, args);
+    };
+  }
+})();"#;
+
+/// Stylesheet inverting the page colors, with media inverted back to their original colors.
+const DARK_MODE_JS: &str = r#"(() => {
+  if (document.getElementById('tabor-dark-mode')) return;
+  const style = document.createElement('style');
+  style.id = 'tabor-dark-mode';
+  style.textContent = 'html { filter: invert(1) hue-rotate(180deg); background: #fff; }'
+    + ' img, video, picture, canvas { filter: invert(1) hue-rotate(180deg); }';
+  document.documentElement.appendChild(style);
+})();"#;
+
+/// Remove the stylesheet of [`DARK_MODE_JS`].
+const DARK_MODE_REMOVE_JS: &str = "document.getElementById('tabor-dark-mode')?.remove();";
+
+/// Banners hidden by focus mode, in addition to `web.focus_mode_selectors`.
+const FOCUS_MODE_SELECTORS: [&str; 5] = [
+    "#onetrust-banner-sdk",
+    "#onetrust-consent-sdk",
+    "#CybotCookiebotDialog",
+    ".cc-window",
+    "#didomi-host",
+];
+
+/// Remove the changes of [`focus_mode_script`].
+const FOCUS_MODE_REMOVE_JS: &str = r#"(() => {
+  window.__taborFocusMode?.disconnect();
+  window.__taborFocusMode = null;
+  document.getElementById('tabor-focus-mode')?.remove();
+  for (const el of document.querySelectorAll('[data-tabor-focus-mode]')) {
+    el.style.position = el.getAttribute('data-tabor-focus-mode');
+    el.removeAttribute('data-tabor-focus-mode');
+  }
+})();"#;
+
+/// Script making fixed and sticky elements scroll with the page, and hiding banners.
+///
+/// Every selector gets its own rule, so invalid ones don't prevent hiding the others. The elements
+/// of the page are checked once it's idle, elements added or restyled later are checked by a
+/// `MutationObserver`. Positions are read before any element is changed, so the page's style is
+/// only recalculated once per batch.
+fn focus_mode_script(selectors: &[String]) -> String {
+    let css: String = FOCUS_MODE_SELECTORS
+        .into_iter()
+        .chain(selectors.iter().map(String::as_str))
+        .map(|selector| format!("{selector} {{ display: none !important; }}\n"))
+        .collect();
+    let css = serde_json::to_string(&css).unwrap_or_default();
+    format!(
+        r#"(() => {{
+  if (window.__taborFocusMode) return;
+  const unpin = (elements) => {{
+    const pinned = elements.filter((el) => {{
+      if (el.hasAttribute('data-tabor-focus-mode')) return false;
+      const position = getComputedStyle(el).position;
+      return position === 'fixed' || position === 'sticky';
+    }});
+    for (const el of pinned) {{
+      el.setAttribute('data-tabor-focus-mode', el.style.position);
+      el.style.setProperty('position', 'static', 'important');
+    }}
+  }};
+  const observer = new MutationObserver((mutations) => {{
+    const elements = new Set();
+    for (const mutation of mutations) {{
+      if (mutation.type === 'attributes') {{
+        elements.add(mutation.target);
+        continue;
+      }}
+      for (const node of mutation.addedNodes) {{
+        if (node.nodeType !== Node.ELEMENT_NODE) continue;
+        elements.add(node);
+        for (const el of node.querySelectorAll('*')) elements.add(el);
+      }}
+    }}
+    unpin([...elements].filter((el) => el.isConnected));
+  }});
+  window.__taborFocusMode = observer;
+  const apply = () => {{
+    if (window.__taborFocusMode !== observer) return;
+    const style = document.createElement('style');
+    style.id = 'tabor-focus-mode';
+    style.textContent = {css};
+    document.documentElement.appendChild(style);
+    observer.observe(document.documentElement, {{
+      childList: true,
+      subtree: true,
+      attributes: true,
+      attributeFilter: ['class', 'style'],
+    }});
+    const idle = window.requestIdleCallback || ((callback) => setTimeout(callback, 0));
+    idle(() => {{
+      if (window.__taborFocusMode === observer) unpin([...document.querySelectorAll('body *')]);
+    }});
+  }};
+  if (document.readyState === 'loading') {{
+    document.addEventListener('DOMContentLoaded', apply, {{ once: true }});
+  }} else {{
+    apply();
+  }}
+}})();"#
+    )
+}
+
+/// Script adding a user stylesheet to the page, identified by its `handle`.
+fn user_css_script(handle: u64, css: &str) -> String {
+    let css = serde_json::to_string(css).unwrap_or_default();
+    format!(
+        r#"(() => {{
+  if (document.getElementById('tabor-user-css-{handle}')) return;
+  const style = document.createElement('style');
+  style.id = 'tabor-user-css-{handle}';
+  style.textContent = {css};
+  document.documentElement.appendChild(style);
+}})();"#
+    )
+}
+
+/// Identifier of the compiled image blocking rules.
+const IMAGE_BLOCK_RULES_ID: &str = "tabor-block-images";
+
+/// Content rules blocking image requests, and hiding the placeholders left behind.
+const IMAGE_BLOCK_RULES_JSON: &str = r#"[
+    {"trigger": {"url-filter": ".*", "resource-type": ["image"]}, "action": {"type": "block"}},
+    {"trigger": {"url-filter": ".*"}, "action": {"type": "css-display-none", "selector": "img, picture"}}
+]"#;
+
 impl WebViewDelegate {
-    fn new(proxy: EventLoopProxy<Event>, window_id: WindowId) -> Retained<Self> {
+    fn new(
+        proxy: EventLoopProxy<Event>,
+        window_id: WindowId,
+        new_window_links: WebNewWindowLinks,
+    ) -> Retained<Self> {
         let mtm = MainThreadMarker::new()
             .expect("WebView delegate must be created on the main thread");
-        let this = WebViewDelegate::alloc(mtm)
-            .set_ivars(WebViewDelegateIvars { proxy, window_id });
+        let this = WebViewDelegate::alloc(mtm).set_ivars(WebViewDelegateIvars {
+            proxy,
+            window_id,
+            new_window_links: Cell::new(new_window_links),
+            javascript_enabled: Cell::new(true),
+        });
         unsafe { msg_send![super(this), init] }
     }
+
+    fn send_load_progress(&self, webview: *mut AnyObject, fraction: f64, finished: bool) {
+        let Some(tab_id) = (unsafe { webview.as_ref() }).and_then(webview_tab_id) else {
+            return;
+        };
+
+        let payload = EventType::WebLoadProgress { fraction, finished };
+        let event = Event::for_tab(payload, self.ivars().window_id, tab_id);
+        let _ = self.ivars().proxy.send_event(event);
+    }
 }
 
 fn webview_key(view: &AnyObject) -> usize {
@@ -225,19 +662,98 @@ fn unregister_webview_tab(view: &AnyObject) {
     });
 }
 
+fn webview_tab_id(view: &AnyObject) -> Option<TabId> {
+    let key = webview_key(view);
+    WEBVIEW_TAB_IDS.with(|cell| cell.borrow().get(&key).copied())
+}
+
 fn take_webview_tab_id(view: &AnyObject) -> Option<TabId> {
     let key = webview_key(view);
     WEBVIEW_TAB_IDS.with(|cell| cell.borrow_mut().remove(&key))
 }
 
+/// Key path of the WKWebView load progress.
+const LOAD_PROGRESS_KEY_PATH: &str = "estimatedProgress";
+
+/// `NSKeyValueObservingOptionNew`.
+const KEY_VALUE_OBSERVING_OPTION_NEW: usize = 1;
+
+fn observe_load_progress(view: &AnyObject, delegate: &AnyObject) {
+    let key_path = NSString::from_str(LOAD_PROGRESS_KEY_PATH);
+    unsafe {
+        let _: () = msg_send![
+            view,
+            addObserver: delegate,
+            forKeyPath: &*key_path,
+            options: KEY_VALUE_OBSERVING_OPTION_NEW,
+            context: ptr::null_mut::<c_void>()
+        ];
+    }
+}
+
+fn unobserve_load_progress(view: &AnyObject, delegate: &AnyObject) {
+    let key_path = NSString::from_str(LOAD_PROGRESS_KEY_PATH);
+    unsafe {
+        let _: () = msg_send![view, removeObserver: delegate, forKeyPath: &*key_path];
+    }
+}
+
 fn set_webview_delegate(view: &AnyObject, delegate: &AnyObject) {
     unsafe {
         let _: () = msg_send![view, setUIDelegate: delegate];
         let _: () = msg_send![view, setNavigationDelegate: delegate];
     }
+
+    let Some(controller) = user_content_controller(view) else {
+        return;
+    };
+
+    // Registering a second handler with the same name raises an exception.
+    let name = NSString::from_str(NOTIFICATION_MESSAGE_HANDLER);
+    unsafe {
+        let _: () = msg_send![&*controller, removeScriptMessageHandlerForName: &*name];
+        let _: () = msg_send![&*controller, addScriptMessageHandler: delegate, name: &*name];
+    }
+}
+
+fn user_content_controller(view: &AnyObject) -> Option<Retained<AnyObject>> {
+    let config: *mut AnyObject = unsafe { msg_send![view, configuration] };
+    if config.is_null() {
+        return None;
+    }
+
+    let controller: *mut AnyObject = unsafe { msg_send![config, userContentController] };
+    unsafe { Retained::retain(controller) }
+}
+
+/// Add a script to every page loaded into a web view, before any of the page's scripts run.
+fn add_user_script(controller: &AnyObject, source: &str) -> bool {
+    let source = NSString::from_str(source);
+    let script: *mut AnyObject = unsafe { msg_send![class!(WKUserScript), alloc] };
+    let script: *mut AnyObject = unsafe {
+        msg_send![
+            script,
+            initWithSource: &*source,
+            injectionTime: USER_SCRIPT_INJECTION_AT_DOCUMENT_START,
+            forMainFrameOnly: Bool::YES
+        ]
+    };
+    let Some(script) = (unsafe { Retained::from_raw(script) }) else {
+        return false;
+    };
+
+    unsafe {
+        let _: () = msg_send![controller, addUserScript: &*script];
+    }
+    true
 }
 
 fn safari_user_agent(view: &AnyObject) -> Result<String, Box<dyn Error>> {
+    // Read the default user agent, rather than a previously applied custom one.
+    unsafe {
+        let _: () = msg_send![view, setCustomUserAgent: Option::<&NSString>::None];
+    }
+
     let key = NSString::from_str("userAgent");
     let value: *mut AnyObject = unsafe { msg_send![view, valueForKey: &*key] };
     if value.is_null() {
@@ -290,8 +806,12 @@ fn safari_version_from_bundle() -> Result<String, Box<dyn Error>> {
     Ok(unsafe { &*(value as *const NSString) }.to_string())
 }
 
-fn apply_safari_user_agent(view: &AnyObject) -> Result<(), Box<dyn Error>> {
-    let agent = safari_user_agent(view)?;
+/// Apply a custom user agent, falling back to the Safari user agent.
+fn apply_user_agent(view: &AnyObject, user_agent: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let agent = match user_agent {
+        Some(agent) => agent.to_owned(),
+        None => safari_user_agent(view)?,
+    };
     let selector = sel!(setCustomUserAgent:);
     let responds: Bool = unsafe { msg_send![view, respondsToSelector: selector] };
     if !responds.as_bool() {
@@ -310,6 +830,61 @@ fn apply_safari_user_agent(view: &AnyObject) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+fn custom_user_agent(view: &AnyObject) -> Option<String> {
+    let agent: *mut AnyObject = unsafe { msg_send![view, customUserAgent] };
+    if agent.is_null() {
+        return None;
+    }
+
+    Some(unsafe { &*(agent as *const NSString) }.to_string()).filter(|agent| !agent.is_empty())
+}
+
+/// Evaluate a script in a web view, passing its result to `callback` as a string.
+fn eval_js_string<F>(view: &AnyObject, script: &str, callback: F)
+where
+    F: FnOnce(Option<String>) + 'static,
+{
+    let _mtm = MainThreadMarker::new().expect("WebView JS requires main thread");
+    let script = NSString::from_str(script);
+    let callback = Rc::new(RefCell::new(Some(callback)));
+    let block = RcBlock::new({
+        let callback = Rc::clone(&callback);
+        move |result: *mut AnyObject, error: *mut AnyObject| {
+            let Some(callback) = callback.borrow_mut().take() else {
+                return;
+            };
+
+            if !error.is_null() {
+                let error_desc: *mut AnyObject = unsafe { msg_send![error, description] };
+                if !error_desc.is_null() {
+                    let error_str = unsafe { &*(error_desc as *const NSString) }.to_string();
+                    debug!("WebView JS error: {error_str}");
+                }
+                callback(None);
+                return;
+            }
+
+            if result.is_null() {
+                callback(None);
+                return;
+            }
+
+            let desc: *mut AnyObject = unsafe { msg_send![result, description] };
+            if desc.is_null() {
+                callback(None);
+                return;
+            }
+
+            let output = unsafe { &*(desc as *const NSString) }.to_string();
+            callback(Some(output));
+        }
+    });
+
+    unsafe {
+        let _: () = msg_send![view, evaluateJavaScript: &*script, completionHandler: &*block];
+    }
+}
+
 fn navigation_action_url(navigation_action: *mut AnyObject) -> Option<String> {
     let request: *mut AnyObject = unsafe { msg_send![navigation_action, request] };
     if request.is_null() {
@@ -333,6 +908,7 @@ fn configure_webview_config(config: &AnyObject) -> Result<(), Box<dyn Error>> {
     enable_web_authentication(config)?;
     enable_web_inspector(config)?;
     enable_web_popups(config)?;
+    enable_web_notifications(config)?;
     Ok(())
 }
 
@@ -369,6 +945,82 @@ fn install_mouse_monitor() -> Result<(), Box<dyn Error>> {
     })
 }
 
+/// Compile the image blocking rules in the background, once for all web views.
+fn compile_image_block_rules() {
+    if IMAGE_BLOCK_RULES_REQUESTED.with(|requested| requested.replace(true)) {
+        return;
+    }
+
+    let store: *mut AnyObject = unsafe { msg_send![class!(WKContentRuleListStore), defaultStore] };
+    if store.is_null() {
+        debug!("No content rule list store available");
+        return;
+    }
+
+    let block = RcBlock::new(|rules: *mut AnyObject, error: *mut AnyObject| {
+        if !error.is_null() {
+            let desc: *mut AnyObject = unsafe { msg_send![error, localizedDescription] };
+            if !desc.is_null() {
+                let desc = unsafe { &*(desc as *const NSString) }.to_string();
+                debug!("Failed to compile image blocking rules: {desc}");
+            }
+            return;
+        }
+
+        let rules = unsafe { Retained::retain(rules) };
+        IMAGE_BLOCK_RULES.with(|cell| *cell.borrow_mut() = rules);
+    });
+
+    let identifier = NSString::from_str(IMAGE_BLOCK_RULES_ID);
+    let rules = NSString::from_str(IMAGE_BLOCK_RULES_JSON);
+    unsafe {
+        let _: () = msg_send![
+            store,
+            compileContentRuleListForIdentifier: &*identifier,
+            encodedContentRuleList: &*rules,
+            completionHandler: &*block
+        ];
+    }
+}
+
+/// Data store holding the cookies, storage and caches of a web view.
+fn website_data_store(view: &AnyObject) -> Option<Retained<AnyObject>> {
+    let config: *mut AnyObject = unsafe { msg_send![view, configuration] };
+    if config.is_null() {
+        return None;
+    }
+
+    let store: *mut AnyObject = unsafe { msg_send![config, websiteDataStore] };
+    unsafe { Retained::retain(store) }
+}
+
+/// Collect the website data records belonging to the site of `host`, or all of them.
+fn site_data_records(records: *mut AnyObject, host: Option<&str>) -> *mut AnyObject {
+    let matching: *mut AnyObject = unsafe { msg_send![class!(NSMutableArray), array] };
+    let count: usize = unsafe { msg_send![records, count] };
+    for index in 0..count {
+        let record: *mut AnyObject = unsafe { msg_send![records, objectAtIndex: index] };
+        let name: *mut AnyObject = unsafe { msg_send![record, displayName] };
+        if name.is_null() {
+            continue;
+        }
+
+        let site = unsafe { &*(name as *const NSString) }.to_string();
+        if host.is_none_or(|host| host_in_site(host, &site)) {
+            let _: () = unsafe { msg_send![matching, addObject: record] };
+        }
+    }
+    matching
+}
+
+/// Check if a web view keeps its browsing data in memory only.
+fn uses_private_store(view: &AnyObject) -> bool {
+    website_data_store(view).is_some_and(|store| {
+        let persistent: bool = unsafe { msg_send![&*store, isPersistent] };
+        !persistent
+    })
+}
+
 fn take_last_mouse_event() -> Option<Retained<NSEvent>> {
     LAST_MOUSE_EVENT.with(|cell| cell.borrow_mut().take())
 }
@@ -379,6 +1031,8 @@ impl WebView {
         size_info: &SizeInfo,
         tab_id: TabId,
         url: &str,
+        user_agent: Option<&str>,
+        private: bool,
         proxy: &EventLoopProxy<Event>,
     ) -> Result<Self, Box<dyn Error>> {
         let _mtm = MainThreadMarker::new().ok_or_else(|| {
@@ -390,6 +1044,7 @@ impl WebView {
 
         super::register_webview();
         install_mouse_monitor()?;
+        compile_image_block_rules();
         let result = (|| {
             let parent = ns_view(window)?;
             let config: *mut AnyObject = unsafe { msg_send![class!(WKWebViewConfiguration), new] };
@@ -400,8 +1055,12 @@ impl WebView {
                 )
             })?;
             configure_webview_config(&*config)?;
-            let store: *mut AnyObject =
-                unsafe { msg_send![class!(WKWebsiteDataStore), defaultDataStore] };
+            // Private tabs get a store of their own, which is discarded with the web view.
+            let store: *mut AnyObject = if private {
+                unsafe { msg_send![class!(WKWebsiteDataStore), nonPersistentDataStore] }
+            } else {
+                unsafe { msg_send![class!(WKWebsiteDataStore), defaultDataStore] }
+            };
             unsafe {
                 let _: () = msg_send![&*config, setWebsiteDataStore: store];
             }
@@ -418,17 +1077,23 @@ impl WebView {
                 let _: () = msg_send![parent, addSubview: &*view];
             }
 
-            let delegate = WebViewDelegate::new(proxy.clone(), window.id());
+            let delegate = WebViewDelegate::new(proxy.clone(), window.id(), Default::default());
             let delegate = unsafe { Retained::cast_unchecked(delegate) };
             set_webview_delegate(&view, &delegate);
             register_webview_tab(&view, tab_id);
-            apply_safari_user_agent(&view)?;
+            apply_user_agent(&view, user_agent)?;
+            observe_load_progress(&view, &delegate);
 
             let mut web_view = Self {
                 view,
                 last_title: None,
                 last_url: None,
-                _delegate: delegate,
+                progress_bar: None,
+                delegate,
+                dark_mode: false,
+                focus_mode: None,
+                user_css: Vec::new(),
+                next_css_handle: 1,
             };
             let initial_url = if url.is_empty() { "about:blank" } else { url };
             web_view.load_url(initial_url);
@@ -458,6 +1123,7 @@ impl WebView {
 
         super::register_webview();
         install_mouse_monitor()?;
+        compile_image_block_rules();
         let result = (|| {
             let parent = ns_view(window)?;
             let config: *mut AnyObject = unsafe { msg_send![&*view, configuration] };
@@ -480,14 +1146,19 @@ impl WebView {
             }
 
             set_webview_delegate(&view, &delegate);
+            observe_load_progress(&view, &delegate);
             register_webview_tab(&view, tab_id);
-            apply_safari_user_agent(&view)?;
 
             Ok(Self {
                 view,
                 last_title: None,
                 last_url: None,
-                _delegate: delegate,
+                progress_bar: None,
+                delegate,
+                dark_mode: false,
+                focus_mode: None,
+                user_css: Vec::new(),
+                next_css_handle: 1,
             })
         })();
 
@@ -498,6 +1169,48 @@ impl WebView {
         result
     }
 
+    /// Move the web view into another window, routing its delegate events there.
+    pub fn reattach(
+        &mut self,
+        window: &Window,
+        size_info: &SizeInfo,
+        tab_id: TabId,
+        proxy: &EventLoopProxy<Event>,
+    ) -> Result<(), Box<dyn Error>> {
+        let parent = ns_view(window)?;
+        let frame = webview_frame(window, size_info);
+        unsafe {
+            let _: () = msg_send![&*self.view, removeFromSuperview];
+            let _: () = msg_send![parent, addSubview: &*self.view];
+            let _: () = msg_send![&*self.view, setFrame: frame];
+        }
+
+        let links = self.delegate_ivars().new_window_links.get();
+        let javascript_enabled = self.delegate_ivars().javascript_enabled.get();
+        let delegate = WebViewDelegate::new(proxy.clone(), window.id(), links);
+        delegate.ivars().javascript_enabled.set(javascript_enabled);
+        let delegate = unsafe { Retained::cast_unchecked(delegate) };
+        set_webview_delegate(&self.view, &delegate);
+        unobserve_load_progress(&self.view, &self.delegate);
+        observe_load_progress(&self.view, &delegate);
+        self.delegate = delegate;
+        register_webview_tab(&self.view, tab_id);
+        self.invalidate_cursor_rects();
+
+        Ok(())
+    }
+
+    /// Set how links opening a new window are handled.
+    pub fn set_new_window_links(&self, links: WebNewWindowLinks) {
+        self.delegate_ivars().new_window_links.set(links);
+    }
+
+    fn delegate_ivars(&self) -> &WebViewDelegateIvars {
+        // Delegates of web views are always created by `WebViewDelegate::new`.
+        let delegate = Retained::as_ptr(&self.delegate) as *const WebViewDelegate;
+        unsafe { &*delegate }.ivars()
+    }
+
     pub fn set_visible(&mut self, visible: bool) {
         unsafe {
             let _: () = msg_send![&*self.view, setHidden: !visible];
@@ -554,6 +1267,226 @@ impl WebView {
         true
     }
 
+    /// Change the user agent, returning `true` if it differs from the previous one.
+    ///
+    /// The new user agent is only sent starting with the next page load.
+    pub fn set_user_agent(&mut self, user_agent: Option<&str>) -> bool {
+        let previous = custom_user_agent(&self.view);
+        if let Err(err) = apply_user_agent(&self.view, user_agent) {
+            debug!("Failed to apply user agent: {err}");
+            return false;
+        }
+        custom_user_agent(&self.view) != previous
+    }
+
+    pub fn page_zoom(&self) -> f64 {
+        let responds: Bool = unsafe { msg_send![&*self.view, respondsToSelector: sel!(pageZoom)] };
+        if !responds.as_bool() {
+            return 1.;
+        }
+
+        unsafe { msg_send![&*self.view, pageZoom] }
+    }
+
+    pub fn set_page_zoom(&mut self, zoom: f64) {
+        let responds: Bool =
+            unsafe { msg_send![&*self.view, respondsToSelector: sel!(setPageZoom:)] };
+        if responds.as_bool() {
+            unsafe {
+                let _: () = msg_send![&*self.view, setPageZoom: zoom];
+            }
+        }
+    }
+
+    /// Force a light or dark appearance for `prefers-color-scheme`, `None` following the system.
+    pub fn set_dark_appearance(&mut self, dark: Option<bool>) {
+        let appearance: *mut AnyObject = match dark {
+            Some(dark) => {
+                let name = if dark { "NSAppearanceNameDarkAqua" } else { "NSAppearanceNameAqua" };
+                let name = NSString::from_str(name);
+                unsafe { msg_send![class!(NSAppearance), appearanceNamed: &*name] }
+            },
+            None => ptr::null_mut(),
+        };
+
+        unsafe {
+            let _: () = msg_send![&*self.view, setAppearance: appearance];
+        }
+    }
+
+    /// Block or allow loading images, returning `false` if the blocking rules are unavailable.
+    ///
+    /// The rules apply to all following page loads, including reloads.
+    pub fn set_images_blocked(&mut self, blocked: bool) -> bool {
+        let Some(rules) = IMAGE_BLOCK_RULES.with(|cell| cell.borrow().clone()) else {
+            return false;
+        };
+
+        let config: *mut AnyObject = unsafe { msg_send![&*self.view, configuration] };
+        if config.is_null() {
+            return false;
+        }
+
+        let controller: *mut AnyObject = unsafe { msg_send![config, userContentController] };
+        if controller.is_null() {
+            return false;
+        }
+
+        unsafe {
+            if blocked {
+                let _: () = msg_send![controller, addContentRuleList: &*rules];
+            } else {
+                let _: () = msg_send![controller, removeContentRuleList: &*rules];
+            }
+        }
+
+        true
+    }
+
+    /// Allow or disallow the scripts of pages, taking effect with the next navigation.
+    ///
+    /// Scripts evaluated by Tabor itself keep working while pages can't run their own.
+    pub fn set_javascript_enabled(&mut self, enabled: bool) {
+        self.delegate_ivars().javascript_enabled.set(enabled);
+    }
+
+    /// Show the text composed by an input method at the caret of the focused element.
+    ///
+    /// The `cursor` is the byte range selected within the composed text, an empty `text` removes
+    /// the composition.
+    pub fn set_marked_text(&mut self, text: &str, cursor: Option<(usize, usize)>) {
+        let utf16_len = |text: &str| text.encode_utf16().count();
+        let selected = match cursor {
+            Some((start, end)) if start <= end && text.is_char_boundary(start) => {
+                let length = text.get(start..end).map_or(0, utf16_len);
+                NSRange::new(utf16_len(&text[..start]), length)
+            },
+            _ => NSRange::new(utf16_len(text), 0),
+        };
+        let replacement = NSRange::new(NSNotFound as usize, 0);
+        let text = NSString::from_str(text);
+
+        unsafe {
+            let _: () = msg_send![
+                &*self.view,
+                setMarkedText: &*text,
+                selectedRange: selected,
+                replacementRange: replacement
+            ];
+        }
+    }
+
+    /// Insert the text committed by an input method, replacing its composition.
+    pub fn insert_text(&mut self, text: &str) {
+        let replacement = NSRange::new(NSNotFound as usize, 0);
+        let text = NSString::from_str(text);
+
+        unsafe {
+            let _: () = msg_send![&*self.view, insertText: &*text, replacementRange: replacement];
+        }
+    }
+
+    /// Invert the colors of the page, including pages loaded later on.
+    pub fn set_dark_mode(&mut self, enabled: bool) -> bool {
+        if enabled == self.dark_mode {
+            return true;
+        }
+
+        let Some(controller) = user_content_controller(&self.view) else {
+            return false;
+        };
+
+        if enabled {
+            if !add_user_script(&controller, DARK_MODE_JS) {
+                return false;
+            }
+            self.exec_js(DARK_MODE_JS);
+        } else {
+            self.dark_mode = false;
+            self.reset_user_scripts(&controller);
+            self.exec_js(DARK_MODE_REMOVE_JS);
+        }
+
+        self.dark_mode = enabled;
+        true
+    }
+
+    /// Unpin fixed elements and hide banners of the page, including pages loaded later on.
+    pub fn set_focus_mode(&mut self, enabled: bool, selectors: &[String]) -> bool {
+        if enabled == self.focus_mode.is_some() {
+            return true;
+        }
+
+        let Some(controller) = user_content_controller(&self.view) else {
+            return false;
+        };
+
+        if enabled {
+            let script = focus_mode_script(selectors);
+            if !add_user_script(&controller, &script) {
+                return false;
+            }
+            self.exec_js(&script);
+            self.focus_mode = Some(script);
+        } else {
+            self.focus_mode = None;
+            self.reset_user_scripts(&controller);
+            self.exec_js(FOCUS_MODE_REMOVE_JS);
+        }
+
+        true
+    }
+
+    /// Add a stylesheet to the page and all pages loaded later on, returning its handle.
+    pub fn inject_css(&mut self, css: &str) -> Option<u64> {
+        let controller = user_content_controller(&self.view)?;
+
+        let handle = self.next_css_handle;
+        let script = user_css_script(handle, css);
+        if !add_user_script(&controller, &script) {
+            return None;
+        }
+        self.exec_js(&script);
+
+        self.next_css_handle += 1;
+        self.user_css.push((handle, script));
+        Some(handle)
+    }
+
+    /// Remove a stylesheet added by [`Self::inject_css`], returning `false` for unknown handles.
+    pub fn remove_css(&mut self, handle: u64) -> bool {
+        let Some(index) = self.user_css.iter().position(|(id, _)| *id == handle) else {
+            return false;
+        };
+        self.user_css.remove(index);
+
+        if let Some(controller) = user_content_controller(&self.view) {
+            self.reset_user_scripts(&controller);
+        }
+        self.exec_js(&format!("document.getElementById('tabor-user-css-{handle}')?.remove();"));
+        true
+    }
+
+    /// Add back all user scripts which are still active.
+    ///
+    /// Scripts can't be removed individually, so they're all removed before.
+    fn reset_user_scripts(&self, controller: &AnyObject) {
+        unsafe {
+            let _: () = msg_send![controller, removeAllUserScripts];
+        }
+
+        add_user_script(controller, NOTIFICATION_JS);
+        if self.dark_mode {
+            add_user_script(controller, DARK_MODE_JS);
+        }
+        if let Some(script) = &self.focus_mode {
+            add_user_script(controller, script);
+        }
+        for (_, script) in &self.user_css {
+            add_user_script(controller, script);
+        }
+    }
+
     pub fn reload(&mut self) {
         unsafe {
             let _: () = msg_send![&*self.view, reload];
@@ -691,6 +1624,75 @@ impl WebView {
         true
     }
 
+    /// Send a synthetic key press to the page.
+    pub fn send_key(
+        &mut self,
+        window: &Window,
+        key: WebKey,
+        text: &str,
+        modifiers: NSEventModifierFlags,
+    ) -> bool {
+        let ns_view = match window.raw_window_handle() {
+            RawWindowHandle::AppKit(handle) => handle.ns_view.as_ptr() as *mut AnyObject,
+            _ => return false,
+        };
+        if ns_view.is_null() {
+            return false;
+        }
+
+        let ns_window: *mut AnyObject = unsafe { msg_send![ns_view, window] };
+        if ns_window.is_null() {
+            return false;
+        }
+        let window_number: NSInteger = unsafe { msg_send![ns_window, windowNumber] };
+
+        // Characters and virtual key codes AppKit reports for these keys.
+        let (characters, key_code) = match key {
+            WebKey::Escape => ("\u{1b}", 0x35),
+            WebKey::Enter => ("\r", 0x24),
+            WebKey::Backspace => ("\u{7f}", 0x33),
+            WebKey::Delete => ("\u{f728}", 0x75),
+            WebKey::Tab => ("\t", 0x30),
+            WebKey::ArrowLeft => ("\u{f702}", 0x7b),
+            WebKey::ArrowRight => ("\u{f703}", 0x7c),
+            WebKey::ArrowDown => ("\u{f701}", 0x7d),
+            WebKey::ArrowUp => ("\u{f700}", 0x7e),
+            WebKey::Other => (text, 0),
+        };
+        let characters = NSString::from_str(characters);
+
+        unsafe {
+            let _: Bool = msg_send![ns_window, makeFirstResponder: &*self.view];
+        }
+
+        for event_type in [NSEventType::KeyDown, NSEventType::KeyUp] {
+            let Some(event) = NSEvent::keyEventWithType_location_modifierFlags_timestamp_windowNumber_context_characters_charactersIgnoringModifiers_isARepeat_keyCode(
+                event_type,
+                NSPoint::new(0.0, 0.0),
+                modifiers,
+                0.0,
+                window_number,
+                None,
+                &characters,
+                &characters,
+                false,
+                key_code,
+            ) else {
+                return false;
+            };
+
+            unsafe {
+                if event_type == NSEventType::KeyDown {
+                    let _: () = msg_send![&*self.view, keyDown: &*event];
+                } else {
+                    let _: () = msg_send![&*self.view, keyUp: &*event];
+                }
+            }
+        }
+
+        true
+    }
+
     pub fn exec_js(&mut self, script: &str) {
         self.eval_js_string(script, |_| {});
     }
@@ -699,45 +1701,42 @@ impl WebView {
     where
         F: FnOnce(Option<String>) + 'static,
     {
-        let _mtm = MainThreadMarker::new().expect("WebView JS requires main thread");
-        let script = NSString::from_str(script);
-        let callback = Rc::new(RefCell::new(Some(callback)));
-        let block = RcBlock::new({
-            let callback = Rc::clone(&callback);
-            move |result: *mut AnyObject, error: *mut AnyObject| {
-                let Some(callback) = callback.borrow_mut().take() else {
-                    return;
-                };
+        eval_js_string(&self.view, script, callback);
+    }
 
-                if !error.is_null() {
-                    let error_desc: *mut AnyObject = unsafe { msg_send![error, description] };
-                    if !error_desc.is_null() {
-                        let error_str = unsafe { &*(error_desc as *const NSString) }.to_string();
-                        debug!("WebView JS error: {error_str}");
-                    }
-                    callback(None);
-                    return;
+    /// Show the page load progress as a bar at the top of the view, hiding it once done.
+    pub fn set_load_progress(&mut self, fraction: f64) {
+        if fraction >= 1. {
+            if let Some(progress_bar) = &self.progress_bar {
+                unsafe {
+                    let _: () = msg_send![&**progress_bar, setHidden: true];
                 }
+            }
+            return;
+        }
 
-                if result.is_null() {
-                    callback(None);
-                    return;
-                }
+        let bounds: CGRect = unsafe { msg_send![&*self.view, bounds] };
+        let flipped: Bool = unsafe { msg_send![&*self.view, isFlipped] };
+        let height = LOAD_PROGRESS_HEIGHT;
+        let y = if flipped.as_bool() { 0. } else { bounds.size.height - height };
+        let frame = CGRect {
+            origin: CGPoint { x: 0., y },
+            size: CGSize { width: bounds.size.width * fraction.max(0.) as CGFloat, height },
+        };
 
-                let desc: *mut AnyObject = unsafe { msg_send![result, description] };
-                if desc.is_null() {
-                    callback(None);
+        let progress_bar = match &self.progress_bar {
+            Some(progress_bar) => progress_bar,
+            None => {
+                let Some(progress_bar) = create_progress_bar(&self.view) else {
                     return;
-                }
-
-                let output = unsafe { &*(desc as *const NSString) }.to_string();
-                callback(Some(output));
-            }
-        });
+                };
+                self.progress_bar.insert(progress_bar)
+            },
+        };
 
         unsafe {
-            let _: () =
-                msg_send![&*self.view, evaluateJavaScript: &*script, completionHandler: &*block];
+            let _: () = msg_send![&**progress_bar, setFrame: frame];
+            let _: () = msg_send![&**progress_bar, setHidden: false];
         }
     }
 
@@ -776,8 +1775,207 @@ impl WebView {
         Some(url)
     }
 
+    pub fn title(&self) -> Option<String> {
+        let title: *mut AnyObject = unsafe { msg_send![&*self.view, title] };
+        if title.is_null() {
+            return None;
+        }
+
+        Some(unsafe { &*(title as *const NSString) }.to_string())
+    }
+
+    /// Render the page to a PDF document.
+    pub fn print_to_pdf<F>(&mut self, callback: F)
+    where
+        F: FnOnce(Result<Vec<u8>, String>) + 'static,
+    {
+        let _mtm = MainThreadMarker::new().expect("WebView PDF export requires main thread");
+        let callback = Rc::new(RefCell::new(Some(callback)));
+        let block = RcBlock::new({
+            let callback = Rc::clone(&callback);
+            move |data: *mut AnyObject, error: *mut AnyObject| {
+                let Some(callback) = callback.borrow_mut().take() else {
+                    return;
+                };
+
+                if !error.is_null() {
+                    let desc: *mut AnyObject = unsafe { msg_send![error, localizedDescription] };
+                    let message = if desc.is_null() {
+                        String::from("Unknown error")
+                    } else {
+                        unsafe { &*(desc as *const NSString) }.to_string()
+                    };
+                    callback(Err(message));
+                    return;
+                }
+
+                if data.is_null() {
+                    callback(Err(String::from("No PDF data")));
+                    return;
+                }
+
+                let length: usize = unsafe { msg_send![data, length] };
+                let bytes: *const c_void = unsafe { msg_send![data, bytes] };
+                if length == 0 || bytes.is_null() {
+                    callback(Err(String::from("No PDF data")));
+                    return;
+                }
+
+                let pdf = unsafe { std::slice::from_raw_parts(bytes as *const u8, length) };
+                callback(Ok(pdf.to_vec()));
+            }
+        });
+
+        unsafe {
+            let configuration: *mut AnyObject = ptr::null_mut();
+            let _: () = msg_send![
+                &*self.view,
+                createPDFWithConfiguration: configuration,
+                completionHandler: &*block
+            ];
+        }
+    }
+
+    /// Connection security of the loaded page.
+    pub fn security_info(&self) -> WebSecurityInfo {
+        let url = self.current_url().and_then(|url| Url::parse(&url).ok());
+        let only_secure_content: Bool = unsafe { msg_send![&*self.view, hasOnlySecureContent] };
+        let trust: *mut SecTrust = unsafe { msg_send![&*self.view, serverTrust] };
+
+        WebSecurityInfo {
+            host: url.as_ref().and_then(|url| url.host_str()).map(String::from),
+            https: url.as_ref().is_some_and(|url| url.scheme() == "https"),
+            only_secure_content: only_secure_content.as_bool(),
+            certificates: certificate_summaries(trust),
+        }
+    }
+
     pub fn current_url(&self) -> Option<String> {
         let url: *mut AnyObject = unsafe { msg_send![&*self.view, URL] };
+        Self::absolute_url_string(url)
+    }
+
+    /// Remove the cookies, storage and caches of the sites `host` belongs to, or of all sites.
+    ///
+    /// WebKit does not report failed removals, so the records are fetched again afterwards. The
+    /// callback receives the number of sites whose data was removed, or why it was not removed.
+    pub fn clear_site_data<F>(&self, host: Option<String>, callback: F)
+    where
+        F: FnOnce(Result<usize, String>) + 'static,
+    {
+        let types: *mut AnyObject =
+            unsafe { msg_send![class!(WKWebsiteDataStore), allWebsiteDataTypes] };
+        let (Some(store), Some(types)) =
+            (website_data_store(&self.view), unsafe { Retained::retain(types) })
+        else {
+            callback(Err(String::from("Website data store is unavailable")));
+            return;
+        };
+
+        let callback = Rc::new(RefCell::new(Some(callback)));
+        let block = RcBlock::new({
+            let store = store.clone();
+            let types = types.clone();
+            move |records: *mut AnyObject| {
+                let Some(callback) = callback.borrow_mut().take() else {
+                    return;
+                };
+                if records.is_null() {
+                    callback(Err(String::from("Could not read site data")));
+                    return;
+                }
+
+                let matching = site_data_records(records, host.as_deref());
+                let removed: usize = unsafe { msg_send![matching, count] };
+
+                // Records which are still present after the removal could not be removed.
+                let callback = RefCell::new(Some(callback));
+                let verify = RcBlock::new({
+                    let host = host.clone();
+                    move |records: *mut AnyObject| {
+                        let Some(callback) = callback.borrow_mut().take() else {
+                            return;
+                        };
+                        let kept: usize = if records.is_null() {
+                            0
+                        } else {
+                            let kept = site_data_records(records, host.as_deref());
+                            unsafe { msg_send![kept, count] }
+                        };
+                        match kept {
+                            0 => callback(Ok(removed)),
+                            kept => callback(Err(format!("Could not clear data of {kept} sites"))),
+                        }
+                    }
+                });
+                let done = RcBlock::new({
+                    let store = store.clone();
+                    let types = types.clone();
+                    move || unsafe {
+                        let _: () = msg_send![
+                            &*store,
+                            fetchDataRecordsOfTypes: &*types,
+                            completionHandler: &*verify
+                        ];
+                    }
+                });
+                unsafe {
+                    let _: () = msg_send![
+                        &*store,
+                        removeDataOfTypes: &*types,
+                        forDataRecords: matching,
+                        completionHandler: &*done
+                    ];
+                }
+            }
+        });
+
+        unsafe {
+            let _: () = msg_send![
+                &*store,
+                fetchDataRecordsOfTypes: &*types,
+                completionHandler: &*block
+            ];
+        }
+    }
+
+    /// Check if the browsing data of the page is discarded with the web view.
+    pub fn is_private(&self) -> bool {
+        uses_private_store(&self.view)
+    }
+
+    /// URL which will be loaded by [`Self::go_back`].
+    pub fn back_url(&self) -> Option<String> {
+        let list: *mut AnyObject = unsafe { msg_send![&*self.view, backForwardList] };
+        if list.is_null() {
+            return None;
+        }
+
+        let item: *mut AnyObject = unsafe { msg_send![list, backItem] };
+        Self::history_item_url(item)
+    }
+
+    /// URL which will be loaded by [`Self::go_forward`].
+    pub fn forward_url(&self) -> Option<String> {
+        let list: *mut AnyObject = unsafe { msg_send![&*self.view, backForwardList] };
+        if list.is_null() {
+            return None;
+        }
+
+        let item: *mut AnyObject = unsafe { msg_send![list, forwardItem] };
+        Self::history_item_url(item)
+    }
+
+    fn history_item_url(item: *mut AnyObject) -> Option<String> {
+        if item.is_null() {
+            return None;
+        }
+
+        let url: *mut AnyObject = unsafe { msg_send![item, URL] };
+        Self::absolute_url_string(url)
+    }
+
+    fn absolute_url_string(url: *mut AnyObject) -> Option<String> {
         if url.is_null() {
             return None;
         }
@@ -802,6 +2000,32 @@ impl WebView {
 
         true
     }
+
+    /// Show the inspector with its console selected.
+    ///
+    /// The console is selected through the private `_WKInspector` API. The remote inspector
+    /// protocol can enable the `Runtime` domain of a page, but it has no message selecting a panel
+    /// of the local inspector frontend, so it can't be used for this. Without `showConsole`, the
+    /// page gets a [`CONSOLE_OVERLAY_JS`] overlay instead and `false` is returned.
+    pub fn show_inspector_console(&mut self) -> bool {
+        let inspector: *mut AnyObject = unsafe { msg_send![&*self.view, _inspector] };
+        let responds = !inspector.is_null() && {
+            let responds: Bool =
+                unsafe { msg_send![inspector, respondsToSelector: sel!(showConsole)] };
+            responds.as_bool()
+        };
+
+        if !responds {
+            self.exec_js(CONSOLE_OVERLAY_JS);
+            return false;
+        }
+
+        unsafe {
+            let _: () = msg_send![inspector, showConsole];
+        }
+
+        true
+    }
 }
 
 fn enable_web_inspector(config: &AnyObject) -> Result<(), Box<dyn Error>> {
@@ -842,6 +2066,25 @@ fn enable_web_popups(config: &AnyObject) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+fn enable_web_notifications(config: &AnyObject) -> Result<(), Box<dyn Error>> {
+    let controller: *mut AnyObject = unsafe { msg_send![config, userContentController] };
+    let Some(controller) = (unsafe { controller.as_ref() }) else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "WKWebViewConfiguration has no userContentController",
+        )
+        .into());
+    };
+
+    if !add_user_script(controller, NOTIFICATION_JS) {
+        return Err(
+            std::io::Error::new(std::io::ErrorKind::Other, "Failed to create WKUserScript").into()
+        );
+    }
+
+    Ok(())
+}
+
 // WebAuthn/passkeys are guarded by WebKit preferences; enable them explicitly.
 fn enable_web_authentication(config: &AnyObject) -> Result<(), Box<dyn Error>> {
     type WebAuthGet = unsafe extern "C" fn(*mut AnyObject) -> Bool;
@@ -892,8 +2135,67 @@ fn enable_web_authentication(config: &AnyObject) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Subject summaries of the certificates in a server trust, leaf first.
+fn certificate_summaries(trust: *mut SecTrust) -> Vec<String> {
+    if trust.is_null() {
+        return Vec::new();
+    }
+
+    let count = unsafe { SecTrustGetCertificateCount(trust) };
+    (0..count)
+        .filter_map(|index| {
+            let certificate = unsafe { SecTrustGetCertificateAtIndex(trust, index) };
+            if certificate.is_null() {
+                return None;
+            }
+
+            let summary = unsafe { SecCertificateCopySubjectSummary(certificate) };
+            if summary.is_null() {
+                return None;
+            }
+
+            // SAFETY: `CFStringRef` is toll-free bridged with `NSString`.
+            let text = unsafe { &*(summary as *const NSString) }.to_string();
+            unsafe { CFRelease(summary) };
+            Some(text)
+        })
+        .collect()
+}
+
+/// Height of the load progress bar in points.
+const LOAD_PROGRESS_HEIGHT: CGFloat = 2.;
+
+/// `NSBoxCustom`.
+const BOX_TYPE_CUSTOM: usize = 4;
+
+/// `NSNoTitle`.
+const BOX_NO_TITLE: usize = 0;
+
+/// Create the load progress bar as a borderless box filled with the accent color.
+fn create_progress_bar(view: &AnyObject) -> Option<Retained<AnyObject>> {
+    let frame = CGRect {
+        origin: CGPoint { x: 0., y: 0. },
+        size: CGSize { width: 0., height: LOAD_PROGRESS_HEIGHT },
+    };
+    let progress_bar: *mut AnyObject = unsafe { msg_send![class!(NSBox), alloc] };
+    let progress_bar: *mut AnyObject = unsafe { msg_send![progress_bar, initWithFrame: frame] };
+    let progress_bar = unsafe { Retained::from_raw(progress_bar) }?;
+
+    unsafe {
+        let color: *mut AnyObject = msg_send![class!(NSColor), controlAccentColor];
+        let _: () = msg_send![&*progress_bar, setBoxType: BOX_TYPE_CUSTOM];
+        let _: () = msg_send![&*progress_bar, setTitlePosition: BOX_NO_TITLE];
+        let _: () = msg_send![&*progress_bar, setBorderWidth: 0. as CGFloat];
+        let _: () = msg_send![&*progress_bar, setFillColor: color];
+        let _: () = msg_send![view, addSubview: &*progress_bar];
+    }
+
+    Some(progress_bar)
+}
+
 impl Drop for WebView {
     fn drop(&mut self) {
+        unobserve_load_progress(&self.view, &self.delegate);
         unregister_webview_tab(&self.view);
         unsafe {
             let _: () = msg_send![&*self.view, removeFromSuperview];
@@ -59,10 +59,13 @@ mod gl {
 
 #[cfg(unix)]
 use crate::cli::{
-    MessageOptions, MsgCloseTab, MsgCreateGroup, MsgCreateTab, MsgDispatchAction, MsgGetTabState,
-    MsgInspector, MsgInspectorAttach, MsgInspectorDetach, MsgInspectorPoll, MsgInspectorSend,
-    MsgMoveTab, MsgOpenInspector, MsgOpenUrl, MsgReloadWeb, MsgRunCommandBar, MsgSelectTab,
-    MsgSendInput, MsgSetGroupName, MsgSetTabPanel, MsgSetTabTitle, MsgSetWebUrl, TabIdArg,
+    MessageOptions, MsgCloseTab, MsgCreateGroup, MsgCreateTab, MsgDispatchAction, MsgGetPageText,
+    MsgGetScroll, MsgGetTabState, MsgGetTypingStatus, MsgInjectCss, MsgInspector,
+    MsgInspectorAttach, MsgInspectorDetach, MsgInspectorPoll, MsgInspectorSend, MsgMoveTab,
+    MsgMoveTabToNewWindow, MsgOpenInspector, MsgOpenUrl, MsgReloadWeb, MsgRemoveCss,
+    MsgRunCommandBar, MsgSelectTab, MsgSendInput, MsgSendInputTyped, MsgSendKey, MsgSession,
+    MsgSetGroupName, MsgSetScroll, MsgSetTabPanel, MsgSetTabTitle, MsgSetWebUrl, MsgSetWindowTitle,
+    TabIdArg,
 };
 #[cfg(unix)]
 use crate::cli::WindowOptions;
@@ -96,12 +99,37 @@ fn main() -> Result<(), Box<dyn Error>> {
         #[cfg(unix)]
         Some(Subcommands::Msg(options)) => msg(options)?,
         Some(Subcommands::Migrate(options)) => migrate::migrate(options),
+        #[cfg(unix)]
+        None if options.new_tab && create_tab_in_running_instance(&options)? => (),
         None => tabor(options)?,
     }
 
     Ok(())
 }
 
+/// Create a tab in the focused window of a running instance.
+///
+/// Returns `false` when no instance could be reached.
+#[cfg(unix)]
+fn create_tab_in_running_instance(options: &Options) -> Result<bool, Box<dyn Error>> {
+    let mut window_options = options.window_options.clone();
+
+    // Resolve the working directory here, since the instance might run in another directory.
+    let working_directory = &mut window_options.terminal_options.working_directory;
+    *working_directory = match working_directory.take() {
+        Some(path) => Some(std::path::absolute(path)?),
+        None => env::current_dir().ok(),
+    };
+
+    let request =
+        ipc::IpcRequest::CreateTab { options: window_options, group_id: None, group_name: None };
+    match ipc::send_message(options.socket.clone(), request) {
+        Ok(Some(ipc::SocketReply::Error { error })) => Err(error.message.into()),
+        Ok(_) => Ok(true),
+        Err(_) => Ok(false),
+    }
+}
+
 /// `msg` subcommand entrypoint.
 #[cfg(unix)]
 #[allow(unused_mut)]
@@ -237,6 +265,14 @@ fn msg(mut options: MessageOptions) -> Result<(), Box<dyn Error>> {
                 },
             )?;
         },
+        crate::cli::MessageCommand::MoveTabToNewWindow(MsgMoveTabToNewWindow { tab_id }) => {
+            send_request(
+                &socket,
+                ipc::IpcRequest::MoveTabToNewWindow {
+                    tab_id: tab_id.map(ipc_tab_id),
+                },
+            )?;
+        },
         crate::cli::MessageCommand::SetTabTitle(MsgSetTabTitle { tab_id, title, clear }) => {
             let title = if clear { None } else { title };
             send_request(
@@ -254,9 +290,16 @@ fn msg(mut options: MessageOptions) -> Result<(), Box<dyn Error>> {
                 ipc::IpcRequest::SetGroupName { group_id, name },
             )?;
         },
+        crate::cli::MessageCommand::SetWindowTitle(MsgSetWindowTitle { title, clear }) => {
+            let title = if clear { None } else { title };
+            send_request(&socket, ipc::IpcRequest::SetWindowTitle { title })?;
+        },
         crate::cli::MessageCommand::RestoreClosedTab => {
             send_request(&socket, ipc::IpcRequest::RestoreClosedTab)?;
         },
+        crate::cli::MessageCommand::GetClosedTabs => {
+            send_request(&socket, ipc::IpcRequest::GetClosedTabs)?;
+        },
         crate::cli::MessageCommand::OpenUrl(MsgOpenUrl { url, new_tab, tab_id }) => {
             let target = if new_tab {
                 ipc::UrlTarget::NewTab
@@ -292,6 +335,50 @@ fn msg(mut options: MessageOptions) -> Result<(), Box<dyn Error>> {
                 },
             )?;
         },
+        crate::cli::MessageCommand::GetScroll(MsgGetScroll { tab_id }) => {
+            send_request(
+                &socket,
+                ipc::IpcRequest::GetScroll {
+                    tab_id: tab_id.map(ipc_tab_id),
+                },
+            )?;
+        },
+        crate::cli::MessageCommand::GetPageText(MsgGetPageText { tab_id }) => {
+            send_request(
+                &socket,
+                ipc::IpcRequest::GetPageText {
+                    tab_id: tab_id.map(ipc_tab_id),
+                },
+            )?;
+        },
+        crate::cli::MessageCommand::SetScroll(MsgSetScroll { x, y, tab_id }) => {
+            send_request(
+                &socket,
+                ipc::IpcRequest::SetScroll {
+                    tab_id: tab_id.map(ipc_tab_id),
+                    x,
+                    y,
+                },
+            )?;
+        },
+        crate::cli::MessageCommand::InjectCss(MsgInjectCss { css, tab_id }) => {
+            send_request(
+                &socket,
+                ipc::IpcRequest::InjectCss {
+                    tab_id: tab_id.map(ipc_tab_id),
+                    css,
+                },
+            )?;
+        },
+        crate::cli::MessageCommand::RemoveCss(MsgRemoveCss { handle, tab_id }) => {
+            send_request(
+                &socket,
+                ipc::IpcRequest::RemoveCss {
+                    tab_id: tab_id.map(ipc_tab_id),
+                    handle,
+                },
+            )?;
+        },
         crate::cli::MessageCommand::GetTabPanel => {
             send_request(&socket, ipc::IpcRequest::GetTabPanel)?;
         },
@@ -362,6 +449,33 @@ fn msg(mut options: MessageOptions) -> Result<(), Box<dyn Error>> {
                 },
             )?;
         },
+        crate::cli::MessageCommand::SendInputTyped(MsgSendInputTyped {
+            text,
+            delay_ms,
+            tab_id,
+        }) => {
+            send_request(
+                &socket,
+                ipc::IpcRequest::SendInputTyped {
+                    tab_id: tab_id.map(ipc_tab_id),
+                    text,
+                    delay_ms,
+                },
+            )?;
+        },
+        crate::cli::MessageCommand::GetTypingStatus(MsgGetTypingStatus { typing_id }) => {
+            send_request(&socket, ipc::IpcRequest::GetTypingStatus { typing_id })?;
+        },
+        crate::cli::MessageCommand::SendKey(MsgSendKey { key, modifiers, tab_id }) => {
+            send_request(
+                &socket,
+                ipc::IpcRequest::SendKey {
+                    tab_id: tab_id.map(ipc_tab_id),
+                    key,
+                    modifiers,
+                },
+            )?;
+        },
         crate::cli::MessageCommand::RunCommandBar(MsgRunCommandBar { input, tab_id }) => {
             send_request(
                 &socket,
@@ -375,12 +489,13 @@ fn msg(mut options: MessageOptions) -> Result<(), Box<dyn Error>> {
             MsgInspector::ListTargets => {
                 send_request(&socket, ipc::IpcRequest::ListInspectorTargets)?;
             },
-            MsgInspector::Attach(MsgInspectorAttach { tab_id, target_id }) => {
+            MsgInspector::Attach(MsgInspectorAttach { tab_id, target_id, domains }) => {
                 send_request(
                     &socket,
                     ipc::IpcRequest::AttachInspector {
                         tab_id: tab_id.map(ipc_tab_id),
                         target_id,
+                        domains,
                     },
                 )?;
             },
@@ -393,10 +508,10 @@ fn msg(mut options: MessageOptions) -> Result<(), Box<dyn Error>> {
                     ipc::IpcRequest::SendInspectorMessage { session_id, message },
                 )?;
             },
-            MsgInspector::Poll(MsgInspectorPoll { session_id, max }) => {
+            MsgInspector::Poll(MsgInspectorPoll { session_id, max, timeout_ms }) => {
                 send_request(
                     &socket,
-                    ipc::IpcRequest::PollInspectorMessages { session_id, max },
+                    ipc::IpcRequest::PollInspectorMessages { session_id, max, timeout_ms },
                 )?;
             },
         },
@@ -413,6 +528,20 @@ fn msg(mut options: MessageOptions) -> Result<(), Box<dyn Error>> {
             }
             println!("\nSee docs/ipc.md for full request schemas and examples.");
         },
+        crate::cli::MessageCommand::Subscribe => {
+            for line in ipc::subscribe(socket)? {
+                println!("{}", line?);
+            }
+        },
+        // Paths are resolved by the client, since Tabor might run in another directory.
+        crate::cli::MessageCommand::SaveSession(MsgSession { path }) => {
+            let path = std::path::absolute(path)?;
+            send_request(&socket, ipc::IpcRequest::SaveSession { path })?;
+        },
+        crate::cli::MessageCommand::RestoreSession(MsgSession { path }) => {
+            let path = std::path::absolute(path)?;
+            send_request(&socket, ipc::IpcRequest::RestoreSession { path })?;
+        },
     }
 
     Ok(())
@@ -454,6 +583,8 @@ fn tabor(mut options: Options) -> Result<(), Box<dyn Error>> {
 
     #[cfg(target_os = "macos")]
     macos::register_open_documents_handler(window_event_loop.create_proxy());
+    #[cfg(target_os = "macos")]
+    macos::register_notification_handler(window_event_loop.create_proxy());
 
     // Initialize the logger as soon as possible as to capture output from other subsystems.
     let log_file = logging::initialize(&options, window_event_loop.create_proxy())
@@ -27,6 +27,9 @@ pub enum MessageType {
 
     /// A message represents a warning.
     Warning,
+
+    /// A message carries information, like command output.
+    Info,
 }
 
 impl Message {
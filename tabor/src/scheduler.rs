@@ -7,6 +7,8 @@ use winit::event_loop::EventLoopProxy;
 use winit::window::WindowId;
 
 use crate::event::Event;
+#[cfg(target_os = "macos")]
+use crate::tabs::TabId;
 
 /// ID uniquely identifying a timer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -31,7 +33,15 @@ pub enum Topic {
     ForegroundProcess,
     TabActivityTick,
     WebCursor,
+    WebScroll,
+    ClipboardPoll,
     Frame,
+    #[cfg(not(windows))]
+    ShellShutdown,
+    #[cfg(unix)]
+    TypedInput,
+    #[cfg(target_os = "macos")]
+    WebAutoReload(TabId),
 }
 
 /// Event scheduled to be emitted at a specific time.
@@ -98,6 +108,11 @@ impl Scheduler {
         self.timers.remove(index)
     }
 
+    /// Cancel all scheduled events of a topic.
+    pub fn unschedule_topic(&mut self, topic: Topic) {
+        self.timers.retain(|timer| timer.id.topic != topic);
+    }
+
     /// Check if a timer is already scheduled.
     pub fn scheduled(&mut self, id: TimerId) -> bool {
         self.timers.iter().any(|timer| timer.id == id)
@@ -107,7 +122,21 @@ impl Scheduler {
     ///
     /// This must be called when a window is removed to ensure that timers on intervals do not
     /// stick around forever and cause a memory leak.
+    ///
+    /// Clipboard polling isn't bound to any window and is kept. Pending shell shutdowns are kept
+    /// too, since the shells of a window's tabs are terminated when it's closed.
     pub fn unschedule_window(&mut self, window_id: WindowId) {
-        self.timers.retain(|timer| timer.id.window_id != window_id);
+        self.timers.retain(|timer| {
+            if timer.id.topic == Topic::ClipboardPoll {
+                return true;
+            }
+
+            #[cfg(not(windows))]
+            if timer.id.topic == Topic::ShellShutdown {
+                return true;
+            }
+
+            timer.id.window_id != window_id
+        });
     }
 }
@@ -1,5 +1,6 @@
 use std::time::{Duration, Instant};
 
+use crate::display::color::Rgb;
 use crate::tabs::TabId;
 use crate::window_kind::TabKind;
 
@@ -41,6 +42,16 @@ pub const TAB_ACTIVITY_TICK_INTERVAL: Duration = Duration::from_millis(500);
 pub struct TabActivity {
     pub last_output: Option<Instant>,
     pub has_unseen_output: bool,
+    pub has_unseen_bell: bool,
+    pub has_unseen_title: bool,
+}
+
+/// Kind of activity which happened in a tab since it was last seen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TabActivityKind {
+    Output,
+    Bell,
+    Title,
 }
 
 impl TabActivity {
@@ -49,8 +60,33 @@ impl TabActivity {
         self.has_unseen_output = !seen;
     }
 
+    pub fn note_bell(&mut self, seen: bool) {
+        self.has_unseen_bell |= !seen;
+    }
+
+    pub fn note_title(&mut self, seen: bool) {
+        self.has_unseen_title |= !seen;
+    }
+
     pub fn mark_seen(&mut self) {
         self.has_unseen_output = false;
+        self.has_unseen_bell = false;
+        self.has_unseen_title = false;
+    }
+
+    /// Most important kind of unseen activity.
+    ///
+    /// Shells often set the title at every prompt, so title changes never hide other activity.
+    pub fn unseen(&self) -> Option<TabActivityKind> {
+        if self.has_unseen_bell {
+            Some(TabActivityKind::Bell)
+        } else if self.has_unseen_output {
+            Some(TabActivityKind::Output)
+        } else if self.has_unseen_title {
+            Some(TabActivityKind::Title)
+        } else {
+            None
+        }
     }
 
     pub fn is_active(&self, now: Instant) -> bool {
@@ -63,9 +99,19 @@ impl TabActivity {
 pub struct TabPanelTab {
     pub tab_id: TabId,
     pub title: String,
+    /// Accent color marking the tab.
+    pub color: Option<Rgb>,
     pub is_active: bool,
     pub kind: TabKind,
     pub activity: Option<TabActivity>,
+    /// Start of the currently running foreground command.
+    pub command_start: Option<Instant>,
+    /// Whether the tab receives broadcast input.
+    pub broadcast: bool,
+    /// Whether the web tab keeps its browsing data in memory only.
+    pub private: bool,
+    /// Interval at which the web tab reloads itself.
+    pub auto_reload: Option<Duration>,
     #[cfg(target_os = "macos")]
     pub favicon: Option<TabFavicon>,
 }
@@ -93,3 +139,32 @@ pub enum TabPanelCommand {
     RenameTab(TabId),
     RenameGroup(usize),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unseen_activity_priority() {
+        let mut activity = TabActivity::default();
+        assert_eq!(activity.unseen(), None);
+
+        activity.note_title(false);
+        assert_eq!(activity.unseen(), Some(TabActivityKind::Title));
+
+        activity.note_output(Instant::now(), false);
+        assert_eq!(activity.unseen(), Some(TabActivityKind::Output));
+
+        activity.note_bell(false);
+        assert_eq!(activity.unseen(), Some(TabActivityKind::Bell));
+
+        activity.mark_seen();
+        assert_eq!(activity.unseen(), None);
+
+        // Activity in the visible tab is not badged.
+        activity.note_bell(true);
+        activity.note_title(true);
+        activity.note_output(Instant::now(), true);
+        assert_eq!(activity.unseen(), None);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::display::color::Rgb;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct TabId {
     pub index: u32,
@@ -20,4 +22,33 @@ pub enum TabCommand {
     SelectPrevious,
     SelectIndex(usize),
     SelectLast,
+    /// Step back through tabs in most recently used order, forward for negative steps.
+    ///
+    /// Repeated steps continue from the last selected tab, the order is only updated once all
+    /// modifiers are released.
+    SelectMru(isize),
+    SetColor(TabId, Option<Rgb>),
+    /// Show a tab next to the active tab, a new terminal tab is created without an index.
+    Split(Option<usize>),
+    CloseSplit,
+    FocusNextPane,
+    SwapPanes,
+    /// Grow the focused pane by the given percentage of the window.
+    ResizePane(i8),
+    /// Move a tab into a new window, the active tab is moved without an id.
+    MoveToNewWindow(Option<TabId>),
+    Broadcast(BroadcastCommand),
+    ToggleTabPanel,
+}
+
+/// Change to the set of terminal tabs receiving typed input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BroadcastCommand {
+    /// Send input to all terminal tabs of the window.
+    All,
+    /// Add or remove the tab at an index, sending input only to the selected tabs.
+    Toggle(usize),
+    /// Send input to the terminal tabs in the active tab's group.
+    Group,
+    Off,
 }
@@ -1,9 +1,27 @@
-pub fn normalize_web_url(input: &str) -> String {
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+use log::debug;
+use url::{Url, form_urlencoded};
+
+#[cfg(target_os = "macos")]
+use crate::cli::shell_escape_arg;
+use crate::logging::LOG_TARGET_WEB;
+use crate::tabs::TabId;
+
+/// Placeholder for the query in search engine URL templates.
+const SEARCH_QUERY_PLACEHOLDER: &str = "%s";
+
+pub fn normalize_web_url(input: &str, search_engines: &HashMap<String, String>) -> String {
     let trimmed = input.trim();
     if trimmed.is_empty() {
         return String::new();
     }
 
+    if let Some(url) = expand_search_keyword(trimmed, search_engines) {
+        return url;
+    }
+
     if trimmed.contains("://")
         || trimmed.starts_with("about:")
         || trimmed.starts_with("file:")
@@ -16,6 +34,72 @@ pub fn normalize_web_url(input: &str) -> String {
     format!("{scheme}://{trimmed}")
 }
 
+/// Check if two URLs point to the same page, ignoring fragments and trailing slashes.
+pub fn same_page_url(a: &str, b: &str) -> bool {
+    fn page(url: &str) -> &str {
+        let url = url.split_once('#').map_or(url, |(page, _)| page);
+        url.trim_end_matches('/')
+    }
+
+    page(a.trim()) == page(b.trim())
+}
+
+/// Remove query parameters matching any of `patterns` from an http(s) URL.
+///
+/// Patterns match parameter names exactly, or by prefix when they end with `*`.
+pub fn strip_query_params(url: String, patterns: &[String]) -> String {
+    if patterns.is_empty() {
+        return url;
+    }
+
+    let Ok(mut parsed) = Url::parse(&url) else {
+        return url;
+    };
+    if !matches!(parsed.scheme(), "http" | "https") || parsed.query().is_none() {
+        return url;
+    }
+
+    let matches = |name: &str| {
+        patterns.iter().any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => name.starts_with(prefix),
+            None => name == pattern,
+        })
+    };
+
+    let pairs = parsed.query_pairs().into_owned().collect::<Vec<_>>();
+    let kept = pairs.iter().filter(|(name, _)| !matches(name)).collect::<Vec<_>>();
+    if kept.len() == pairs.len() {
+        return url;
+    }
+
+    if kept.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(kept);
+    }
+    parsed.into()
+}
+
+/// Fill the `%s` placeholders of a shell command template with the quoted `url`.
+#[cfg(target_os = "macos")]
+pub fn url_shell_command(template: &str, url: &str) -> String {
+    template.replace("%s", &shell_escape_arg(url))
+}
+
+/// Expand search engine keywords like `g rust` using the engine's URL template.
+fn expand_search_keyword(input: &str, search_engines: &HashMap<String, String>) -> Option<String> {
+    let (keyword, query) = input.split_once(char::is_whitespace)?;
+    let template = search_engines.get(keyword)?;
+
+    let query = query.trim();
+    if query.is_empty() {
+        return None;
+    }
+
+    let query = form_urlencoded::byte_serialize(query.as_bytes()).collect::<String>();
+    Some(template.replace(SEARCH_QUERY_PLACEHOLDER, &query))
+}
+
 fn is_local_host(input: &str) -> bool {
     let end = input.find(|c| matches!(c, '/' | '?' | '#')).unwrap_or(input.len());
     let mut host = &input[..end];
@@ -45,3 +129,123 @@ fn is_local_host(input: &str) -> bool {
 
     host.bytes().all(|b| b.is_ascii_digit() || b == b'.') && host.starts_with("127.")
 }
+
+/// Source of a web navigation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WebNavigationTrigger {
+    Omnibar,
+    Hint,
+    Clipboard,
+    Back,
+    Forward,
+    Mark,
+    LastUrl,
+    ViewSource,
+    ParentUrl,
+    NewTab,
+    Ipc,
+    External,
+    Drop,
+    MiddleClick,
+}
+
+impl Display for WebNavigationTrigger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let trigger = match self {
+            Self::Omnibar => "omnibar",
+            Self::Hint => "hint",
+            Self::Clipboard => "clipboard",
+            Self::Back => "back",
+            Self::Forward => "forward",
+            Self::Mark => "mark",
+            Self::LastUrl => "last_url",
+            Self::ViewSource => "view_source",
+            Self::ParentUrl => "parent_url",
+            Self::NewTab => "new_tab",
+            Self::Ipc => "ipc",
+            Self::External => "external",
+            Self::Drop => "drop",
+            Self::MiddleClick => "middle_click",
+        };
+        f.write_str(trigger)
+    }
+}
+
+/// Log a navigation of the web tab `tab_id`.
+pub fn log_web_navigation(
+    tab_id: TabId,
+    from: Option<&str>,
+    to: &str,
+    trigger: WebNavigationTrigger,
+) {
+    debug!(
+        target: LOG_TARGET_WEB,
+        "tab={}:{} from={} to={to} trigger={trigger}",
+        tab_id.index,
+        tab_id.generation,
+        from.unwrap_or("-"),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn engines() -> HashMap<String, String> {
+        HashMap::from([
+            (String::from("g"), String::from("https://www.google.com/search?q=%s")),
+            (String::from("gh"), String::from("https://github.com/search?q=%s")),
+        ])
+    }
+
+    #[test]
+    fn search_keyword() {
+        let engines = engines();
+        assert_eq!(normalize_web_url("gh rust", &engines), "https://github.com/search?q=rust");
+        assert_eq!(
+            normalize_web_url("g  tabs & windows ", &engines),
+            "https://www.google.com/search?q=tabs+%26+windows"
+        );
+    }
+
+    #[test]
+    fn unknown_keyword() {
+        let engines = engines();
+        assert_eq!(normalize_web_url("gh", &engines), "https://gh");
+        assert_eq!(normalize_web_url("example.org", &engines), "https://example.org");
+        assert_eq!(normalize_web_url("localhost:8080", &engines), "http://localhost:8080");
+    }
+
+    #[test]
+    fn strip_tracking_params() {
+        let patterns = vec![String::from("utm_*"), String::from("fbclid")];
+        let strip = |url: &str| strip_query_params(url.into(), &patterns);
+
+        assert_eq!(
+            strip("https://example.org/a?id=3&utm_source=x&fbclid=y#top"),
+            "https://example.org/a?id=3#top"
+        );
+        assert_eq!(strip("https://example.org/?utm_medium=x"), "https://example.org/");
+        assert_eq!(strip("https://example.org/?fbclid2=x"), "https://example.org/?fbclid2=x");
+        assert_eq!(strip("file:///tmp/a.html?utm_source=x"), "file:///tmp/a.html?utm_source=x");
+        assert_eq!(strip_query_params("https://a.b/?utm_x=1".into(), &[]), "https://a.b/?utm_x=1");
+    }
+
+    #[test]
+    #[cfg(target_os = "macos")]
+    fn shell_command_template() {
+        assert_eq!(
+            url_shell_command("curl -sSL %s | less", "https://example.org/?q=a&b=it's"),
+            r"curl -sSL 'https://example.org/?q=a&b=it'\''s' | less"
+        );
+        assert_eq!(url_shell_command("curl", "https://example.org"), "curl");
+    }
+
+    #[test]
+    fn same_page() {
+        assert!(same_page_url("https://example.org/", "https://example.org"));
+        assert!(same_page_url("https://example.org/docs#intro", "https://example.org/docs/"));
+        assert!(!same_page_url("https://example.org/docs", "https://example.org/doc"));
+        assert!(!same_page_url("https://example.org/?a=1", "https://example.org/?a=2"));
+    }
+}
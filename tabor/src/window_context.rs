@@ -6,19 +6,31 @@ use std::io::Write;
 use std::mem;
 #[cfg(not(windows))]
 use std::os::unix::io::{AsRawFd, RawFd};
+#[cfg(unix)]
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
-use std::time::Instant;
+#[cfg(unix)]
+use std::collections::VecDeque;
+#[cfg(unix)]
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 use glutin::config::Config as GlutinConfig;
 use glutin::display::GetGlDisplay;
 #[cfg(all(feature = "x11", not(any(target_os = "macos", windows))))]
 use glutin::platform::x11::X11GlConfigExt;
-use log::info;
+use log::{error, info, warn};
+#[cfg(target_os = "macos")]
+use objc2_app_kit::NSEventModifierFlags;
 #[cfg(target_os = "macos")]
 use serde::Deserialize;
 use serde_json as json;
-use winit::event::{Event as WinitEvent, Ime, Modifiers, WindowEvent};
+use url::Url;
+use winit::dpi::PhysicalPosition;
+#[cfg(target_os = "macos")]
+use winit::event::MouseButton;
+use winit::event::{ElementState, Event as WinitEvent, Ime, Modifiers, WindowEvent};
 use winit::event_loop::{ActiveEventLoop, EventLoopProxy};
 use winit::raw_window_handle::HasDisplayHandle;
 use winit::window::WindowId;
@@ -31,7 +43,7 @@ use tabor_terminal::grid::{Dimensions, Scroll};
 use tabor_terminal::index::Direction;
 use tabor_terminal::sync::FairMutex;
 use tabor_terminal::term::test::TermSize;
-use tabor_terminal::term::{Term, TermMode};
+use tabor_terminal::term::{Config as TermConfig, Term, TermMode};
 #[cfg(target_os = "macos")]
 use tabor_terminal::term::MIN_COLUMNS;
 use tabor_terminal::tty;
@@ -42,65 +54,133 @@ use crate::clipboard::Clipboard;
 #[cfg(unix)]
 use crate::config::Action;
 use crate::config::UiConfig;
+use crate::config::tabs::{NewTabPosition, SshProfile, TabRuleTarget};
+use crate::daemon::spawn_daemon;
 #[cfg(not(windows))]
-use crate::daemon::foreground_process_name;
-use crate::display::Display;
+use crate::daemon::{
+    foreground_process_args, foreground_process_name, foreground_process_path, ssh_destination,
+};
 use crate::display::color::Rgb;
 use crate::display::window::Window;
+use crate::display::{Display, SplitLayout, SplitPane};
 #[cfg(target_os = "macos")]
 use crate::display::{TabPanelEditOutcome, TabPanelEditTarget};
 use crate::event::{
     request_web_cursor_update, ActionContext, CommandHistory, CommandState, Event, EventProxy,
-    EventType, InlineSearchState, Mouse, SearchState, TouchPurpose,
+    EventType, InlineSearchState, Mouse, PipeTarget, SearchState, TouchPurpose, ViMarks,
+    WebTabRequest,
 };
 #[cfg(target_os = "macos")]
-use crate::event::WebCommand;
+use crate::event::{
+    WEB_HOVER_LINK_MESSAGE_TARGET, WEB_PASSTHROUGH_MESSAGE_TARGET, WebCommand,
+    web_hints_start_script,
+};
+use crate::input::macros::Macros;
 #[cfg(unix)]
 use crate::logging::LOG_TARGET_IPC_CONFIG;
 use crate::message_bar::MessageBuffer;
 #[cfg(unix)]
 use crate::ipc::{
-    IpcError, IpcErrorCode, IpcInspectorMessage, IpcInspectorSession, IpcInspectorTarget,
-    IpcTabActivity, IpcTabGroup, IpcTabKind, IpcTabPanelState, IpcTabState, TabSelection,
+    IpcError, IpcErrorCode, IpcEvent, IpcInspectorMessage, IpcInspectorSession, IpcInspectorTarget,
+    IpcScrollback, IpcSessionGroup, IpcSessionTab, IpcSessionWindow, IpcTabActivity, IpcTabGroup,
+    IpcTabKind, IpcTabPanelState, IpcTabState, TabSelection,
 };
 use crate::scheduler::Scheduler;
-use crate::tab_panel::TabActivity;
-use crate::tabs::TabId;
+#[cfg(not(windows))]
+use crate::scheduler::{TimerId, Topic};
+use crate::tab_panel::{TabActivity, TabActivityKind};
+use crate::tabs::{BroadcastCommand, TabId};
 use crate::window_kind::WindowKind;
 use crate::{input, renderer};
 
 #[cfg(target_os = "macos")]
-use crate::macos::web_commands::WebCommandState;
+use crate::macos::web_commands::{
+    WebCommandState, WebElementInfo, WebFormField, parse_web_key, parse_web_modifiers,
+};
 #[cfg(target_os = "macos")]
 use crate::macos::favicon::{fetch_favicon, resolve_favicon_url, FaviconImage};
 #[cfg(target_os = "macos")]
-use crate::macos::webview::{take_pending_popup, PendingPopup, WebView};
+use crate::macos::site_settings::{SiteSettings, site_host, site_settings};
+#[cfg(target_os = "macos")]
+use crate::config::web::{WebConfig, WebNewWindowLinks, user_agent_preset};
+#[cfg(target_os = "macos")]
+use crate::macos::webview::{
+    notification_permission_script, take_pending_popup, PendingPopup, WebView,
+};
 #[cfg(not(target_os = "macos"))]
 type PendingPopup = ();
 #[cfg(target_os = "macos")]
 use crate::macos::remote_inspector::{
-    match_tab_for_target, match_target_for_tab, InspectorError, InspectorTabInfo,
-    RemoteInspectorClient,
+    match_tab_for_target, match_target_for_tab, InspectorError, InspectorMessageSignal,
+    InspectorTabInfo, RemoteInspectorClient,
 };
 #[cfg(target_os = "macos")]
 use crate::tab_panel::TabFavicon;
+use crate::web_url::{log_web_navigation, same_page_url};
+#[cfg(target_os = "macos")]
+use crate::web_url::{WebNavigationTrigger, strip_query_params};
+
+/// Id of the next input typed by `send_input_typed`.
+#[cfg(unix)]
+static NEXT_TYPED_INPUT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Text typed into a tab one character at a time.
+#[cfg(unix)]
+struct TypedInput {
+    id: u64,
+    tab_id: TabId,
+    chars: VecDeque<char>,
+    delay: Duration,
+}
+
+/// Tab removed from its window with its PTY and web view still running.
+pub struct DetachedTab(Box<TabState>);
 
 struct TabState {
     id: TabId,
     title: String,
     custom_title: Option<String>,
+    /// Accent color set for the tab with `:tabcolor`.
+    color: Option<Rgb>,
+    /// Accent color of the matching SSH profile or first matching tab rule.
+    rule_color: Option<Rgb>,
+    /// Whether closing the tab requires confirmation.
+    protected: bool,
+    /// Whether the web view keeps its browsing data in memory only.
+    private: bool,
+    /// Interval at which the web view is reloaded, set with `:reload-every`.
+    auto_reload: Option<Duration>,
+    /// Host connected to with `:ssh`.
+    ssh_host: Option<String>,
+    /// Host of the `ssh` session running in the foreground.
+    remote_host: Option<String>,
+    /// Title set by the matching SSH profile, replaced only if the tab wasn't renamed since.
+    profile_title: Option<String>,
+    /// Input typed into the shell once it printed its first output.
+    startup_input: Option<String>,
+    /// Scrollback history lines of the matching SSH profile.
+    scrollback: Option<usize>,
+    /// Last blocked attempt to close the protected tab.
+    close_requested: Option<Instant>,
+    /// Tab focused once this tab is closed.
+    opener: Option<TabId>,
     program_name: String,
     kind: WindowKind,
     activity: TabActivity,
     terminal: Arc<FairMutex<Term<EventProxy>>>,
     notifier: Notifier,
+    /// Event proxy shared with the terminal and its PTY event loop.
+    event_proxy: EventProxy,
     search_state: SearchState,
     inline_search_state: InlineSearchState,
+    vi_marks: ViMarks,
     command_state: CommandState,
     mouse: Mouse,
     touch: TouchPurpose,
     cursor_blink_timed_out: bool,
+    cursor_color: Option<Rgb>,
     prev_bell_cmd: Option<Instant>,
+    running_command: Option<RunningCommand>,
     #[cfg(target_os = "macos")]
     web_view: Option<WebView>,
     #[cfg(target_os = "macos")]
@@ -109,15 +189,77 @@ struct TabState {
     favicon: Option<TabFavicon>,
     #[cfg(target_os = "macos")]
     favicon_pending: bool,
+    /// Host whose site settings were applied to the web view.
+    #[cfg(target_os = "macos")]
+    site_host: Option<String>,
     #[cfg(not(windows))]
     master_fd: RawFd,
     #[cfg(not(windows))]
     shell_pid: u32,
 }
 
+/// Time during which a repeated close of a protected tab is accepted.
+const CLOSE_CONFIRM_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Smallest share of the window columns given to a split pane.
+const MIN_SPLIT_RATIO: f32 = 0.1;
+
+/// Two tabs shown side by side.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Split {
+    left: TabId,
+    right: TabId,
+    /// Share of the columns given to the left pane.
+    ratio: f32,
+}
+
+impl Split {
+    fn contains(&self, tab_id: TabId) -> bool {
+        self.left == tab_id || self.right == tab_id
+    }
+
+    /// Pane next to `tab_id`.
+    fn other(&self, tab_id: TabId) -> Option<TabId> {
+        if self.left == tab_id {
+            Some(self.right)
+        } else if self.right == tab_id {
+            Some(self.left)
+        } else {
+            None
+        }
+    }
+}
+
+/// Terminal tabs receiving typed input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Broadcast {
+    All,
+    Tabs(Vec<TabId>),
+    /// Tabs in the group of the active tab.
+    Group,
+}
+
+/// Shell command reported through prompt marks.
+struct RunningCommand {
+    command: String,
+    start: Instant,
+}
+
+/// Maximum number of closed tabs which can be restored.
+#[cfg(target_os = "macos")]
+const MAX_CLOSED_TABS: usize = 25;
+
+/// Tab which was closed, with everything needed to restore it.
 #[cfg(target_os = "macos")]
 struct ClosedTab {
     kind: WindowKind,
+    title: String,
+    /// Name given to the tab by the user.
+    custom_title: Option<String>,
+    /// Working directory of the shell in terminal tabs.
+    working_directory: Option<PathBuf>,
+    /// Position of the tab in display order.
+    position: usize,
 }
 
 #[cfg(target_os = "macos")]
@@ -176,6 +318,11 @@ fn select_favicon_base(page_url: &str, base_uri: &str, referrer: &str) -> String
 }
 
 impl TabState {
+    /// Accent color of the tab, preferring the one set explicitly over rules.
+    fn accent_color(&self) -> Option<Rgb> {
+        self.color.or(self.rule_color)
+    }
+
     fn panel_title(&self) -> String {
         if let Some(custom_title) = &self.custom_title {
             return custom_title.clone();
@@ -204,6 +351,57 @@ struct TabGroup {
     tabs: Vec<TabId>,
 }
 
+/// Tabs in most recently used order, the active tab first.
+#[derive(Debug, Default)]
+struct TabMru {
+    order: Vec<TabId>,
+    /// Position in `order` while stepping through it.
+    cycle: Option<usize>,
+}
+
+impl TabMru {
+    /// Add a tab as the least recently used one.
+    fn push(&mut self, tab_id: TabId) {
+        if !self.order.contains(&tab_id) {
+            self.order.push(tab_id);
+        }
+    }
+
+    /// Move a tab to the front, unless the tabs are being stepped through.
+    fn touch(&mut self, tab_id: TabId) {
+        if self.cycle.is_none() {
+            self.order.retain(|id| *id != tab_id);
+            self.order.insert(0, tab_id);
+        }
+    }
+
+    fn remove(&mut self, tab_id: TabId) {
+        self.order.retain(|id| *id != tab_id);
+        self.cycle = None;
+    }
+
+    /// Step through the tabs without reordering them, backwards for negative steps.
+    fn step(&mut self, step: isize) -> Option<TabId> {
+        if self.order.len() < 2 {
+            return None;
+        }
+
+        let position = self.cycle.unwrap_or(0) as isize + step;
+        let index = position.rem_euclid(self.order.len() as isize) as usize;
+        self.cycle = Some(index);
+        self.order.get(index).copied()
+    }
+
+    /// Finish stepping through the tabs, moving the `active` tab to the front.
+    fn commit(&mut self, active: Option<TabId>) {
+        if self.cycle.take().is_some() {
+            if let Some(active) = active {
+                self.touch(active);
+            }
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum DrawMode {
     Terminal,
@@ -218,12 +416,22 @@ fn draw_mode(kind: &WindowKind) -> DrawMode {
     }
 }
 
+/// User agent of a web tab, preferring the one remembered for its site.
+#[cfg(target_os = "macos")]
+fn site_user_agent<'a>(config: &'a WebConfig, settings: &'a SiteSettings) -> Option<&'a str> {
+    match &settings.user_agent {
+        Some(user_agent) => user_agent_preset(user_agent),
+        None => config.user_agent(),
+    }
+}
+
 struct TabManager {
     slots: Vec<TabSlot>,
     free: Vec<usize>,
     active: Option<TabId>,
     groups: Vec<TabGroup>,
     next_group_id: usize,
+    mru: TabMru,
 }
 
 impl TabManager {
@@ -234,6 +442,7 @@ impl TabManager {
             active: None,
             groups: Vec::new(),
             next_group_id: 1,
+            mru: TabMru::default(),
         }
     }
 
@@ -254,6 +463,7 @@ impl TabManager {
         tab: TabState,
         group_id: Option<usize>,
         group_name: Option<String>,
+        position: NewTabPosition,
     ) -> Result<(), String> {
         if self.slots.len() <= tab_id.slot_index() {
             self.slots.resize_with(tab_id.slot_index() + 1, || TabSlot {
@@ -292,12 +502,23 @@ impl TabManager {
                 .unwrap_or(0)
         };
 
-        if !self.groups[target_index].tabs.contains(&tab_id) {
-            self.groups[target_index].tabs.push(tab_id);
+        let group = &mut self.groups[target_index];
+        if !group.tabs.contains(&tab_id) {
+            let active_index =
+                self.active.and_then(|active| group.tabs.iter().position(|id| *id == active));
+            let index = match (position, active_index) {
+                (NewTabPosition::Start, _) => 0,
+                (NewTabPosition::AfterCurrent, Some(active_index)) => active_index + 1,
+                (NewTabPosition::AfterCurrent, None) | (NewTabPosition::End, _) => group.tabs.len(),
+            };
+            group.tabs.insert(index, tab_id);
         }
 
+        self.mru.push(tab_id);
+
         if self.active.is_none() {
             self.active = Some(tab_id);
+            self.mru.touch(tab_id);
         }
         Ok(())
     }
@@ -337,9 +558,20 @@ impl TabManager {
         }
 
         self.active = Some(tab_id);
+        self.mru.touch(tab_id);
         true
     }
 
+    /// Step through the most recently used tabs, without reordering them.
+    fn select_mru(&mut self, step: isize) -> Option<TabId> {
+        self.mru.step(step)
+    }
+
+    /// Finish stepping through the most recently used tabs, moving the active tab to the front.
+    fn commit_mru(&mut self) {
+        self.mru.commit(self.active);
+    }
+
     fn iter(&self) -> impl Iterator<Item = &TabState> {
         self.slots.iter().filter_map(|slot| slot.tab.as_ref())
     }
@@ -371,8 +603,12 @@ impl TabManager {
         }
         self.prune_empty_groups();
 
+        self.mru.remove(tab_id);
         if self.active == Some(tab_id) {
             self.active = self.ordered_tabs().first().copied();
+            if let Some(active) = self.active {
+                self.mru.touch(active);
+            }
         }
 
         Some(tab)
@@ -469,6 +705,24 @@ impl TabManager {
         true
     }
 
+    /// Move a tab to a position in display order, joining the group of the tab it displaces.
+    #[cfg(target_os = "macos")]
+    fn move_to_position(&mut self, tab_id: TabId, position: usize) -> bool {
+        let tabs: Vec<_> = self.ordered_tabs().into_iter().filter(|id| *id != tab_id).collect();
+        let target = match tabs.get(position) {
+            Some(displaced) => self.group_for_tab(*displaced),
+            None => tabs
+                .last()
+                .and_then(|last| self.group_for_tab(*last))
+                .map(|(group_id, index)| (group_id, index + 1)),
+        };
+
+        match target {
+            Some((group_id, index)) => self.move_tab(tab_id, Some(group_id), Some(index)),
+            None => false,
+        }
+    }
+
     fn ordered_tabs(&self) -> Vec<TabId> {
         self.groups
             .iter()
@@ -477,6 +731,16 @@ impl TabManager {
             .collect()
     }
 
+    /// Display index of the first web tab showing the page at `url`.
+    fn web_tab_index(&self, url: &str) -> Option<usize> {
+        self.ordered_tabs().into_iter().position(|tab_id| {
+            self.get(tab_id).is_some_and(|tab| match &tab.kind {
+                WindowKind::Web { url: tab_url } => same_page_url(tab_url, url),
+                WindowKind::Terminal => false,
+            })
+        })
+    }
+
     fn set_title(&mut self, tab_id: TabId, title: String) -> bool {
         let Some(tab) = self.get_mut(tab_id) else {
             return false;
@@ -553,7 +817,7 @@ impl TabManager {
         true
     }
 
-    fn panel_groups(&self) -> Vec<crate::tab_panel::TabPanelGroup> {
+    fn panel_groups(&self, broadcast: &[TabId]) -> Vec<crate::tab_panel::TabPanelGroup> {
         let active = self.active;
         self.groups
             .iter()
@@ -570,6 +834,7 @@ impl TabManager {
                         self.get(*tab_id).map(|tab| crate::tab_panel::TabPanelTab {
                             tab_id: *tab_id,
                             title: tab.panel_title(),
+                            color: tab.accent_color(),
                             is_active: Some(*tab_id) == active,
                             kind: crate::window_kind::TabKind::from(&tab.kind),
                             activity: if tab.kind.is_web() {
@@ -577,6 +842,10 @@ impl TabManager {
                             } else {
                                 Some(tab.activity.clone())
                             },
+                            command_start: tab.running_command.as_ref().map(|cmd| cmd.start),
+                            broadcast: broadcast.contains(tab_id),
+                            private: tab.private,
+                            auto_reload: tab.auto_reload,
                             #[cfg(target_os = "macos")]
                             favicon: tab.favicon.clone(),
                         })
@@ -636,8 +905,16 @@ pub struct WindowContext {
     pub display: Display,
     pub dirty: bool,
     command_history: CommandHistory,
+    macros: Macros,
     event_queue: Vec<WinitEvent<Event>>,
     tabs: TabManager,
+    split: Option<Split>,
+    broadcast: Option<Broadcast>,
+    mouse_position: Option<PhysicalPosition<f64>>,
+    #[cfg(unix)]
+    ipc_events: Vec<IpcEvent>,
+    #[cfg(unix)]
+    typed_input: VecDeque<TypedInput>,
     #[cfg(target_os = "macos")]
     closed_tabs: Vec<ClosedTab>,
     #[cfg(target_os = "macos")]
@@ -650,7 +927,11 @@ pub struct WindowContext {
     occluded: bool,
     window_focused: bool,
     preserve_title: bool,
+    /// Window title before it was overridden over IPC.
+    overridden_title: Option<String>,
     window_config: ParsedOptions,
+    /// Theme selected with `:theme`, replacing the configured colors.
+    theme: Option<String>,
     config: Rc<UiConfig>,
 }
 
@@ -712,6 +993,51 @@ impl WindowContext {
         mut options: WindowOptions,
         config_overrides: ParsedOptions,
     ) -> Result<Self, Box<dyn Error>> {
+        let display = Self::additional_display(gl_config, event_loop, &config, &mut options)?;
+
+        let mut window_context = Self::new(display, config, options, proxy)?;
+
+        // Set the config overrides at startup.
+        //
+        // These are already applied to `config`, so no update is necessary.
+        window_context.window_config = config_overrides;
+
+        Ok(window_context)
+    }
+
+    /// Create additional context around a tab detached from another window.
+    ///
+    /// The tab is handed back if the window could not be created.
+    pub fn with_detached_tab(
+        gl_config: &GlutinConfig,
+        event_loop: &ActiveEventLoop,
+        proxy: &EventLoopProxy<Event>,
+        config: Rc<UiConfig>,
+        mut options: WindowOptions,
+        config_overrides: ParsedOptions,
+        tab: DetachedTab,
+    ) -> Result<(Self, TabId), (Box<dyn Error>, DetachedTab)> {
+        let display = match Self::additional_display(gl_config, event_loop, &config, &mut options) {
+            Ok(display) => display,
+            Err(err) => return Err((err, tab)),
+        };
+
+        let preserve_title = options.window_identity.title.is_some();
+        let mut window_context =
+            Self::with_tabs(display, config, TabManager::new(), preserve_title);
+        window_context.window_config = config_overrides;
+
+        let tab_id = window_context.attach_tab(tab, proxy).map_err(|(err, tab)| (err.into(), tab))?;
+        Ok((window_context, tab_id))
+    }
+
+    /// Create the display for an additional window.
+    fn additional_display(
+        gl_config: &GlutinConfig,
+        event_loop: &ActiveEventLoop,
+        config: &UiConfig,
+        options: &mut WindowOptions,
+    ) -> Result<Display, Box<dyn Error>> {
         let gl_display = gl_config.display();
 
         let mut identity = config.window.identity.clone();
@@ -722,9 +1048,9 @@ impl WindowContext {
 
         let window = Window::new(
             event_loop,
-            &config,
+            config,
             &identity,
-            &mut options,
+            options,
             #[cfg(all(feature = "x11", not(any(target_os = "macos", windows))))]
             gl_config.x11_visual(),
         )?;
@@ -734,16 +1060,7 @@ impl WindowContext {
         let gl_context =
             renderer::platform::create_gl_context(&gl_display, gl_config, Some(raw_window_handle))?;
 
-        let display = Display::new(window, gl_context, &config, tabbed)?;
-
-        let mut window_context = Self::new(display, config, options, proxy)?;
-
-        // Set the config overrides at startup.
-        //
-        // These are already applied to `config`, so no update is necessary.
-        window_context.window_config = config_overrides;
-
-        Ok(window_context)
+        Ok(Display::new(window, gl_context, config, tabbed)?)
     }
 
     /// Create a new terminal window context.
@@ -776,24 +1093,51 @@ impl WindowContext {
             pty_config,
             &proxy,
             options.window_kind,
+            options.private,
             None,
             None,
             None,
         )?;
 
-        // Create context for the Tabor window.
-        let mut context = WindowContext {
+        let mut context = Self::with_tabs(display, config, tabs, preserve_title);
+
+        #[cfg(unix)]
+        context.ipc_events.push(IpcEvent::TabCreated { tab_id: first_tab.into() });
+        context.set_active_tab(first_tab);
+        context.send_startup_input(first_tab, command_input);
+        context.refresh_tab_panel();
+        Ok(context)
+    }
+
+    /// Create context for the Tabor window.
+    fn with_tabs(
+        display: Display,
+        config: Rc<UiConfig>,
+        tabs: TabManager,
+        preserve_title: bool,
+    ) -> Self {
+        WindowContext {
             preserve_title,
+            overridden_title: None,
             display,
             config,
             message_buffer: Default::default(),
             command_history: Default::default(),
+            macros: Default::default(),
             window_config: Default::default(),
+            theme: Default::default(),
             event_queue: Default::default(),
             modifiers: Default::default(),
             occluded: Default::default(),
             window_focused: Default::default(),
             tabs,
+            split: Default::default(),
+            broadcast: Default::default(),
+            mouse_position: Default::default(),
+            #[cfg(unix)]
+            ipc_events: Default::default(),
+            #[cfg(unix)]
+            typed_input: Default::default(),
             #[cfg(target_os = "macos")]
             closed_tabs: Default::default(),
             #[cfg(target_os = "macos")]
@@ -803,12 +1147,7 @@ impl WindowContext {
             #[cfg(target_os = "macos")]
             remote_inspector: None,
             dirty: Default::default(),
-        };
-
-        context.set_active_tab(first_tab);
-        context.send_startup_input(first_tab, command_input);
-        context.refresh_tab_panel();
-        Ok(context)
+        }
     }
 
     fn spawn_tab(
@@ -818,6 +1157,7 @@ impl WindowContext {
         pty_config: tty::Options,
         proxy: &EventLoopProxy<Event>,
         window_kind: WindowKind,
+        private: bool,
         pending_popup: Option<PendingPopup>,
         group_id: Option<usize>,
         group_name: Option<String>,
@@ -864,13 +1204,18 @@ impl WindowContext {
 
         #[cfg(target_os = "macos")]
         let web_view = match (&window_kind, pending_popup) {
-            (WindowKind::Web { url }, None) => Some(WebView::new(
-                &display.window,
-                &display.size_info,
-                tab_id,
-                url,
-                proxy,
-            )?),
+            (WindowKind::Web { url }, None) => {
+                let settings = site_host(url).map(|host| site_settings(&host)).unwrap_or_default();
+                Some(WebView::new(
+                    &display.window,
+                    &display.size_info,
+                    tab_id,
+                    url,
+                    site_user_agent(&config.web, &settings),
+                    private,
+                    proxy,
+                )?)
+            },
             (WindowKind::Web { .. }, Some(popup)) => Some(WebView::from_existing(
                 &display.window,
                 &display.size_info,
@@ -888,6 +1233,15 @@ impl WindowContext {
             },
         };
 
+        #[cfg(target_os = "macos")]
+        if let Some(web_view) = &web_view {
+            web_view.set_new_window_links(config.web.new_window_links);
+        }
+
+        // Popups share the data store of the page which opened them.
+        #[cfg(target_os = "macos")]
+        let private = web_view.as_ref().is_some_and(WebView::is_private);
+
         let title = match &window_kind {
             WindowKind::Terminal => config.window.identity.title.clone(),
             WindowKind::Web { url } => {
@@ -903,18 +1257,34 @@ impl WindowContext {
             id: tab_id,
             title,
             custom_title: None,
+            color: None,
+            rule_color: None,
+            protected: false,
+            private,
+            auto_reload: None,
+            ssh_host: None,
+            opener: None,
+            remote_host: None,
+            profile_title: None,
+            scrollback: None,
+            startup_input: config.terminal.startup_input.clone().filter(|_| !window_kind.is_web()),
+            close_requested: None,
             program_name: String::new(),
             kind: window_kind,
             activity: TabActivity::default(),
             terminal,
             notifier: Notifier(loop_tx),
+            event_proxy,
             search_state: Default::default(),
             inline_search_state: Default::default(),
+            vi_marks: Default::default(),
             command_state: Default::default(),
             mouse: Default::default(),
             touch: Default::default(),
             cursor_blink_timed_out: Default::default(),
+            cursor_color: None,
             prev_bell_cmd: Default::default(),
+            running_command: None,
             #[cfg(target_os = "macos")]
             web_view,
             #[cfg(target_os = "macos")]
@@ -923,13 +1293,15 @@ impl WindowContext {
             favicon: None,
             #[cfg(target_os = "macos")]
             favicon_pending: false,
+            #[cfg(target_os = "macos")]
+            site_host: None,
             #[cfg(not(windows))]
             master_fd,
             #[cfg(not(windows))]
             shell_pid,
         };
 
-        tabs.insert(tab_id, tab, group_id, group_name)
+        tabs.insert(tab_id, tab, group_id, group_name, config.tabs.new_position)
             .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))?;
         Ok(tab_id)
     }
@@ -940,7 +1312,7 @@ impl WindowContext {
             return;
         }
 
-        let groups = self.tabs.panel_groups();
+        let groups = self.tabs.panel_groups(&self.broadcast_targets());
         let new_group_id = Some(self.tabs.preview_group_id());
         if self.display.set_tab_panel_groups(groups, new_group_id) {
             self.dirty = true;
@@ -963,6 +1335,23 @@ impl WindowContext {
         }
 
         tab.activity.note_output(Instant::now(), is_active);
+        let startup_input = tab.startup_input.take();
+        self.send_startup_input(tab_id, startup_input);
+        self.refresh_tab_panel();
+    }
+
+    /// Flag a bell or title change of a tab which is not currently visible.
+    fn note_tab_activity(&mut self, tab_id: TabId, kind: TabActivityKind) {
+        let seen = self.tabs.active_id() == Some(tab_id);
+        let Some(tab) = self.tabs.get_mut(tab_id) else {
+            return;
+        };
+
+        match kind {
+            TabActivityKind::Bell => tab.activity.note_bell(seen),
+            TabActivityKind::Title => tab.activity.note_title(seen),
+            TabActivityKind::Output => tab.activity.note_output(Instant::now(), seen),
+        }
         self.refresh_tab_panel();
     }
 
@@ -972,6 +1361,12 @@ impl WindowContext {
             .any(|tab| !tab.kind.is_web() && tab.activity.is_active(now))
     }
 
+    /// Check if the tab panel needs periodic redraws for activity or command durations.
+    pub(crate) fn needs_tab_activity_tick(&self, now: Instant) -> bool {
+        self.has_active_terminal_output(now)
+            || self.tabs.iter().any(|tab| tab.running_command.is_some())
+    }
+
     #[cfg(target_os = "macos")]
     pub(crate) fn tab_panel_enabled(&self) -> bool {
         self.display.tab_panel.is_enabled()
@@ -1078,15 +1473,23 @@ impl WindowContext {
         #[cfg(target_os = "macos")]
         {
             let active_id = self.tabs.active_id();
+            let pane_id = self.split.zip(active_id).and_then(|(split, id)| split.other(id));
             for tab in self.tabs.iter_mut() {
                 let Some(web_view) = tab.web_view.as_mut() else {
                     continue;
                 };
 
-                let visible = Some(tab.id) == active_id;
-                web_view.set_visible(visible);
-                if visible {
-                    web_view.update_frame(&self.display.window, &self.display.size_info);
+                let size_info = if Some(tab.id) == active_id {
+                    Some(self.display.size_info)
+                } else if Some(tab.id) == pane_id {
+                    self.display.pane_size_info
+                } else {
+                    None
+                };
+
+                web_view.set_visible(size_info.is_some());
+                if let Some(size_info) = size_info {
+                    web_view.update_frame(&self.display.window, &size_info);
                 }
             }
         }
@@ -1097,6 +1500,7 @@ impl WindowContext {
         {
             let mut pending_scroll = None;
             let mut url_update = None;
+            let mut private = false;
             let mut favicon_request = None;
             let mut favicon_cleared = false;
             let title = {
@@ -1119,7 +1523,10 @@ impl WindowContext {
                     active_tab.favicon_pending = false;
                     favicon_cleared = true;
                     favicon_request = Some((active_tab.id, url.clone()));
+                    self.ipc_events
+                        .push(IpcEvent::TabFaviconChanged { tab_id: active_tab.id.into() });
                     pending_scroll = active_tab.web_command_state.take_pending_scroll(&url);
+                    private = active_tab.private;
                     url_update = Some(url);
                 }
 
@@ -1130,10 +1537,17 @@ impl WindowContext {
                 self.update_tab_title(tab_id, title);
             }
 
-            if let Some(url) = url_update.clone() {
+            if let Some(url) = url_update.clone().filter(|_| !private) {
                 self.command_history.record_url(url);
             }
 
+            if let Some(tab_id) = url_update.as_ref().and(self.tabs.active_id()) {
+                self.apply_site_settings(tab_id);
+                if self.apply_tab_rules(tab_id) {
+                    self.refresh_tab_panel();
+                }
+            }
+
             if let Some((scroll_x, scroll_y)) = pending_scroll {
                 if let Some(active_tab) = self.tabs.active_mut() {
                     if let Some(web_view) = active_tab.web_view.as_mut() {
@@ -1152,6 +1566,35 @@ impl WindowContext {
         }
     }
 
+    /// Restore the zoom and user agent remembered for the site a web tab navigated to.
+    #[cfg(target_os = "macos")]
+    fn apply_site_settings(&mut self, tab_id: TabId) {
+        let Some(tab) = self.tabs.get_mut(tab_id) else {
+            return;
+        };
+        let host = match &tab.kind {
+            WindowKind::Web { url } => site_host(url),
+            WindowKind::Terminal => return,
+        };
+        if tab.site_host == host {
+            return;
+        }
+        let Some(web_view) = tab.web_view.as_mut() else {
+            return;
+        };
+
+        let settings = host.as_deref().map(site_settings).unwrap_or_default();
+        web_view.set_page_zoom(settings.zoom.unwrap_or(1.));
+
+        // The page was already requested with the previous user agent.
+        let user_agent = site_user_agent(&self.config.web, &settings);
+        if web_view.set_user_agent(user_agent) {
+            web_view.reload();
+        }
+
+        tab.site_host = host;
+    }
+
     #[cfg(target_os = "macos")]
     fn request_web_favicon(
         &mut self,
@@ -1234,6 +1677,7 @@ impl WindowContext {
         };
         tab.favicon_pending = false;
         tab.favicon = Some(TabFavicon::new(id, character, Arc::new(icon)));
+        self.ipc_events.push(IpcEvent::TabFaviconChanged { tab_id: tab_id.into() });
         self.refresh_tab_panel();
         self.dirty = true;
     }
@@ -1263,6 +1707,68 @@ impl WindowContext {
         }
     }
 
+    /// Preview the target of the hovered link in the message bar.
+    #[cfg(target_os = "macos")]
+    fn handle_web_hover_link(&mut self, tab_id: TabId, url: Option<String>) {
+        let Some(tab) = self.tabs.get_mut(tab_id) else {
+            return;
+        };
+
+        if !tab.kind.is_web() || tab.web_command_state.hover_link() == url.as_deref() {
+            return;
+        }
+
+        tab.web_command_state.set_hover_link(url.clone());
+        if Some(tab_id) != self.tabs.active_id() {
+            return;
+        }
+
+        self.message_buffer.remove_target(WEB_HOVER_LINK_MESSAGE_TARGET);
+        if let Some(url) = url {
+            let mut message =
+                crate::message_bar::Message::new(url, crate::message_bar::MessageType::Info);
+            message.set_target(String::from(WEB_HOVER_LINK_MESSAGE_TARGET));
+            self.message_buffer.push(message);
+        }
+        self.display.pending_update.dirty = true;
+        self.dirty = true;
+    }
+
+    #[cfg(target_os = "macos")]
+    fn handle_web_load_progress(&mut self, tab_id: TabId, fraction: f64, finished: bool) {
+        let is_active = self.tabs.active_id() == Some(tab_id);
+        let Some(tab) = self.tabs.get_mut(tab_id) else {
+            return;
+        };
+        let Some(web_view) = tab.web_view.as_mut() else {
+            return;
+        };
+
+        web_view.set_load_progress(fraction);
+
+        // Site rules take precedence over the dark mode toggle of the tab.
+        let url = web_view.current_url();
+        let dark_mode = url.as_deref().and_then(|url| self.config.web.dark_mode(url));
+        web_view.set_dark_mode(dark_mode.unwrap_or(tab.web_command_state.dark_mode()));
+
+        if is_active {
+            let autofill = url.as_deref().and_then(|url| self.config.web.autofill(url));
+            crate::macos::set_site_autofill(autofill);
+        }
+
+        // Show the hints configured for the loaded page, error pages get none.
+        if !finished || !is_active {
+            return;
+        }
+        let Some(auto_hints) = url.and_then(|url| self.config.web.auto_hints(&url).cloned()) else {
+            return;
+        };
+        if tab.web_command_state.start_auto_hints(Instant::now()) {
+            web_view.exec_js(&web_hints_start_script(false, auto_hints.links.as_deref()));
+            self.dirty = true;
+        }
+    }
+
     #[cfg(target_os = "macos")]
     fn handle_web_cursor_request(
         &mut self,
@@ -1324,10 +1830,18 @@ impl WindowContext {
             return;
         }
 
+        // Selecting a tab outside of the split closes it.
+        if self.split.is_some_and(|split| !split.contains(tab_id)) {
+            self.split = None;
+        }
+
         let changed = self.tabs.set_active(tab_id);
+        self.update_split_layout();
 
         if changed {
             self.update_tab_program_name(tab_id);
+            #[cfg(unix)]
+            self.ipc_events.push(IpcEvent::TabSelected { tab_id: tab_id.into() });
         }
 
         if changed {
@@ -1350,9 +1864,16 @@ impl WindowContext {
                     self.display.window.set_mouse_cursor(CursorIcon::Default);
                     active_tab.web_command_state.set_last_cursor(CursorIcon::Default);
                     active_tab.web_command_state.set_cursor_pending(false);
+
+                    let url = active_tab.web_view.as_ref().and_then(WebView::current_url);
+                    let autofill = url.as_deref().and_then(|url| self.config.web.autofill(url));
+                    crate::macos::set_site_autofill(autofill);
                 }
             }
-            if !self.preserve_title && self.config.window.dynamic_title {
+            if !self.preserve_title
+                && self.overridden_title.is_none()
+                && self.config.window.dynamic_title
+            {
                 let title = active_tab.custom_title.clone().unwrap_or_else(|| active_tab.title.clone());
                 self.display.window.set_title(title);
             }
@@ -1363,7 +1884,10 @@ impl WindowContext {
                 if let Some(previous_tab) = self.tabs.get_mut(previous_id) {
                     previous_tab.command_state.cancel();
                     #[cfg(target_os = "macos")]
-                    previous_tab.web_command_state.reset_mode();
+                    {
+                        previous_tab.web_command_state.reset_mode();
+                        previous_tab.web_command_state.set_hover_link(None);
+                    }
                 }
             }
             if let Some(active_tab) = self.tabs.active_mut() {
@@ -1371,6 +1895,11 @@ impl WindowContext {
                 #[cfg(target_os = "macos")]
                 active_tab.web_command_state.reset_mode();
             }
+            #[cfg(target_os = "macos")]
+            {
+                self.message_buffer.remove_target(WEB_PASSTHROUGH_MESSAGE_TARGET);
+                self.message_buffer.remove_target(WEB_HOVER_LINK_MESSAGE_TARGET);
+            }
             self.display.tab_panel.cancel_edit();
             self.update_webview_visibility();
             self.display.pending_update.dirty = true;
@@ -1388,6 +1917,35 @@ impl WindowContext {
         self.create_tab_with_popup(options, proxy, None, None, None)
     }
 
+    /// Open a web page in a new tab, switching to an open tab with `web.switch_to_existing`.
+    pub(crate) fn open_web_tab(
+        &mut self,
+        request: WebTabRequest,
+        proxy: &EventLoopProxy<Event>,
+    ) -> Result<(), Box<dyn Error>> {
+        let WebTabRequest { url, from, trigger, private, working_directory } = request;
+        let existing =
+            self.config.web.switch_to_existing.then(|| self.tabs.web_tab_index(&url)).flatten();
+        if let Some(index) = existing {
+            if !private {
+                self.command_history.record_url(url);
+            }
+            self.handle_tab_command(crate::tabs::TabCommand::SelectIndex(index), proxy);
+            return Ok(());
+        }
+
+        let mut options = WindowOptions::default();
+        options.window_kind = WindowKind::Web { url: url.clone() };
+        options.private = private;
+        options.terminal_options.working_directory = working_directory;
+        let tab_id = self.create_tab(options, proxy)?;
+        log_web_navigation(tab_id, from.as_deref(), &url, trigger);
+        if !private {
+            self.command_history.record_url(url);
+        }
+        Ok(())
+    }
+
     pub(crate) fn create_tab_in_group(
         &mut self,
         options: WindowOptions,
@@ -1414,6 +1972,9 @@ impl WindowContext {
         let mut pty_config = self.config.pty_config();
         options.terminal_options.override_pty_config(&mut pty_config);
         let command_input = options.command_input.clone();
+        let ssh_host = options.ssh_host.clone();
+        let opener = self.tabs.active_id().filter(|_| options.return_focus);
+        let background = options.background && self.tabs.active_id().is_some();
         let tab_id = Self::spawn_tab(
             &mut self.tabs,
             &self.display,
@@ -1421,17 +1982,46 @@ impl WindowContext {
             pty_config,
             proxy,
             options.window_kind,
+            options.private,
             pending_popup,
             group_id,
             group_name,
         )?;
-        self.set_active_tab(tab_id);
-        self.send_startup_input(tab_id, terminal_command_input);
-        if let Some(input) = command_input.as_deref() {
-            if let Some(active_tab) = self.tabs.active_mut() {
-                active_tab.command_state.start_with_input(':', input);
-                self.display.pending_update.dirty = true;
-                self.display.damage_tracker.frame().mark_fully_damaged();
+        #[cfg(unix)]
+        self.ipc_events.push(IpcEvent::TabCreated { tab_id: tab_id.into() });
+        if let Some(tab) = self.tabs.get_mut(tab_id) {
+            // SSH tabs type the snippet of their profile into the remote shell instead.
+            if let Some(host) = &ssh_host {
+                tab.startup_input = self
+                    .config
+                    .tabs
+                    .ssh_profile(host)
+                    .and_then(|profile| profile.startup_input.clone());
+            }
+            tab.ssh_host = ssh_host;
+            tab.opener = opener;
+        }
+        #[cfg(target_os = "macos")]
+        {
+            let dark = self.web_dark_appearance();
+            let tab = self.tabs.get_mut(tab_id);
+            if let Some(web_view) = tab.and_then(|tab| tab.web_view.as_mut()) {
+                web_view.set_dark_appearance(dark);
+            }
+        }
+        self.apply_tab_rules(tab_id);
+        if background {
+            self.update_webview_visibility();
+            self.dirty = true;
+        } else {
+            self.set_active_tab(tab_id);
+        }
+        self.send_startup_input(tab_id, terminal_command_input);
+        if let Some(input) = command_input.as_deref() {
+            if let Some(active_tab) = self.tabs.active_mut() {
+                active_tab.command_state.start_with_input(':', input);
+                self.display.pending_update.dirty = true;
+                self.display.damage_tracker.frame().mark_fully_damaged();
                 self.dirty = true;
             }
         }
@@ -1473,12 +2063,52 @@ impl WindowContext {
         self.create_tab_with_popup(options, proxy, Some(popup), None, None)
     }
 
-    pub(crate) fn handle_tab_command(&mut self, command: crate::tabs::TabCommand) {
+    pub(crate) fn handle_tab_command(
+        &mut self,
+        command: crate::tabs::TabCommand,
+        proxy: &EventLoopProxy<Event>,
+    ) {
         let target = match command {
             crate::tabs::TabCommand::SelectNext => self.tabs.select_next(),
             crate::tabs::TabCommand::SelectPrevious => self.tabs.select_previous(),
             crate::tabs::TabCommand::SelectIndex(index) => self.tabs.select_by_index(index),
             crate::tabs::TabCommand::SelectLast => self.tabs.select_last(),
+            crate::tabs::TabCommand::SelectMru(step) => self.tabs.select_mru(step),
+            crate::tabs::TabCommand::SetColor(tab_id, color) => {
+                self.set_tab_color(tab_id, color);
+                return;
+            },
+            crate::tabs::TabCommand::Split(index) => {
+                self.split_tab(index, proxy);
+                return;
+            },
+            crate::tabs::TabCommand::CloseSplit => {
+                self.split = None;
+                self.update_split_layout();
+                return;
+            },
+            crate::tabs::TabCommand::FocusNextPane => {
+                self.split.zip(self.tabs.active_id()).and_then(|(split, id)| split.other(id))
+            },
+            crate::tabs::TabCommand::SwapPanes => {
+                self.swap_panes();
+                return;
+            },
+            crate::tabs::TabCommand::ResizePane(percent) => {
+                self.resize_pane(percent);
+                return;
+            },
+            // Creating windows requires the event loop, so the processor handles this.
+            crate::tabs::TabCommand::MoveToNewWindow(_) => return,
+            crate::tabs::TabCommand::ToggleTabPanel => {
+                #[cfg(unix)]
+                self.set_tab_panel(Some(!self.config.window.tab_panel.enabled), None);
+                return;
+            },
+            crate::tabs::TabCommand::Broadcast(command) => {
+                self.set_broadcast(command);
+                return;
+            },
         };
 
         if let Some(tab_id) = target {
@@ -1486,6 +2116,200 @@ impl WindowContext {
         }
     }
 
+    /// Show a tab next to the active tab.
+    ///
+    /// Without an index, a new terminal tab is created in the active tab's working directory.
+    fn split_tab(&mut self, index: Option<usize>, proxy: &EventLoopProxy<Event>) {
+        let Some(active_id) = self.tabs.active_id() else {
+            return;
+        };
+
+        let tab_id = match index {
+            Some(index) => match self.tabs.select_by_index(index) {
+                Some(tab_id) if tab_id != active_id => tab_id,
+                _ => return,
+            },
+            None => {
+                let mut options = WindowOptions::default();
+                #[cfg(not(windows))]
+                if let Some(tab) = self.tabs.get(active_id).filter(|tab| !tab.kind.is_web()) {
+                    options.terminal_options.working_directory =
+                        foreground_process_path(tab.master_fd, tab.shell_pid).ok();
+                }
+
+                match self.create_tab(options, proxy) {
+                    Ok(tab_id) => tab_id,
+                    Err(err) => {
+                        error!("Could not create tab: {err:?}");
+                        return;
+                    },
+                }
+            },
+        };
+
+        self.split = Some(Split { left: active_id, right: tab_id, ratio: 0.5 });
+        self.set_active_tab(tab_id);
+    }
+
+    /// Swap the left and right panes of the split, keeping focus on the same tab.
+    fn swap_panes(&mut self) {
+        let Some(split) = self.split.as_mut() else {
+            return;
+        };
+
+        mem::swap(&mut split.left, &mut split.right);
+        split.ratio = 1. - split.ratio;
+        self.update_split_layout();
+    }
+
+    /// Grow the focused pane of the split by `percent` of the window width.
+    fn resize_pane(&mut self, percent: i8) {
+        let active_id = self.tabs.active_id();
+        let Some(split) = self.split.as_mut() else {
+            return;
+        };
+
+        let delta = f32::from(percent) / 100.;
+        let delta = if Some(split.left) == active_id { delta } else { -delta };
+        split.ratio = (split.ratio + delta).clamp(MIN_SPLIT_RATIO, 1. - MIN_SPLIT_RATIO);
+        self.update_split_layout();
+    }
+
+    /// Propagate the split to the display.
+    fn update_split_layout(&mut self) {
+        let active_id = self.tabs.active_id();
+        let layout = self.split.map(|split| SplitLayout {
+            ratio: split.ratio,
+            focus_left: Some(split.left) == active_id,
+        });
+
+        if self.display.split == layout {
+            return;
+        }
+
+        self.display.split = layout;
+        self.update_webview_visibility();
+        self.display.pending_update.dirty = true;
+        self.display.damage_tracker.frame().mark_fully_damaged();
+        self.dirty = true;
+    }
+
+    /// Change the terminal tabs receiving typed input.
+    fn set_broadcast(&mut self, command: BroadcastCommand) {
+        self.broadcast = match command {
+            BroadcastCommand::All => Some(Broadcast::All),
+            BroadcastCommand::Group => Some(Broadcast::Group),
+            BroadcastCommand::Off => None,
+            BroadcastCommand::Toggle(index) => {
+                let tab = self.tabs.select_by_index(index).and_then(|id| self.tabs.get(id));
+                let Some(tab_id) = tab.filter(|tab| !tab.kind.is_web()).map(|tab| tab.id) else {
+                    return;
+                };
+
+                let mut tabs = match self.broadcast.take() {
+                    Some(Broadcast::Tabs(tabs)) => tabs,
+                    _ => Vec::new(),
+                };
+                match tabs.iter().position(|id| *id == tab_id) {
+                    Some(position) => {
+                        tabs.remove(position);
+                    },
+                    None => tabs.push(tab_id),
+                }
+
+                (!tabs.is_empty()).then_some(Broadcast::Tabs(tabs))
+            },
+        };
+
+        self.update_broadcast_indicator();
+        self.refresh_tab_panel();
+    }
+
+    /// Propagate the broadcast state to the display.
+    fn update_broadcast_indicator(&mut self) {
+        let broadcast = self.broadcast.is_some();
+        if self.display.broadcast == broadcast {
+            return;
+        }
+
+        self.display.broadcast = broadcast;
+        self.display.damage_tracker.frame().mark_fully_damaged();
+        self.dirty = true;
+    }
+
+    /// Terminal tabs receiving typed input, including the active tab.
+    ///
+    /// Targets are recomputed on every use, so tabs joining or leaving the active group are
+    /// picked up immediately. Web tabs never receive broadcast input.
+    fn broadcast_targets(&self) -> Vec<TabId> {
+        let Some(broadcast) = &self.broadcast else {
+            return Vec::new();
+        };
+
+        let active_id = self.tabs.active_id();
+        let group_of = |tab_id| self.tabs.group_for_tab(tab_id).map(|(group_id, _)| group_id);
+        let active_group = active_id.and_then(group_of);
+        self.tabs
+            .iter()
+            .filter(|tab| !tab.kind.is_web())
+            .filter(|tab| {
+                Some(tab.id) == active_id
+                    || match broadcast {
+                        Broadcast::All => true,
+                        Broadcast::Tabs(tabs) => tabs.contains(&tab.id),
+                        Broadcast::Group => group_of(tab.id) == active_group,
+                    }
+            })
+            .map(|tab| tab.id)
+            .collect()
+    }
+
+    /// Notifiers of the tabs receiving typed input next to the active tab.
+    fn broadcast_notifiers(&self) -> Vec<Notifier> {
+        let active_id = self.tabs.active_id();
+        self.broadcast_targets()
+            .into_iter()
+            .filter(|tab_id| Some(*tab_id) != active_id)
+            .filter_map(|tab_id| self.tabs.get(tab_id))
+            .map(|tab| Notifier(tab.notifier.0.clone()))
+            .collect()
+    }
+
+    /// Focus the split pane below the mouse cursor.
+    fn focus_pane_at_mouse(&mut self) -> bool {
+        let (Some(position), Some(pane_size)) = (self.mouse_position, self.display.pane_size_info)
+        else {
+            return false;
+        };
+
+        if !pane_size.contains_point(position.x as usize, position.y as usize) {
+            return false;
+        }
+
+        let pane_id = self.split.zip(self.tabs.active_id()).and_then(|(split, id)| split.other(id));
+        match pane_id {
+            Some(pane_id) => {
+                self.set_active_tab(pane_id);
+                true
+            },
+            None => false,
+        }
+    }
+
+    fn set_tab_color(&mut self, tab_id: TabId, color: Option<Rgb>) {
+        let Some(tab) = self.tabs.get_mut(tab_id) else {
+            return;
+        };
+
+        if tab.color == color {
+            return;
+        }
+
+        tab.color = color;
+        self.refresh_tab_panel();
+        self.dirty = true;
+    }
+
     pub(crate) fn active_tab_id(&self) -> Option<TabId> {
         self.tabs.active_id()
     }
@@ -1494,18 +2318,106 @@ impl WindowContext {
         self.tabs.get(tab_id).map(|tab| &tab.kind)
     }
 
+    /// Close a tab on request, confirming the close of protected tabs.
+    pub(crate) fn shutdown_tab(&mut self, tab_id: TabId, scheduler: &mut Scheduler) -> bool {
+        self.confirm_close(tab_id) && self.shutdown_confirmed_tab(tab_id, scheduler)
+    }
+
+    /// Close a tab whose close was already confirmed.
+    fn shutdown_confirmed_tab(&mut self, tab_id: TabId, scheduler: &mut Scheduler) -> bool {
+        #[cfg(windows)]
+        let _ = scheduler;
+
+        #[cfg(not(windows))]
+        if self.tabs.get(tab_id).is_some_and(|tab| !tab.kind.is_web()) {
+            self.schedule_shell_shutdown(scheduler);
+        }
+
+        #[cfg(target_os = "macos")]
+        scheduler.unschedule(TimerId::new(Topic::WebAutoReload(tab_id), self.display.window.id()));
+
+        self.close_tab(tab_id)
+    }
+
+    /// Reap the shells terminated by closing tabs once their shutdown grace period is over.
+    #[cfg(not(windows))]
+    pub(crate) fn schedule_shell_shutdown(&self, scheduler: &mut Scheduler) {
+        let Some(grace) = self.config.terminal.shutdown_grace() else {
+            return;
+        };
+
+        let window_id = self.display.window.id();
+        let timer_id = TimerId::new(Topic::ShellShutdown, window_id);
+        if !scheduler.scheduled(timer_id) {
+            let event = Event::new(EventType::ShellShutdown, window_id);
+            scheduler.schedule(event, grace, false, timer_id);
+        }
+    }
+
+    /// Check whether a tab may be closed, requiring a repeated close for protected tabs.
+    fn confirm_close(&mut self, tab_id: TabId) -> bool {
+        self.confirm_close_tabs(&[tab_id], "Tab is protected, close it again to confirm")
+    }
+
+    /// Check whether the window may be closed, requiring a repeated close with protected tabs.
+    fn confirm_close_window(&mut self) -> bool {
+        let tab_ids: Vec<_> = self.tabs.iter().map(|tab| tab.id).collect();
+        self.confirm_close_tabs(&tab_ids, "Window has protected tabs, close it again to confirm")
+    }
+
+    /// Check whether tabs may be closed.
+    ///
+    /// Protected tabs are only closed when they are closed again within
+    /// [`CLOSE_CONFIRM_TIMEOUT`], otherwise `message` is shown.
+    fn confirm_close_tabs(&mut self, tab_ids: &[TabId], message: &str) -> bool {
+        let now = Instant::now();
+        let confirmed = tab_ids
+            .iter()
+            .filter_map(|tab_id| self.tabs.get(*tab_id))
+            .filter(|tab| tab.protected)
+            .all(|tab| tab.close_requested.is_some_and(|time| time + CLOSE_CONFIRM_TIMEOUT > now));
+        if confirmed {
+            return true;
+        }
+
+        for tab_id in tab_ids {
+            if let Some(tab) = self.tabs.get_mut(*tab_id).filter(|tab| tab.protected) {
+                tab.close_requested = Some(now);
+            }
+        }
+
+        self.message_buffer.push(crate::message_bar::Message::new(
+            String::from(message),
+            crate::message_bar::MessageType::Warning,
+        ));
+        self.display.pending_update.dirty = true;
+        self.dirty = true;
+
+        false
+    }
+
     pub(crate) fn close_tab(&mut self, tab_id: TabId) -> bool {
-        let was_active = self.tabs.active_id() == Some(tab_id);
-        let Some(tab) = self.tabs.remove(tab_id) else {
+        #[cfg(target_os = "macos")]
+        let position = self.tabs.ordered_tabs().iter().position(|id| *id == tab_id);
+
+        let Some(tab) = self.remove_tab(tab_id) else {
             return false;
         };
 
+        // Private tabs leave no trace of the pages they visited.
         #[cfg(target_os = "macos")]
-        if tab.kind.is_web() {
+        if !tab.private {
+            let working_directory = match tab.kind {
+                WindowKind::Terminal => foreground_process_path(tab.master_fd, tab.shell_pid).ok(),
+                WindowKind::Web { .. } => None,
+            };
             self.closed_tabs.push(ClosedTab {
                 kind: tab.kind.clone(),
+                title: tab.title.clone(),
+                custom_title: tab.custom_title.clone(),
+                working_directory,
+                position: position.unwrap_or_default(),
             });
-            const MAX_CLOSED_TABS: usize = 10;
             if self.closed_tabs.len() > MAX_CLOSED_TABS {
                 self.closed_tabs.remove(0);
             }
@@ -1513,8 +2425,96 @@ impl WindowContext {
 
         let _ = tab.notifier.0.send(Msg::Shutdown);
 
+        self.tabs.active_id().is_none()
+    }
+
+    /// Remove a tab from the window, keeping its PTY and web view alive.
+    ///
+    /// The last tab of a window cannot be detached.
+    pub(crate) fn detach_tab(&mut self, tab_id: TabId) -> Option<DetachedTab> {
+        if self.tabs.iter().count() < 2 {
+            return None;
+        }
+
+        self.remove_tab(tab_id).map(|tab| DetachedTab(Box::new(tab)))
+    }
+
+    /// Adopt a tab detached from another window and focus it.
+    ///
+    /// On failure the tab is handed back, so it can be returned to its previous window.
+    pub(crate) fn attach_tab(
+        &mut self,
+        DetachedTab(mut tab): DetachedTab,
+        proxy: &EventLoopProxy<Event>,
+    ) -> Result<TabId, (String, DetachedTab)> {
+        let tab_id = self.tabs.allocate_id();
+        tab.id = tab_id;
+        tab.event_proxy.retarget(self.display.window.id(), tab_id);
+
+        // Timers stay with the old window, so auto reloading stops with the move.
+        tab.auto_reload = None;
+
+        #[cfg(target_os = "macos")]
+        if let Some(web_view) = tab.web_view.as_mut() {
+            let size_info = &self.display.size_info;
+            if let Err(err) = web_view.reattach(&self.display.window, size_info, tab_id, proxy) {
+                error!("Could not move web view to new window: {err}");
+            }
+            web_view.set_new_window_links(self.config.web.new_window_links);
+        }
+        #[cfg(not(target_os = "macos"))]
+        let _ = proxy;
+
+        let size_info = self.display.size_info;
+        {
+            let mut terminal = tab.terminal.lock();
+            if terminal.screen_lines() != size_info.screen_lines()
+                || terminal.columns() != size_info.columns()
+            {
+                tab.notifier.on_resize(size_info.into());
+                terminal.resize(size_info);
+            }
+        }
+
+        if let Err(err) = self.tabs.insert(tab_id, *tab, None, None, NewTabPosition::End) {
+            // The tab is stored before its group is resolved, so it can be taken back out.
+            let tab = self.tabs.remove(tab_id).expect("stored tab");
+            return Err((err, DetachedTab(Box::new(tab))));
+        }
+
+        #[cfg(unix)]
+        self.ipc_events.push(IpcEvent::TabCreated { tab_id: tab_id.into() });
+        self.set_active_tab(tab_id);
+        self.refresh_tab_panel();
+        self.dirty = true;
+
+        Ok(tab_id)
+    }
+
+    /// Remove a tab and move focus and the split away from it.
+    fn remove_tab(&mut self, tab_id: TabId) -> Option<TabState> {
+        let was_active = self.tabs.active_id() == Some(tab_id);
+        let tab = self.tabs.remove(tab_id)?;
+        #[cfg(unix)]
+        self.ipc_events.push(IpcEvent::TabClosed { tab_id: tab_id.into() });
+
+        if self.split.is_some_and(|split| split.contains(tab_id)) {
+            self.split = None;
+            self.update_split_layout();
+        }
+
+        if let Some(Broadcast::Tabs(tabs)) = &mut self.broadcast {
+            tabs.retain(|id| *id != tab_id);
+            if tabs.is_empty() {
+                self.broadcast = None;
+                self.update_broadcast_indicator();
+                self.refresh_tab_panel();
+            }
+        }
+
         if was_active {
-            if let Some(active_id) = self.tabs.active_id() {
+            let opener = tab.opener.filter(|opener| self.tabs.get(*opener).is_some());
+            if let Some(active_id) = opener.or(self.tabs.active_id()) {
                 self.set_active_tab(active_id);
             }
         }
@@ -1522,7 +2522,7 @@ impl WindowContext {
         self.refresh_tab_panel();
         self.dirty = true;
 
-        self.tabs.active_id().is_none()
+        Some(tab)
     }
 
     #[cfg(target_os = "macos")]
@@ -1536,25 +2536,50 @@ impl WindowContext {
 
         let mut options = WindowOptions::default();
         options.window_kind = closed.kind;
-        let _ = self.create_tab(options, proxy)?;
+        options.terminal_options.working_directory = closed.working_directory;
+        let tab_id = self.create_tab(options, proxy)?;
+
+        self.update_tab_title(tab_id, closed.title);
+        if closed.custom_title.is_some() {
+            self.rename_tab(tab_id, closed.custom_title);
+        }
+        if self.tabs.move_to_position(tab_id, closed.position) {
+            self.refresh_tab_panel();
+        }
         Ok(())
     }
 
+    /// Number of closed tabs which can be restored.
+    #[cfg(unix)]
+    pub(crate) fn ipc_closed_tab_count(&self) -> usize {
+        #[cfg(target_os = "macos")]
+        return self.closed_tabs.len();
+
+        #[cfg(not(target_os = "macos"))]
+        0
+    }
+
     #[cfg(target_os = "macos")]
     pub(crate) fn open_web_url_in_tab(
         &mut self,
         tab_id: TabId,
         url: String,
+        trigger: WebNavigationTrigger,
     ) -> Result<(), String> {
         let Some(tab) = self.tabs.get_mut(tab_id) else {
             return Err(String::from("Tab not found"));
         };
 
+        let private = tab.private;
         if let WindowKind::Web { url: current_url } = &mut tab.kind {
-            *current_url = url.clone();
+            let previous_url = mem::replace(current_url, url.clone());
             if let Some(web_view) = tab.web_view.as_mut() {
                 if web_view.load_url(&url) {
-                    self.command_history.record_url(url.clone());
+                    let from = Some(previous_url.as_str()).filter(|prev| !prev.is_empty());
+                    log_web_navigation(tab_id, from, &url, trigger);
+                    if !private {
+                        self.command_history.record_url(url.clone());
+                    }
                     self.update_tab_title(tab_id, url);
                     return Ok(());
                 }
@@ -1570,11 +2595,19 @@ impl WindowContext {
         &mut self,
         url: String,
         proxy: &EventLoopProxy<Event>,
+        trigger: WebNavigationTrigger,
+        background: bool,
+        private: bool,
     ) -> Result<(), Box<dyn Error>> {
         let mut options = WindowOptions::default();
         options.window_kind = WindowKind::Web { url: url.clone() };
-        let _ = self.create_tab(options, proxy)?;
-        self.command_history.record_url(url);
+        options.background = background;
+        options.private = private;
+        let tab_id = self.create_tab(options, proxy)?;
+        log_web_navigation(tab_id, None, &url, trigger);
+        if !private {
+            self.command_history.record_url(url);
+        }
         Ok(())
     }
 
@@ -1583,7 +2616,12 @@ impl WindowContext {
         self.window_focused
     }
 
+    /// Take the tab lifecycle events queued for IPC subscribers.
     #[cfg(unix)]
+    pub(crate) fn take_ipc_events(&mut self) -> Vec<IpcEvent> {
+        mem::take(&mut self.ipc_events)
+    }
+
     pub(crate) fn has_tab(&self, tab_id: TabId) -> bool {
         self.tabs.get(tab_id).is_some()
     }
@@ -1613,9 +2651,12 @@ impl WindowContext {
                             is_active: Some(*tab_id) == active,
                             title: tab.title.clone(),
                             custom_title: tab.custom_title.clone(),
+                            color: tab.accent_color().map(|color| color.to_string()),
                             program_name: tab.program_name.clone(),
                             kind: IpcTabKind::from(&tab.kind),
                             activity,
+                            cwd: None,
+                            scrollback: Self::ipc_scrollback(tab),
                         })
                     })
                     .collect();
@@ -1641,12 +2682,99 @@ impl WindowContext {
             is_active: Some(tab_id) == self.tabs.active_id(),
             title: tab.title.clone(),
             custom_title: tab.custom_title.clone(),
+            color: tab.accent_color().map(|color| color.to_string()),
             program_name: tab.program_name.clone(),
             kind: IpcTabKind::from(&tab.kind),
             activity,
+            cwd: None,
+            scrollback: Self::ipc_scrollback(tab),
         })
     }
 
+    /// Snapshot of the groups and tabs for `save_session`.
+    #[cfg(unix)]
+    pub(crate) fn ipc_session_window(&self) -> IpcSessionWindow {
+        let active = self.tabs.active_id();
+        let groups = self
+            .tabs
+            .groups
+            .iter()
+            .map(|group| {
+                let tabs = group
+                    .tabs
+                    .iter()
+                    .filter_map(|tab_id| {
+                        let tab = self.tabs.get(*tab_id)?;
+                        Some(IpcSessionTab {
+                            kind: IpcTabKind::from(&tab.kind),
+                            custom_title: tab.custom_title.clone(),
+                            cwd: self.ipc_tab_cwd(*tab_id),
+                            is_active: Some(*tab_id) == active,
+                        })
+                    })
+                    .collect();
+
+                IpcSessionGroup { name: group.name.clone(), tabs }
+            })
+            .collect();
+
+        IpcSessionWindow { groups }
+    }
+
+    /// Recreate the groups and tabs of a saved window.
+    ///
+    /// The first tab must be the one this window was created with.
+    #[cfg(unix)]
+    pub(crate) fn ipc_restore_session(
+        &mut self,
+        groups: Vec<IpcSessionGroup>,
+        proxy: &EventLoopProxy<Event>,
+    ) -> Result<(), IpcError> {
+        let mut initial_tab = self.tabs.active_id();
+        let mut active = None;
+
+        for group in groups.into_iter().filter(|group| !group.tabs.is_empty()) {
+            // The initial tab's group takes the name of the first saved group.
+            let group_id = match initial_tab.and_then(|tab_id| self.tabs.group_for_tab(tab_id)) {
+                Some((group_id, _)) => {
+                    self.ipc_set_group_name(group_id, group.name)?;
+                    group_id
+                },
+                None => self.ipc_create_group(group.name)?,
+            };
+
+            for tab in group.tabs {
+                let tab_id = match initial_tab.take() {
+                    Some(tab_id) => tab_id,
+                    None => {
+                        self.ipc_create_tab(tab.window_options(), Some(group_id), None, proxy)?
+                    },
+                };
+
+                if tab.custom_title.is_some() {
+                    self.ipc_set_tab_title(tab_id, tab.custom_title)?;
+                }
+
+                if tab.is_active {
+                    active = Some(tab_id);
+                }
+            }
+        }
+
+        if let Some(tab_id) = active {
+            self.set_active_tab(tab_id);
+        }
+
+        Ok(())
+    }
+
+    /// Working directory of the shell in a terminal tab.
+    #[cfg(unix)]
+    pub(crate) fn ipc_tab_cwd(&self, tab_id: TabId) -> Option<PathBuf> {
+        let tab = self.tabs.get(tab_id).filter(|tab| !tab.kind.is_web())?;
+        foreground_process_path(tab.master_fd, tab.shell_pid).ok()
+    }
+
     #[cfg(unix)]
     pub(crate) fn ipc_tab_kind(&self, tab_id: TabId) -> Option<IpcTabKind> {
         self.tabs.get(tab_id).map(|tab| IpcTabKind::from(&tab.kind))
@@ -1676,11 +2804,21 @@ impl WindowContext {
     }
 
     #[cfg(unix)]
-    pub(crate) fn ipc_close_tab(&mut self, tab_id: TabId) -> Result<bool, IpcError> {
+    pub(crate) fn ipc_close_tab(
+        &mut self,
+        tab_id: TabId,
+        scheduler: &mut Scheduler,
+    ) -> Result<bool, IpcError> {
         if self.tabs.get(tab_id).is_none() {
             return Err(IpcError::new(IpcErrorCode::NotFound, "Tab not found"));
         }
-        Ok(self.close_tab(tab_id))
+        if !self.confirm_close(tab_id) {
+            return Err(IpcError::new(
+                IpcErrorCode::InvalidRequest,
+                "Tab is protected, close it again to confirm",
+            ));
+        }
+        Ok(self.shutdown_confirmed_tab(tab_id, scheduler))
     }
 
     #[cfg(unix)]
@@ -1735,7 +2873,32 @@ impl WindowContext {
         if self.tabs.set_custom_title(tab_id, title) {
             self.refresh_tab_panel();
         }
-        Ok(())
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    pub(crate) fn ipc_set_window_title(&mut self, title: Option<String>) {
+        match title {
+            Some(title) => {
+                if self.overridden_title.is_none() {
+                    self.overridden_title = Some(self.display.window.title().to_owned());
+                }
+                self.display.window.set_title(title);
+            },
+            None => {
+                let Some(previous) = self.overridden_title.take() else {
+                    return;
+                };
+
+                let title = match self.tabs.active() {
+                    Some(tab) if self.dynamic_title() => {
+                        tab.custom_title.clone().unwrap_or_else(|| tab.title.clone())
+                    },
+                    _ => previous,
+                };
+                self.display.window.set_title(title);
+            },
+        }
     }
 
     #[cfg(unix)]
@@ -1784,7 +2947,7 @@ impl WindowContext {
         {
             let _ = proxy;
             return self
-                .open_web_url_in_tab(tab_id, url)
+                .open_web_url_in_tab(tab_id, url, WebNavigationTrigger::Ipc)
                 .map_err(|err| IpcError::new(IpcErrorCode::InvalidRequest, err));
         }
 
@@ -1811,6 +2974,7 @@ impl WindowContext {
             let tab_id = self
                 .create_tab(options, proxy)
                 .map_err(|err| IpcError::new(IpcErrorCode::Internal, err.to_string()))?;
+            log_web_navigation(tab_id, None, &url, WebNavigationTrigger::Ipc);
             self.command_history.record_url(url);
             return Ok(tab_id);
         }
@@ -1912,6 +3076,13 @@ impl WindowContext {
             ));
         }
 
+        self.set_tab_panel(enabled, width);
+        Ok(())
+    }
+
+    /// Change the tab panel through window config overrides.
+    #[cfg(unix)]
+    fn set_tab_panel(&mut self, enabled: Option<bool>, width: Option<usize>) {
         let mut options = Vec::new();
         if let Some(enabled) = enabled {
             options.push(format!("window.tab_panel.enabled={enabled}"));
@@ -1922,7 +3093,6 @@ impl WindowContext {
 
         let parsed = ParsedOptions::from_options(&options);
         self.add_window_config(self.config.clone(), &parsed);
-        Ok(())
     }
 
     #[cfg(unix)]
@@ -1955,6 +3125,333 @@ impl WindowContext {
         Ok(())
     }
 
+    /// Queue text to be typed into a tab, one character after each `delay`.
+    ///
+    /// Returns the id used to query the progress of the input.
+    #[cfg(unix)]
+    pub(crate) fn ipc_send_input_typed(
+        &mut self,
+        tab_id: TabId,
+        text: String,
+        delay: Duration,
+        scheduler: &mut Scheduler,
+    ) -> Result<u64, IpcError> {
+        if self.tabs.get(tab_id).is_none() {
+            return Err(IpcError::new(IpcErrorCode::NotFound, "Tab not found"));
+        }
+
+        let id = NEXT_TYPED_INPUT_ID.fetch_add(1, Ordering::Relaxed);
+        let chars = text.chars().collect();
+        self.typed_input.push_back(TypedInput { id, tab_id, chars, delay });
+        if self.typed_input.len() == 1 {
+            self.schedule_typed_input(scheduler);
+        }
+
+        Ok(id)
+    }
+
+    #[cfg(unix)]
+    fn schedule_typed_input(&self, scheduler: &mut Scheduler) {
+        let Some(input) = self.typed_input.front() else {
+            return;
+        };
+
+        let window_id = self.id();
+        let timer_id = TimerId::new(Topic::TypedInput, window_id);
+        let event = Event::new(EventType::TypedInput, window_id);
+        scheduler.schedule(event, input.delay, false, timer_id);
+    }
+
+    /// Type the next queued character, then schedule the one after it.
+    ///
+    /// Web tabs receive the character as a key press, while terminal tabs receive it as input.
+    #[cfg(unix)]
+    #[cfg_attr(not(target_os = "macos"), allow(unused_variables))]
+    pub(crate) fn type_next_input(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        event_proxy: &EventLoopProxy<Event>,
+        clipboard: &mut Clipboard,
+        scheduler: &mut Scheduler,
+    ) {
+        let Some(input) = self.typed_input.front_mut() else {
+            return;
+        };
+        let tab_id = input.tab_id;
+        let next = input.chars.pop_front();
+        if input.chars.is_empty() {
+            self.typed_input.pop_front();
+        }
+
+        if let Some(ch) = next {
+            let result = match self.tab_kind(tab_id) {
+                #[cfg(target_os = "macos")]
+                Some(kind) if kind.is_web() => {
+                    let key = if ch == '\n' { String::from("enter") } else { ch.to_string() };
+                    let mods = parse_web_modifiers(&[]).unwrap_or_default();
+                    match parse_web_key(&key) {
+                        Some((web_key, text)) => self.with_action_context(
+                            tab_id,
+                            event_loop,
+                            event_proxy,
+                            clipboard,
+                            scheduler,
+                            |ctx| {
+                                ctx.web_send_key(web_key, &text, mods);
+                            },
+                        ),
+                        None => Err(IpcError::new(IpcErrorCode::InvalidRequest, "Unknown key")),
+                    }
+                },
+                Some(_) => self.ipc_send_input(tab_id, ch.to_string()),
+                None => Err(IpcError::new(IpcErrorCode::NotFound, "Tab not found")),
+            };
+
+            // Stop typing into tabs which were closed in the meantime.
+            if result.is_err() {
+                self.typed_input.retain(|input| input.tab_id != tab_id);
+            }
+        }
+
+        self.schedule_typed_input(scheduler);
+    }
+
+    /// Characters left to type of queued input, `None` if it isn't queued in this window.
+    #[cfg(unix)]
+    pub(crate) fn typed_input_remaining(&self, typing_id: u64) -> Option<usize> {
+        self.typed_input.iter().find(|input| input.id == typing_id).map(|input| input.chars.len())
+    }
+
+    /// Whether `typing_id` was returned by an earlier `send_input_typed` request.
+    #[cfg(unix)]
+    pub(crate) fn typed_input_issued(typing_id: u64) -> bool {
+        typing_id > 0 && typing_id < NEXT_TYPED_INPUT_ID.load(Ordering::Relaxed)
+    }
+
+    /// Scroll a web tab to an absolute offset in CSS pixels.
+    #[cfg(unix)]
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn ipc_set_scroll(
+        &mut self,
+        tab_id: TabId,
+        x: f64,
+        y: f64,
+        event_loop: &ActiveEventLoop,
+        event_proxy: &EventLoopProxy<Event>,
+        clipboard: &mut Clipboard,
+        scheduler: &mut Scheduler,
+    ) -> Result<(), IpcError> {
+        #[cfg(target_os = "macos")]
+        {
+            match self.tab_kind(tab_id) {
+                Some(kind) if kind.is_web() => (),
+                Some(_) => {
+                    return Err(IpcError::new(IpcErrorCode::InvalidRequest, "Not a web tab"));
+                },
+                None => return Err(IpcError::new(IpcErrorCode::NotFound, "Tab not found")),
+            }
+
+            self.with_action_context(
+                tab_id,
+                event_loop,
+                event_proxy,
+                clipboard,
+                scheduler,
+                |ctx| ctx.web_scroll_to(x, y),
+            )
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (tab_id, x, y, event_loop, event_proxy, clipboard, scheduler);
+            Err(IpcError::new(
+                IpcErrorCode::Unsupported,
+                "Web tabs are only supported on macOS",
+            ))
+        }
+    }
+
+    /// Add a stylesheet to a web tab, returning the handle used to remove it.
+    #[cfg(unix)]
+    pub(crate) fn ipc_inject_css(&mut self, tab_id: TabId, css: String) -> Result<u64, IpcError> {
+        #[cfg(target_os = "macos")]
+        {
+            let Some(tab) = self.tabs.get_mut(tab_id) else {
+                return Err(IpcError::new(IpcErrorCode::NotFound, "Tab not found"));
+            };
+            let Some(web_view) = tab.web_view.as_mut() else {
+                return Err(IpcError::new(IpcErrorCode::InvalidRequest, "Not a web tab"));
+            };
+
+            web_view
+                .inject_css(&css)
+                .ok_or_else(|| IpcError::new(IpcErrorCode::Internal, "Unable to add stylesheet"))
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (tab_id, css);
+            Err(IpcError::new(
+                IpcErrorCode::Unsupported,
+                "Web tabs are only supported on macOS",
+            ))
+        }
+    }
+
+    /// Remove a stylesheet added to a web tab by [`Self::ipc_inject_css`].
+    #[cfg(unix)]
+    pub(crate) fn ipc_remove_css(&mut self, tab_id: TabId, handle: u64) -> Result<(), IpcError> {
+        #[cfg(target_os = "macos")]
+        {
+            let Some(tab) = self.tabs.get_mut(tab_id) else {
+                return Err(IpcError::new(IpcErrorCode::NotFound, "Tab not found"));
+            };
+            let Some(web_view) = tab.web_view.as_mut() else {
+                return Err(IpcError::new(IpcErrorCode::InvalidRequest, "Not a web tab"));
+            };
+
+            if !web_view.remove_css(handle) {
+                return Err(IpcError::new(IpcErrorCode::NotFound, "Stylesheet not found"));
+            }
+            Ok(())
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (tab_id, handle);
+            Err(IpcError::new(
+                IpcErrorCode::Unsupported,
+                "Web tabs are only supported on macOS",
+            ))
+        }
+    }
+
+    /// Read the scroll offset of a web tab, passing it to `callback` once the page replies.
+    #[cfg(unix)]
+    pub(crate) fn ipc_get_scroll<F>(&mut self, tab_id: TabId, callback: F) -> Result<(), IpcError>
+    where
+        F: FnOnce(Option<(f64, f64)>) + 'static,
+    {
+        #[cfg(target_os = "macos")]
+        {
+            let Some(tab) = self.tabs.get_mut(tab_id) else {
+                return Err(IpcError::new(IpcErrorCode::NotFound, "Tab not found"));
+            };
+            let Some(web_view) = tab.web_view.as_mut() else {
+                return Err(IpcError::new(IpcErrorCode::InvalidRequest, "Not a web tab"));
+            };
+
+            let script = "JSON.stringify([window.scrollX, window.scrollY]);";
+            web_view.eval_js_string(script, move |result| {
+                callback(result.and_then(|result| json::from_str(&result).ok()));
+            });
+            Ok(())
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (tab_id, callback);
+            Err(IpcError::new(
+                IpcErrorCode::Unsupported,
+                "Web tabs are only supported on macOS",
+            ))
+        }
+    }
+
+    /// Read the text of a web page, passing it to `callback` once the page replies.
+    #[cfg(unix)]
+    pub(crate) fn ipc_get_page_text<F>(
+        &mut self,
+        tab_id: TabId,
+        callback: F,
+    ) -> Result<(), IpcError>
+    where
+        F: FnOnce(Option<String>) + 'static,
+    {
+        #[cfg(target_os = "macos")]
+        {
+            let Some(tab) = self.tabs.get_mut(tab_id) else {
+                return Err(IpcError::new(IpcErrorCode::NotFound, "Tab not found"));
+            };
+            let Some(web_view) = tab.web_view.as_mut() else {
+                return Err(IpcError::new(IpcErrorCode::InvalidRequest, "Not a web tab"));
+            };
+
+            let script = "document.body ? document.body.innerText : \"\";";
+            web_view.eval_js_string(script, callback);
+            Ok(())
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (tab_id, callback);
+            Err(IpcError::new(
+                IpcErrorCode::Unsupported,
+                "Web tabs are only supported on macOS",
+            ))
+        }
+    }
+
+    #[cfg(unix)]
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn ipc_send_key(
+        &mut self,
+        tab_id: TabId,
+        key: String,
+        modifiers: Vec<String>,
+        event_loop: &ActiveEventLoop,
+        event_proxy: &EventLoopProxy<Event>,
+        clipboard: &mut Clipboard,
+        scheduler: &mut Scheduler,
+    ) -> Result<(), IpcError> {
+        #[cfg(target_os = "macos")]
+        {
+            let Some((web_key, text)) = parse_web_key(&key) else {
+                let message = format!("Unknown key: {key}");
+                return Err(IpcError::new(IpcErrorCode::InvalidRequest, message));
+            };
+            let Some(mods) = parse_web_modifiers(&modifiers) else {
+                let message = format!("Unknown modifiers: {}", modifiers.join(", "));
+                return Err(IpcError::new(IpcErrorCode::InvalidRequest, message));
+            };
+
+            match self.tab_kind(tab_id) {
+                Some(kind) if kind.is_web() => (),
+                Some(_) => {
+                    return Err(IpcError::new(IpcErrorCode::InvalidRequest, "Not a web tab"));
+                },
+                None => return Err(IpcError::new(IpcErrorCode::NotFound, "Tab not found")),
+            }
+
+            let mut delivered = false;
+            self.with_action_context(
+                tab_id,
+                event_loop,
+                event_proxy,
+                clipboard,
+                scheduler,
+                |ctx| {
+                    delivered = ctx.web_send_key(web_key, &text, mods);
+                },
+            )?;
+
+            if delivered {
+                Ok(())
+            } else {
+                Err(IpcError::new(IpcErrorCode::Internal, "Web view is unavailable"))
+            }
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (tab_id, key, modifiers, event_loop, event_proxy, clipboard, scheduler);
+            Err(IpcError::new(
+                IpcErrorCode::Unsupported,
+                "Web tabs are only supported on macOS",
+            ))
+        }
+    }
+
     #[cfg(unix)]
     pub(crate) fn ipc_run_command_bar(
         &mut self,
@@ -2139,7 +3636,7 @@ impl WindowContext {
         &mut self,
         session_id: String,
         max: Option<usize>,
-    ) -> Result<Vec<IpcInspectorMessage>, IpcError> {
+    ) -> Result<(Vec<IpcInspectorMessage>, bool), IpcError> {
         #[cfg(not(target_os = "macos"))]
         {
             let _ = (session_id, max);
@@ -2152,20 +3649,21 @@ impl WindowContext {
         #[cfg(target_os = "macos")]
         {
             self.ensure_remote_inspector()?;
-            let messages = self
+            let poll = self
                 .remote_inspector
                 .as_ref()
                 .expect("remote inspector should be initialized")
                 .poll_messages(&session_id, max)
                 .map_err(map_inspector_error)?;
-            let mapped = messages
+            let mapped = poll
+                .messages
                 .into_iter()
                 .map(|message| IpcInspectorMessage {
                     session_id: message.session_id,
                     payload: message.payload,
                 })
                 .collect();
-            Ok(mapped)
+            Ok((mapped, poll.dropped))
         }
     }
 
@@ -2219,9 +3717,12 @@ impl WindowContext {
             let mut terminal = active_tab.terminal.lock();
             let mut context = ActionContext {
                 cursor_blink_timed_out: &mut active_tab.cursor_blink_timed_out,
+                cursor_color: &mut active_tab.cursor_color,
                 prev_bell_cmd: &mut active_tab.prev_bell_cmd,
                 message_buffer: &mut self.message_buffer,
                 inline_search_state: &mut active_tab.inline_search_state,
+                vi_marks: &mut active_tab.vi_marks,
+                macros: &mut self.macros,
                 search_state: &mut active_tab.search_state,
                 command_state: &mut active_tab.command_state,
                 command_history: &mut self.command_history,
@@ -2233,6 +3734,7 @@ impl WindowContext {
                 web_command_state: &mut active_tab.web_command_state,
                 modifiers: &mut self.modifiers,
                 notifier: &mut active_tab.notifier,
+                broadcast: Vec::new(),
                 display: &mut self.display,
                 mouse: &mut active_tab.mouse,
                 touch: &mut active_tab.touch,
@@ -2243,7 +3745,7 @@ impl WindowContext {
                 master_fd: active_tab.master_fd,
                 #[cfg(not(windows))]
                 shell_pid: active_tab.shell_pid,
-                preserve_title: self.preserve_title,
+                preserve_title: self.preserve_title || self.overridden_title.is_some(),
                 config: &self.config,
                 event_proxy,
                 #[cfg(target_os = "macos")]
@@ -2261,13 +3763,24 @@ impl WindowContext {
 
     #[cfg(target_os = "macos")]
     fn ensure_remote_inspector(&mut self) -> Result<(), IpcError> {
-        if self.remote_inspector.is_none() {
-            let inspector = RemoteInspectorClient::connect().map_err(map_inspector_error)?;
-            self.remote_inspector = Some(inspector);
+        let queue_limit = self.config.web.inspector_queue_limit;
+        match &self.remote_inspector {
+            Some(inspector) => inspector.set_queue_limit(queue_limit),
+            None => {
+                let inspector =
+                    RemoteInspectorClient::connect(queue_limit).map_err(map_inspector_error)?;
+                self.remote_inspector = Some(inspector);
+            },
         }
         Ok(())
     }
 
+    /// Signal for messages queued on this window's inspector sessions.
+    #[cfg(target_os = "macos")]
+    pub(crate) fn inspector_message_signal(&self) -> Option<Arc<InspectorMessageSignal>> {
+        self.remote_inspector.as_ref().map(RemoteInspectorClient::message_signal)
+    }
+
     #[cfg(target_os = "macos")]
     fn inspector_tabs(&self) -> Vec<InspectorTabInfo> {
         self.tabs
@@ -2311,8 +3824,10 @@ impl WindowContext {
     fn apply_ipc_display_update(&mut self, old_is_searching: bool) {
         if self.display.pending_update.dirty {
             if let Some(active_id) = self.tabs.active_id() {
+                let pane_id = self.split.and_then(|split| split.other(active_id));
                 Self::submit_display_update(
                     active_id,
+                    pane_id,
                     &mut self.tabs,
                     &mut self.display,
                     &self.message_buffer,
@@ -2324,10 +3839,23 @@ impl WindowContext {
         }
     }
 
+    #[cfg(unix)]
+    fn ipc_scrollback(tab: &TabState) -> Option<IpcScrollback> {
+        if tab.kind.is_web() {
+            return None;
+        }
+
+        let terminal = tab.terminal.lock();
+        let grid = terminal.primary_grid();
+        Some(IpcScrollback { bytes: grid.history_bytes(), evicted_lines: grid.evicted_lines() })
+    }
+
     #[cfg(unix)]
     fn ipc_activity(activity: &TabActivity, now: Instant) -> IpcTabActivity {
         IpcTabActivity {
             has_unseen_output: activity.has_unseen_output,
+            has_unseen_bell: activity.has_unseen_bell,
+            has_unseen_title: activity.has_unseen_title,
             last_output_ms_ago: activity
                 .last_output
                 .map(|last| now.saturating_duration_since(last).as_millis() as u64),
@@ -2367,13 +3895,20 @@ impl WindowContext {
         }
     }
 
+    /// Whether the window title follows the title of the active tab.
+    fn dynamic_title(&self) -> bool {
+        !self.preserve_title
+            && self.overridden_title.is_none()
+            && self.config.window.dynamic_title
+    }
+
     fn update_tab_title(&mut self, tab_id: TabId, title: String) {
         let custom_title = self.tabs.custom_title(tab_id).map(str::to_string);
         if self.tabs.set_title(tab_id, title.clone()) {
-            if Some(tab_id) == self.tabs.active_id()
-                && !self.preserve_title
-                && self.config.window.dynamic_title
-            {
+            #[cfg(unix)]
+            self.ipc_events
+                .push(IpcEvent::TabTitleChanged { tab_id: tab_id.into(), title: title.clone() });
+            if Some(tab_id) == self.tabs.active_id() && self.dynamic_title() {
                 let window_title = custom_title.clone().unwrap_or(title);
                 self.display.window.set_title(window_title);
             }
@@ -2387,11 +3922,10 @@ impl WindowContext {
         if !self.tabs.set_custom_title(tab_id, name.clone()) {
             return;
         }
+        #[cfg(unix)]
+        self.ipc_events.push(IpcEvent::TabRenamed { tab_id: tab_id.into(), name: name.clone() });
 
-        if Some(tab_id) == self.tabs.active_id()
-            && !self.preserve_title
-            && self.config.window.dynamic_title
-        {
+        if Some(tab_id) == self.tabs.active_id() && self.dynamic_title() {
             let title = match name {
                 Some(title) => title,
                 None => self.tabs.get(tab_id).map(|tab| tab.title.clone()).unwrap_or_default(),
@@ -2426,33 +3960,143 @@ impl WindowContext {
         let Some(tab) = self.tabs.get(tab_id) else {
             return false;
         };
-
-        if tab.kind.is_web() {
-            return false;
+
+        if tab.kind.is_web() {
+            return false;
+        }
+
+        let Ok(program_name) = foreground_process_name(tab.master_fd, tab.shell_pid) else {
+            return false;
+        };
+
+        let (master_fd, shell_pid) = (tab.master_fd, tab.shell_pid);
+        let is_ssh = program_name == "ssh";
+        let changed = self.tabs.set_program_name(tab_id, program_name);
+
+        if changed {
+            let remote_host = is_ssh
+                .then(|| foreground_process_args(master_fd, shell_pid).ok())
+                .flatten()
+                .and_then(|args| ssh_destination(&args));
+            if let Some(tab) = self.tabs.get_mut(tab_id) {
+                tab.remote_host = remote_host;
+            }
+        }
+
+        self.apply_tab_rules(tab_id) || changed
+    }
+
+    #[cfg(windows)]
+    fn update_tab_program_name(&mut self, _tab_id: TabId) -> bool {
+        false
+    }
+
+    /// Update the tab's accent color, protection and SSH profile from the config.
+    ///
+    /// Returns `true` if the tab's rule color or title has changed.
+    fn apply_tab_rules(&mut self, tab_id: TabId) -> bool {
+        let Some(tab) = self.tabs.get_mut(tab_id) else {
+            return false;
+        };
+
+        let tabs = &self.config.tabs;
+        let ssh_host = tab.ssh_host.as_deref().or(tab.remote_host.as_deref());
+        let rule = match &tab.kind {
+            // Hosts of SSH tabs take precedence over the `ssh` process.
+            WindowKind::Terminal => ssh_host
+                .and_then(|host| tabs.matching_rule(TabRuleTarget::Host(host)))
+                .or_else(|| tabs.matching_rule(TabRuleTarget::Process(&tab.program_name))),
+            WindowKind::Web { url } => {
+                let host = Url::parse(url).ok().and_then(|url| url.host_str().map(str::to_owned));
+                host.and_then(|host| tabs.matching_rule(TabRuleTarget::Host(&host)))
+            },
+        };
+        let profile = ssh_host.and_then(|host| tabs.ssh_profile(host));
+
+        // Profiles are more specific than rules, while `:tabcolor` overrides both.
+        let rule_color =
+            profile.and_then(|profile| profile.color).or(rule.and_then(|rule| rule.color));
+        tab.protected = rule.is_some_and(|rule| rule.protected);
+        let color_changed = tab.rule_color != rule_color;
+        tab.rule_color = rule_color;
+
+        let scrollback = profile.and_then(SshProfile::scrollback);
+        if tab.scrollback != scrollback {
+            tab.scrollback = scrollback;
+            tab.terminal.lock().set_options(Self::tab_term_options(&self.config, scrollback));
+        }
+
+        // Tabs opened with `:ssh` are named after their host even without a profile.
+        let profile_title = match (profile, ssh_host) {
+            (Some(profile), Some(host)) => Some(profile.title(host)),
+            (None, Some(host)) if tab.ssh_host.is_some() => Some(host.to_owned()),
+            _ => None,
+        };
+        if tab.profile_title == profile_title {
+            return color_changed;
         }
 
-        let Ok(program_name) = foreground_process_name(tab.master_fd, tab.shell_pid) else {
-            return false;
-        };
+        // Names set with `:T` or over IPC take precedence over the profile.
+        let renamed = tab.custom_title != tab.profile_title;
+        tab.profile_title = profile_title.clone();
+        if renamed {
+            return color_changed;
+        }
 
-        self.tabs.set_program_name(tab_id, program_name)
+        self.rename_tab(tab_id, profile_title);
+        true
     }
 
-    #[cfg(windows)]
-    fn update_tab_program_name(&mut self, _tab_id: TabId) -> bool {
-        false
+    /// Terminal options of a tab, using the scrollback of its SSH profile.
+    fn tab_term_options(config: &UiConfig, scrollback: Option<usize>) -> TermConfig {
+        let mut options = config.term_options();
+        if let Some(scrollback) = scrollback {
+            options.scrolling_history = scrollback;
+        }
+        options
     }
 
     /// Update the terminal window to the latest config.
     pub fn update_config(&mut self, new_config: Rc<UiConfig>) {
         let old_config = mem::replace(&mut self.config, new_config);
 
+        // Apply the window's theme before the overrides, so these can still change its colors.
+        if let Some(colors) = self.theme.as_ref().and_then(|theme| self.config.themes.get(theme)) {
+            let colors = colors.clone();
+            Rc::make_mut(&mut self.config).colors = colors;
+        }
+
         // Apply ipc config if there are overrides.
         self.config = self.window_config.override_config_rc(self.config.clone());
 
         self.display.update_config(&self.config);
         for tab in self.tabs.iter_mut() {
-            tab.terminal.lock().set_options(self.config.term_options());
+            let options = Self::tab_term_options(&self.config, tab.scrollback);
+            tab.terminal.lock().set_options(options);
+        }
+
+        #[cfg(target_os = "macos")]
+        for web_view in self.tabs.iter_mut().filter_map(|tab| tab.web_view.as_mut()) {
+            web_view.set_new_window_links(self.config.web.new_window_links);
+        }
+
+        #[cfg(target_os = "macos")]
+        if old_config.colors != self.config.colors {
+            let dark = self.web_dark_appearance();
+            for web_view in self.tabs.iter_mut().filter_map(|tab| tab.web_view.as_mut()) {
+                web_view.set_dark_appearance(dark);
+            }
+        }
+
+        #[cfg(target_os = "macos")]
+        if old_config.web.user_agent() != self.config.web.user_agent() {
+            for tab in self.tabs.iter_mut() {
+                let Some(web_view) = tab.web_view.as_mut() else {
+                    continue;
+                };
+                let settings = tab.site_host.as_deref().map(site_settings).unwrap_or_default();
+                web_view.set_user_agent(site_user_agent(&self.config.web, &settings));
+            }
         }
 
         // Reload cursor if its thickness has changed.
@@ -2492,6 +4136,7 @@ impl WindowContext {
         // │ N  │       Y       │              N              ││     N     │
         // │ N  │       N       │              _              ││     Y     │
         if !self.preserve_title
+            && self.overridden_title.is_none()
             && (!self.config.window.dynamic_title
                 || self.display.window.title() == old_config.window.identity.title)
         {
@@ -2514,6 +4159,15 @@ impl WindowContext {
         // Update hint keys.
         self.display.hint_state.update_alphabet(self.config.hints.alphabet());
 
+        // Re-evaluate tab rules.
+        if old_config.tabs != self.config.tabs {
+            let tab_ids: Vec<_> = self.tabs.iter().map(|tab| tab.id).collect();
+            for tab_id in tab_ids {
+                self.apply_tab_rules(tab_id);
+            }
+            self.refresh_tab_panel();
+        }
+
         // Update cursor blinking.
         let event = Event::new(TerminalEvent::CursorBlinkingChange.into(), None);
         self.event_queue.push(event.into());
@@ -2534,6 +4188,7 @@ impl WindowContext {
         self.message_buffer.remove_target(LOG_TARGET_IPC_CONFIG);
 
         self.window_config.clear();
+        self.theme = None;
 
         // Reload current config to pull new IPC config.
         self.update_config(config);
@@ -2551,6 +4206,28 @@ impl WindowContext {
         self.update_config(config);
     }
 
+    /// Override the window colors with one of the configured themes.
+    #[cfg(unix)]
+    pub fn set_theme(&mut self, config: Rc<UiConfig>, name: &str) {
+        if !config.themes.contains_key(name) {
+            return;
+        }
+
+        self.theme = Some(name.to_owned());
+
+        self.message_buffer.remove_target(LOG_TARGET_IPC_CONFIG);
+        self.update_config(config);
+
+        self.display.damage_tracker.frame().mark_fully_damaged();
+        self.display.pending_update.dirty = true;
+    }
+
+    /// Appearance of web pages matching the window's theme, `None` following the system.
+    #[cfg(target_os = "macos")]
+    fn web_dark_appearance(&self) -> Option<bool> {
+        self.theme.is_some().then(|| self.config.colors.primary.background.luminance() < 0.5)
+    }
+
     /// Draw the window.
     pub fn draw(&mut self, scheduler: &mut Scheduler) {
         self.display.window.requested_redraw = false;
@@ -2564,8 +4241,8 @@ impl WindowContext {
         // Force the display to process any pending display update.
         self.display.process_renderer_update();
 
-        // Request immediate re-draw if visual bell animation is not finished yet.
-        if !self.display.visual_bell.completed() {
+        // Request immediate re-draw if visual bell or cursor animations are not finished yet.
+        if !self.display.visual_bell.completed() || !self.display.cursor_motion.completed() {
             // We can get an OS redraw which bypasses tabor's frame throttling, thus
             // marking the window as dirty when we don't have frame yet.
             if self.display.window.has_frame {
@@ -2575,11 +4252,31 @@ impl WindowContext {
             }
         }
 
+        // Collect the unfocused pane of the split.
+        let pane = self
+            .split
+            .zip(self.tabs.active_id())
+            .and_then(|(split, id)| split.other(id))
+            .and_then(|pane_id| self.tabs.get(pane_id))
+            .map(|pane| {
+                let terminal = match draw_mode(&pane.kind) {
+                    DrawMode::Terminal => Some(pane.terminal.clone()),
+                    DrawMode::Web => None,
+                };
+                (terminal, pane.cursor_color)
+            });
+        let split_pane = pane.as_ref().map(|(terminal, cursor_color)| SplitPane {
+            terminal: terminal.as_ref().map(|terminal| terminal.lock()),
+            cursor_color: *cursor_color,
+        });
+
         // Redraw the window.
         let Some(tab) = self.tabs.active_mut() else {
             return;
         };
 
+        self.display.cursor_color = tab.cursor_color;
+
         match draw_mode(&tab.kind) {
             DrawMode::Web => {
                 self.display.draw_web(
@@ -2587,6 +4284,7 @@ impl WindowContext {
                     &self.message_buffer,
                     &self.config,
                     &tab.command_state,
+                    split_pane,
                 );
             },
             DrawMode::Terminal => {
@@ -2598,6 +4296,7 @@ impl WindowContext {
                     &self.config,
                     &mut tab.search_state,
                     &tab.command_state,
+                    split_pane,
                 );
             },
         }
@@ -2642,11 +4341,50 @@ impl WindowContext {
                 self.window_focused = *is_focused;
             }
 
+            // Releasing all modifiers picks the tab reached by stepping through recent tabs.
+            match &event {
+                WinitEvent::WindowEvent { event: WindowEvent::ModifiersChanged(modifiers), .. }
+                    if modifiers.state().is_empty() =>
+                {
+                    self.tabs.commit_mru();
+                },
+                WinitEvent::WindowEvent { event: WindowEvent::Focused(false), .. } => {
+                    self.tabs.commit_mru();
+                },
+                _ => (),
+            }
+
+            // Track the mouse to focus split panes on click.
+            match &event {
+                WinitEvent::WindowEvent {
+                    event: WindowEvent::CursorMoved { position, .. },
+                    ..
+                } => self.mouse_position = Some(*position),
+                WinitEvent::WindowEvent {
+                    event: WindowEvent::MouseInput { state: ElementState::Pressed, .. },
+                    ..
+                } if self.focus_pane_at_mouse() => continue,
+                _ => (),
+            }
+
+            // Closing the window closes all its tabs, which might be protected.
+            if let WinitEvent::WindowEvent { event: WindowEvent::CloseRequested, .. } = &event {
+                if !self.confirm_close_window() {
+                    continue;
+                }
+            }
+
             if let WinitEvent::UserEvent(event) = &event {
                 match event.payload() {
                     #[cfg(target_os = "macos")]
                     EventType::WebCommand(command) => {
-                        self.handle_web_command_event(event, command, clipboard, event_proxy);
+                        self.handle_web_command_event(
+                            event,
+                            command,
+                            clipboard,
+                            event_proxy,
+                            scheduler,
+                        );
                         continue;
                     },
                     #[cfg(target_os = "macos")]
@@ -2666,6 +4404,56 @@ impl WindowContext {
                         continue;
                     },
                     #[cfg(target_os = "macos")]
+                    EventType::WebNotification { title, body } => {
+                        let Some(tab_id) = event.tab_id() else {
+                            continue;
+                        };
+                        if self.web_notifications_allowed(tab_id) {
+                            crate::macos::post_web_notification(self.id(), tab_id, title, body);
+                        }
+                        continue;
+                    },
+                    #[cfg(target_os = "macos")]
+                    EventType::WebNotificationPermission => {
+                        let Some(tab_id) = event.tab_id() else {
+                            continue;
+                        };
+                        let granted = self.web_notifications_allowed(tab_id);
+                        let tab = self.tabs.get_mut(tab_id);
+                        if let Some(web_view) = tab.and_then(|tab| tab.web_view.as_mut()) {
+                            web_view.exec_js(&notification_permission_script(granted));
+                        }
+                        continue;
+                    },
+                    #[cfg(target_os = "macos")]
+                    EventType::WebNotificationClicked => {
+                        let Some(tab_id) = event.tab_id() else {
+                            continue;
+                        };
+                        if self.tabs.get(tab_id).is_some() {
+                            self.set_active_tab(tab_id);
+                            self.display.window.focus_window();
+                            self.dirty = true;
+                        }
+                        continue;
+                    },
+                    #[cfg(target_os = "macos")]
+                    EventType::WebHoverLink { url } => {
+                        let Some(tab_id) = event.tab_id() else {
+                            continue;
+                        };
+                        self.handle_web_hover_link(tab_id, url.clone());
+                        continue;
+                    },
+                    #[cfg(target_os = "macos")]
+                    EventType::WebLoadProgress { fraction, finished } => {
+                        let Some(tab_id) = event.tab_id() else {
+                            continue;
+                        };
+                        self.handle_web_load_progress(tab_id, *fraction, *finished);
+                        continue;
+                    },
+                    #[cfg(target_os = "macos")]
                     EventType::WebCursorRequest => {
                         let Some(tab_id) = event.tab_id() else {
                             continue;
@@ -2689,11 +4477,28 @@ impl WindowContext {
                         match term_event {
                             TerminalEvent::Title(title) => {
                                 self.update_tab_title(tab_id, title.clone());
+                                self.note_tab_activity(tab_id, TabActivityKind::Title);
                             },
                             TerminalEvent::ResetTitle => {
                                 let title = self.config.window.identity.title.clone();
                                 self.update_tab_title(tab_id, title);
                             },
+                            TerminalEvent::Bell => {
+                                self.note_tab_activity(tab_id, TabActivityKind::Bell);
+                                #[cfg(target_os = "macos")]
+                                self.notify_bell(tab_id);
+                            },
+                            TerminalEvent::CommandExecuted(command) => {
+                                if let Some(tab) = self.tabs.get_mut(tab_id) {
+                                    let command = command.clone();
+                                    let start = Instant::now();
+                                    tab.running_command = Some(RunningCommand { command, start });
+                                }
+                                self.refresh_tab_panel();
+                            },
+                            TerminalEvent::CommandFinished(exit_code) => {
+                                self.command_finished(tab_id, *exit_code);
+                            },
                             _ => (),
                         }
 
@@ -2702,6 +4507,27 @@ impl WindowContext {
                             continue;
                         }
                     },
+                    // Scrolling of tabs which are no longer visible is discarded.
+                    #[cfg(target_os = "macos")]
+                    EventType::WebScroll if event.tab_id() != active_id => {
+                        let tab = event.tab_id().and_then(|tab_id| self.tabs.get_mut(tab_id));
+                        if let Some(tab) = tab {
+                            tab.web_command_state.take_smooth_scroll();
+                        }
+                        continue;
+                    },
+                    // Output is pasted into the tab which ran the command.
+                    EventType::PipeOutput(output)
+                        if output.target == PipeTarget::Paste && event.tab_id() != active_id =>
+                    {
+                        self.message_buffer.push(crate::message_bar::Message::new(
+                            format!("Discarded output of {}, its tab is inactive", output.command),
+                            crate::message_bar::MessageType::Info,
+                        ));
+                        self.display.pending_update.dirty = true;
+                        self.dirty = true;
+                        continue;
+                    },
                     EventType::UpdateTabProgramName => {
                         let Some(tab_id) = event.tab_id() else {
                             continue;
@@ -2723,6 +4549,7 @@ impl WindowContext {
             .tabs
             .active()
             .is_some_and(|tab| tab.search_state.history_index.is_some());
+        let broadcast = self.broadcast_notifiers();
 
         {
             let Some(active_tab) = self.tabs.active_mut() else {
@@ -2732,9 +4559,12 @@ impl WindowContext {
             let mut terminal = active_tab.terminal.lock();
             let context = ActionContext {
                 cursor_blink_timed_out: &mut active_tab.cursor_blink_timed_out,
+                cursor_color: &mut active_tab.cursor_color,
                 prev_bell_cmd: &mut active_tab.prev_bell_cmd,
                 message_buffer: &mut self.message_buffer,
                 inline_search_state: &mut active_tab.inline_search_state,
+                vi_marks: &mut active_tab.vi_marks,
+                macros: &mut self.macros,
                 search_state: &mut active_tab.search_state,
                 command_state: &mut active_tab.command_state,
                 command_history: &mut self.command_history,
@@ -2746,6 +4576,7 @@ impl WindowContext {
                 web_command_state: &mut active_tab.web_command_state,
                 modifiers: &mut self.modifiers,
                 notifier: &mut active_tab.notifier,
+                broadcast,
                 display: &mut self.display,
                 mouse: &mut active_tab.mouse,
                 touch: &mut active_tab.touch,
@@ -2756,7 +4587,7 @@ impl WindowContext {
                 master_fd: active_tab.master_fd,
                 #[cfg(not(windows))]
                 shell_pid: active_tab.shell_pid,
-                preserve_title: self.preserve_title,
+                preserve_title: self.preserve_title || self.overridden_title.is_some(),
                 config: &self.config,
                 event_proxy,
                 #[cfg(target_os = "macos")]
@@ -2774,8 +4605,10 @@ impl WindowContext {
         // Process DisplayUpdate events.
         if self.display.pending_update.dirty {
             if let Some(active_id) = self.tabs.active_id() {
+                let pane_id = self.split.and_then(|split| split.other(active_id));
                 Self::submit_display_update(
                     active_id,
+                    pane_id,
                     &mut self.tabs,
                     &mut self.display,
                     &self.message_buffer,
@@ -2967,6 +4800,24 @@ impl WindowContext {
         }
     }
 
+    /// Whether a tab, or the active one without a tab, is private.
+    #[cfg(target_os = "macos")]
+    fn is_private_tab(&self, tab_id: Option<TabId>) -> bool {
+        let tab = match tab_id {
+            Some(tab_id) => self.tabs.get(tab_id),
+            None => self.tabs.active(),
+        };
+        tab.is_some_and(|tab| tab.private)
+    }
+
+    /// Check if the page of a web tab is allowed to post notifications.
+    #[cfg(target_os = "macos")]
+    fn web_notifications_allowed(&self, tab_id: TabId) -> bool {
+        let web_view = self.tabs.get(tab_id).and_then(|tab| tab.web_view.as_ref());
+        let url = web_view.and_then(|web_view| web_view.current_url());
+        url.is_some_and(|url| self.config.web.notifications_allowed(&url))
+    }
+
     #[cfg(target_os = "macos")]
     fn handle_web_command_event(
         &mut self,
@@ -2974,11 +4825,17 @@ impl WindowContext {
         command: &WebCommand,
         clipboard: &mut Clipboard,
         event_proxy: &EventLoopProxy<Event>,
+        scheduler: &mut Scheduler,
     ) {
         match command {
-            WebCommand::CopyToClipboard { text } => {
-                if !text.is_empty() {
-                    clipboard.store(tabor_terminal::term::ClipboardType::Clipboard, text.clone());
+            WebCommand::CopyToClipboard { text, html } => {
+                match html {
+                    _ if text.is_empty() => (),
+                    Some(html) => clipboard.store_rich_text(text.clone(), html),
+                    None => {
+                        let ty = tabor_terminal::term::ClipboardType::Clipboard;
+                        clipboard.store(ty, text.clone());
+                    },
                 }
                 if let Some(tab_id) = event.tab_id().or(self.tabs.active_id()) {
                     if let Some(tab) = self.tabs.get_mut(tab_id) {
@@ -2986,9 +4843,63 @@ impl WindowContext {
                     }
                 }
             },
+            WebCommand::SaveScroll { url, scroll_x, scroll_y } => {
+                if let Some(tab) = event.tab_id().and_then(|tab_id| self.tabs.get_mut(tab_id)) {
+                    tab.web_command_state.remember_scroll(url.clone(), *scroll_x, *scroll_y);
+                }
+            },
+            WebCommand::MiddleClick { url: Some(url) } => {
+                let url = strip_query_params(url.clone(), &self.config.web.strip_query_params);
+                let background =
+                    self.config.web.new_window_links == WebNewWindowLinks::BackgroundTab;
+                let private = self.is_private_tab(event.tab_id());
+                if let Err(err) = self.open_web_url_new_tab(
+                    url,
+                    event_proxy,
+                    WebNavigationTrigger::MiddleClick,
+                    background,
+                    private,
+                ) {
+                    self.message_buffer.push(crate::message_bar::Message::new(
+                        format!("Failed to open URL: {err}"),
+                        crate::message_bar::MessageType::Error,
+                    ));
+                    self.display.pending_update.dirty = true;
+                }
+            },
+            WebCommand::MiddleClick { url: None } => {
+                // Pass clicks outside of links on to the page.
+                let Some(tab) = event.tab_id().and_then(|tab_id| self.tabs.get_mut(tab_id)) else {
+                    return;
+                };
+                let Some(position) = tab.web_command_state.last_cursor_pos() else {
+                    return;
+                };
+                let Some(web_view) = tab.web_view.as_mut() else {
+                    return;
+                };
+                for state in [ElementState::Pressed, ElementState::Released] {
+                    web_view.handle_mouse_input(
+                        &self.display.window,
+                        &self.display.size_info,
+                        position,
+                        state,
+                        MouseButton::Middle,
+                        NSEventModifierFlags::empty(),
+                    );
+                }
+            },
             WebCommand::OpenUrl { url, new_tab } => {
+                let url = strip_query_params(url.clone(), &self.config.web.strip_query_params);
                 if *new_tab {
-                    if let Err(err) = self.open_web_url_new_tab(url.clone(), event_proxy) {
+                    let private = self.is_private_tab(event.tab_id());
+                    if let Err(err) = self.open_web_url_new_tab(
+                        url,
+                        event_proxy,
+                        WebNavigationTrigger::Hint,
+                        false,
+                        private,
+                    ) {
                         self.message_buffer.push(crate::message_bar::Message::new(
                             format!("Failed to open URL: {err}"),
                             crate::message_bar::MessageType::Error,
@@ -3002,7 +4913,9 @@ impl WindowContext {
                     return;
                 };
 
-                if let Err(message) = self.open_web_url_in_tab(tab_id, url.clone()) {
+                if let Err(message) =
+                    self.open_web_url_in_tab(tab_id, url, WebNavigationTrigger::Hint)
+                {
                     self.message_buffer.push(crate::message_bar::Message::new(
                         message,
                         crate::message_bar::MessageType::Error,
@@ -3013,6 +4926,159 @@ impl WindowContext {
                     tab.web_command_state.reset_mode();
                 }
             },
+            WebCommand::JumpToHeading { found } => {
+                let Some(tab) = event.tab_id().and_then(|tab_id| self.tabs.get_mut(tab_id)) else {
+                    return;
+                };
+                tab.web_command_state.reset_mode();
+
+                if !found {
+                    self.message_buffer.push(crate::message_bar::Message::new(
+                        String::from("No headings on this page"),
+                        crate::message_bar::MessageType::Warning,
+                    ));
+                    self.display.pending_update.dirty = true;
+                }
+            },
+            WebCommand::InspectElement { description } => {
+                let message = match WebElementInfo::parse(description) {
+                    Some(info) => {
+                        clipboard.store(
+                            tabor_terminal::term::ClipboardType::Clipboard,
+                            info.selector.clone(),
+                        );
+                        crate::message_bar::Message::new(
+                            info.to_string(),
+                            crate::message_bar::MessageType::Info,
+                        )
+                    },
+                    None => crate::message_bar::Message::new(
+                        String::from("Could not inspect element"),
+                        crate::message_bar::MessageType::Error,
+                    ),
+                };
+                self.message_buffer.push(message);
+                self.display.pending_update.dirty = true;
+
+                if let Some(tab_id) = event.tab_id().or(self.tabs.active_id()) {
+                    if let Some(tab) = self.tabs.get_mut(tab_id) {
+                        tab.web_command_state.reset_mode();
+                    }
+                }
+            },
+            WebCommand::DumpForm { description } => {
+                let message = match WebFormField::parse_all(description) {
+                    Some(fields) if fields.is_empty() => crate::message_bar::Message::new(
+                        String::from("No form fields on the page"),
+                        crate::message_bar::MessageType::Warning,
+                    ),
+                    Some(fields) => {
+                        if let Ok(pretty) = json::to_string_pretty(&fields) {
+                            clipboard.store(tabor_terminal::term::ClipboardType::Clipboard, pretty);
+                        }
+                        let summary: Vec<_> = fields.iter().map(ToString::to_string).collect();
+                        crate::message_bar::Message::new(
+                            summary.join("\n"),
+                            crate::message_bar::MessageType::Info,
+                        )
+                    },
+                    None => crate::message_bar::Message::new(
+                        String::from("Could not read form fields"),
+                        crate::message_bar::MessageType::Error,
+                    ),
+                };
+                self.message_buffer.push(message);
+                self.display.pending_update.dirty = true;
+            },
+            WebCommand::SiteDataCleared { result } => {
+                let tab = event.tab_id().and_then(|tab_id| self.tabs.get_mut(tab_id));
+                if let Some(web_view) = tab.and_then(|tab| tab.web_view.as_mut()) {
+                    web_view.reload();
+                }
+                let message = match result {
+                    Ok(message) => crate::message_bar::Message::new(
+                        message.clone(),
+                        crate::message_bar::MessageType::Info,
+                    ),
+                    Err(err) => crate::message_bar::Message::new(
+                        err.clone(),
+                        crate::message_bar::MessageType::Error,
+                    ),
+                };
+                self.message_buffer.push(message);
+                self.display.pending_update.dirty = true;
+            },
+            WebCommand::SetAutoReload { interval } => {
+                let Some(tab_id) = event.tab_id() else {
+                    return;
+                };
+                let Some(tab) = self.tabs.get_mut(tab_id) else {
+                    return;
+                };
+                tab.auto_reload = *interval;
+
+                let window_id = self.display.window.id();
+                let timer_id = TimerId::new(Topic::WebAutoReload(tab_id), window_id);
+                scheduler.unschedule(timer_id);
+                if let Some(interval) = *interval {
+                    let reload = EventType::WebCommand(WebCommand::AutoReload);
+                    let event = Event::for_tab(reload, window_id, tab_id);
+                    scheduler.schedule(event, interval, true, timer_id);
+                }
+
+                self.refresh_tab_panel();
+            },
+            WebCommand::AutoReload => {
+                let Some(tab_id) = event.tab_id() else {
+                    return;
+                };
+                let tab = self.tabs.get_mut(tab_id).filter(|tab| tab.auto_reload.is_some());
+                let Some(tab) = tab.filter(|tab| tab.web_view.is_some()) else {
+                    // The tab was closed or moved to another window.
+                    let timer_id = TimerId::new(Topic::WebAutoReload(tab_id), self.id());
+                    scheduler.unschedule(timer_id);
+                    return;
+                };
+                if let Some(web_view) = tab.web_view.as_mut() {
+                    web_view.reload();
+                }
+                tab.web_command_state.set_cursor_bootstrapped(false);
+                tab.web_command_state.clear_last_cursor_request();
+            },
+            WebCommand::SaveFavicon { path } => {
+                let tab = event.tab_id().and_then(|tab_id| self.tabs.get(tab_id));
+                let message = match tab.and_then(|tab| tab.favicon.as_ref()) {
+                    Some(favicon) => match favicon.image.save_png(path) {
+                        Ok(()) => crate::message_bar::Message::new(
+                            format!("Saved favicon to {}", path.display()),
+                            crate::message_bar::MessageType::Info,
+                        ),
+                        Err(err) => crate::message_bar::Message::new(
+                            format!("Could not save favicon to {}: {err}", path.display()),
+                            crate::message_bar::MessageType::Error,
+                        ),
+                    },
+                    None => crate::message_bar::Message::new(
+                        String::from("No favicon for this page"),
+                        crate::message_bar::MessageType::Error,
+                    ),
+                };
+                self.message_buffer.push(message);
+                self.display.pending_update.dirty = true;
+            },
+            WebCommand::ConfirmFormSubmit => {
+                let Some(tab_id) = event.tab_id().or(self.tabs.active_id()) else {
+                    return;
+                };
+                if let Some(tab) = self.tabs.get_mut(tab_id) {
+                    tab.web_command_state.arm_form_submit();
+                }
+                self.message_buffer.push(crate::message_bar::Message::new(
+                    String::from("Press Enter again to submit the form"),
+                    crate::message_bar::MessageType::Warning,
+                ));
+                self.display.pending_update.dirty = true;
+            },
             WebCommand::SetMark {
                 name,
                 url,
@@ -3030,12 +5096,63 @@ impl WindowContext {
         }
     }
 
+    /// Clear the running command and notify about it if it took long in the background.
+    fn command_finished(&mut self, tab_id: TabId, exit_code: Option<i32>) {
+        let background = !self.window_focused || self.tabs.active_id() != Some(tab_id);
+        let Some(tab) = self.tabs.get_mut(tab_id) else {
+            return;
+        };
+        let Some(running) = tab.running_command.take() else {
+            return;
+        };
+        self.refresh_tab_panel();
+
+        let Some(threshold) = self.config.notify.long_command_threshold() else {
+            return;
+        };
+        if !background || running.start.elapsed() < threshold {
+            return;
+        }
+
+        #[cfg(target_os = "macos")]
+        if let Some(tab) = self.tabs.get(tab_id) {
+            crate::macos::post_command_notification(
+                &tab.panel_title(),
+                &running.command,
+                exit_code,
+            );
+        }
+        #[cfg(not(target_os = "macos"))]
+        let _ = exit_code;
+    }
+
+    /// Post a notification for bells which the user can't see.
+    #[cfg(target_os = "macos")]
+    fn notify_bell(&self, tab_id: TabId) {
+        if !self.config.bell.notify
+            || (self.window_focused && self.tabs.active_id() == Some(tab_id))
+        {
+            return;
+        }
+
+        if let Some(tab) = self.tabs.get(tab_id) {
+            crate::macos::post_bell_notification(&tab.panel_title());
+        }
+    }
+
     fn handle_inactive_terminal_event(
         &mut self,
         tab_id: TabId,
         event: &TerminalEvent,
         clipboard: &mut Clipboard,
     ) {
+        // Output of the unfocused split pane is visible.
+        if matches!(event, TerminalEvent::Wakeup)
+            && self.split.is_some_and(|split| split.contains(tab_id))
+        {
+            self.dirty = true;
+        }
+
         let Some(tab) = self.tabs.get_mut(tab_id) else {
             return;
         };
@@ -3072,6 +5189,30 @@ impl WindowContext {
             TerminalEvent::PtyWrite(text) => {
                 tab.notifier.notify(text.clone().into_bytes());
             },
+            TerminalEvent::Bell => {
+                // Background tabs are badged instead of ringing the visual bell.
+                let bell = &self.config.bell;
+                let urgency_hints = tab.terminal.lock().mode().contains(TermMode::URGENCY_HINTS);
+                if bell.background_urgency && urgency_hints && !self.window_focused {
+                    self.display.window.set_urgent(true);
+                }
+
+                if let Some(bell_command) = bell.take_command(&mut tab.prev_bell_cmd) {
+                    #[cfg(not(windows))]
+                    let result = spawn_daemon(
+                        bell_command.program(),
+                        bell_command.args(),
+                        tab.master_fd,
+                        tab.shell_pid,
+                    );
+                    #[cfg(windows)]
+                    let result = spawn_daemon(bell_command.program(), bell_command.args());
+
+                    if let Err(err) = result {
+                        warn!("Unable to launch bell command {}: {err}", bell_command.program());
+                    }
+                }
+            },
             _ => (),
         }
     }
@@ -3116,6 +5257,7 @@ impl WindowContext {
     /// Submit the pending changes to the `Display`.
     fn submit_display_update(
         active_id: TabId,
+        pane_id: Option<TabId>,
         tabs: &mut TabManager,
         display: &mut Display,
         message_buffer: &MessageBuffer,
@@ -3160,19 +5302,24 @@ impl WindowContext {
             }
         }
 
+        let pane_size = |tab_id| match display.pane_size_info {
+            Some(pane_size) if Some(tab_id) == pane_id => pane_size,
+            _ => display.size_info,
+        };
+
         #[cfg(target_os = "macos")]
         for tab in tabs.iter_mut() {
             if let Some(web_view) = tab.web_view.as_mut() {
-                web_view.update_frame(&display.window, &display.size_info);
+                web_view.update_frame(&display.window, &pane_size(tab.id));
             }
         }
 
-        let new_size = display.size_info;
         for tab in tabs.iter_mut() {
             if tab.id == active_id {
                 continue;
             }
 
+            let new_size = pane_size(tab.id);
             let mut tab_terminal = tab.terminal.lock();
             if tab_terminal.screen_lines() != new_size.screen_lines()
                 || tab_terminal.columns() != new_size.columns()
@@ -3228,4 +5375,64 @@ mod tests {
         let mode = draw_mode(&WindowKind::Terminal);
         assert_eq!(mode, DrawMode::Terminal);
     }
+
+    /// MRU order with tabs `0..count` selected in ascending order, the last one active.
+    fn recent_tabs(count: u32) -> TabMru {
+        let mut mru = TabMru::default();
+        for index in 0..count {
+            mru.push(TabId::new(index, 0));
+            mru.touch(TabId::new(index, 0));
+        }
+        mru
+    }
+
+    #[test]
+    fn mru_steps_through_recent_tabs() {
+        let mut mru = recent_tabs(3);
+        assert_eq!(mru.step(1), Some(TabId::new(1, 0)));
+        assert_eq!(mru.step(1), Some(TabId::new(0, 0)));
+        assert_eq!(mru.step(1), Some(TabId::new(2, 0)));
+
+        // Selecting tabs while stepping keeps the order.
+        mru.touch(TabId::new(2, 0));
+        assert_eq!(mru.step(1), Some(TabId::new(1, 0)));
+    }
+
+    #[test]
+    fn mru_steps_backwards() {
+        let mut mru = recent_tabs(3);
+        assert_eq!(mru.step(-1), Some(TabId::new(0, 0)));
+        assert_eq!(mru.step(-1), Some(TabId::new(1, 0)));
+        assert_eq!(mru.step(1), Some(TabId::new(0, 0)));
+    }
+
+    #[test]
+    fn mru_commit_moves_reached_tab_to_front() {
+        let mut mru = recent_tabs(3);
+        mru.step(1);
+        mru.step(1);
+        mru.commit(Some(TabId::new(0, 0)));
+        assert_eq!(mru.order, [TabId::new(0, 0), TabId::new(2, 0), TabId::new(1, 0)]);
+
+        // Stepping starts again from the front.
+        assert_eq!(mru.step(1), Some(TabId::new(2, 0)));
+
+        // Committing without stepping keeps the order.
+        let mut mru = recent_tabs(2);
+        mru.commit(Some(TabId::new(0, 0)));
+        assert_eq!(mru.order, [TabId::new(1, 0), TabId::new(0, 0)]);
+    }
+
+    #[test]
+    fn mru_needs_two_tabs() {
+        let mut mru = recent_tabs(1);
+        assert_eq!(mru.step(1), None);
+        assert_eq!(mru.step(-1), None);
+
+        let mut mru = TabMru::default();
+        mru.push(TabId::new(0, 0));
+        mru.push(TabId::new(1, 0));
+        mru.remove(TabId::new(1, 0));
+        assert_eq!(mru.step(1), None);
+    }
 }
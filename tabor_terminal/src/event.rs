@@ -50,6 +50,12 @@ pub enum Event {
     /// Terminal bell ring.
     Bell,
 
+    /// Shell command was submitted, with the text of its command line.
+    CommandExecuted(String),
+
+    /// Shell command has finished, with its exit code when reported by the shell.
+    CommandFinished(Option<i32>),
+
     /// Shutdown request.
     Exit,
 
@@ -71,6 +77,8 @@ impl Debug for Event {
             Event::ResetTitle => write!(f, "ResetTitle"),
             Event::Wakeup => write!(f, "Wakeup"),
             Event::Bell => write!(f, "Bell"),
+            Event::CommandExecuted(command) => write!(f, "CommandExecuted({command})"),
+            Event::CommandFinished(code) => write!(f, "CommandFinished({code:?})"),
             Event::Exit => write!(f, "Exit"),
             Event::ChildExit(code) => write!(f, "ChildExit({code})"),
         }
@@ -15,8 +15,12 @@ use log::error;
 use polling::{Event as PollingEvent, Events, PollMode};
 
 use crate::event::{self, Event, EventListener, WindowSize};
+use crate::grid::Dimensions;
+use crate::index::{Column, Point};
+use crate::prompt_mark::{PromptMark, PromptMarkParser};
 use crate::sync::FairMutex;
 use crate::term::Term;
+use crate::term::cell::Flags;
 use crate::{thread, tty};
 use vte::ansi;
 
@@ -150,8 +154,15 @@ where
                 writer.write_all(&buf[..unprocessed]).unwrap();
             }
 
-            // Parse the incoming bytes.
-            state.parser.advance(&mut **terminal, &buf[..unprocessed]);
+            // Parse the incoming bytes, stopping at prompt marks to inspect the terminal state.
+            let mut bytes = &buf[..unprocessed];
+            while let Some((len, mark)) = state.prompt_marks.next_mark(bytes) {
+                state.parser.advance(&mut **terminal, &bytes[..len]);
+                bytes = &bytes[len..];
+
+                self.handle_prompt_mark(state, terminal, mark);
+            }
+            state.parser.advance(&mut **terminal, bytes);
 
             processed += unprocessed;
             unprocessed = 0;
@@ -170,6 +181,24 @@ where
         Ok(())
     }
 
+    /// Forward shell integration prompt marks to the event listener.
+    fn handle_prompt_mark(&self, state: &mut State, terminal: &Term<U>, mark: PromptMark) {
+        match mark {
+            PromptMark::CommandStart => state.command_start = Some(terminal.grid().cursor.point),
+            PromptMark::CommandExecuted => {
+                let command = match state.command_start.take() {
+                    Some(start) => command_text(terminal, start.column),
+                    None => String::new(),
+                };
+                self.event_proxy.send_event(Event::CommandExecuted(command));
+            },
+            PromptMark::CommandFinished(exit_code) => {
+                self.event_proxy.send_event(Event::CommandFinished(exit_code));
+            },
+            PromptMark::PromptStart => (),
+        }
+    }
+
     #[inline]
     fn pty_write(&mut self, state: &mut State) -> io::Result<()> {
         state.ensure_next();
@@ -392,6 +421,38 @@ impl EventLoopSender {
     }
 }
 
+/// Extract the submitted command line, which ends right before the cursor.
+///
+/// Only the column of the command's start is used, since the terminal might have scrolled after
+/// the command was submitted. The start line is found by following wrapped lines upwards instead.
+fn command_text<T>(terminal: &Term<T>, start_column: Column) -> String {
+    let grid = terminal.grid();
+    let cursor = grid.cursor.point;
+
+    // Ignore the line break which was written when the command was submitted.
+    let end = if cursor.column == 0 && cursor.line > terminal.topmost_line() {
+        Point::new(cursor.line - 1, terminal.last_column())
+    } else {
+        cursor
+    };
+
+    let mut start_line = end.line;
+    while start_line > terminal.topmost_line() {
+        let prev_line = start_line - 1i32;
+        if !grid[prev_line][terminal.last_column()].flags.contains(Flags::WRAPLINE) {
+            break;
+        }
+        start_line = prev_line;
+    }
+
+    let start = Point::new(start_line, start_column);
+    if start > end {
+        return String::new();
+    }
+
+    terminal.bounds_to_string(start, end).trim().to_owned()
+}
+
 /// All of the mutable state needed to run the event loop.
 ///
 /// Contains list of items to write, current write state, etc. Anything that
@@ -401,6 +462,8 @@ pub struct State {
     write_list: VecDeque<Cow<'static, [u8]>>,
     writing: Option<Writing>,
     parser: ansi::Processor,
+    prompt_marks: PromptMarkParser,
+    command_start: Option<Point>,
 }
 
 impl State {
@@ -1,6 +1,7 @@
 //! A specialized 2D grid implementation optimized for use in a terminal.
 
 use std::cmp::{max, min};
+use std::mem;
 use std::ops::{Bound, Deref, Index, IndexMut, Range, RangeBounds};
 
 #[cfg(feature = "serde")]
@@ -135,6 +136,14 @@ pub struct Grid<T> {
 
     /// Maximum number of lines in history.
     max_scroll_limit: usize,
+
+    /// Maximum approximate size of the history in bytes, `None` if it's unlimited.
+    #[cfg_attr(feature = "serde", serde(default))]
+    max_scroll_bytes: Option<usize>,
+
+    /// Number of history lines dropped to stay within the byte limit.
+    #[cfg_attr(feature = "serde", serde(default))]
+    evicted_lines: usize,
 }
 
 impl<T: GridCell + Default + PartialEq> Grid<T> {
@@ -142,6 +151,8 @@ impl<T: GridCell + Default + PartialEq> Grid<T> {
         Grid {
             raw: Storage::with_capacity(lines, columns),
             max_scroll_limit,
+            max_scroll_bytes: None,
+            evicted_lines: 0,
             display_offset: 0,
             saved_cursor: Cursor::default(),
             cursor: Cursor::default(),
@@ -160,6 +171,31 @@ impl<T: GridCell + Default + PartialEq> Grid<T> {
         self.max_scroll_limit = history_size;
     }
 
+    /// Update the maximum size of the scrollback history in bytes, `None` removes the limit.
+    pub fn update_history_bytes(&mut self, max_bytes: Option<usize>) {
+        self.max_scroll_bytes = max_bytes;
+        self.shrink_history_to_limit();
+    }
+
+    /// Approximate memory used by the scrollback history, in bytes.
+    pub fn history_bytes(&self) -> usize {
+        self.history_size() * self.line_bytes()
+    }
+
+    /// Number of history lines dropped to stay within the byte limit.
+    pub fn evicted_lines(&self) -> usize {
+        self.evicted_lines
+    }
+
+    /// Drop the oldest history lines exceeding the history limits.
+    fn shrink_history_to_limit(&mut self) {
+        let excess = self.history_size().saturating_sub(self.history_limit());
+        if excess != 0 {
+            self.evicted_lines += excess;
+            self.decrease_scroll_limit(excess);
+        }
+    }
+
     pub fn scroll_display(&mut self, scroll: Scroll) {
         self.display_offset = match scroll {
             Scroll::Delta(count) => {
@@ -173,7 +209,13 @@ impl<T: GridCell + Default + PartialEq> Grid<T> {
     }
 
     fn increase_scroll_limit(&mut self, count: usize) {
-        let count = min(count, self.max_scroll_limit - self.history_size());
+        let history_size = self.history_size();
+        let line_count = min(count, self.max_scroll_limit.saturating_sub(history_size));
+        let count = min(line_count, self.history_limit().saturating_sub(history_size));
+
+        // Lines which only fell out of the history due to its byte limit.
+        self.evicted_lines += line_count - count;
+
         if count != 0 {
             self.raw.initialize(count, self.columns);
         }
@@ -265,7 +307,7 @@ impl<T: GridCell + Default + PartialEq> Grid<T> {
 
         // Update display offset when not pinned to active area.
         if self.display_offset != 0 {
-            self.display_offset = min(self.display_offset + positions, self.max_scroll_limit);
+            self.display_offset = min(self.display_offset + positions, self.history_limit());
         }
 
         // Only rotate the entire history if the active region starts at the top.
@@ -353,6 +395,19 @@ impl<T: GridCell + Default + PartialEq> Grid<T> {
 }
 
 impl<T> Grid<T> {
+    /// Approximate memory used by a single line.
+    fn line_bytes(&self) -> usize {
+        mem::size_of::<Row<T>>() + self.columns * mem::size_of::<T>()
+    }
+
+    /// Maximum number of lines in history, considering both its line and byte limits.
+    fn history_limit(&self) -> usize {
+        match self.max_scroll_bytes {
+            Some(max_bytes) => min(self.max_scroll_limit, max_bytes / self.line_bytes()),
+            None => self.max_scroll_limit,
+        }
+    }
+
     /// Reset a visible region within the grid.
     pub fn reset_region<D, R: RangeBounds<Line>>(&mut self, bounds: R)
     where
@@ -398,7 +453,7 @@ impl<T> Grid<T> {
         self.truncate();
 
         // Initialize everything with empty new lines.
-        self.raw.initialize(self.max_scroll_limit - self.history_size(), self.columns);
+        self.raw.initialize(self.history_limit().saturating_sub(self.history_size()), self.columns);
     }
 
     /// This is used only for truncating before saving ref-tests.
@@ -31,6 +31,9 @@ impl<T: GridCell + Default + PartialEq> Grid<T> {
             Ordering::Equal => (),
         }
 
+        // Wider lines take more memory, so fewer of them fit into the history.
+        self.shrink_history_to_limit();
+
         // Restore template cell.
         self.cursor.template = template;
     }
@@ -118,6 +118,36 @@ fn scroll_down_with_history() {
     assert_eq!(grid[Line(9)].occ, 1);
 }
 
+// History lines exceeding the byte limit are evicted.
+#[test]
+fn history_byte_limit() {
+    let mut grid = Grid::<usize>::new(2, 1, 10);
+    let line_bytes = grid.line_bytes();
+    grid.update_history_bytes(Some(line_bytes * 3));
+
+    for _ in 0..5 {
+        grid.scroll_up::<usize>(&(Line(0)..Line(2)), 1);
+    }
+
+    assert_eq!(grid.history_size(), 3);
+    assert_eq!(grid.history_bytes(), line_bytes * 3);
+    assert_eq!(grid.evicted_lines(), 2);
+
+    grid.update_history_bytes(Some(line_bytes));
+
+    assert_eq!(grid.history_size(), 1);
+    assert_eq!(grid.evicted_lines(), 4);
+
+    // Without a byte limit, only the line limit applies.
+    grid.update_history_bytes(None);
+    for _ in 0..20 {
+        grid.scroll_up::<usize>(&(Line(0)..Line(2)), 1);
+    }
+
+    assert_eq!(grid.history_size(), 10);
+    assert_eq!(grid.evicted_lines(), 4);
+}
+
 // Test that GridIterator works.
 #[test]
 fn test_iter() {
@@ -8,6 +8,7 @@ pub mod event;
 pub mod event_loop;
 pub mod grid;
 pub mod index;
+mod prompt_mark;
 pub mod selection;
 pub mod sync;
 pub mod term;
@@ -0,0 +1,195 @@
+//! Detection of shell integration prompt marks (OSC 133).
+//!
+//! These sequences are not handled by the VTE parser, so they're picked out of the raw PTY byte
+//! stream before it is passed on for parsing.
+
+/// Maximum length of an OSC 133 payload.
+///
+/// Longer sequences are dropped to avoid buffering unrelated OSC payloads like clipboard content.
+const MAX_PAYLOAD_LEN: usize = 256;
+
+/// OSC 133 payload prefix.
+const PROMPT_MARK_PREFIX: &[u8] = b"133;";
+
+/// Shell integration prompt mark.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PromptMark {
+    /// Start of the prompt (`A`).
+    PromptStart,
+
+    /// End of the prompt and start of the command line (`B`).
+    CommandStart,
+
+    /// Command line was submitted and the command is running (`C`).
+    CommandExecuted,
+
+    /// Command has finished, with its exit code when reported by the shell (`D`).
+    CommandFinished(Option<i32>),
+}
+
+impl PromptMark {
+    fn parse(payload: &[u8]) -> Option<Self> {
+        let mut params = payload.strip_prefix(PROMPT_MARK_PREFIX)?.split(|&b| b == b';');
+        let mark = match params.next()? {
+            b"A" => Self::PromptStart,
+            b"B" => Self::CommandStart,
+            b"C" => Self::CommandExecuted,
+            b"D" => {
+                let exit_code = params
+                    .next()
+                    .and_then(|code| std::str::from_utf8(code).ok())
+                    .and_then(|code| code.parse().ok());
+                Self::CommandFinished(exit_code)
+            },
+            _ => return None,
+        };
+        Some(mark)
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+enum State {
+    #[default]
+    Ground,
+    Escape,
+    Payload,
+    PayloadEscape,
+    Ignore,
+    IgnoreEscape,
+}
+
+/// Incremental OSC 133 scanner.
+///
+/// Sequences can be split across multiple PTY reads, so partial sequences are kept until the next
+/// call to [`PromptMarkParser::next_mark`].
+#[derive(Debug, Default)]
+pub struct PromptMarkParser {
+    state: State,
+    payload: Vec<u8>,
+}
+
+impl PromptMarkParser {
+    /// Scan `bytes` for the next complete prompt mark.
+    ///
+    /// Returns the mark together with the number of bytes up to and including its terminator, or
+    /// `None` if all bytes were consumed without completing a mark.
+    pub fn next_mark(&mut self, bytes: &[u8]) -> Option<(usize, PromptMark)> {
+        let mut i = 0;
+        while i < bytes.len() {
+            // Skip ahead to the next escape.
+            if self.state == State::Ground {
+                let offset = bytes[i..].iter().position(|&b| b == 0x1b)?;
+                self.state = State::Escape;
+                i += offset + 1;
+                continue;
+            }
+
+            let byte = bytes[i];
+            i += 1;
+
+            self.state = match (self.state, byte) {
+                (State::Escape, b']') => {
+                    self.payload.clear();
+                    State::Payload
+                },
+                (State::Escape, 0x1b) => State::Escape,
+                (State::Ground | State::Escape, _) => State::Ground,
+                (State::Payload, 0x07) => {
+                    self.state = State::Ground;
+                    match PromptMark::parse(&self.payload) {
+                        Some(mark) => return Some((i, mark)),
+                        None => continue,
+                    }
+                },
+                (State::Payload, 0x1b) => State::PayloadEscape,
+                (State::Payload, _) => {
+                    self.payload.push(byte);
+
+                    let prefix_len = self.payload.len().min(PROMPT_MARK_PREFIX.len());
+                    if self.payload[..prefix_len] != PROMPT_MARK_PREFIX[..prefix_len]
+                        || self.payload.len() > MAX_PAYLOAD_LEN
+                    {
+                        State::Ignore
+                    } else {
+                        State::Payload
+                    }
+                },
+                (State::PayloadEscape, b'\\') => {
+                    self.state = State::Ground;
+                    match PromptMark::parse(&self.payload) {
+                        Some(mark) => return Some((i, mark)),
+                        None => continue,
+                    }
+                },
+                // Any other escape aborts the OSC and starts a new sequence.
+                (State::PayloadEscape | State::IgnoreEscape, b']') => {
+                    self.payload.clear();
+                    State::Payload
+                },
+                (State::PayloadEscape | State::IgnoreEscape, 0x1b) => State::Escape,
+                (State::PayloadEscape | State::IgnoreEscape, _) => State::Ground,
+                (State::Ignore, 0x07) => State::Ground,
+                (State::Ignore, 0x1b) => State::IgnoreEscape,
+                (State::Ignore, _) => State::Ignore,
+            };
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marks(parser: &mut PromptMarkParser, mut bytes: &[u8]) -> Vec<PromptMark> {
+        let mut marks = Vec::new();
+        while let Some((len, mark)) = parser.next_mark(bytes) {
+            marks.push(mark);
+            bytes = &bytes[len..];
+        }
+        marks
+    }
+
+    #[test]
+    fn bel_and_st_terminators() {
+        let mut parser = PromptMarkParser::default();
+        let bytes = b"\x1b]133;A\x07$ \x1b]133;B\x1b\\ls\r\n\x1b]133;C\x07out\x1b]133;D;2\x07";
+        assert_eq!(marks(&mut parser, bytes), vec![
+            PromptMark::PromptStart,
+            PromptMark::CommandStart,
+            PromptMark::CommandExecuted,
+            PromptMark::CommandFinished(Some(2)),
+        ]);
+    }
+
+    #[test]
+    fn consumed_length() {
+        let mut parser = PromptMarkParser::default();
+        let bytes = b"abc\x1b]133;C\x07def";
+        assert_eq!(parser.next_mark(bytes), Some((11, PromptMark::CommandExecuted)));
+        assert_eq!(parser.next_mark(&bytes[11..]), None);
+    }
+
+    #[test]
+    fn split_sequence() {
+        let mut parser = PromptMarkParser::default();
+        assert_eq!(parser.next_mark(b"text\x1b]13"), None);
+        assert_eq!(parser.next_mark(b"3;D"), None);
+        assert_eq!(parser.next_mark(b";0;aid=1\x1b"), None);
+        assert_eq!(parser.next_mark(b"\\"), Some((1, PromptMark::CommandFinished(Some(0)))));
+    }
+
+    #[test]
+    fn missing_exit_code() {
+        let mut parser = PromptMarkParser::default();
+        assert_eq!(marks(&mut parser, b"\x1b]133;D\x07"), vec![PromptMark::CommandFinished(None)]);
+    }
+
+    #[test]
+    fn ignore_other_sequences() {
+        let mut parser = PromptMarkParser::default();
+        let bytes = b"\x1b]0;133;A\x07\x1b]52;c;MTMzO0E=\x07\x1b[31m\x1b]133;Z\x07\x1b]133;B\x07";
+        assert_eq!(marks(&mut parser, bytes), vec![PromptMark::CommandStart]);
+    }
+}
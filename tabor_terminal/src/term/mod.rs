@@ -335,6 +335,9 @@ pub struct Config {
     /// The maximum amount of scrolling history.
     pub scrolling_history: usize,
 
+    /// The maximum approximate size of the scrolling history in bytes.
+    pub scrolling_history_bytes: Option<usize>,
+
     /// Default cursor style to reset the cursor to.
     pub default_cursor_style: CursorStyle,
 
@@ -357,6 +360,7 @@ impl Default for Config {
     fn default() -> Self {
         Self {
             scrolling_history: 10000,
+            scrolling_history_bytes: None,
             semantic_escape_chars: SEMANTIC_ESCAPE_CHARS.to_owned(),
             default_cursor_style: Default::default(),
             vi_mode_cursor_style: Default::default(),
@@ -412,7 +416,8 @@ impl<T> Term<T> {
         let num_lines = dimensions.screen_lines();
 
         let history_size = config.scrolling_history;
-        let grid = Grid::new(num_lines, num_cols, history_size);
+        let mut grid = Grid::new(num_lines, num_cols, history_size);
+        grid.update_history_bytes(config.scrolling_history_bytes);
         let inactive_grid = Grid::new(num_lines, num_cols, 0);
 
         let tabs = TabStops::new(grid.columns());
@@ -511,8 +516,10 @@ impl<T> Term<T> {
 
         if self.mode.contains(TermMode::ALT_SCREEN) {
             self.inactive_grid.update_history(self.config.scrolling_history);
+            self.inactive_grid.update_history_bytes(self.config.scrolling_history_bytes);
         } else {
             self.grid.update_history(self.config.scrolling_history);
+            self.grid.update_history_bytes(self.config.scrolling_history_bytes);
         }
 
         if self.config.kitty_keyboard != old_config.kitty_keyboard {
@@ -651,6 +658,11 @@ impl<T> Term<T> {
         &mut self.grid
     }
 
+    /// Grid of the primary screen, which holds the scrollback history.
+    pub fn primary_grid(&self) -> &Grid<Cell> {
+        if self.mode.contains(TermMode::ALT_SCREEN) { &self.inactive_grid } else { &self.grid }
+    }
+
     /// Resize terminal to new dimensions.
     pub fn resize<S: Dimensions>(&mut self, size: S) {
         let old_cols = self.columns();
@@ -3,6 +3,7 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use std::{env, io};
 
 use polling::{Event, PollMode, Poller};
@@ -31,6 +32,12 @@ pub struct Options {
     /// Drain the child process output before exiting the terminal.
     pub drain_on_exit: bool,
 
+    /// Time the child process has to exit after `SIGTERM` once the PTY is dropped, before it is
+    /// killed by `reap_terminated_children`.
+    ///
+    /// [`None`] only hangs up the child process.
+    pub shutdown_grace: Option<Duration>,
+
     /// Extra environment variables.
     pub env: HashMap<String, String>,
 
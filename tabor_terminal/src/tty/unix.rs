@@ -13,10 +13,12 @@ use std::os::unix::process::CommandExt;
 use std::path::Path;
 use std::process::{Child, Command};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use std::{env, ptr};
 
-use libc::{F_GETFL, F_SETFL, O_NONBLOCK, TIOCSCTTY, c_int, fcntl};
+use libc::{F_GETFL, F_SETFL, O_NONBLOCK, TIOCSCTTY, c_int, fcntl, pid_t};
 use log::error;
+use parking_lot::Mutex;
 use polling::{Event, PollMode, Poller};
 use rustix_openpty::openpty;
 use rustix_openpty::rustix::termios::Winsize;
@@ -34,6 +36,12 @@ pub(crate) const PTY_READ_WRITE_TOKEN: usize = 0;
 // Interest in new child events.
 pub(crate) const PTY_CHILD_EVENT_TOKEN: usize = 1;
 
+/// Children sent `SIGTERM` by a dropped [`Pty`], with the time at which they are killed.
+///
+/// The children are only reaped in [`reap_terminated_children`], so their PIDs can't be reused
+/// before they are killed.
+static TERMINATED_CHILDREN: Mutex<Vec<(pid_t, Instant)>> = Mutex::new(Vec::new());
+
 macro_rules! die {
     ($($arg:tt)*) => {{
         error!($($arg)*);
@@ -104,6 +112,7 @@ pub struct Pty {
     file: File,
     signals: UnixStream,
     sig_id: SigId,
+    shutdown_grace: Option<Duration>,
 }
 
 impl Pty {
@@ -114,6 +123,33 @@ impl Pty {
     pub fn file(&self) -> &File {
         &self.file
     }
+
+}
+
+/// Reap the children terminated by dropped PTYs, killing the ones past their grace period.
+///
+/// Returns the time at which the next child still running should be killed.
+pub fn reap_terminated_children() -> Option<Instant> {
+    let now = Instant::now();
+    let mut children = TERMINATED_CHILDREN.lock();
+    children.retain(|&(pid, deadline)| {
+        let mut status = 0;
+        if unsafe { libc::waitpid(pid, &mut status, libc::WNOHANG) } != 0 {
+            return false;
+        }
+
+        if deadline > now {
+            return true;
+        }
+
+        unsafe {
+            libc::kill(pid, libc::SIGKILL);
+            libc::waitpid(pid, &mut status, 0);
+        }
+        false
+    });
+
+    children.iter().map(|&(_, deadline)| deadline).min()
 }
 
 /// User information that is required for a new shell session.
@@ -293,7 +329,8 @@ pub fn from_fd(config: &Options, window_id: u64, master: OwnedFd, slave: OwnedFd
                 set_nonblocking(master_fd);
             }
 
-            Ok(Pty { child, file: File::from(master), signals, sig_id })
+            let shutdown_grace = config.shutdown_grace;
+            Ok(Pty { child, file: File::from(master), signals, sig_id, shutdown_grace })
         },
         Err(err) => Err(Error::new(
             err.kind(),
@@ -316,6 +353,17 @@ impl Drop for Pty {
         // Clear signal-hook handler.
         unregister_signal(self.sig_id);
 
+        // Leave a running child its grace period, it's reaped once it exited or was killed.
+        let running = matches!(self.child.try_wait(), Ok(None));
+        if let Some(grace) = self.shutdown_grace.filter(|_| running) {
+            let pid = self.child.id() as pid_t;
+            unsafe {
+                libc::kill(pid, libc::SIGTERM);
+            }
+            TERMINATED_CHILDREN.lock().push((pid, Instant::now() + grace));
+            return;
+        }
+
         let _ = self.child.wait();
     }
 }